@@ -1,6 +1,7 @@
 use std::{cmp::Ordering, str::FromStr};
 
-use dao::configuration::get_variable;
+use dao::configuration::{get_variable, FeeRecipient, FeeSplit};
+use dao::modules::Role;
 use dao::{
     bid_escrow::contract::BidEscrowContractDeployer,
     core_contracts::{
@@ -9,8 +10,10 @@ use dao::{
     },
     utils_contracts::{CSPRRateProviderContractDeployer, DaoIdsContractDeployer},
     voting_contracts::{
-        AdminContractDeployer, KycVoterContractDeployer, OnboardingRequestContractDeployer,
-        RepoVoterContractDeployer, ReputationVoterContractDeployer, SimpleVoterContractDeployer,
+        AccountRecoveryVoterContractDeployer, AdminContractDeployer, InsurancePoolContractDeployer,
+        KycVoterContractDeployer, OffboardingRequestContractDeployer,
+        OnboardingRequestContractDeployer, RepoVoterContractDeployer,
+        ReputationVoterContractDeployer, SimpleVoterContractDeployer,
         SlashingVoterContractDeployer,
     },
 };
@@ -96,6 +99,7 @@ pub fn deploy_all() {
         *reputation_token.address(),
         *va_token.address(),
         *kyc_token.address(),
+        chain_name(),
     );
     contracts.add_contract("KycVoterContract", kyc_voter.address());
 
@@ -145,21 +149,53 @@ pub fn deploy_all() {
         *va_token.address(),
     );
     contracts.add_contract("OnboardingRequestContract", onboarding.address());
+
+    // Deploy Insurance Pool.
+    client_env::set_gas(cspr(600));
+    let insurance_pool = InsurancePoolContractDeployer::init(
+        *variable_repository.address(),
+        *reputation_token.address(),
+        *va_token.address(),
+        *bid_escrow.address(),
+    );
+    contracts.add_contract("InsurancePoolContract", insurance_pool.address());
+
+    // Deploy Offboarding.
+    client_env::set_gas(cspr(600));
+    let offboarding = OffboardingRequestContractDeployer::init(
+        *variable_repository.address(),
+        *reputation_token.address(),
+        *va_token.address(),
+        *slashing_voter.address(),
+    );
+    contracts.add_contract("OffboardingRequestContract", offboarding.address());
+
+    // Deploy Account Recovery.
+    client_env::set_gas(cspr(600));
+    let account_recovery = AccountRecoveryVoterContractDeployer::init(
+        *variable_repository.address(),
+        *reputation_token.address(),
+        *va_token.address(),
+        *kyc_token.address(),
+    );
+    contracts.add_contract("AccountRecoveryVoterContract", account_recovery.address());
 }
 
-macro_rules! whitelist_all {
-    ( $( $source:ident => [$( $target:ident ),+] ),+ ) => {
+macro_rules! grant_roles {
+    ( $( $source:ident => { $( $role:ident => [$( $target:ident ),+] ),+ } ),+ ) => {
         $(
             $(
-                client_env::set_gas(cspr(5));
-                $source.add_to_whitelist(*$target.address());
+                $(
+                    client_env::set_gas(cspr(5));
+                    $source.grant_role(Role::$role, *$target.address());
+                )+
             )+
         )+
     }
 }
 
-/// Whitelist neccessary contracts.
-pub fn whitelist() {
+/// Grant the roles required by the contracts.
+pub fn grant_roles() {
     let DaoSnapshot {
         mut ids,
         mut admin,
@@ -174,31 +210,83 @@ pub fn whitelist() {
         mut simple_voter,
         mut bid_escrow,
         mut onboarding,
+        mut insurance_pool,
+        mut offboarding,
+        mut account_recovery,
         ..
     } = DaoSnapshot::load();
 
-    whitelist_all!(
-        ids => [admin, kyc_voter, slashing_voter, repo_voter, reputation_voter, simple_voter, bid_escrow, onboarding],
-        variable_repository => [repo_voter],
-        reputation_token => [admin, repo_voter, reputation_voter, kyc_voter, slashing_voter, simple_voter, bid_escrow, onboarding],
-        va_token => [slashing_voter, bid_escrow, onboarding],
-        kyc_token => [kyc_voter],
-        admin => [slashing_voter],
-        kyc_voter => [slashing_voter],
-        onboarding => [slashing_voter],
-        repo_voter => [slashing_voter, simple_voter],
-        reputation_voter => [slashing_voter],
-        simple_voter => [slashing_voter],
-        slashing_voter => [slashing_voter],
-        bid_escrow => [slashing_voter]
+    grant_roles!(
+        ids => {
+            VotingCreator => [admin, kyc_voter, slashing_voter, repo_voter, reputation_voter, simple_voter, bid_escrow, onboarding, insurance_pool, account_recovery]
+        },
+        variable_repository => {
+            VariableEditor => [repo_voter]
+        },
+        reputation_token => {
+            Minter => [admin, repo_voter, reputation_voter, kyc_voter, slashing_voter, simple_voter, bid_escrow, onboarding, insurance_pool, offboarding, account_recovery],
+            Burner => [admin, repo_voter, reputation_voter, kyc_voter, slashing_voter, simple_voter, bid_escrow, onboarding, insurance_pool, offboarding, account_recovery],
+            Staker => [admin, repo_voter, reputation_voter, kyc_voter, slashing_voter, simple_voter, bid_escrow, onboarding, insurance_pool, offboarding, account_recovery],
+            Migrator => [account_recovery]
+        },
+        va_token => {
+            Minter => [bid_escrow, onboarding],
+            Burner => [slashing_voter, offboarding],
+            Migrator => [account_recovery]
+        },
+        kyc_token => {
+            Minter => [kyc_voter],
+            Burner => [kyc_voter],
+            Operator => [kyc_voter],
+            Migrator => [account_recovery]
+        },
+        admin => { Slasher => [slashing_voter, offboarding], Migrator => [account_recovery] },
+        kyc_voter => { Slasher => [slashing_voter, offboarding], Migrator => [account_recovery] },
+        onboarding => { Slasher => [slashing_voter, offboarding, kyc_voter], Migrator => [account_recovery] },
+        repo_voter => { Slasher => [slashing_voter, offboarding], Migrator => [account_recovery] },
+        reputation_voter => { Slasher => [slashing_voter, offboarding], Migrator => [account_recovery] },
+        simple_voter => { Slasher => [slashing_voter, offboarding], Migrator => [account_recovery] },
+        slashing_voter => { Slasher => [slashing_voter, offboarding], Migrator => [account_recovery] },
+        bid_escrow => { Slasher => [slashing_voter, offboarding, kyc_voter], Migrator => [account_recovery] },
+        insurance_pool => { Slasher => [slashing_voter, offboarding], Migrator => [account_recovery] },
+        account_recovery => { Slasher => [slashing_voter, offboarding] }
     );
+
+    // The deployer mints the initial tokens and configures the contracts.
+    let deployer = client_env::caller();
+    for role in [Role::Minter, Role::Burner] {
+        client_env::set_gas(cspr(5));
+        reputation_token.grant_role(role, deployer);
+        client_env::set_gas(cspr(5));
+        va_token.grant_role(role, deployer);
+        client_env::set_gas(cspr(5));
+        kyc_token.grant_role(role, deployer);
+    }
+    client_env::set_gas(cspr(5));
+    variable_repository.grant_role(Role::VariableEditor, deployer);
+    client_env::set_gas(cspr(5));
+    bid_escrow.grant_role(Role::Operator, deployer);
+    client_env::set_gas(cspr(5));
+    kyc_voter.grant_role(Role::Operator, deployer);
+    client_env::set_gas(cspr(5));
+    slashing_voter.grant_role(Role::Operator, deployer);
+    client_env::set_gas(cspr(5));
+    offboarding.grant_role(Role::Operator, deployer);
+    client_env::set_gas(cspr(5));
+    account_recovery.grant_role(Role::Operator, deployer);
+
+    client_env::set_gas(cspr(5));
+    bid_escrow.set_insurance_pool(*insurance_pool.address());
+
+    client_env::set_gas(cspr(5));
+    kyc_voter.update_kyc_revocation_contracts(vec![*bid_escrow.address(), *onboarding.address()]);
 }
 
-/// Add slashable contracts to slashing voter.
+/// Add slashable contracts to slashing voter and offboarding, and migratable contracts to account recovery.
 pub fn setup_slashing_voter() {
     let mut dao = DaoSnapshot::load();
 
-    let slashable_contracts: Vec<Address> = vec![
+    let migratable_contracts: Vec<Address> = vec![
         dao.admin.address(),
         dao.kyc_voter.address(),
         dao.onboarding.address(),
@@ -207,14 +295,25 @@ pub fn setup_slashing_voter() {
         dao.simple_voter.address(),
         dao.slashing_voter.address(),
         dao.bid_escrow.address(),
+        dao.insurance_pool.address(),
     ]
     .into_iter()
     .cloned()
     .collect();
+    let mut slashable_contracts = migratable_contracts.clone();
+    slashable_contracts.push(*dao.account_recovery.address());
 
     client_env::set_gas(cspr(20));
     dao.slashing_voter
+        .update_slashable_contracts(slashable_contracts.clone());
+
+    client_env::set_gas(cspr(20));
+    dao.offboarding
         .update_slashable_contracts(slashable_contracts);
+
+    client_env::set_gas(cspr(20));
+    dao.account_recovery
+        .update_migratable_contracts(migratable_contracts);
 }
 
 /// Print addresses of deployed contracts.
@@ -315,6 +414,16 @@ pub fn print_variables() {
                 name,
                 get_variable::<bool>(name.as_str(), &variables)
             )),
+            VariableType::FeeSplit => log::info(format!(
+                "{}: {:?}",
+                name,
+                get_variable::<FeeSplit>(name.as_str(), &variables)
+            )),
+            VariableType::StringList => log::info(format!(
+                "{}: {:?}",
+                name,
+                get_variable::<Vec<String>>(name.as_str(), &variables)
+            )),
             VariableType::Unknown => log::info(format!("Unknown variable type: {}", name)),
         }
     }
@@ -370,6 +479,40 @@ pub fn set_variable(name: &str, value: &str) {
             );
             log::info(format!("Updated {} to {}", name, value));
         }
+        VariableType::FeeSplit => {
+            // Expects comma separated `address:ratio` pairs.
+            let recipients = value
+                .split(',')
+                .filter(|recipient| !recipient.is_empty())
+                .map(|recipient| {
+                    let (address, ratio) = recipient.rsplit_once(':').unwrap();
+                    FeeRecipient {
+                        address: address.parse::<Address>().unwrap(),
+                        ratio: Balance::from(ratio.parse::<u64>().unwrap()),
+                    }
+                })
+                .collect();
+            dao.variable_repository.update_at(
+                name.to_string(),
+                FeeSplit { recipients }.serialize().unwrap().into(),
+                None,
+            );
+            log::info(format!("Updated {} to {}", name, value));
+        }
+        VariableType::StringList => {
+            // Expects comma separated values.
+            let list = value
+                .split(',')
+                .filter(|item| !item.is_empty())
+                .map(String::from)
+                .collect::<Vec<String>>();
+            dao.variable_repository.update_at(
+                name.to_string(),
+                list.serialize().unwrap().into(),
+                None,
+            );
+            log::info(format!("Updated {} to {}", name, value));
+        }
         VariableType::Unknown => {
             log::info(format!("Unknown variable: {}", name));
         }
@@ -401,6 +544,7 @@ pub fn get_voting(voting_id: &str, contract: &str) {
         "repo_voter" => dao.repo_voter.get_voting(voting_id),
         "simple_voter" => dao.simple_voter.get_voting(voting_id),
         "onboarding" => dao.onboarding.get_voting(voting_id),
+        "insurance_pool" => dao.insurance_pool.get_voting(voting_id),
         "admin" => dao.admin.get_voting(voting_id),
         _ => panic!("Unknown contract: {}", contract),
     };
@@ -419,3 +563,9 @@ pub fn get_account(account_hash: &str) {
         account_hash, stake, reputation, kyc_token, va_token
     ));
 }
+
+/// Returns the name of the network the contracts are deployed to.
+fn chain_name() -> String {
+    std::env::var("ODRA_CASPER_LIVENET_CHAIN_NAME")
+        .expect("ODRA_CASPER_LIVENET_CHAIN_NAME not set.")
+}
@@ -13,8 +13,8 @@ struct Cli {
 enum Commands {
     /// Deploys all DAO contracts
     DeployAll,
-    /// Configures whitelists of all contracts
-    Whitelist,
+    /// Grants the roles required by the contracts
+    GrantRoles,
     /// Sets up a slashing voter
     SetupSlashingVoter,
     /// Prints addresses of all contracts
@@ -64,7 +64,7 @@ pub fn parse() {
     use Commands::*;
     match Cli::parse().command {
         DeployAll => actions::deploy_all(),
-        Whitelist => actions::whitelist(),
+        GrantRoles => actions::grant_roles(),
         SetupSlashingVoter => actions::setup_slashing_voter(),
         PrintAddresses => actions::print_addresses(),
         SetupVA {
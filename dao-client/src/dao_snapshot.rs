@@ -10,7 +10,10 @@ use dao::{
         DaoIdsContractRef,
     },
     voting_contracts::{
-        AdminContractDeployer, AdminContractRef, KycVoterContractDeployer, KycVoterContractRef,
+        AccountRecoveryVoterContractDeployer, AccountRecoveryVoterContractRef,
+        AdminContractDeployer, AdminContractRef, InsurancePoolContractDeployer,
+        InsurancePoolContractRef, KycVoterContractDeployer, KycVoterContractRef,
+        OffboardingRequestContractDeployer, OffboardingRequestContractRef,
         OnboardingRequestContractDeployer, OnboardingRequestContractRef, RepoVoterContractDeployer,
         RepoVoterContractRef, ReputationVoterContractDeployer, ReputationVoterContractRef,
         SimpleVoterContractDeployer, SimpleVoterContractRef, SlashingVoterContractDeployer,
@@ -36,6 +39,9 @@ pub struct DaoSnapshot {
     pub slashing_voter: SlashingVoterContractRef,
     pub bid_escrow: BidEscrowContractRef,
     pub onboarding: OnboardingRequestContractRef,
+    pub insurance_pool: InsurancePoolContractRef,
+    pub offboarding: OffboardingRequestContractRef,
+    pub account_recovery: AccountRecoveryVoterContractRef,
 }
 
 impl DaoSnapshot {
@@ -78,6 +84,15 @@ impl From<DeployedContractsToml> for DaoSnapshot {
             onboarding: OnboardingRequestContractDeployer::register(
                 contracts.address("OnboardingRequestContract"),
             ),
+            insurance_pool: InsurancePoolContractDeployer::register(
+                contracts.address("InsurancePoolContract"),
+            ),
+            offboarding: OffboardingRequestContractDeployer::register(
+                contracts.address("OffboardingRequestContract"),
+            ),
+            account_recovery: AccountRecoveryVoterContractDeployer::register(
+                contracts.address("AccountRecoveryVoterContract"),
+            ),
         }
     }
 }
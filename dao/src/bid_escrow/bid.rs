@@ -3,14 +3,23 @@ use crate::bid_escrow::job::{JobStatus, PickBidRequest};
 use crate::bid_escrow::job_offer::{AuctionState, JobOfferStatus};
 use crate::bid_escrow::types::{BidId, JobOfferId};
 use crate::rules::validation::bid_escrow::{
-    CanBeOnboarded, CanBidBeCancelled, CanBidOnAuctionState, CanBidOnOwnJob, CanPickBid,
-    DoesProposedPaymentExceedBudget, HasPermissionsToCancelBid, IsBidStakeCorrect, IsGracePeriod,
-    IsStakeNonZero,
+    CanBeOnboarded, CanBidBeCancelled, CanBidBePicked, CanBidOnAuctionState, CanBidOnOwnJob,
+    CanConfirmTeamBid, CanOnboardOnJob, CanPickBid, CanRevealBid, DoesBidMatchCommitment,
+    DoesProposedPaymentExceedBudget, HasMatchingSkill, HasPermissionsToCancelBid, IsBidModeCorrect,
+    IsBidStakeCorrect, IsCsprStakeAttached, IsGracePeriod, IsStakeNonZero, IsTeamConfirmed,
+    IsTeamMember, IsTeamValid,
 };
 use crate::rules::validation::IsUserKyced;
 use crate::rules::RulesBuilder;
-use odra::types::{Address, Balance, BlockTime};
-use odra::OdraType;
+use crate::utils::types::KycLevel;
+use crate::utils::Error;
+use odra::contract_env;
+use odra::prelude::{vec, vec::Vec};
+use odra::types::{Address, Balance, BlockTime, Bytes, OdraType as _};
+use odra::{OdraType, UnwrapOrRevert};
+
+/// The sum of all the shares in a team [`Bid`] - shares are expressed in per mils.
+pub const TEAM_SHARES_TOTAL: u32 = 1000;
 
 /// Bid status representation
 #[derive(OdraType, PartialEq, Debug, Copy)]
@@ -25,6 +34,19 @@ pub enum BidStatus {
     Reclaimed,
     /// Cancel due eg. the `Job Poster` was slashed.
     Canceled,
+    /// Placed on a sealed Job Offer, awaiting to be revealed.
+    Sealed,
+}
+
+/// A member of a team that submitted a joint [`Bid`].
+#[derive(OdraType, PartialEq, Debug, Copy)]
+pub struct TeamMember {
+    /// Member address.
+    pub address: Address,
+    /// Member share of the payment and reputation, in per mils.
+    pub share: u32,
+    /// If the member confirmed the participation in the Bid.
+    pub confirmed: bool,
 }
 
 /// Data required to create a Bid.
@@ -47,8 +69,10 @@ pub struct SubmitBidRequest {
     pub onboard: bool,
     /// [Worker](crate::bid_escrow#definitions) address.
     pub worker: Address,
-    /// If the `Worker` passed the KYC process.
-    pub worker_kyced: bool,
+    /// The KYC level of the `Worker`.
+    pub worker_kyc_level: KycLevel,
+    /// The KYC level required by the Job Offer.
+    pub required_kyc_level: KycLevel,
     /// If the `Worker` is a [VA](crate::bid_escrow#definitions).
     pub worker_is_va: bool,
     /// [JobPoster](crate::bid_escrow#definitions) address.
@@ -59,6 +83,44 @@ pub struct SubmitBidRequest {
     pub auction_state: AuctionState,
     /// Is allowed a VA bid on public auction (not an External Worker only).
     pub va_can_bid_on_public_auction: bool,
+    /// If the `Worker` must have a skill required by the Job Offer.
+    pub skill_match_required: bool,
+    /// If the `Worker` declared a skill required by the Job Offer.
+    pub has_matching_skill: bool,
+    /// Team members and their shares, including the `Worker`. Empty for a solo Bid.
+    pub team: Vec<(Address, u32)>,
+    /// If the Job Offer requires sealed Bids.
+    pub sealed_offer: bool,
+    /// If the Job Offer is recurring.
+    pub recurring_offer: bool,
+    /// The commitment to the Bid terms - for a sealed Bid.
+    pub commitment: Option<Bytes>,
+}
+
+/// Data required to reveal a sealed Bid.
+pub struct RevealBidRequest {
+    /// Address who reveals the Bid.
+    pub caller: Address,
+    /// Proposed Job completion time.
+    pub proposed_timeframe: BlockTime,
+    /// Proposed payment for the Job.
+    pub proposed_payment: Balance,
+    /// The salt used to create the commitment.
+    pub salt: Bytes,
+    /// The Job max budget
+    pub max_budget: Balance,
+    /// Auction state
+    pub auction_state: AuctionState,
+}
+
+/// Data required to confirm a team member participation in a Bid.
+pub struct ConfirmTeamBidRequest {
+    /// Address who confirms the Bid.
+    pub caller: Address,
+    /// The KYC level of the caller.
+    pub caller_kyc_level: KycLevel,
+    /// The KYC level required by the Job Offer.
+    pub required_kyc_level: KycLevel,
 }
 
 /// Data required to cancel a bid.
@@ -87,8 +149,10 @@ pub struct ReclaimBidRequest {
     pub new_worker: Address,
     /// If the `Worker` is a [VA](crate::bid_escrow#definitions).
     pub new_worker_va: bool,
-    /// If the `Worker` passed the KYC process.
-    pub new_worker_kyced: bool,
+    /// The KYC level of the `Worker`.
+    pub new_worker_kyc_level: KycLevel,
+    /// The KYC level required by the Job Offer.
+    pub required_kyc_level: KycLevel,
     /// The related Job creator.
     pub job_poster: Address,
     /// Should be onborded when the Job is done.
@@ -99,6 +163,8 @@ pub struct ReclaimBidRequest {
     pub job_status: JobStatus,
     /// The related `Job` finish time.
     pub job_finish_time: BlockTime,
+    /// If the related `Job` is recurring.
+    pub recurring_job: bool,
 }
 
 /// Serializable representation of a `Bid`.
@@ -122,8 +188,12 @@ pub struct Bid {
     pub cspr_stake: Option<Balance>,
     /// Should be onborded when the Job is done.
     pub onboard: bool,
-    /// [Worker](crate::bid_escrow#definitions) address.
+    /// [Worker](crate::bid_escrow#definitions) address. In a team Bid - the team leader.
     pub worker: Address,
+    /// Team members of a joint Bid, including the `Worker`. Empty for a solo Bid.
+    pub team: Vec<TeamMember>,
+    /// The commitment to the Bid terms - for a sealed Bid.
+    pub commitment: Option<Bytes>,
 }
 
 impl Bid {
@@ -133,19 +203,32 @@ impl Bid {
     /// * [`IsUserKyced`]
     /// * [`CanBidOnOwnJob`]
     /// * [`CanBeOnboarded`]
+    /// * [`CanOnboardOnJob`]
     /// * [`DoesProposedPaymentExceedBudget`]
     /// * [`CanBidOnAuctionState`]
+    /// * [`HasMatchingSkill`]
+    /// * [`IsBidStakeCorrect`]
+    /// * [`IsTeamValid`]
+    /// * [`IsBidModeCorrect`]
+    /// * [`IsCsprStakeAttached`] - the `Worker` attaches its share of the CSPR stake
     ///
     /// Stops contract execution if any validation fails.
     #[allow(clippy::too_many_arguments)]
     pub fn new(request: SubmitBidRequest) -> Bid {
         RulesBuilder::new()
-            .add_validation(IsUserKyced::create(request.worker_kyced))
+            .add_validation(IsUserKyced::create(
+                request.worker_kyc_level,
+                request.required_kyc_level,
+            ))
             .add_validation(CanBidOnOwnJob::create(request.worker, request.job_poster))
             .add_validation(CanBeOnboarded::create(
                 request.worker_is_va,
                 request.onboard,
             ))
+            .add_validation(CanOnboardOnJob::create(
+                request.onboard,
+                request.recurring_offer,
+            ))
             .add_validation(DoesProposedPaymentExceedBudget::create(
                 request.proposed_payment,
                 request.max_budget,
@@ -155,17 +238,46 @@ impl Bid {
                 request.worker_is_va,
                 request.va_can_bid_on_public_auction,
             ))
+            .add_validation(HasMatchingSkill::create(
+                request.skill_match_required,
+                request.has_matching_skill,
+            ))
             .add_validation(IsBidStakeCorrect::create(
                 request.worker_is_va,
                 request.cspr_stake,
                 request.reputation_stake,
             ))
+            .add_validation(IsTeamValid::create(
+                request.worker,
+                request.job_poster,
+                request.team.clone(),
+            ))
+            .add_validation(IsBidModeCorrect::create(
+                request.sealed_offer,
+                request.commitment.is_some(),
+            ))
             .build()
             .validate_generic_validations();
 
-        Bid {
+        let team = request
+            .team
+            .iter()
+            .map(|(address, share)| TeamMember {
+                address: *address,
+                share: *share,
+                confirmed: *address == request.worker,
+            })
+            .collect();
+
+        let status = if request.commitment.is_some() {
+            BidStatus::Sealed
+        } else {
+            BidStatus::Created
+        };
+
+        let bid = Bid {
             bid_id: request.bid_id,
-            status: BidStatus::Created,
+            status,
             timestamp: request.timestamp,
             job_offer_id: request.job_offer_id,
             proposed_timeframe: request.proposed_timeframe,
@@ -174,7 +286,18 @@ impl Bid {
             cspr_stake: request.cspr_stake,
             onboard: request.onboard,
             worker: request.worker,
-        }
+            team,
+            commitment: request.commitment,
+        };
+
+        RulesBuilder::new()
+            .add_validation(IsCsprStakeAttached::create(
+                bid.cspr_stake_of(&request.worker),
+            ))
+            .build()
+            .validate_generic_validations();
+
+        bid
     }
 
     /// Conditionally changes the status to [Reclaimed](BidStatus::Reclaimed), creates a new bid
@@ -184,13 +307,17 @@ impl Bid {
     /// * [`IsUserKyced`]
     /// * [`CanBidOnOwnJob`]
     /// * [`CanBeOnboarded`]
+    /// * [`CanOnboardOnJob`]
     /// * [`IsStakeNonZero`]
     /// * [`IsGracePeriod`]
     ///
     /// Stops contract execution if any validation fails.
     pub fn reclaim(&mut self, request: &ReclaimBidRequest) -> Bid {
         RulesBuilder::new()
-            .add_validation(IsUserKyced::create(request.new_worker_kyced))
+            .add_validation(IsUserKyced::create(
+                request.new_worker_kyc_level,
+                request.required_kyc_level,
+            ))
             .add_validation(CanBidOnOwnJob::create(
                 request.new_worker,
                 request.job_poster,
@@ -199,6 +326,10 @@ impl Bid {
                 request.new_worker_va,
                 request.onboard,
             ))
+            .add_validation(CanOnboardOnJob::create(
+                request.onboard,
+                request.recurring_job,
+            ))
             .add_validation(IsStakeNonZero::create(
                 request.reputation_stake,
                 request.cspr_stake,
@@ -223,19 +354,95 @@ impl Bid {
             self.timestamp + self.proposed_timeframe + self.proposed_timeframe;
         new_bid.cspr_stake = request.cspr_stake;
         new_bid.onboard = request.onboard;
+        new_bid.team = vec![];
+        new_bid.commitment = None;
 
         new_bid
     }
 
+    /// Conditionally sets the proposed terms of a sealed Bid and changes the status
+    /// to [Created](BidStatus::Created).
+    ///
+    /// Runs validation:
+    /// * [`CanRevealBid`]
+    /// * [`DoesBidMatchCommitment`]
+    /// * [`DoesProposedPaymentExceedBudget`]
+    ///
+    /// Stops contract execution if any validation fails.
+    pub fn reveal(&mut self, request: RevealBidRequest) {
+        RulesBuilder::new()
+            .add_validation(CanRevealBid::create(
+                request.caller,
+                self.worker,
+                self.status,
+                request.auction_state,
+            ))
+            .add_validation(DoesBidMatchCommitment::create(
+                self.commitment.clone(),
+                Self::commitment(
+                    self.worker,
+                    self.job_offer_id,
+                    request.proposed_timeframe,
+                    request.proposed_payment,
+                    &request.salt,
+                ),
+            ))
+            .add_validation(DoesProposedPaymentExceedBudget::create(
+                request.proposed_payment,
+                request.max_budget,
+            ))
+            .build()
+            .validate_generic_validations();
+
+        self.proposed_timeframe = request.proposed_timeframe;
+        self.proposed_payment = request.proposed_payment;
+        self.status = BidStatus::Created;
+    }
+
+    /// Calculates the commitment to the terms of a sealed Bid.
+    ///
+    /// The commitment binds the terms to the bidder and the Job Offer, so it cannot be replayed
+    /// by another bidder nor on another Job Offer.
+    pub fn commitment(
+        worker: Address,
+        job_offer_id: JobOfferId,
+        proposed_timeframe: BlockTime,
+        proposed_payment: Balance,
+        salt: &Bytes,
+    ) -> Bytes {
+        let mut input = worker
+            .serialize()
+            .unwrap_or_revert_with(Error::BytesConversionError);
+        input.extend(
+            job_offer_id
+                .serialize()
+                .unwrap_or_revert_with(Error::BytesConversionError),
+        );
+        input.extend(
+            proposed_timeframe
+                .serialize()
+                .unwrap_or_revert_with(Error::BytesConversionError),
+        );
+        input.extend(
+            proposed_payment
+                .serialize()
+                .unwrap_or_revert_with(Error::BytesConversionError),
+        );
+        input.extend_from_slice(salt.as_slice());
+        Bytes::from(contract_env::hash(input))
+    }
+
     /// Conditionally changes the status to [Picked](BidStatus::Picked).
     ///
     /// Runs validation:
     /// * [`CanPickBid`]
+    /// * [`IsTeamConfirmed`]
     ///
-    /// Stops contract execution if the validation fails.
+    /// Stops contract execution if any validation fails.
     pub fn picked(&mut self, request: &PickBidRequest) {
         RulesBuilder::new()
             .add_validation(CanPickBid::create(request.caller, request.poster))
+            .add_validation(IsTeamConfirmed::create(self.is_team_confirmed()))
             .build()
             .validate_generic_validations();
 
@@ -261,6 +468,7 @@ impl Bid {
                 self.worker,
             ))
             .add_validation(CanBidBeCancelled::create(
+                self.status,
                 request.job_offer_status,
                 request.block_time,
                 self.timestamp,
@@ -271,13 +479,165 @@ impl Bid {
         self.status = BidStatus::Canceled;
     }
 
+    /// Checks if the Bid has not been picked until `VABidAcceptanceTimeout` passed since it was placed.
+    pub fn is_expired(&self, block_time: BlockTime, va_bid_acceptance_timeout: BlockTime) -> bool {
+        self.status == BidStatus::Created
+            && block_time >= self.timestamp + va_bid_acceptance_timeout
+    }
+
     /// Unconditionally changes the status to [Canceled](BidStatus::Canceled).
     pub fn cancel_without_validation(&mut self) {
         self.status = BidStatus::Canceled;
     }
 
+    /// Conditionally marks the caller's participation in a team Bid as confirmed.
+    ///
+    /// Runs validation:
+    /// * [`IsUserKyced`]
+    /// * [`IsTeamMember`]
+    /// * [`CanConfirmTeamBid`]
+    /// * [`CanBidBePicked`]
+    /// * [`IsCsprStakeAttached`] - the caller attaches its share of the CSPR stake
+    ///
+    /// Stops contract execution if any validation fails.
+    pub fn confirm_team_membership(&mut self, request: ConfirmTeamBidRequest) {
+        RulesBuilder::new()
+            .add_validation(IsUserKyced::create(
+                request.caller_kyc_level,
+                request.required_kyc_level,
+            ))
+            .add_validation(IsTeamMember::create(self.is_team_member(&request.caller)))
+            .add_validation(CanConfirmTeamBid::create(
+                self.has_confirmed(&request.caller),
+            ))
+            .add_validation(CanBidBePicked::create(self.status))
+            .add_validation(IsCsprStakeAttached::create(
+                self.cspr_stake_of(&request.caller),
+            ))
+            .build()
+            .validate_generic_validations();
+
+        for member in self.team.iter_mut() {
+            if member.address == request.caller {
+                member.confirmed = true;
+            }
+        }
+    }
+
+    /// Replaces the `from` address with the `to` address, if it is the `Worker` or a team member.
+    ///
+    /// Returns true if the Bid has been changed.
+    pub fn migrate_account(&mut self, from: Address, to: Address) -> bool {
+        let mut migrated = false;
+        if self.worker == from {
+            self.worker = to;
+            migrated = true;
+        }
+        for member in self.team.iter_mut() {
+            if member.address == from {
+                member.address = to;
+                migrated = true;
+            }
+        }
+        migrated
+    }
+
     /// Gets the bid id.
     pub fn bid_id(&self) -> BidId {
         self.bid_id
     }
+
+    /// Checks if the Bid was submitted by a team.
+    pub fn is_team_bid(&self) -> bool {
+        !self.team.is_empty()
+    }
+
+    /// Checks if the given address is a team member.
+    pub fn is_team_member(&self, address: &Address) -> bool {
+        self.team.iter().any(|member| &member.address == address)
+    }
+
+    /// Checks if all the team members confirmed the Bid. Always true for a solo Bid.
+    pub fn is_team_confirmed(&self) -> bool {
+        self.team.iter().all(|member| member.confirmed)
+    }
+
+    /// Checks if the given address confirmed the Bid. The `Worker` of a solo Bid confirms it by submitting.
+    pub fn has_confirmed(&self, address: &Address) -> bool {
+        if !self.is_team_bid() {
+            return &self.worker == address;
+        }
+        self.team
+            .iter()
+            .any(|member| &member.address == address && member.confirmed)
+    }
+
+    /// Gets the share of the reputation stake the given address puts up for the Bid.
+    pub fn reputation_stake_of(&self, address: &Address) -> Balance {
+        match self.cspr_stake {
+            None => self.stake_of(address),
+            Some(_) => Balance::zero(),
+        }
+    }
+
+    /// Gets the share of the CSPR stake the given address puts up for the Bid,
+    /// or `None` if the Bid is staked with reputation.
+    pub fn cspr_stake_of(&self, address: &Address) -> Option<Balance> {
+        self.cspr_stake.map(|_| self.stake_of(address))
+    }
+
+    /// Gets the shares of the stake put up by the `Worker` and the team members who confirmed the Bid.
+    /// The stakes are in CSPR if the Bid has a CSPR stake, in reputation otherwise.
+    pub fn stakes(&self) -> Vec<(Address, Balance)> {
+        let stake = self.cspr_stake.unwrap_or(self.reputation_stake);
+        self.split_between_team(stake)
+            .into_iter()
+            .filter(|(address, part)| !part.is_zero() && self.has_confirmed(address))
+            .collect()
+    }
+
+    /// Gets the shares of the stake put up by the team members other than the `Worker`.
+    pub fn team_members_stakes(&self) -> Vec<(Address, Balance)> {
+        self.stakes()
+            .into_iter()
+            .filter(|(address, _)| address != &self.worker)
+            .collect()
+    }
+
+    fn stake_of(&self, address: &Address) -> Balance {
+        let stake = self.cspr_stake.unwrap_or(self.reputation_stake);
+        self.split_between_team(stake)
+            .into_iter()
+            .find(|(member, _)| member == address)
+            .map(|(_, part)| part)
+            .unwrap_or_default()
+    }
+
+    /// Splits the given amount between the team members according to their shares.
+    /// The rounding remainder goes to the `Worker`. In a solo Bid, the `Worker` gets everything.
+    pub fn split_between_team(&self, amount: Balance) -> Vec<(Address, Balance)> {
+        if !self.is_team_bid() {
+            return vec![(self.worker, amount)];
+        }
+
+        let total = Balance::from(TEAM_SHARES_TOTAL);
+        let mut remainder = amount;
+        let mut split: Vec<(Address, Balance)> = self
+            .team
+            .iter()
+            .map(|member| {
+                let part = amount * Balance::from(member.share) / total;
+                remainder -= part;
+                (member.address, part)
+            })
+            .collect();
+
+        if let Some((_, part)) = split
+            .iter_mut()
+            .find(|(address, _)| *address == self.worker)
+        {
+            *part += remainder;
+        }
+        split
+    }
 }
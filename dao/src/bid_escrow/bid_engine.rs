@@ -4,31 +4,48 @@
 #![allow(unused_imports)]
 extern crate alloc;
 
-use crate::bid_escrow::bid::{Bid, BidStatus, CancelBidRequest, SubmitBidRequest};
+use crate::bid_escrow::bid::{
+    Bid, BidStatus, CancelBidRequest, ConfirmTeamBidRequest, RevealBidRequest, SubmitBidRequest,
+};
 #[allow(unused_imports)]
 use crate::bid_escrow::events::CSPRTransfer;
 use crate::bid_escrow::events::{
-    BidCancelled, BidSubmitted, JobCreated, JobOfferCreated, TransferReason,
+    BidCancelled, BidRevealed, BidSubmitted, JobCreated, JobOfferCreated, JobOfferExpired,
+    JobOfferUpdated, PaymentTokenAdded, PaymentTokenRemoved, SkillsDeclared, TeamBidConfirmed,
+    TeamBidSubmitted, TransferReason,
 };
 use crate::bid_escrow::job::{Job, PickBidRequest};
-use crate::bid_escrow::job_offer::{CancelJobOfferRequest, JobOffer, PostJobOfferRequest};
+use crate::bid_escrow::job_offer::{
+    AuctionState, CancelJobOfferRequest, JobOffer, JobOfferParams, JobOfferStatus, PaymentToken,
+    PostJobOfferRequest, UpdateJobOfferRequest,
+};
 use crate::bid_escrow::storage::{BidStorage, JobStorage};
 use crate::bid_escrow::types::{BidId, JobOfferId};
 use crate::configuration::{Configuration, ConfigurationBuilder};
 use crate::modules::refs::ContractRefs;
-use crate::utils::withdraw;
+use crate::modules::{AccessControl, Claims};
+use crate::rules::validation::bid_escrow::AreTagsAllowed;
+use crate::rules::RulesBuilder;
+use crate::utils::consts::BASIC_KYC_LEVEL;
+use crate::utils::types::{DocumentHash, KycLevel};
+use crate::utils::{deposit_tokens, Error};
+use crate::utils_contracts::CSPRRateProviderContractRef;
+use crate::voting::cspr_redistribution::redistribute_to_insurance_pool;
 use alloc::rc::Rc;
 use odra::contract_env;
-use odra::contract_env::{caller, get_block_time};
-use odra::prelude::{vec, vec::Vec};
-use odra::types::{event::OdraEvent, Address, Balance, BlockTime};
+use odra::contract_env::{caller, get_block_time, revert};
+use odra::prelude::{string::String, vec, vec::Vec};
+use odra::types::{event::OdraEvent, Address, Balance, BlockTime, Bytes};
+use odra::UnwrapOrRevert;
 
 /// Manages the Bidding process.
-#[odra::module(events = [JobCreated, JobOfferCreated, BidSubmitted, BidCancelled, CSPRTransfer])]
+#[odra::module(events = [JobCreated, JobOfferCreated, JobOfferUpdated, JobOfferExpired, BidSubmitted, TeamBidSubmitted, TeamBidConfirmed, BidRevealed, BidCancelled, CSPRTransfer, PaymentTokenAdded, PaymentTokenRemoved, SkillsDeclared])]
 pub struct BidEngine {
     bid_storage: BidStorage,
     job_storage: JobStorage,
     refs: ContractRefs,
+    claims: Claims,
+    access_control: AccessControl,
 }
 
 impl BidEngine {
@@ -77,23 +94,48 @@ impl BidEngine {
         self.bid_storage.get_job_offer_configuration(job)
     }
 
-    pub fn post_job_offer(
-        &mut self,
-        expected_timeframe: BlockTime,
-        budget: Balance,
-        dos_fee: Balance,
-    ) {
+    /// Posts a [JobOffer].
+    ///
+    /// If the offer is paid in a CEP-18 token, the token:fiat rate is read from the token's rate provider
+    /// and stored in the offer, so the reputation minted for the Job reflects the fiat value of the payment.
+    ///
+    /// # Errors
+    /// * [`InvalidKycLevel`](crate::utils::Error::InvalidKycLevel) - if the required KYC level is zero.
+    /// * [`PaymentTokenNotWhitelisted`](crate::utils::Error::PaymentTokenNotWhitelisted) - if the payment
+    /// token is not whitelisted.
+    pub fn post_job_offer(&mut self, params: JobOfferParams) {
+        self.access_control.ensure_not_paused();
+        let required_kyc_level = params.required_kyc_level.unwrap_or(BASIC_KYC_LEVEL);
+        if required_kyc_level < BASIC_KYC_LEVEL {
+            revert(Error::InvalidKycLevel);
+        }
+        let payment_token = params.payment_token.map(|payment_token| {
+            let rate_provider = self
+                .bid_storage
+                .get_payment_token_rate_provider(&payment_token)
+                .unwrap_or_revert_with(Error::PaymentTokenNotWhitelisted);
+            PaymentToken {
+                address: payment_token,
+                fiat_rate: CSPRRateProviderContractRef::at(&rate_provider).get_rate(),
+            }
+        });
         let caller = caller();
         let configuration = self.configuration();
 
         let request = PostJobOfferRequest {
             job_offer_id: self.bid_storage.next_job_offer_id(),
-            job_poster_kyced: !self.refs.kyc_token().balance_of(&caller).is_zero(),
+            job_poster_kyc_level: self.refs.kyc_token().kyc_level(caller),
             job_poster: caller,
-            max_budget: budget,
-            expected_timeframe,
-            dos_fee,
+            max_budget: params.budget,
+            expected_timeframe: params.expected_timeframe,
+            dos_fee: params.dos_fee,
             start_time: get_block_time(),
+            payment_token,
+            sealed: params.sealed,
+            category: params.category,
+            skills: params.skills,
+            periods: params.periods.unwrap_or(1),
+            required_kyc_level,
             configuration,
         };
 
@@ -102,9 +144,81 @@ impl BidEngine {
         JobOfferCreated::new(&job_offer).emit();
         self.bid_storage
             .add_to_active_offers(job_offer.job_offer_id);
+        if let Some(category) = &job_offer.category {
+            self.bid_storage
+                .add_to_category_offers(category, job_offer.job_offer_id);
+        }
         self.bid_storage.store_job_offer(job_offer);
     }
 
+    /// Whitelists a CEP-18 token as a Job payment method.
+    ///
+    /// The `rate_provider` must expose the same interface as
+    /// [`CSPRRateProviderContract`](crate::utils_contracts::CSPRRateProviderContract)
+    /// and return the token:fiat rate.
+    pub fn add_payment_token(&mut self, token: Address, rate_provider: Address) {
+        self.bid_storage
+            .set_payment_token_rate_provider(token, Some(rate_provider));
+        PaymentTokenAdded {
+            token,
+            rate_provider,
+        }
+        .emit();
+    }
+
+    /// Removes a CEP-18 token from the whitelist. Existing Job Offers are not affected.
+    pub fn remove_payment_token(&mut self, token: Address) {
+        self.bid_storage
+            .set_payment_token_rate_provider(token, None);
+        PaymentTokenRemoved { token }.emit();
+    }
+
+    /// Gets the rate provider of a whitelisted payment token or `None` if the token is not whitelisted.
+    pub fn get_payment_token_rate_provider(&self, token: Address) -> Option<Address> {
+        self.bid_storage.get_payment_token_rate_provider(&token)
+    }
+
+    /// Declares the skills of the caller. Replaces the previously declared skills.
+    ///
+    /// The skills must be a subset of `SkillTags`.
+    pub fn declare_skills(&mut self, skills: Vec<String>) {
+        self.access_control.ensure_not_paused();
+        let caller = caller();
+        if self.refs.va_token().balance_of(&caller).is_zero() {
+            revert(Error::OnlyVaCanDeclareSkills);
+        }
+        RulesBuilder::new()
+            .add_validation(AreTagsAllowed::create(
+                self.configuration(),
+                None,
+                skills.clone(),
+            ))
+            .build()
+            .validate_generic_validations();
+
+        self.bid_storage.set_skills(caller, skills.clone());
+        SkillsDeclared { va: caller, skills }.emit();
+    }
+
+    /// Gets the skills declared by a given address.
+    pub fn get_skills(&self, address: Address) -> Vec<String> {
+        self.bid_storage.get_skills(&address)
+    }
+
+    /// Gets the ids of [JobOffer]s in a given category, that are open for Bids.
+    pub fn get_open_job_offers_by_category(&self, category: String) -> Vec<JobOfferId> {
+        self.bid_storage
+            .get_category_offers(&category)
+            .into_iter()
+            .filter(|job_offer_id| {
+                self.bid_storage
+                    .get_job_offer(job_offer_id)
+                    .map(|job_offer| job_offer.status == JobOfferStatus::Created)
+                    .unwrap_or_default()
+            })
+            .collect()
+    }
+
     pub fn submit_bid(
         &mut self,
         job_offer_id: JobOfferId,
@@ -114,57 +228,106 @@ impl BidEngine {
         onboard: bool,
         cspr_stake: Option<Balance>,
     ) {
-        let worker = caller();
-
-        let job_offer: JobOffer = self.bid_storage.get_job_offer_or_revert(&job_offer_id);
-        let bid_id = self.bid_storage.next_bid_id();
-        let block_time = get_block_time();
-
-        let submit_bid_request = SubmitBidRequest {
-            bid_id,
-            timestamp: block_time,
+        self.access_control.ensure_not_paused();
+        self.create_bid(
             job_offer_id,
-            proposed_timeframe: time,
-            proposed_payment: payment,
+            time,
+            payment,
             reputation_stake,
+            onboard,
             cspr_stake,
+            vec![],
+            None,
+        );
+    }
+
+    pub fn submit_sealed_bid(
+        &mut self,
+        job_offer_id: JobOfferId,
+        commitment: Bytes,
+        reputation_stake: Balance,
+        onboard: bool,
+        cspr_stake: Option<Balance>,
+    ) {
+        self.access_control.ensure_not_paused();
+        self.create_bid(
+            job_offer_id,
+            0,
+            Balance::zero(),
+            reputation_stake,
             onboard,
-            worker,
-            worker_kyced: !self.refs.kyc_token().balance_of(&worker).is_zero(),
-            worker_is_va: !self.refs.va_token().balance_of(&worker).is_zero(),
-            job_poster: job_offer.job_poster,
-            max_budget: job_offer.max_budget,
-            auction_state: job_offer.auction_state(block_time),
-            va_can_bid_on_public_auction: job_offer.configuration.va_can_bid_on_public_auction(),
-        };
+            cspr_stake,
+            vec![],
+            Some(commitment),
+        );
+    }
 
-        let bid = Bid::new(submit_bid_request);
+    pub fn reveal_bid(
+        &mut self,
+        bid_id: BidId,
+        proposed_timeframe: BlockTime,
+        proposed_payment: Balance,
+        salt: Bytes,
+    ) {
+        self.access_control.ensure_not_paused();
+        let mut bid = self.bid_storage.get_bid_or_revert(&bid_id);
+        let job_offer = self.bid_storage.get_job_offer_or_revert(&bid.job_offer_id);
 
-        let cspr_stake =
-            self.stake_cspr_or_reputation_for_bid(reputation_stake, cspr_stake, worker);
+        bid.reveal(RevealBidRequest {
+            caller: caller(),
+            proposed_timeframe,
+            proposed_payment,
+            salt,
+            max_budget: job_offer.max_budget,
+            auction_state: job_offer.auction_state(get_block_time()),
+        });
 
+        BidRevealed::new(&bid).emit();
         self.bid_storage.store_bid(bid);
-        self.bid_storage.store_bid_id(job_offer_id, bid_id);
-        self.bid_storage
-            .add_to_active_bids(worker, job_offer_id, bid_id);
-
-        let reputation_stake = if reputation_stake.is_zero() {
-            None
-        } else {
-            Some(reputation_stake)
-        };
+    }
 
-        BidSubmitted::new(
-            bid_id,
+    pub fn submit_team_bid(
+        &mut self,
+        job_offer_id: JobOfferId,
+        time: BlockTime,
+        payment: Balance,
+        reputation_stake: Balance,
+        cspr_stake: Option<Balance>,
+        team: Vec<(Address, u32)>,
+    ) {
+        self.access_control.ensure_not_paused();
+        let bid = self.create_bid(
             job_offer_id,
-            worker,
-            onboard,
             time,
             payment,
             reputation_stake,
+            false,
             cspr_stake,
-        )
-        .emit();
+            team,
+            None,
+        );
+        TeamBidSubmitted::new(&bid).emit();
+    }
+
+    pub fn confirm_team_bid(&mut self, bid_id: BidId) {
+        self.access_control.ensure_not_paused();
+        let caller = caller();
+        let mut bid = self.bid_storage.get_bid_or_revert(&bid_id);
+
+        let job_offer = self.bid_storage.get_job_offer_or_revert(&bid.job_offer_id);
+        bid.confirm_team_membership(ConfirmTeamBidRequest {
+            caller,
+            caller_kyc_level: self.refs.kyc_token().kyc_level(caller),
+            required_kyc_level: job_offer.required_kyc_level,
+        });
+
+        let reputation_stake = bid.reputation_stake_of(&caller);
+        if !reputation_stake.is_zero() {
+            self.refs.reputation_token().stake(caller, reputation_stake);
+        }
+
+        self.bid_storage.store_bid(bid);
+        TeamBidConfirmed::new(bid_id, caller).emit();
     }
 
     pub fn cancel_bid(&mut self, bid_id: BidId) {
@@ -214,17 +377,49 @@ impl BidEngine {
         self.bid_storage.remove_from_active_offers(job_offer_id);
     }
 
+    /// Updates the budget, the timeframe and the description of a [`Job Offer`](JobOffer) that has no Bid picked yet.
+    /// Bids exceeding the new budget are rejected and their stakes returned.
+    ///
+    /// Executes validations: [`CanProgressJobOffer`] and [`CanJobOfferBeModified`].
+    ///
+    /// [`CanProgressJobOffer`]: crate::rules::validation::bid_escrow::CanProgressJobOffer
+    /// [`CanJobOfferBeModified`]: crate::rules::validation::bid_escrow::CanJobOfferBeModified
+    pub fn update_job_offer(
+        &mut self,
+        job_offer_id: JobOfferId,
+        max_budget: Balance,
+        expected_timeframe: BlockTime,
+        description: DocumentHash,
+    ) {
+        self.access_control.ensure_not_paused();
+        let mut job_offer = self.bid_storage.get_job_offer_or_revert(&job_offer_id);
+        job_offer.update(UpdateJobOfferRequest {
+            caller: caller(),
+            max_budget,
+            expected_timeframe,
+            description,
+        });
+
+        let rejected_bids = self.reject_bids_exceeding_budget(&job_offer_id, max_budget);
+
+        JobOfferUpdated::new(&job_offer, rejected_bids).emit();
+        self.bid_storage.update_job_offer(&job_offer_id, job_offer);
+    }
+
     pub fn pick_bid(&mut self, job_offer_id: JobOfferId, bid_id: BidId, cspr_amount: Balance) {
+        self.access_control.ensure_not_paused();
         let mut job_offer = self.bid_storage.get_job_offer_or_revert(&job_offer_id);
         let mut bid = self.bid_storage.get_bid_or_revert(&bid_id);
         let job_id = self.job_storage.next_job_id();
+        let caller = caller();
+        let transferred = self.collect_job_payment(&job_offer, caller, cspr_amount);
 
         self.unstake_not_picked(&job_offer_id, &bid_id);
         let pick_bid_request = PickBidRequest {
             job_id,
             job_offer_id,
             bid_id,
-            caller: caller(),
+            caller,
             poster: job_offer.job_poster,
             worker: bid.worker,
             is_worker_va: !self.refs.va_token().balance_of(&bid.worker).is_zero(),
@@ -232,10 +427,12 @@ impl BidEngine {
             block_time: get_block_time(),
             timeframe: bid.proposed_timeframe,
             payment: bid.proposed_payment,
-            transferred_cspr: contract_env::attached_value(),
+            periods: job_offer.periods,
+            payment_token: job_offer.payment_token_address(),
+            transferred_cspr: transferred,
             cspr_amount,
-            stake: bid.reputation_stake,
-            external_worker_cspr_stake: bid.cspr_stake.unwrap_or_default(),
+            stake: bid.reputation_stake_of(&bid.worker),
+            external_worker_cspr_stake: bid.cspr_stake_of(&bid.worker).unwrap_or_default(),
             bid_status: bid.status,
         };
 
@@ -254,6 +451,76 @@ impl BidEngine {
         self.job_storage.add_to_active_jobs(job_id);
     }
 
+    /// Settles at most `limit` [`Job Offers`](JobOffer) that expired with no Bid picked.
+    /// All the Bids are canceled and their stakes returned, the `DOS Fee` is returned to the `Job Poster`
+    /// except for the reward paid to the `keeper`.
+    ///
+    /// Returns the ids of the expired offers.
+    pub fn expire_job_offers(&mut self, keeper: Address, limit: u32) -> Vec<JobOfferId> {
+        let block_time = get_block_time();
+        let mut expired = vec![];
+        for job_offer_id in self.bid_storage.get_active_offers() {
+            if expired.len() as u32 >= limit {
+                break;
+            }
+            let mut job_offer = self.bid_storage.get_job_offer_or_revert(&job_offer_id);
+            if !job_offer.is_expired(block_time) {
+                continue;
+            }
+
+            job_offer.expire();
+            let keeper_reward = job_offer.take_keeper_reward();
+            self.claims
+                .credit(&keeper, keeper_reward, TransferReason::KeeperReward);
+            self.bid_storage.update_job_offer(&job_offer_id, job_offer);
+
+            self.cancel_all_bids(&job_offer_id);
+            self.return_job_offer_poster_dos_fee(&job_offer_id);
+            self.bid_storage.remove_from_active_offers(job_offer_id);
+
+            JobOfferExpired::new(job_offer_id, keeper, keeper_reward).emit();
+            expired.push(job_offer_id);
+        }
+        expired
+    }
+
+    /// Cancels at most `limit` Bids that have not been picked until `VABidAcceptanceTimeout` passed since
+    /// they were placed, in the [`Job Offers`](JobOffer) that are still open. The stakes are returned to the Bidders.
+    ///
+    /// Returns the ids of the canceled Bids.
+    pub fn expire_bids(&mut self, keeper: Address, limit: u32) -> Vec<BidId> {
+        let block_time = get_block_time();
+        let mut expired = vec![];
+        for job_offer_id in self.bid_storage.get_active_offers() {
+            let job_offer = self.bid_storage.get_job_offer_or_revert(&job_offer_id);
+            // The Bids of an expired offer are settled with the offer.
+            if job_offer.status != JobOfferStatus::Created || job_offer.is_expired(block_time) {
+                continue;
+            }
+            let va_bid_acceptance_timeout = job_offer.configuration.va_bid_acceptance_timeout();
+            for i in 0..self.bid_storage.get_bids_count(&job_offer_id) {
+                if expired.len() as u32 >= limit {
+                    return expired;
+                }
+                let mut bid = self.bid_storage.get_nth_bid(&job_offer_id, i);
+                if !bid.is_expired(block_time, va_bid_acceptance_timeout) {
+                    continue;
+                }
+
+                bid.cancel_without_validation();
+                self.unstake_cspr_or_reputation_for_bid(&bid);
+
+                BidCancelled::new(bid.bid_id, keeper, job_offer_id).emit();
+
+                self.bid_storage
+                    .remove_from_active_bids(bid.worker, job_offer_id);
+                expired.push(bid.bid_id);
+                self.bid_storage.store_bid(bid);
+            }
+        }
+        expired
+    }
+
     pub fn slash_voter(&mut self, voter: Address) -> (Vec<JobOfferId>, Vec<BidId>) {
         let mut slashed_job_offers = vec![];
         let mut slashed_bids = vec![];
@@ -269,33 +536,194 @@ impl BidEngine {
         }
         (slashed_job_offers, slashed_bids)
     }
+
+    /// Cancels the open Job Offers posted by the `address` and rejects its Bids in the open Job Offers.
+    ///
+    /// Returns ids of the cancelled Job Offers and ids of the rejected Bids.
+    pub fn revoke_kyc(&mut self, address: Address) -> (Vec<JobOfferId>, Vec<BidId>) {
+        let mut cancelled_job_offers = vec![];
+        let mut rejected_bids = vec![];
+        for job_offer_id in self.bid_storage.get_active_offers() {
+            let job_offer = self.bid_storage.get_job_offer_or_revert(&job_offer_id);
+            if job_offer.status != JobOfferStatus::Created {
+                continue;
+            }
+            if address == job_offer.job_poster {
+                self.slash_job_offer(job_offer);
+                cancelled_job_offers.push(job_offer_id);
+            } else if let Some(bid_id) = self.reject_bid(address, job_offer_id) {
+                rejected_bids.push(bid_id);
+            }
+        }
+        (cancelled_job_offers, rejected_bids)
+    }
+
+    /// Moves the open Job Offers posted by the `from` address, its Bids in the open Job Offers
+    /// and its declared skills to the `to` address.
+    ///
+    /// Returns ids of the migrated Job Offers and ids of the migrated Bids.
+    pub fn migrate_account(&mut self, from: Address, to: Address) -> (Vec<JobOfferId>, Vec<BidId>) {
+        let mut migrated_job_offers = vec![];
+        let mut migrated_bids = vec![];
+        for job_offer_id in self.bid_storage.get_active_offers() {
+            let mut job_offer = self.bid_storage.get_job_offer_or_revert(&job_offer_id);
+            if job_offer.job_poster == from {
+                job_offer.job_poster = to;
+                self.bid_storage.update_job_offer(&job_offer_id, job_offer);
+                migrated_job_offers.push(job_offer_id);
+            }
+
+            for i in 0..self.bid_storage.get_bids_count(&job_offer_id) {
+                let mut bid = self.bid_storage.get_nth_bid(&job_offer_id, i);
+                if bid.migrate_account(from, to) {
+                    migrated_bids.push(bid.bid_id());
+                    self.bid_storage.store_bid(bid);
+                }
+            }
+
+            if let Some(bid_id) = self.bid_storage.get_active_bid_id(from, job_offer_id) {
+                self.bid_storage.remove_from_active_bids(from, job_offer_id);
+                self.bid_storage
+                    .add_to_active_bids(to, job_offer_id, bid_id);
+            }
+        }
+
+        let skills = self.bid_storage.get_skills(&from);
+        if !skills.is_empty() {
+            self.bid_storage.set_skills(to, skills);
+            self.bid_storage.set_skills(from, vec![]);
+        }
+
+        (migrated_job_offers, migrated_bids)
+    }
 }
 
 impl BidEngine {
-    fn stake_cspr_or_reputation_for_bid(
+    /// Returns the amount paid for the Job - the attached CSPR, or the tokens pulled from the `Job Poster`
+    /// if the offer is paid in a CEP-18 token.
+    fn collect_job_payment(
+        &mut self,
+        job_offer: &JobOffer,
+        caller: Address,
+        amount: Balance,
+    ) -> Balance {
+        let attached_value = contract_env::attached_value();
+        match job_offer.payment_token_address() {
+            None => attached_value,
+            Some(token) => {
+                if !attached_value.is_zero() {
+                    revert(Error::UnexpectedCSPRTransfer);
+                }
+                // Let the validation reject the request if the caller is not the Job Poster.
+                if caller != job_offer.job_poster {
+                    return Balance::zero();
+                }
+                deposit_tokens(&token, &caller, amount)
+            }
+        }
+    }
+
+    /// Validates and stores a new Bid. A non-empty `team` makes it a team Bid,
+    /// a `commitment` makes it a sealed Bid.
+    #[allow(clippy::too_many_arguments)]
+    fn create_bid(
         &mut self,
+        job_offer_id: JobOfferId,
+        time: BlockTime,
+        payment: Balance,
         reputation_stake: Balance,
+        onboard: bool,
         cspr_stake: Option<Balance>,
-        worker: Address,
-    ) -> Option<Balance> {
-        match cspr_stake {
-            None => {
-                self.refs.reputation_token().stake(worker, reputation_stake);
-                None
-            }
-            Some(cspr_stake) => Some(cspr_stake),
+        team: Vec<(Address, u32)>,
+        commitment: Option<Bytes>,
+    ) -> Bid {
+        let worker = caller();
+
+        let job_offer: JobOffer = self.bid_storage.get_job_offer_or_revert(&job_offer_id);
+        let bid_id = self.bid_storage.next_bid_id();
+        let block_time = get_block_time();
+        let auction_state = job_offer.auction_state(block_time);
+        let skill_match_required = auction_state == AuctionState::Internal
+            && job_offer
+                .configuration
+                .internal_auction_skill_match_required();
+
+        let submit_bid_request = SubmitBidRequest {
+            bid_id,
+            timestamp: block_time,
+            job_offer_id,
+            proposed_timeframe: time,
+            proposed_payment: payment,
+            reputation_stake,
+            cspr_stake,
+            onboard,
+            worker,
+            worker_kyc_level: self.refs.kyc_token().kyc_level(worker),
+            required_kyc_level: job_offer.required_kyc_level,
+            worker_is_va: !self.refs.va_token().balance_of(&worker).is_zero(),
+            job_poster: job_offer.job_poster,
+            max_budget: job_offer.max_budget,
+            auction_state,
+            va_can_bid_on_public_auction: job_offer.configuration.va_can_bid_on_public_auction(),
+            skill_match_required,
+            has_matching_skill: job_offer.matches_skills(&self.bid_storage.get_skills(&worker)),
+            team,
+            sealed_offer: job_offer.sealed,
+            recurring_offer: job_offer.is_recurring(),
+            commitment,
+        };
+
+        let bid = Bid::new(submit_bid_request);
+
+        let worker_reputation_stake = bid.reputation_stake_of(&worker);
+        if !worker_reputation_stake.is_zero() {
+            self.refs
+                .reputation_token()
+                .stake(worker, worker_reputation_stake);
         }
+
+        self.bid_storage.store_bid(bid.clone());
+        self.bid_storage.store_bid_id(job_offer_id, bid_id);
+        self.bid_storage
+            .add_to_active_bids(worker, job_offer_id, bid_id);
+
+        let reputation_stake = if reputation_stake.is_zero() {
+            None
+        } else {
+            Some(reputation_stake)
+        };
+
+        BidSubmitted::new(
+            bid_id,
+            job_offer_id,
+            worker,
+            onboard,
+            time,
+            payment,
+            reputation_stake,
+            cspr_stake,
+        )
+        .emit();
+
+        bid
     }
 
     fn unstake_cspr_or_reputation_for_bid(&mut self, bid: &Bid) {
-        match bid.cspr_stake {
-            None => {
-                self.refs
-                    .reputation_token()
-                    .unstake(bid.worker, bid.reputation_stake);
-            }
-            Some(cspr_stake) => {
-                withdraw(&bid.worker, cspr_stake, TransferReason::BidStakeReturn);
+        let mut unstakes: Vec<(Address, Balance)> = Vec::new();
+        self.return_bid_stakes(bid, &mut unstakes);
+        self.refs.reputation_token().bulk_unstake(unstakes);
+    }
+
+    /// Returns the CSPR stakes of the `Worker` and the team members who confirmed the Bid,
+    /// the reputation stakes to unstake are collected in `unstakes`.
+    fn return_bid_stakes(&mut self, bid: &Bid, unstakes: &mut Vec<(Address, Balance)>) {
+        for (address, stake) in bid.stakes() {
+            match bid.cspr_stake {
+                None => unstakes.push((address, stake)),
+                Some(_) => {
+                    self.claims
+                        .credit(&address, stake, TransferReason::BidStakeReturn);
+                }
             }
         }
     }
@@ -305,10 +733,14 @@ impl BidEngine {
         let mut unstakes: Vec<(Address, Balance)> = Vec::new();
         for i in 0..bids_amount {
             let mut bid = self.bid_storage.get_nth_bid(job_offer_id, i);
-            if let Some(cspr) = bid.cspr_stake {
-                withdraw(&bid.worker, cspr, TransferReason::BidStakeReturn);
+            // The stakes of the canceled and rejected Bids have been already returned.
+            if bid.status != BidStatus::Created && bid.status != BidStatus::Sealed {
+                continue;
+            }
+            if bid.status == BidStatus::Sealed {
+                self.forfeit_unrevealed_bid_stake(&bid);
             } else {
-                unstakes.push((bid.worker, bid.reputation_stake));
+                self.return_bid_stakes(&bid, &mut unstakes);
             }
             bid.cancel_without_validation();
             self.bid_storage.store_bid(bid);
@@ -318,11 +750,14 @@ impl BidEngine {
 
     pub fn return_job_offer_poster_dos_fee(&mut self, job_offer_id: &JobOfferId) {
         let job_offer = self.bid_storage.get_job_offer_or_revert(job_offer_id);
-        withdraw(
-            &job_offer.job_poster,
+        let dos_fee = redistribute_to_insurance_pool(
             job_offer.dos_fee,
-            TransferReason::DOSFeeReturn,
+            &job_offer.configuration,
+            &self.refs,
+            &mut self.claims,
         );
+        self.claims
+            .credit(&job_offer.job_poster, dos_fee, TransferReason::DOSFeeReturn);
     }
 
     fn unstake_not_picked(&mut self, job_offer_id: &JobOfferId, bid_id: &BidId) {
@@ -332,16 +767,73 @@ impl BidEngine {
             let mut bid = self.bid_storage.get_nth_bid(job_offer_id, i);
 
             if bid.bid_id != *bid_id && bid.status == BidStatus::Created {
-                if let Some(cspr) = bid.cspr_stake {
-                    withdraw(&bid.worker, cspr, TransferReason::BidStakeReturn);
-                } else {
-                    unstakes.push((bid.worker, bid.reputation_stake));
-                }
+                self.return_bid_stakes(&bid, &mut unstakes);
+                bid.reject_without_validation();
+                self.bid_storage.store_bid(bid);
+            } else if bid.status == BidStatus::Sealed {
+                self.forfeit_unrevealed_bid_stake(&bid);
+                bid.reject_without_validation();
+                self.bid_storage.store_bid(bid);
+            }
+        }
+        self.refs.reputation_token().bulk_unstake(unstakes);
+    }
+
+    /// Returns the stake of a Bid that has never been revealed, except for the part defined by
+    /// the `UnrevealedBidForfeitRatio` variable. The forfeited CSPR goes to the Bid Escrow Wallet
+    /// and the Insurance Pool, the forfeited reputation is burnt.
+    fn forfeit_unrevealed_bid_stake(&mut self, bid: &Bid) {
+        let job_offer = self.bid_storage.get_job_offer_or_revert(&bid.job_offer_id);
+        let configuration = &job_offer.configuration;
+        match bid.cspr_stake {
+            Some(cspr) => {
+                let forfeit = configuration.apply_unrevealed_bid_forfeit_ratio_to(cspr);
+                let to_wallet = redistribute_to_insurance_pool(
+                    forfeit,
+                    configuration,
+                    &self.refs,
+                    &mut self.claims,
+                );
+                self.claims.credit(
+                    &configuration.bid_escrow_wallet_address(),
+                    to_wallet,
+                    TransferReason::BidStakeForfeit,
+                );
+                self.claims
+                    .credit(&bid.worker, cspr - forfeit, TransferReason::BidStakeReturn);
+            }
+            None => {
+                let forfeit =
+                    configuration.apply_unrevealed_bid_forfeit_ratio_to(bid.reputation_stake);
+                let mut reputation_token = self.refs.reputation_token();
+                reputation_token.unstake(bid.worker, bid.reputation_stake);
+                reputation_token.burn(bid.worker, forfeit);
+            }
+        }
+    }
+
+    fn reject_bids_exceeding_budget(
+        &mut self,
+        job_offer_id: &JobOfferId,
+        max_budget: Balance,
+    ) -> Vec<BidId> {
+        let bids_amount = self.bid_storage.get_bids_count(job_offer_id);
+        let mut unstakes: Vec<(Address, Balance)> = Vec::new();
+        let mut rejected_bids = Vec::new();
+        for i in 0..bids_amount {
+            let mut bid = self.bid_storage.get_nth_bid(job_offer_id, i);
+
+            if bid.status == BidStatus::Created && bid.proposed_payment > max_budget {
+                self.return_bid_stakes(&bid, &mut unstakes);
                 bid.reject_without_validation();
+                rejected_bids.push(bid.bid_id);
+                self.bid_storage
+                    .remove_from_active_bids(bid.worker, *job_offer_id);
                 self.bid_storage.store_bid(bid);
             }
         }
         self.refs.reputation_token().bulk_unstake(unstakes);
+        rejected_bids
     }
 
     /// Builds Configuration for a Bid Escrow Entities
@@ -366,6 +858,17 @@ impl BidEngine {
         self.bid_storage.update_job_offer(&job_offer_id, job_offer);
     }
 
+    fn reject_bid(&mut self, worker: Address, job_offer_id: JobOfferId) -> Option<BidId> {
+        let bid_id = self.bid_storage.get_active_bid_id(worker, job_offer_id)?;
+        let mut bid = self.bid_storage.get_bid_or_revert(&bid_id);
+        self.unstake_cspr_or_reputation_for_bid(&bid);
+        bid.reject_without_validation();
+        self.bid_storage.store_bid(bid);
+        self.bid_storage
+            .remove_from_active_bids(worker, job_offer_id);
+        Some(bid_id)
+    }
+
     fn slash_bid(&mut self, worker: Address, job_offer_id: JobOfferId) {
         let bid_id = self.bid_storage.get_active_bid_id(worker, job_offer_id);
         let bid_id = match bid_id {
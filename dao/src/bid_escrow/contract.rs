@@ -166,6 +166,63 @@
 //! # Returning DOS Fee
 //! The final step of the process is returning the `CSPR` `DOS Fee` to the `Job Poster`.
 //!
+//! # Payment in CEP-18 tokens
+//! Instead of `CSPR`, a `Job Offer` can be paid in a `CEP-18` token whitelisted in the contract
+//! together with a contract providing the token:fiat rate.
+//! The `Job Poster` approves the contract to spend the payment before picking a `Bid`, and all the payouts
+//! (`Governance Payment`, `VAs'` and `External Worker` payments, returns) are made in the same token.
+//! `DOS Fee` and `External Worker` stakes are always paid in `CSPR`.
+//! The `Reputation` minted after a successful `Job` is calculated from the fiat value of the payment
+//! converted to `CSPR`, so it does not depend on the way the `Job` is paid.
+//!
+//! # Team Bids
+//! Several `Workers` (`VAs` and `External Workers` alike) can submit a joint `Bid`. The submitter becomes the
+//! team leader and the `Worker` of the `Job` - submits the `Job Proof`.
+//! Nobody in the team is onboarded.
+//! The `Bid` declares the share of each member in per mils, the shares must sum up to 1000.
+//! The stake of the `Bid` is split according to the shares - the leader stakes its share when submitting the `Bid`,
+//! every other member stakes its share when confirming the participation, in the same currency as the leader.
+//! The `Bid` can be picked only when all the members confirmed it.
+//! When the `Job` is done, the `Reputation` minted for the `Worker` and the `External Worker` payment are split
+//! according to the declared shares and the members' stakes are returned. If the `Job` fails, is cancelled
+//! or reclaimed, the leader is slashed as a solo `Worker` and every other member loses its stake.
+//!
+//! # Deadline extension
+//! Before the deadline passes and the `Job Proof` is submitted, the `Worker` can request more time, explaining the reason
+//! in a document. The `Job Poster` accepts or rejects the request. Once accepted, the `Job` deadline is moved and
+//! the `Grace Period` starts after the new deadline. A `Job` can be extended at most `MaxDeadlineExtensions` times.
+//!
+//! # Sealed Bids
+//! A `Job Poster` can post a sealed `Job Offer`. During the auction the `Workers` do not disclose their terms,
+//! they submit a commitment - a hash of the bidder address, the `Job Offer` id, the proposed timeframe, payment
+//! and a secret salt - along with the stake. A sealed `Bid` cannot be canceled.
+//! Once the auction is over, each `Worker` reveals the terms. Only revealed `Bids` can be picked.
+//! When the `Job Poster` picks a `Bid`, the stakes of unrevealed `Bids` are returned partially -
+//! the `UnrevealedBidForfeitRatio` part of a `CSPR` stake goes to the `Bid Escrow Wallet`,
+//! the same part of a `Reputation` stake is burnt. The same applies when the `Job Offer` is canceled.
+//!
+//! # Recurring Jobs
+//! Ongoing work can be posted as a recurring `Job Offer` - the budget and the timeframe are defined per period,
+//! and the `Job Poster` pre-funds all the periods when picking a `Bid`.
+//! The picked `Worker` submits a `Job Proof` for every period. Each period gets its own `Voting` and payout.
+//! When a period is done, the next one starts right away with the same `Worker` and stake. If the `Worker`
+//! cannot afford the stake anymore, the `Job` ends as if it was terminated.
+//! A recurring `Job` does not onboard the `Worker`. The `DOS Fee` and the `External Worker` stake are returned
+//! after the last period.
+//! Before the `Job Proof` for the current period is submitted, either side can terminate the `Job`. The payment for
+//! the remaining periods is refunded to the `Job Poster`, just like when the `Job` fails, is canceled or expires.
+//!
+//! # Categories and Skills
+//! A `Job Offer` can be assigned to a category and labeled with skill tags. Both come from a taxonomy
+//! governed by the `JobCategories` and `SkillTags` variables. `VAs` declare their skills on their profile.
+//! The open `Job Offers` can be listed by category.
+//! If `InternalAuctionSkillMatchRequired` is set, during the `Internal Auction` only `VAs` who declared
+//! at least one of the skills of the `Job Offer` can bid. A `Job Offer` with no skills is open to every `VA`.
+//!
+//! # KYC Levels
+//! A `Job Offer` can require an enhanced KYC level. Both the `Job Poster` and the `Workers` bidding on the offer
+//! must be KYC'd at least at the required level. By default the basic level is required.
+//!
 //! # Grace Period
 //! However, if `External Worker` do not post a `Job Proof` in time, his `CSPR` stake is redistributed
 //! between all `VA’s`.
@@ -180,21 +237,51 @@
 //! The `CSPR` paid by the `Job Poster` is returned along with the `DOS Fee`.
 //! This is a special implementation of [positional parameters].
 //!
+//! # Expiration
+//! A `Job Offer` with no `Bid` picked until `VABidAcceptanceTimeout` passes after the auction end, and a `Job`
+//! with no `Job Proof` submitted until the end of the `Grace Period`, can be settled by anyone
+//! calling [`process_expired()`](BidEscrowContract::process_expired()). The caller is rewarded with
+//! the `KeeperRewardRatio` part of the `DOS Fee`.
+//!
+//! # Insurance Pool
+//! If the [`Insurance Pool`] is set, the `InsurancePoolRatio` part of every forfeited `CSPR` stake
+//! (of a failed or canceled `External Worker`, a `Worker` replaced in the `Grace Period`, an unrevealed sealed `Bid`)
+//! and of every `DOS Fee` returned to the `Job Poster` is credited to the pool.
+//! The pool collects the credited `CSPR` itself, so a paused pool does not block the settlements.
+//! The pool compensates `Job Posters` for defective `Jobs`.
+//!
+//! # KYC Revocation
+//! When the KYC of an address is revoked by the [`KYC Voter`], the open `Job Offers` posted by the address are cancelled,
+//! and its `Bids` in the open `Job Offers` are rejected. The stakes and the `DOS Fees` are returned.
+//!
+//! # Claims
+//! The contract never pushes `CSPR` to the recipients. Payments, returned stakes and fees, and redistributed
+//! `CSPR` are credited to a claimable balance, and the recipient withdraws them calling
+//! [`claim()`](BidEscrowContract::claim()). A recipient that cannot receive funds does not block the settlement.
+//! Payments in `CEP-18` tokens are credited the same way, per token, and withdrawn calling
+//! [`claim_token()`](BidEscrowContract::claim_token()).
+//!
 //! [`Variable Repository Contract`]: crate::core_contracts::VariableRepositoryContract
 //! [`VotingEngine`]: VotingEngine
 //! [`Slashing Voter`]: crate::voting_contracts::SlashingVoterContract
 //! [`Reputation`]: crate::core_contracts::ReputationContract
 //! [`Governance Variable`]: crate::core_contracts::VariableRepositoryContract#available-keys
+//! [`Insurance Pool`]: crate::voting_contracts::InsurancePoolContract
+//! [`KYC Voter`]: crate::voting_contracts::KycVoterContract
 
 use crate::bid_escrow::bid::Bid;
 use crate::bid_escrow::bid_engine::BidEngine;
-use crate::bid_escrow::events::BidEscrowSlashResults;
+use crate::bid_escrow::events::{
+    BidEscrowAccountMigrated, BidEscrowSlashResults, KycRevocationHandled,
+};
 use crate::bid_escrow::job::Job;
 use crate::bid_escrow::job_engine::JobEngine;
-use crate::bid_escrow::job_offer::JobOffer;
+use crate::bid_escrow::job_offer::{JobOffer, JobOfferParams};
 use crate::bid_escrow::types::{BidId, JobId, JobOfferId};
+use crate::bid_escrow::worker_stats::WorkerStats;
+use crate::modules::claims::{PendingClaim, PendingTokenClaim};
 use crate::modules::refs::ContractRefs;
-use crate::modules::AccessControl;
+use crate::modules::{AccessControl, Claims, OwnerAction, OwnerActionArgs, OwnerProposal, Role};
 use crate::utils::types::DocumentHash;
 use crate::voting::ballot::{Ballot, Choice};
 use crate::voting::types::VotingId;
@@ -203,7 +290,8 @@ use crate::voting::voting_engine::voting_state_machine::{
 };
 use crate::voting::voting_engine::VotingEngine;
 use odra::contract_env::{caller, self_balance};
-use odra::types::{event::OdraEvent, Address, Balance, BlockTime};
+use odra::prelude::{string::String, vec::Vec};
+use odra::types::{event::OdraEvent, Address, Balance, BlockTime, Bytes};
 
 use super::storage::{BidStorage, JobStorage};
 use crate::voting_contracts::SlashedVotings;
@@ -217,14 +305,15 @@ use crate::voting_contracts::SlashedVotings;
 pub struct BidEscrowContract {
     refs: ContractRefs,
     access_control: AccessControl,
-    #[odra(using = "refs, voting_engine, job_storage, bid_storage")]
+    #[odra(using = "refs, voting_engine, job_storage, bid_storage, claims, access_control")]
     job_engine: JobEngine,
-    #[odra(using = "refs, job_storage, bid_storage")]
+    #[odra(using = "refs, job_storage, bid_storage, claims, access_control")]
     bid_engine: BidEngine,
     #[odra(using = "refs")]
     voting_engine: VotingEngine,
     job_storage: JobStorage,
     bid_storage: BidStorage,
+    claims: Claims,
 }
 
 #[odra::module]
@@ -253,17 +342,44 @@ impl BidEscrowContract {
         to self.bid_engine {
             /// Job Poster post a new Job Offer.
             ///
+            /// Besides the timeframe, the budget and the DOS Fee, the `params` optionally define:
+            /// * a whitelisted CEP-18 token the Job is paid in - the `DOS Fee` is still paid in CSPR,
+            /// * if the offer accepts sealed Bids only,
+            /// * the category and the skill tags of the Job,
+            /// * the number of periods of a recurring Job - the budget and the timeframe are defined per period,
+            /// picking a Bid requires the payment for all the periods,
+            /// * the KYC level required from the Job Poster and Workers.
+            ///
             /// # Errors
             /// * [`NotKyced`](crate::utils::Error::NotKyced) - if the caller is not KYCed
+            /// * [`KycLevelTooLow`](crate::utils::Error::KycLevelTooLow) - if the KYC level of the caller is lower than required
+            /// * [`InvalidKycLevel`](crate::utils::Error::InvalidKycLevel) - if the required KYC level is zero
             /// * [`DosFeeTooLow`](crate::utils::Error::DosFeeTooLow) - if the caller has not sent enough DOS Fee
+            /// * [`PaymentTokenNotWhitelisted`](crate::utils::Error::PaymentTokenNotWhitelisted) - if the token is not whitelisted
+            /// * [`UnknownJobCategory`](crate::utils::Error::UnknownJobCategory) - if the category is not in `JobCategories`
+            /// * [`UnknownSkillTag`](crate::utils::Error::UnknownSkillTag) - if any of the skills is not in `SkillTags`
+            /// * [`InvalidJobPeriods`](crate::utils::Error::InvalidJobPeriods) - if the number of periods is zero
             ///
             /// # Events
             /// * [`JobOfferCreated`](crate::bid_escrow::events::JobOfferCreated)
             #[odra(payable)]
-            pub fn post_job_offer(&mut self, expected_timeframe: BlockTime, budget: Balance, dos_fee: Balance);
+            pub fn post_job_offer(&mut self, params: JobOfferParams);
+
+            /// VA declares their skills. Replaces the previously declared skills.
+            ///
+            /// # Events
+            /// * [`SkillsDeclared`](crate::bid_escrow::events::SkillsDeclared)
+            ///
+            /// # Errors
+            /// * [`OnlyVaCanDeclareSkills`](crate::utils::Error::OnlyVaCanDeclareSkills) - if the caller is not a VA
+            /// * [`UnknownSkillTag`](crate::utils::Error::UnknownSkillTag) - if any of the skills is not in `SkillTags`
+            pub fn declare_skills(&mut self, skills: Vec<String>);
 
             /// Job poster picks a bid. This creates a new Job object and saves it in a storage.
             ///
+            /// If the Job Offer is paid in a CEP-18 token, `cspr_amount` tokens are transferred from the Job Poster,
+            /// who must approve the contract to spend them beforehand.
+            ///
             /// # Events
             /// * [`JobCreated`](crate::bid_escrow::events::JobCreated)
             /// * [`Unstake`](crate::core_contracts::Unstake) - in case there were other bids on the same Job Offer
@@ -271,6 +387,9 @@ impl BidEscrowContract {
             /// # Errors
             /// * [`OnlyJobPosterCanPickABid`](crate::utils::Error::OnlyJobPosterCanPickABid) - if the caller is not the Job Poster
             /// * [`PurseBalanceMismatch`](crate::utils::Error::PurseBalanceMismatch) - if the purse balance does not match the bid amount
+            /// * [`UnexpectedCSPRTransfer`](crate::utils::Error::UnexpectedCSPRTransfer) - if CSPR is attached to a token payment
+            /// * [`TeamBidNotConfirmed`](crate::utils::Error::TeamBidNotConfirmed) - if not all the team members confirmed the Bid
+            /// * [`BidNotRevealed`](crate::utils::Error::BidNotRevealed) - if a sealed Bid has not been revealed
             #[odra(payable)]
             pub fn pick_bid(&mut self, job_offer_id: JobOfferId, bid_id: BidId, cspr_amount: Balance);
 
@@ -294,7 +413,12 @@ impl BidEscrowContract {
             /// * [`InsufficientBalance`](crate::utils::Error::InsufficientBalance) - if the Worker has not enough balance to pay for the
             /// bid
             /// * [`ZeroStake`](crate::utils::Error::ZeroStake) - if the Worker tries to stake 0 reputation
-            /// * [`NotWhitelisted`](crate::utils::Error::NotWhitelisted) - if the contract is not whitelisted for Reputation Staking
+            /// * [`MissingRole`](crate::utils::Error::MissingRole) - if the contract does not have the `Staker` role in the Reputation Token
+            /// * [`SealedBidRequired`](crate::utils::Error::SealedBidRequired) - if the Job Offer accepts sealed Bids only
+            /// * [`CannotOnboardOnRecurringJob`](crate::utils::Error::CannotOnboardOnRecurringJob) - if the Worker requests onboarding
+            /// on a recurring Job Offer
+            /// * [`NoMatchingSkill`](crate::utils::Error::NoMatchingSkill) - if the auction is internal, skill match is required
+            /// and the VA has not declared any of the skills of the Job Offer
             #[odra(payable)]
             pub fn submit_bid(
                 &mut self,
@@ -306,6 +430,90 @@ impl BidEscrowContract {
                 cspr_stake: Option<Balance>
             );
 
+            /// Worker submits a sealed [Bid] for a sealed [Job] Offer. The `commitment` is a hash of the Worker address,
+            /// the Job Offer id, the proposed timeframe, payment and a secret salt - see [`Bid::commitment()`].
+            ///
+            /// # Events
+            /// * [`BidSubmitted`](crate::bid_escrow::events::BidSubmitted)
+            /// * [`Stake`](crate::core_contracts::Stake)
+            ///
+            /// # Errors
+            /// The same as in [`submit_bid()`](Self::submit_bid()), except for:
+            /// * [`JobOfferNotSealed`](crate::utils::Error::JobOfferNotSealed) - if the Job Offer does not accept sealed Bids
+            #[odra(payable)]
+            pub fn submit_sealed_bid(
+                &mut self,
+                job_offer_id: JobOfferId,
+                commitment: Bytes,
+                reputation_stake: Balance,
+                onboard: bool,
+                cspr_stake: Option<Balance>
+            );
+
+            /// Worker reveals the terms of a sealed [Bid] once the auction is over.
+            ///
+            /// # Events
+            /// * [`BidRevealed`](crate::bid_escrow::events::BidRevealed)
+            ///
+            /// # Errors
+            /// * [`OnlyBidderCanRevealBid`](crate::utils::Error::OnlyBidderCanRevealBid) - if the caller is not the Worker
+            /// * [`BidNotSealed`](crate::utils::Error::BidNotSealed) - if the Bid is not sealed or has been revealed already
+            /// * [`CannotRevealBidDuringAuction`](crate::utils::Error::CannotRevealBidDuringAuction) - if the auction is still running
+            /// * [`BidCommitmentMismatch`](crate::utils::Error::BidCommitmentMismatch) - if the terms do not match the commitment
+            /// * [`PaymentExceedsMaxBudget`](crate::utils::Error::PaymentExceedsMaxBudget) - if the proposed payment exceeds the maximum budget
+            pub fn reveal_bid(
+                &mut self,
+                bid_id: BidId,
+                proposed_timeframe: BlockTime,
+                proposed_payment: Balance,
+                salt: Bytes
+            );
+
+            /// Worker submits a [Bid] for a [Job] on behalf of a team. `team` lists all the members (including the caller)
+            /// and their shares in per mils. Other members must confirm the Bid before it can be picked.
+            /// A team Bid never onboards its members.
+            ///
+            /// # Events
+            /// * [`BidSubmitted`](crate::bid_escrow::events::BidSubmitted)
+            /// * [`TeamBidSubmitted`](crate::bid_escrow::events::TeamBidSubmitted)
+            /// * [`Stake`](crate::core_contracts::Stake)
+            ///
+            /// # Errors
+            /// The same as in [`submit_bid()`](Self::submit_bid()) and:
+            /// * [`InvalidTeamMember`](crate::utils::Error::InvalidTeamMember) - if the caller is not in the team, the team
+            /// includes the Job Poster or a duplicated member
+            /// * [`InvalidTeamShares`](crate::utils::Error::InvalidTeamShares) - if the shares do not sum up to 1000
+            #[odra(payable)]
+            pub fn submit_team_bid(
+                &mut self,
+                job_offer_id: JobOfferId,
+                time: BlockTime,
+                payment: Balance,
+                reputation_stake: Balance,
+                cspr_stake: Option<Balance>,
+                team: Vec<(Address, u32)>
+            );
+
+            /// Team member confirms the participation in a team [Bid] and stakes its share of the Bid stake.
+            /// If the Bid is staked with CSPR, the share must be attached to the call.
+            ///
+            /// # Events
+            /// * [`TeamBidConfirmed`](crate::bid_escrow::events::TeamBidConfirmed)
+            /// * [`Stake`](crate::core_contracts::Stake)
+            ///
+            /// # Errors
+            /// * [`NotKyced`](crate::utils::Error::NotKyced) - if the caller is not KYCed
+            /// * [`NotATeamMember`](crate::utils::Error::NotATeamMember) - if the caller is not in the team
+            /// * [`TeamBidAlreadyConfirmed`](crate::utils::Error::TeamBidAlreadyConfirmed) - if the caller has already
+            /// confirmed the Bid
+            /// * [`BidAlreadyPicked`](crate::utils::Error::BidAlreadyPicked) - if the Bid cannot be picked anymore
+            /// * [`AttachedValueMismatch`](crate::utils::Error::AttachedValueMismatch) - if the attached value is not
+            /// the caller's share of the CSPR stake
+            /// * [`InsufficientBalanceForStake`](crate::utils::Error::InsufficientBalanceForStake) - if the caller
+            /// cannot afford its share of the reputation stake
+            #[odra(payable)]
+            pub fn confirm_team_bid(&mut self, bid_id: BidId);
+
             /// Worker cancels a [Bid] for a [Job].
             ///
             /// Bid can be cancelled only after VABidAcceptanceTimeout time has passed after submitting a Bid.
@@ -317,6 +525,7 @@ impl BidEscrowContract {
             /// # Errors:
             /// * [`CannotCancelNotOwnedBid`](crate::utils::Error::CannotCancelNotOwnedBid) when trying to cancel a Bid
             /// that is not owned by the Worker
+            /// * [`CannotCancelSealedBid`](crate::utils::Error::CannotCancelSealedBid) when trying to cancel a sealed Bid
             /// * [`CannotCancelBidOnCompletedJobOffer`](crate::utils::Error::CannotCancelBidOnCompletedJobOffer) when
             /// trying to cancel a Bid on a Job Offer that is already completed
             /// * [`CannotCancelBidBeforeAcceptanceTimeout`](crate::utils::Error::CannotCancelBidBeforeAcceptanceTimeout)
@@ -327,6 +536,24 @@ impl BidEscrowContract {
             /// [`Read more`](BidEngine::cancel_job_offer()).
             pub fn cancel_job_offer(&mut self, job_offer_id: JobOfferId);
 
+            /// Job Poster updates the budget, the expected timeframe and the description of a [`Job Offer`](JobOffer)
+            /// before a Bid is picked. Bids exceeding the new budget are rejected and their stakes returned.
+            ///
+            /// # Events
+            /// * [`JobOfferUpdated`](crate::bid_escrow::events::JobOfferUpdated)
+            /// * [`Unstake`](crate::core_contracts::Unstake) - in case any Bid has been rejected
+            ///
+            /// # Errors
+            /// * [`OnlyJobPosterCanModifyJobOffer`](crate::utils::Error::OnlyJobPosterCanModifyJobOffer) - if the caller is not the Job Poster
+            /// * [`JobOfferCannotBeModified`](crate::utils::Error::JobOfferCannotBeModified) - if the Job Offer is not in the `Created` state
+            pub fn update_job_offer(
+                &mut self,
+                job_offer_id: JobOfferId,
+                max_budget: Balance,
+                expected_timeframe: BlockTime,
+                description: DocumentHash
+            );
+
             /// Returns the total number of job offers.
             pub fn job_offers_count(&self) -> u32;
 
@@ -338,6 +565,15 @@ impl BidEscrowContract {
 
             /// Returns a Bid with given [BidId].
             pub fn get_bid(&self, bid_id: BidId) -> Option<Bid>;
+
+            /// Returns the rate provider of a whitelisted payment token, or `None` if the token is not whitelisted.
+            pub fn get_payment_token_rate_provider(&self, token: Address) -> Option<Address>;
+
+            /// Returns the skills declared by a given address.
+            pub fn get_skills(&self, address: Address) -> Vec<String>;
+
+            /// Returns the ids of Job Offers in a given category, that are open for Bids.
+            pub fn get_open_job_offers_by_category(&self, category: String) -> Vec<JobOfferId>;
         }
 
         to self.job_engine {
@@ -369,6 +605,52 @@ impl BidEscrowContract {
 
             pub fn cancel_job(&mut self, job_id: JobId);
 
+            /// Job Poster or Worker terminates a recurring [`Job`] before the proof for the current period is submitted.
+            /// The unused payment and the `DOS Fee` are returned to the Job Poster, the stake - to the Worker.
+            ///
+            /// # Events
+            /// * [`JobTerminated`](crate::bid_escrow::events::JobTerminated)
+            /// * [`Unstake`](crate::core_contracts::Unstake)
+            ///
+            /// # Errors
+            /// * [`CannotTerminateJob`](crate::utils::Error::CannotTerminateJob) - if the caller is neither the Job Poster nor the Worker
+            /// * [`JobCannotBeTerminated`](crate::utils::Error::JobCannotBeTerminated) - if the Job is not recurring or the proof is submitted
+            pub fn terminate_job(&mut self, job_id: JobId);
+
+            /// Worker requests the deadline of a [`Job`] to be extended by `time`. The number of extensions
+            /// is bounded by the `MaxDeadlineExtensions` governance variable.
+            ///
+            /// # Events
+            /// * [`JobExtensionRequested`](crate::bid_escrow::events::JobExtensionRequested)
+            ///
+            /// # Errors
+            /// * [`OnlyWorkerCanRequestJobExtension`](crate::utils::Error::OnlyWorkerCanRequestJobExtension) - if the caller is not the Worker
+            /// * [`JobExtensionNotAllowed`](crate::utils::Error::JobExtensionNotAllowed) - if the proof is submitted or the deadline has passed
+            /// * [`JobExtensionAlreadyRequested`](crate::utils::Error::JobExtensionAlreadyRequested) - if a request awaits the response
+            /// * [`JobExtensionsLimitReached`](crate::utils::Error::JobExtensionsLimitReached) - if no more extensions are allowed
+            pub fn request_job_extension(&mut self, job_id: JobId, time: BlockTime, reason: DocumentHash);
+
+            /// Job Poster accepts the pending deadline extension request of a [`Job`].
+            ///
+            /// # Events
+            /// * [`JobExtensionAccepted`](crate::bid_escrow::events::JobExtensionAccepted)
+            ///
+            /// # Errors
+            /// * [`OnlyJobPosterCanRespondToJobExtension`](crate::utils::Error::OnlyJobPosterCanRespondToJobExtension) - if the caller is not the Job Poster
+            /// * [`JobExtensionNotRequested`](crate::utils::Error::JobExtensionNotRequested) - if there is no pending request
+            /// * [`JobExtensionNotAllowed`](crate::utils::Error::JobExtensionNotAllowed) - if the proof is submitted or the deadline has passed
+            pub fn accept_job_extension(&mut self, job_id: JobId);
+
+            /// Job Poster rejects the pending deadline extension request of a [`Job`].
+            ///
+            /// # Events
+            /// * [`JobExtensionRejected`](crate::bid_escrow::events::JobExtensionRejected)
+            ///
+            /// # Errors
+            /// * [`OnlyJobPosterCanRespondToJobExtension`](crate::utils::Error::OnlyJobPosterCanRespondToJobExtension) - if the caller is not the Job Poster
+            /// * [`JobExtensionNotRequested`](crate::utils::Error::JobExtensionNotRequested) - if there is no pending request
+            pub fn reject_job_extension(&mut self, job_id: JobId);
+
             /// Casts a vote over a job.
             ///
             /// # Events
@@ -403,6 +685,45 @@ impl BidEscrowContract {
 
             /// Returns a job with given [JobId].
             pub fn get_job(&self, job_id: JobId) -> Option<Job>;
+
+            /// Returns the track record of a given `Worker` - the number of completed, rejected and cancelled
+            /// Jobs, the average delay and the total payment.
+            pub fn get_worker_stats(&self, worker: Address) -> WorkerStats;
+        }
+
+        to self.claims {
+            /// Transfers all the CSPR credited to the caller.
+            ///
+            /// # Errors
+            /// * [`NothingToClaim`](crate::utils::Error::NothingToClaim) - if there is nothing to claim
+            ///
+            /// # Events
+            /// * [`CSPRTransfer`](crate::bid_escrow::events::CSPRTransfer)
+            pub fn claim(&mut self);
+
+            /// Returns the CSPR credited to a given address and awaiting to be claimed, grouped by the reason.
+            pub fn get_pending_claims(&self, address: Address) -> Vec<PendingClaim>;
+
+            /// Transfers all the CSPR credited to the calling [Insurance Pool](crate::voting_contracts::InsurancePoolContract).
+            ///
+            /// # Errors
+            /// * [`NothingToClaim`](crate::utils::Error::NothingToClaim) - if there is nothing to claim
+            ///
+            /// # Events
+            /// * [`CSPRTransfer`](crate::bid_escrow::events::CSPRTransfer)
+            pub fn claim_insurance_pool_deposits(&mut self);
+
+            /// Transfers all the tokens of the given CEP-18 `token` credited to the caller.
+            ///
+            /// # Errors
+            /// * [`NothingToClaim`](crate::utils::Error::NothingToClaim) - if there is nothing to claim
+            ///
+            /// # Events
+            /// * [`TokenTransfer`](crate::bid_escrow::events::TokenTransfer)
+            pub fn claim_token(&mut self, token: Address);
+
+            /// Returns the CEP-18 tokens credited to a given address and awaiting to be claimed, grouped by the token.
+            pub fn get_pending_token_claims(&self, address: Address) -> Vec<PendingTokenClaim>;
         }
 
         to self.access_control {
@@ -415,9 +736,6 @@ impl BidEscrowContract {
             /// [`Read more`](AccessControl::accept_new_owner())
             pub fn accept_new_owner(&mut self);
 
-            /// Adds a new address to the whitelist.
-            /// [`Read more`](AccessControl::add_to_whitelist())
-            pub fn add_to_whitelist(&mut self, address: Address);
 
             /// Remove address from the whitelist.
             /// [`Read more`](AccessControl::remove_from_whitelist());
@@ -430,6 +748,54 @@ impl BidEscrowContract {
             /// Returns the address of the current owner.
             /// [`Read more`](AccessControl::get_owner()).
             pub fn get_owner(&self) -> Option<Address>;
+            /// Grants the `role` to the `address`.
+            /// [`Read more`](AccessControl::grant_role())
+            pub fn grant_role(&mut self, role: Role, address: Address);
+            /// Revokes the `role` from the `address`.
+            /// [`Read more`](AccessControl::revoke_role())
+            pub fn revoke_role(&mut self, role: Role, address: Address);
+            /// Sets the admin role of the `role`, whose holders grant and revoke the `role`.
+            /// [`Read more`](AccessControl::set_role_admin())
+            pub fn set_role_admin(&mut self, role: Role, admin_role: Role);
+            /// Returns the admin role of the `role`, whose holders grant and revoke the `role`.
+            /// [`Read more`](AccessControl::get_role_admin())
+            pub fn get_role_admin(&self, role: Role) -> Role;
+            /// Checks whether the `address` has the `role`.
+            /// [`Read more`](AccessControl::has_role())
+            pub fn has_role(&self, role: Role, address: Address) -> bool;
+            /// Replaces the whitelist entry of the `address` with the `roles`.
+            /// [`Read more`](AccessControl::migrate_to_roles())
+            pub fn migrate_to_roles(&mut self, address: Address, roles: Vec<Role>);
+            /// Hands the ownership over to the `signers`.
+            /// [`Read more`](AccessControl::enable_multisig_ownership())
+            pub fn enable_multisig_ownership(&mut self, signers: Vec<Address>, threshold: u32);
+            /// Proposes an owner-only action.
+            /// [`Read more`](AccessControl::propose_owner_action())
+            pub fn propose_owner_action(&mut self, action: OwnerAction, action_args: OwnerActionArgs) -> u32;
+            /// Approves a proposed owner-only action.
+            /// [`Read more`](AccessControl::approve_owner_action())
+            pub fn approve_owner_action(&mut self, proposal_id: u32);
+            /// Executes a proposed owner-only action approved by the current signers.
+            /// [`Read more`](AccessControl::execute_owner_action())
+            pub fn execute_owner_action(&mut self, proposal_id: u32);
+            /// Returns the signers of the multisig ownership.
+            /// [`Read more`](AccessControl::get_signers())
+            pub fn get_signers(&self) -> Vec<Address>;
+            /// Returns the number of approvals required to execute an owner-only action.
+            /// [`Read more`](AccessControl::get_threshold())
+            pub fn get_threshold(&self) -> u32;
+            /// Returns the proposal of an owner-only action.
+            /// [`Read more`](AccessControl::get_owner_proposal())
+            pub fn get_owner_proposal(&self, proposal_id: u32) -> Option<OwnerProposal>;
+            /// Pauses the contract.
+            /// [`Read more`](AccessControl::pause())
+            pub fn pause(&mut self);
+            /// Unpauses the contract.
+            /// [`Read more`](AccessControl::unpause())
+            pub fn unpause(&mut self);
+            /// Returns true if the contract is paused.
+            /// [`Read more`](AccessControl::is_paused())
+            pub fn is_paused(&self) -> bool;
         }
     }
 
@@ -440,12 +806,12 @@ impl BidEscrowContract {
     /// * Sets up the contract by saving addresses of [`Variable Repository`](crate::core_contracts::VariableRepositoryContract),
     /// [`Reputation Token`](crate::core_contracts::ReputationContract), [`VA Token`](crate::core_contracts::VaNftContract), [`KYC Token`](crate::core_contracts::KycNftContract).
     /// * Sets [`caller`] as the owner of the contract.
-    /// * Adds [`caller`] to the whitelist.
+    /// * Grants [`caller`] the [`Admin`](crate::modules::Role::Admin) role.
     ///
     /// # Events
     /// Emits:
     /// * [`OwnerChanged`](crate::modules::owner::events::OwnerChanged),
-    /// * [`AddedToWhitelist`](crate::modules::whitelist::events::AddedToWhitelist),
+    /// * [`RoleGranted`](crate::modules::roles::events::RoleGranted),
     #[odra(init)]
     pub fn init(
         &mut self,
@@ -466,20 +832,66 @@ impl BidEscrowContract {
         self_balance()
     }
 
+    /// Whitelists a CEP-18 token, so Job Offers can be paid in it.
+    /// Only the holders of the [`Operator`](crate::modules::Role::Operator) role are permitted to call this method.
+    ///
+    /// `rate_provider` is a contract returning the token:fiat rate, see
+    /// [`CSPRRateProviderContract`](crate::utils_contracts::CSPRRateProviderContract).
+    ///
+    /// # Errors
+    /// * [crate::utils::Error::MissingRole]
+    ///
+    /// # Events
+    /// * [`PaymentTokenAdded`](crate::bid_escrow::events::PaymentTokenAdded)
+    pub fn add_payment_token(&mut self, token: Address, rate_provider: Address) {
+        self.access_control.ensure_role(Role::Operator);
+        self.bid_engine.add_payment_token(token, rate_provider);
+    }
+
+    /// Removes a CEP-18 token from the whitelist. Already posted Job Offers keep using it.
+    /// Only the holders of the [`Operator`](crate::modules::Role::Operator) role are permitted to call this method.
+    ///
+    /// # Errors
+    /// * [crate::utils::Error::MissingRole]
+    ///
+    /// # Events
+    /// * [`PaymentTokenRemoved`](crate::bid_escrow::events::PaymentTokenRemoved)
+    pub fn remove_payment_token(&mut self, token: Address) {
+        self.access_control.ensure_role(Role::Operator);
+        self.bid_engine.remove_payment_token(token);
+    }
+
+    /// Sets the [Insurance Pool](crate::voting_contracts::InsurancePoolContract) the `InsurancePoolRatio`
+    /// part of forfeited stakes and `DOS Fees` is credited to.
+    /// Only the holders of the [`Operator`](crate::modules::Role::Operator) role are permitted to call this method.
+    ///
+    /// # Errors
+    /// * [crate::utils::Error::MissingRole]
+    pub fn set_insurance_pool(&mut self, insurance_pool: Address) {
+        self.access_control.ensure_role(Role::Operator);
+        self.refs.set_insurance_pool(insurance_pool);
+    }
+
+    /// Returns the address of the [Insurance Pool](crate::voting_contracts::InsurancePoolContract), if set.
+    pub fn get_insurance_pool(&self) -> Option<Address> {
+        self.refs.insurance_pool_address()
+    }
+
     /// Erases the VA from the all bids, offers and jobs.
-    /// Only a whitelisted account is permitted to call this method.
+    /// Only the holders of the [`Slasher`](crate::modules::Role::Slasher) role are permitted to call this method.
     /// Interacts with [Reputation Token Contract](crate::core_contracts::ReputationContract).
     ///
     /// # Errors
     /// * [crate::utils::Error::BidNotFound]
     /// * [crate::utils::Error::JobOfferNotFound]
     /// * [crate::utils::Error::CannotCancelBidOnCompletedJobOffer]
-    /// * [crate::utils::Error::NotWhitelisted]
+    /// * [crate::utils::Error::MissingRole]
     ///
     /// # Events
     /// * [`BidEscrowSlashResults`](BidEscrowSlashResults)
     pub fn slash_voter(&mut self, voter: Address) -> SlashedVotings {
-        self.access_control.ensure_whitelisted();
+        self.access_control.ensure_not_paused();
+        self.access_control.ensure_role(Role::Slasher);
         let (slashed_job_offers, slashed_bids) = self.bid_engine.slash_voter(voter);
         let (slashed_jobs, cancelled_votings, affected_votings) =
             self.job_engine.slash_voter(voter);
@@ -499,6 +911,86 @@ impl BidEscrowContract {
         }
     }
 
+    /// Cancels the open Job Offers posted by the `address` and rejects its Bids, after its KYC has been revoked.
+    /// Only the holders of the [`Slasher`](crate::modules::Role::Slasher) role are permitted to call this method.
+    ///
+    /// # Errors
+    /// * [crate::utils::Error::MissingRole]
+    ///
+    /// # Events
+    /// * [`KycRevocationHandled`](KycRevocationHandled)
+    pub fn revoke_kyc(&mut self, address: Address) {
+        self.access_control.ensure_not_paused();
+        self.access_control.ensure_role(Role::Slasher);
+        let (cancelled_job_offers, rejected_bids) = self.bid_engine.revoke_kyc(address);
+
+        KycRevocationHandled {
+            address,
+            cancelled_job_offers,
+            rejected_bids,
+        }
+        .emit();
+    }
+
+    /// Moves the open Job Offers, the Bids, the Jobs in progress, the unclaimed CSPR and tokens of the `from` address
+    /// and its ballots in the ongoing votings to the `to` address, as a result of
+    /// an [account recovery](crate::voting_contracts::AccountRecoveryVoterContract).
+    /// Only the holders of the [`Migrator`](crate::modules::Role::Migrator) role are permitted to call this method.
+    ///
+    /// Returns ids of the affected votings.
+    ///
+    /// # Errors
+    /// * [crate::utils::Error::MissingRole]
+    ///
+    /// # Events
+    /// * [`BidEscrowAccountMigrated`](BidEscrowAccountMigrated)
+    pub fn migrate_account(&mut self, from: Address, to: Address) -> Vec<VotingId> {
+        self.access_control.ensure_not_paused();
+        self.access_control.ensure_role(Role::Migrator);
+        let (migrated_job_offers, mut migrated_bids) = self.bid_engine.migrate_account(from, to);
+        let (migrated_jobs, migrated_job_bids, migrated_votings) =
+            self.job_engine.migrate_account(from, to);
+        migrated_bids.extend(migrated_job_bids);
+        self.claims.migrate(from, to);
+
+        BidEscrowAccountMigrated {
+            from,
+            to,
+            migrated_job_offers,
+            migrated_bids,
+            migrated_jobs,
+            migrated_votings: migrated_votings.clone(),
+        }
+        .emit();
+
+        migrated_votings
+    }
+
+    /// Settles at most `limit` expired Job Offers, Bids and Jobs. Anyone can call this method.
+    ///
+    /// * A Job Offer expires if no Bid has been picked until `VABidAcceptanceTimeout` passed after the auction end.
+    /// All the Bids are canceled, the stakes and the `DOS Fee` are returned.
+    /// * A Bid in an open Job Offer expires if it has not been picked until `VABidAcceptanceTimeout` passed
+    /// since it was placed. The Bid is canceled and the stake is returned.
+    /// * A Job expires if the `Job Proof` has not been submitted until the end of the [Grace Period](crate::bid_escrow#grace-period).
+    /// The Job is canceled as in [`cancel_job()`](Self::cancel_job()).
+    ///
+    /// The caller receives the `KeeperRewardRatio` part of the `DOS Fee` of each settled Job Offer or Job.
+    /// Returns the number of settled Job Offers, Bids and Jobs.
+    ///
+    /// # Events
+    /// * [`JobOfferExpired`](crate::bid_escrow::events::JobOfferExpired)
+    /// * [`BidCancelled`](crate::bid_escrow::events::BidCancelled)
+    /// * [`JobCancelled`](crate::bid_escrow::events::JobCancelled)
+    pub fn process_expired(&mut self, limit: u32) -> u32 {
+        self.access_control.ensure_not_paused();
+        let keeper = caller();
+        let mut settled = self.bid_engine.expire_job_offers(keeper, limit).len() as u32;
+        settled += self.bid_engine.expire_bids(keeper, limit - settled).len() as u32;
+        settled += self.job_engine.expire_jobs(keeper, limit - settled).len() as u32;
+        settled
+    }
+
     /// Cancels a voting that has not been finished in defined time
     ///
     /// # Errors
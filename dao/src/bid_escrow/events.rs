@@ -1,10 +1,11 @@
 //! BidEscrow-related events.
 
+use crate::bid_escrow::bid::Bid;
 use crate::bid_escrow::job::Job;
 use crate::bid_escrow::job_offer::JobOffer;
 use crate::bid_escrow::types::{BidId, JobId, JobOfferId};
 use crate::configuration::Configuration;
-use crate::utils::types::DocumentHash;
+use crate::utils::types::{DocumentHash, KycLevel};
 use crate::voting::types::VotingId;
 use odra::prelude::{
     string::{String, ToString},
@@ -20,10 +21,22 @@ pub struct JobOfferCreated {
     job_offer_id: JobOfferId,
     /// The address of an account that created the offer.
     job_poster: Address,
-    /// Max CSPR amount to be paid to the `Worker`.
+    /// Max amount to be paid to the `Worker`.
     max_budget: Balance,
     /// Offer validity time.
     expected_timeframe: BlockTime,
+    /// The token the Job is paid in, `None` if paid in CSPR.
+    payment_token: Option<Address>,
+    /// If Bids are sealed until the auction ends.
+    sealed: bool,
+    /// The category the offer is assigned to.
+    category: Option<String>,
+    /// The skills the offer is labeled with.
+    skills: Vec<String>,
+    /// The number of periods the Job is repeated.
+    periods: u32,
+    /// The KYC level required from the `Job Poster` and `Workers`.
+    required_kyc_level: KycLevel,
 }
 
 impl JobOfferCreated {
@@ -34,6 +47,134 @@ impl JobOfferCreated {
             job_poster: job_offer.job_poster,
             max_budget: job_offer.max_budget,
             expected_timeframe: job_offer.expected_timeframe,
+            payment_token: job_offer.payment_token_address(),
+            sealed: job_offer.sealed,
+            category: job_offer.category.clone(),
+            skills: job_offer.skills.clone(),
+            periods: job_offer.periods,
+            required_kyc_level: job_offer.required_kyc_level,
+        }
+    }
+}
+
+/// Event emitted when a [Job Offer](JobOffer) has been updated by the `Job Poster`.
+#[derive(Debug, PartialEq, Eq, Event)]
+pub struct JobOfferUpdated {
+    /// The offer id.
+    job_offer_id: JobOfferId,
+    /// The address of an account that updated the offer.
+    job_poster: Address,
+    /// Max amount to be paid to the `Worker`.
+    max_budget: Balance,
+    /// Offer validity time.
+    expected_timeframe: BlockTime,
+    /// The hash of the Job description.
+    description: Option<DocumentHash>,
+    /// Bids rejected as exceeding the new budget.
+    rejected_bids: Vec<BidId>,
+}
+
+impl JobOfferUpdated {
+    /// Creates a new event.
+    pub fn new(job_offer: &JobOffer, rejected_bids: Vec<BidId>) -> Self {
+        JobOfferUpdated {
+            job_offer_id: job_offer.job_offer_id,
+            job_poster: job_offer.job_poster,
+            max_budget: job_offer.max_budget,
+            expected_timeframe: job_offer.expected_timeframe,
+            description: job_offer.description.clone(),
+            rejected_bids,
+        }
+    }
+}
+
+/// Event emitted when a [Job Offer](JobOffer) expired with no Bid picked.
+#[derive(Debug, PartialEq, Eq, Event)]
+pub struct JobOfferExpired {
+    /// The offer id.
+    job_offer_id: JobOfferId,
+    /// The address of an account that settled the offer.
+    keeper: Address,
+    /// The part of the `DOS Fee` paid to the keeper.
+    keeper_reward: Balance,
+}
+
+impl JobOfferExpired {
+    /// Creates a new event.
+    pub fn new(job_offer_id: JobOfferId, keeper: Address, keeper_reward: Balance) -> Self {
+        JobOfferExpired {
+            job_offer_id,
+            keeper,
+            keeper_reward,
+        }
+    }
+}
+
+/// Event emitted when the `Worker` requested a [Job] deadline extension.
+#[derive(Debug, PartialEq, Eq, Event)]
+pub struct JobExtensionRequested {
+    /// The Job id.
+    job_id: JobId,
+    /// The address of the Worker.
+    worker: Address,
+    /// Requested additional time.
+    time: BlockTime,
+    /// The hash of a document explaining the reason of the request.
+    reason: DocumentHash,
+}
+
+impl JobExtensionRequested {
+    /// Creates a new event.
+    pub fn new(job: &Job, time: BlockTime, reason: DocumentHash) -> Self {
+        JobExtensionRequested {
+            job_id: job.job_id(),
+            worker: job.worker(),
+            time,
+            reason,
+        }
+    }
+}
+
+/// Event emitted when the `Job Poster` accepted a [Job] deadline extension.
+#[derive(Debug, PartialEq, Eq, Event)]
+pub struct JobExtensionAccepted {
+    /// The Job id.
+    job_id: JobId,
+    /// The address of the Job Poster.
+    job_poster: Address,
+    /// The new Job deadline.
+    finish_time: BlockTime,
+    /// The number of extensions granted so far.
+    extensions: u32,
+}
+
+impl JobExtensionAccepted {
+    /// Creates a new event.
+    pub fn new(job: &Job) -> Self {
+        JobExtensionAccepted {
+            job_id: job.job_id(),
+            job_poster: job.poster(),
+            finish_time: job.finish_time(),
+            extensions: job.extensions(),
+        }
+    }
+}
+
+/// Event emitted when the `Job Poster` rejected a [Job] deadline extension.
+#[derive(Debug, PartialEq, Eq, Event)]
+pub struct JobExtensionRejected {
+    /// The Job id.
+    job_id: JobId,
+    /// The address of the Job Poster.
+    job_poster: Address,
+}
+
+impl JobExtensionRejected {
+    /// Creates a new event.
+    pub fn new(job: &Job) -> Self {
+        JobExtensionRejected {
+            job_id: job.job_id(),
+            job_poster: job.poster(),
         }
     }
 }
@@ -77,6 +218,72 @@ impl BidSubmitted {
     }
 }
 
+/// Event emitted when a [Bid](crate::bid_escrow::bid::Bid) has been submitted by a team.
+#[derive(Debug, PartialEq, Eq, Event)]
+pub struct TeamBidSubmitted {
+    /// The bid id.
+    bid_id: BidId,
+    /// Team members' addresses.
+    members: Vec<Address>,
+    /// Team members' shares, in per mils.
+    shares: Vec<u32>,
+}
+
+impl TeamBidSubmitted {
+    /// Creates a new event.
+    pub fn new(bid: &Bid) -> Self {
+        TeamBidSubmitted {
+            bid_id: bid.bid_id,
+            members: bid.team.iter().map(|member| member.address).collect(),
+            shares: bid.team.iter().map(|member| member.share).collect(),
+        }
+    }
+}
+
+/// Event emitted when a team member confirmed the participation in a [Bid](crate::bid_escrow::bid::Bid).
+#[derive(Debug, PartialEq, Eq, Event)]
+pub struct TeamBidConfirmed {
+    /// The bid id.
+    bid_id: BidId,
+    /// The address of the member who confirmed the Bid.
+    member: Address,
+}
+
+impl TeamBidConfirmed {
+    /// Creates a new event.
+    pub fn new(bid_id: BidId, member: Address) -> Self {
+        TeamBidConfirmed { bid_id, member }
+    }
+}
+
+/// Event emitted when a sealed [Bid](crate::bid_escrow::bid::Bid) has been revealed.
+#[derive(Debug, PartialEq, Eq, Event)]
+pub struct BidRevealed {
+    /// The bid id.
+    bid_id: BidId,
+    /// The offer id.
+    job_offer_id: JobOfferId,
+    /// The address of the Worker.
+    worker: Address,
+    /// Proposed Job completion time.
+    proposed_timeframe: BlockTime,
+    /// Proposed payment for the Job.
+    proposed_payment: Balance,
+}
+
+impl BidRevealed {
+    /// Creates a new event.
+    pub fn new(bid: &Bid) -> Self {
+        BidRevealed {
+            bid_id: bid.bid_id,
+            job_offer_id: bid.job_offer_id,
+            worker: bid.worker,
+            proposed_timeframe: bid.proposed_timeframe,
+            proposed_payment: bid.proposed_payment,
+        }
+    }
+}
+
 /// Event emitted when that a [Bid](crate::bid_escrow::bid::Bid) has been cancelled.
 #[derive(Debug, PartialEq, Eq, Event)]
 pub struct BidCancelled {
@@ -170,6 +377,31 @@ impl JobCancelled {
     }
 }
 
+/// Event emitted when a recurring [Job](Job) has been terminated before the last period.
+#[derive(Debug, PartialEq, Eq, Event)]
+pub struct JobTerminated {
+    job_id: JobId,
+    caller: Address,
+    job_poster: Address,
+    worker: Address,
+    period: u32,
+    refunded_amount: Balance,
+}
+
+impl JobTerminated {
+    /// Creates a new event.
+    pub fn new(job: &Job, caller: Address) -> JobTerminated {
+        JobTerminated {
+            job_id: job.job_id(),
+            caller,
+            job_poster: job.poster(),
+            worker: job.worker(),
+            period: job.period(),
+            refunded_amount: job.remaining_payment(),
+        }
+    }
+}
+
 /// Event emitted when `Voting` on the [Job](Job) passed.
 #[derive(Debug, PartialEq, Eq, Event)]
 pub struct JobDone {
@@ -277,6 +509,12 @@ pub enum TransferReason {
     JobPaymentAndDOSFeeReturn,
     Redistribution,
     OnboardingStakeReturn,
+    BidStakeForfeit,
+    KeeperReward,
+    Claim,
+    InsurancePoolDeposit,
+    InsurancePayout,
+    OnboardingDepositForfeit,
 }
 
 impl ToString for TransferReason {
@@ -291,6 +529,12 @@ impl ToString for TransferReason {
             TransferReason::JobPaymentAndDOSFeeReturn => "JobPaymentAndDOSFeeReturn".to_string(),
             TransferReason::Redistribution => "Redistribution".to_string(),
             TransferReason::OnboardingStakeReturn => "OnboardingStakeReturn".to_string(),
+            TransferReason::BidStakeForfeit => "BidStakeForfeit".to_string(),
+            TransferReason::KeeperReward => "KeeperReward".to_string(),
+            TransferReason::Claim => "Claim".to_string(),
+            TransferReason::InsurancePoolDeposit => "InsurancePoolDeposit".to_string(),
+            TransferReason::InsurancePayout => "InsurancePayout".to_string(),
+            TransferReason::OnboardingDepositForfeit => "OnboardingDepositForfeit".to_string(),
         }
     }
 }
@@ -304,6 +548,55 @@ pub struct CSPRTransfer {
     pub reason: String,
 }
 
+/// Event emitted when a CEP-18 token transfer has been made.
+#[derive(Debug, PartialEq, Eq, Event)]
+pub struct TokenTransfer {
+    pub token: Address,
+    pub from: Address,
+    pub to: Address,
+    pub amount: Balance,
+    pub reason: String,
+}
+
+/// Event emitted when a CEP-18 token has been whitelisted as a Job payment method.
+#[derive(Debug, PartialEq, Eq, Event)]
+pub struct PaymentTokenAdded {
+    pub token: Address,
+    pub rate_provider: Address,
+}
+
+/// Event emitted when a CEP-18 token has been removed from the payment tokens whitelist.
+#[derive(Debug, PartialEq, Eq, Event)]
+pub struct PaymentTokenRemoved {
+    pub token: Address,
+}
+
+/// Event emitted when a VA has declared their skills.
+#[derive(Debug, PartialEq, Eq, Event)]
+pub struct SkillsDeclared {
+    pub va: Address,
+    pub skills: Vec<String>,
+}
+
+/// Event emitted when the activity of an address which KYC has been revoked has been cancelled.
+#[derive(Debug, PartialEq, Eq, Event)]
+pub struct KycRevocationHandled {
+    pub address: Address,
+    pub cancelled_job_offers: Vec<JobOfferId>,
+    pub rejected_bids: Vec<BidId>,
+}
+
+/// Event emitted when the activity of an address has been moved to another address by an account recovery.
+#[derive(Debug, PartialEq, Eq, Event)]
+pub struct BidEscrowAccountMigrated {
+    pub from: Address,
+    pub to: Address,
+    pub migrated_job_offers: Vec<JobOfferId>,
+    pub migrated_bids: Vec<BidId>,
+    pub migrated_jobs: Vec<JobId>,
+    pub migrated_votings: Vec<VotingId>,
+}
+
 /// Events out the result of the slash in the BidEscrow contract.
 #[derive(Debug, PartialEq, Eq, Event)]
 pub struct BidEscrowSlashResults {
@@ -3,7 +3,9 @@
 use crate::bid_escrow::bid::BidStatus;
 use crate::bid_escrow::types::{BidId, JobId, JobOfferId};
 use crate::rules::validation::bid_escrow::{
-    CanBidBePicked, CanPickBid, DoesProposedPaymentMatchTransferred,
+    CanBidBePicked, CanJobBeExtended, CanJobBeTerminated, CanPickBid, CanRequestJobExtension,
+    DoesProposedPaymentMatchTransferred, HasPermissionsToRequestJobExtension,
+    HasPermissionsToRespondToJobExtension, HasPermissionsToTerminateJob, IsJobExtensionRequested,
 };
 use crate::rules::RulesBuilder;
 use crate::utils::types::DocumentHash;
@@ -11,7 +13,7 @@ use crate::utils::Error;
 use crate::voting::types::VotingId;
 use odra::contract_env::revert;
 use odra::types::{Address, Balance, BlockTime};
-use odra::OdraType;
+use odra::{OdraType, UnwrapOrRevert};
 
 /// Serializable Job status.
 #[derive(OdraType, Copy, PartialEq, Eq, Debug, Default)]
@@ -23,6 +25,8 @@ pub enum JobStatus {
     /// Job proof submitted.
     Submitted,
     Completed,
+    /// Recurring Job terminated before the last period.
+    Terminated,
 }
 
 /// Data required to pick the Bid.
@@ -49,7 +53,11 @@ pub struct PickBidRequest {
     pub timeframe: BlockTime,
     /// Job reward.
     pub payment: Balance,
-    /// The amount transferred by `Job Poster`.
+    /// The number of periods the Job is repeated, 1 for a one-off Job.
+    pub periods: u32,
+    /// The token the Job is paid in, `None` if paid in CSPR.
+    pub payment_token: Option<Address>,
+    /// The amount transferred by `Job Poster` (in CSPR or in the payment token).
     pub transferred_cspr: Balance,
     /// The amount declared to be transferred by `Job Poster`.
     pub cspr_amount: Balance,
@@ -88,6 +96,29 @@ pub struct SubmitJobProofRequest {
     pub block_time: BlockTime,
 }
 
+/// Data required to request a Job deadline extension.
+pub struct RequestJobExtensionRequest {
+    /// The request creator.
+    pub caller: Address,
+    /// Requested additional time.
+    pub time: BlockTime,
+    /// The hash of a document explaining the reason of the request.
+    pub reason: DocumentHash,
+    /// Block time.
+    pub block_time: BlockTime,
+    /// Maximum number of extensions of a single Job.
+    pub max_extensions: Balance,
+}
+
+/// A deadline extension requested by the `Worker`, awaiting the `Job Poster` response.
+#[derive(OdraType, PartialEq, Eq, Debug)]
+pub struct JobExtension {
+    /// Requested additional time.
+    pub time: BlockTime,
+    /// The hash of a document explaining the reason of the request.
+    pub reason: DocumentHash,
+}
+
 /// Serializable representation of a `Job`.
 #[derive(OdraType, Debug)]
 pub struct Job {
@@ -96,6 +127,7 @@ pub struct Job {
     job_offer_id: JobOfferId,
     voting_id: Option<VotingId>,
     job_proof: Option<DocumentHash>,
+    proof_submission_time: Option<BlockTime>,
     start_time: BlockTime,
     time_for_job: BlockTime,
     status: JobStatus,
@@ -103,9 +135,15 @@ pub struct Job {
     worker_type: WorkerType,
     poster: Address,
     payment: Balance,
+    payment_token: Option<Address>,
     stake: Balance,
     external_worker_cspr_stake: Balance,
     followed_by: Option<JobId>,
+    extended_time: BlockTime,
+    extensions: u32,
+    pending_extension: Option<JobExtension>,
+    period: u32,
+    periods: u32,
 }
 
 impl Job {
@@ -121,7 +159,7 @@ impl Job {
             .add_validation(CanPickBid::create(request.caller, request.poster))
             .add_validation(CanBidBePicked::create(request.bid_status))
             .add_validation(DoesProposedPaymentMatchTransferred::create(
-                request.payment,
+                request.payment * Balance::from(request.periods),
                 request.transferred_cspr,
                 request.cspr_amount,
             ))
@@ -142,6 +180,7 @@ impl Job {
             job_offer_id: request.job_offer_id,
             voting_id: None,
             job_proof: None,
+            proof_submission_time: None,
             start_time: request.block_time,
             time_for_job: request.timeframe,
             status: JobStatus::Created,
@@ -149,9 +188,15 @@ impl Job {
             worker_type,
             poster: request.poster,
             payment: request.payment,
+            payment_token: request.payment_token,
             stake: request.stake,
             external_worker_cspr_stake: request.external_worker_cspr_stake,
             followed_by: None,
+            extended_time: 0,
+            extensions: 0,
+            pending_extension: None,
+            period: 1,
+            periods: request.periods,
         }
     }
 
@@ -173,6 +218,7 @@ impl Job {
             job_offer_id: self.job_offer_id,
             voting_id: None,
             job_proof: None,
+            proof_submission_time: Some(request.block_time),
             start_time: request.block_time,
             time_for_job: request.proposed_timeframe,
             status: JobStatus::Submitted,
@@ -180,9 +226,46 @@ impl Job {
             worker_type,
             poster: self.poster,
             payment: self.payment,
+            payment_token: self.payment_token,
             stake: request.reputation_stake,
             external_worker_cspr_stake: request.cspr_stake.unwrap_or_default(),
             followed_by: None,
+            extended_time: 0,
+            extensions: 0,
+            pending_extension: None,
+            period: self.period,
+            periods: self.periods,
+        }
+    }
+
+    /// Creates a job for the next period of a recurring Job, carried out by the same `Worker`
+    /// with the same stake.
+    pub fn next_period(&mut self, new_job_id: JobId, block_time: BlockTime) -> Job {
+        self.followed_by = Some(new_job_id);
+
+        Job {
+            job_id: new_job_id,
+            bid_id: self.bid_id,
+            job_offer_id: self.job_offer_id,
+            voting_id: None,
+            job_proof: None,
+            proof_submission_time: None,
+            start_time: block_time,
+            time_for_job: self.time_for_job,
+            status: JobStatus::Created,
+            worker: self.worker,
+            worker_type: self.worker_type,
+            poster: self.poster,
+            payment: self.payment,
+            payment_token: self.payment_token,
+            stake: self.stake,
+            external_worker_cspr_stake: self.external_worker_cspr_stake,
+            followed_by: None,
+            extended_time: 0,
+            extensions: 0,
+            pending_extension: None,
+            period: self.period + 1,
+            periods: self.periods,
         }
     }
 
@@ -203,6 +286,27 @@ impl Job {
         Ok(())
     }
 
+    /// Checks if the job is still not submitted after the [Grace Period](crate::bid_escrow#grace-period) ended.
+    pub fn is_expired(&self, block_time: BlockTime) -> bool {
+        self.status() == JobStatus::Created && self.finish_time() + self.grace_period() < block_time
+    }
+
+    /// Replaces the `from` address with the `to` address, if it is the `Worker` or the `Job Poster`.
+    ///
+    /// Returns true if the Job has been changed.
+    pub fn migrate_account(&mut self, from: Address, to: Address) -> bool {
+        let mut migrated = false;
+        if self.worker == from {
+            self.worker = to;
+            migrated = true;
+        }
+        if self.poster == from {
+            self.poster = to;
+            migrated = true;
+        }
+        migrated
+    }
+
     /// Changes status to the Cancelled
     pub fn cancel(&mut self) {
         self.status = JobStatus::Cancelled;
@@ -213,6 +317,35 @@ impl Job {
         self.status = JobStatus::Completed;
     }
 
+    /// Conditionally changes status to the [Terminated](JobStatus::Terminated).
+    ///
+    /// Runs validation:
+    /// * [`HasPermissionsToTerminateJob`]
+    /// * [`CanJobBeTerminated`]
+    ///
+    /// Stops contract execution if any validation fails.
+    pub fn terminate(&mut self, caller: Address) {
+        RulesBuilder::new()
+            .add_validation(HasPermissionsToTerminateJob::create(
+                caller,
+                self.poster(),
+                self.worker(),
+            ))
+            .add_validation(CanJobBeTerminated::create(
+                self.is_recurring(),
+                self.status(),
+            ))
+            .build()
+            .validate_generic_validations();
+
+        self.terminate_without_validation();
+    }
+
+    /// Unconditionally changes status to the [Terminated](JobStatus::Terminated).
+    pub fn terminate_without_validation(&mut self) {
+        self.status = JobStatus::Terminated;
+    }
+
     /// Sets a proof and updates the state to [`Submitted`](JobStatus::Submitted).
     ///
     /// # Errors
@@ -231,9 +364,95 @@ impl Job {
         }
 
         self.job_proof = Some(request.proof);
+        self.proof_submission_time = Some(request.block_time);
         self.status = JobStatus::Submitted;
     }
 
+    /// Conditionally records a deadline extension request.
+    ///
+    /// Runs validation:
+    /// * [`HasPermissionsToRequestJobExtension`]
+    /// * [`CanJobBeExtended`]
+    /// * [`CanRequestJobExtension`]
+    ///
+    /// Stops contract execution if any validation fails.
+    pub fn request_extension(&mut self, request: RequestJobExtensionRequest) {
+        RulesBuilder::new()
+            .add_validation(HasPermissionsToRequestJobExtension::create(
+                request.caller,
+                self.worker(),
+            ))
+            .add_validation(CanJobBeExtended::create(
+                self.status(),
+                self.finish_time(),
+                request.block_time,
+            ))
+            .add_validation(CanRequestJobExtension::create(
+                self.pending_extension.is_some(),
+                self.extensions,
+                request.max_extensions,
+            ))
+            .build()
+            .validate_generic_validations();
+
+        self.pending_extension = Some(JobExtension {
+            time: request.time,
+            reason: request.reason,
+        });
+    }
+
+    /// Conditionally extends the deadline by the requested time.
+    ///
+    /// Runs validation:
+    /// * [`HasPermissionsToRespondToJobExtension`]
+    /// * [`IsJobExtensionRequested`]
+    /// * [`CanJobBeExtended`]
+    ///
+    /// Stops contract execution if any validation fails.
+    pub fn accept_extension(&mut self, caller: Address, block_time: BlockTime) {
+        RulesBuilder::new()
+            .add_validation(HasPermissionsToRespondToJobExtension::create(
+                caller,
+                self.poster(),
+            ))
+            .add_validation(IsJobExtensionRequested::create(
+                self.pending_extension.is_some(),
+            ))
+            .add_validation(CanJobBeExtended::create(
+                self.status(),
+                self.finish_time(),
+                block_time,
+            ))
+            .build()
+            .validate_generic_validations();
+
+        let extension = self.take_pending_extension();
+        self.extended_time += extension.time;
+        self.extensions += 1;
+    }
+
+    /// Conditionally discards the pending extension request.
+    ///
+    /// Runs validation:
+    /// * [`HasPermissionsToRespondToJobExtension`]
+    /// * [`IsJobExtensionRequested`]
+    ///
+    /// Stops contract execution if any validation fails.
+    pub fn reject_extension(&mut self, caller: Address) {
+        RulesBuilder::new()
+            .add_validation(HasPermissionsToRespondToJobExtension::create(
+                caller,
+                self.poster(),
+            ))
+            .add_validation(IsJobExtensionRequested::create(
+                self.pending_extension.is_some(),
+            ))
+            .build()
+            .validate_generic_validations();
+
+        self.take_pending_extension();
+    }
+
     /// Gets the job's status.
     pub fn status(&self) -> JobStatus {
         self.status
@@ -244,6 +463,16 @@ impl Job {
         self.worker
     }
 
+    /// Gets the time the job was started.
+    pub fn start_time(&self) -> BlockTime {
+        self.start_time
+    }
+
+    /// Gets the time the job proof was submitted.
+    pub fn proof_submission_time(&self) -> Option<BlockTime> {
+        self.proof_submission_time
+    }
+
     /// Gets the job's poster.
     pub fn poster(&self) -> Address {
         self.poster
@@ -264,11 +493,46 @@ impl Job {
         self.job_offer_id
     }
 
-    /// Gets the job's payment amount.
+    /// Gets the job's payment amount, for a recurring Job - per period.
     pub fn payment(&self) -> Balance {
         self.payment
     }
 
+    /// Gets the payment for the current and all the following periods.
+    pub fn remaining_payment(&self) -> Balance {
+        self.payment * Balance::from(self.periods - self.period + 1)
+    }
+
+    /// Gets the current period of the job, starting from 1.
+    pub fn period(&self) -> u32 {
+        self.period
+    }
+
+    /// Gets the number of periods of the job, 1 for a one-off Job.
+    pub fn periods(&self) -> u32 {
+        self.periods
+    }
+
+    /// Checks if the job is repeated in more than one period.
+    pub fn is_recurring(&self) -> bool {
+        self.periods > 1
+    }
+
+    /// Checks if the current period is the last one.
+    pub fn is_last_period(&self) -> bool {
+        self.period >= self.periods
+    }
+
+    /// Gets the id of the job that follows this one - in the Grace Period or in the next period.
+    pub fn followed_by(&self) -> Option<JobId> {
+        self.followed_by
+    }
+
+    /// Gets the address of the token the job is paid in, `None` if paid in CSPR.
+    pub fn payment_token(&self) -> Option<Address> {
+        self.payment_token
+    }
+
     /// Gets the job's voting id.
     pub fn voting_id(&self) -> Option<VotingId> {
         self.voting_id
@@ -279,9 +543,19 @@ impl Job {
         self.job_proof.as_ref()
     }
 
-    /// Gets the job's finish time.
+    /// Gets the job's finish time, including granted extensions.
     pub fn finish_time(&self) -> BlockTime {
-        self.start_time + self.time_for_job
+        self.start_time + self.time_for_job + self.extended_time
+    }
+
+    /// Gets the number of granted deadline extensions.
+    pub fn extensions(&self) -> u32 {
+        self.extensions
+    }
+
+    /// Gets the deadline extension awaiting the `Job Poster` response.
+    pub fn pending_extension(&self) -> Option<&JobExtension> {
+        self.pending_extension.as_ref()
     }
 
     /// Gets the job's worker type.
@@ -318,6 +592,12 @@ impl Job {
     fn grace_period(&self) -> BlockTime {
         self.time_for_job
     }
+
+    fn take_pending_extension(&mut self) -> JobExtension {
+        self.pending_extension
+            .take()
+            .unwrap_or_revert_with(Error::JobExtensionNotRequested)
+    }
 }
 
 /// Serializable [Worker](crate::bid_escrow#definitions) type.
@@ -3,19 +3,25 @@
 use crate::bid_escrow::bid::{Bid, ReclaimBidRequest};
 #[allow(unused_imports)]
 use crate::bid_escrow::events::{
-    BidEscrowVotingCreated, CSPRTransfer, JobCancelled, JobDone, JobRejected, JobSubmitted,
-    TransferReason,
+    BidEscrowVotingCreated, CSPRTransfer, JobCancelled, JobCreated, JobDone, JobExtensionAccepted,
+    JobExtensionRejected, JobExtensionRequested, JobRejected, JobSubmitted, JobTerminated,
+    TokenTransfer, TransferReason,
+};
+use crate::bid_escrow::job::{
+    Job, ReclaimJobRequest, RequestJobExtensionRequest, SubmitJobProofRequest, WorkerType,
 };
-use crate::bid_escrow::job::{Job, ReclaimJobRequest, SubmitJobProofRequest, WorkerType};
 use crate::bid_escrow::storage::{BidStorage, JobStorage};
-use crate::bid_escrow::types::JobId;
+use crate::bid_escrow::types::{BidId, JobId};
+use crate::bid_escrow::worker_stats::WorkerStats;
 use crate::configuration::Configuration;
 use crate::modules::refs::ContractRefs;
+use crate::modules::{AccessControl, Claims};
 use crate::utils::types::DocumentHash;
-use crate::utils::{withdraw, Error};
+use crate::utils::Error;
 use crate::voting::ballot::Choice;
 use crate::voting::cspr_redistribution::{
-    redistribute_cspr_to_all_vas, redistribute_to_governance,
+    redistribute_cspr_to_all_vas, redistribute_payment_to_all_vas,
+    redistribute_payment_to_governance, redistribute_to_governance, redistribute_to_insurance_pool,
 };
 use crate::voting::types::VotingId;
 use crate::voting::voting_engine::voting_state_machine::{VotingResult, VotingSummary, VotingType};
@@ -23,17 +29,19 @@ use crate::voting::voting_engine::VotingEngine;
 use odra::contract_env::{attached_value, caller, get_block_time, revert};
 use odra::prelude::{collections::BTreeMap, vec, vec::Vec};
 use odra::types::Address;
-use odra::types::{event::OdraEvent, Balance};
+use odra::types::{event::OdraEvent, Balance, BlockTime};
 use odra::UnwrapOrRevert;
 
 /// Manages Jobs lifecycle.
-#[odra::module(events = [JobSubmitted, JobRejected, JobCancelled, JobDone, BidEscrowVotingCreated, CSPRTransfer])]
+#[odra::module(events = [JobCreated, JobSubmitted, JobRejected, JobCancelled, JobTerminated, JobDone, JobExtensionRequested, JobExtensionAccepted, JobExtensionRejected, BidEscrowVotingCreated, CSPRTransfer, TokenTransfer])]
 pub struct JobEngine {
     job_storage: JobStorage,
     bid_storage: BidStorage,
     refs: ContractRefs,
     #[odra(using = "refs")]
     voting_engine: VotingEngine,
+    claims: Claims,
+    access_control: AccessControl,
 }
 
 #[odra::module]
@@ -44,6 +52,8 @@ impl JobEngine {
             pub fn jobs_count(&self) -> u32;
             /// Gets the [job](Job) with a given id or `None`.
             pub fn get_job(&self, job_id: JobId) -> Option<Job>;
+            /// Gets the track record of a given `Worker`.
+            pub fn get_worker_stats(&self, worker: Address) -> WorkerStats;
         }
     }
 
@@ -53,6 +63,7 @@ impl JobEngine {
     /// # Errors
     /// If a proof has been submitted before, reverts with [`Error::JobAlreadySubmitted`].
     pub fn submit_job_proof(&mut self, job_id: JobId, proof: DocumentHash) {
+        self.access_control.ensure_not_paused();
         let mut job = self.job_storage.get_job_or_revert(job_id);
         let job_offer = self
             .bid_storage
@@ -118,6 +129,7 @@ impl JobEngine {
         reputation_stake: Balance,
         onboard: bool,
     ) {
+        self.access_control.ensure_not_paused();
         let cspr_stake = {
             let attached_value = attached_value();
             if attached_value.is_zero() {
@@ -139,9 +151,8 @@ impl JobEngine {
         let configuration = self.bid_storage.get_job_offer_configuration(&old_job);
 
         // redistribute original cspr stake
-        if let Some(cspr_stake) = old_bid.cspr_stake {
-            let left = redistribute_to_governance(cspr_stake, &configuration);
-            redistribute_cspr_to_all_vas(left, &self.refs);
+        if let Some(cspr_stake) = old_bid.cspr_stake_of(&old_bid.worker) {
+            self.redistribute_forfeited_cspr_stake(cspr_stake, &configuration);
         }
 
         // burn original reputation stake
@@ -152,6 +163,9 @@ impl JobEngine {
             self.slash_worker(&old_job);
         }
 
+        // the original team loses its stakes
+        self.slash_team_members(&old_bid, &configuration);
+
         let reclaim_bid_request = ReclaimBidRequest {
             new_bid_id: self.bid_storage.next_bid_id(),
             caller,
@@ -159,12 +173,17 @@ impl JobEngine {
             reputation_stake,
             new_worker,
             new_worker_va: !self.refs.va_token().balance_of(&new_worker).is_zero(),
-            new_worker_kyced: !self.refs.kyc_token().balance_of(&new_worker).is_zero(),
+            new_worker_kyc_level: self.refs.kyc_token().kyc_level(new_worker),
+            required_kyc_level: self
+                .bid_storage
+                .get_job_offer_or_revert(&old_job.job_offer_id())
+                .required_kyc_level,
             job_poster: old_job.poster(),
             onboard,
             block_time,
             job_status: old_job.status(),
             job_finish_time: old_job.finish_time(),
+            recurring_job: old_job.is_recurring(),
         };
 
         let new_bid = old_bid.reclaim(&reclaim_bid_request);
@@ -214,24 +233,127 @@ impl JobEngine {
     /// If the state in which the process cannot be canceled, the execution reverts with
     /// [Error::CannotCancelJob] or [Error::JobCannotBeYetCanceled].
     pub fn cancel_job(&mut self, job_id: JobId) {
+        self.access_control.ensure_not_paused();
         let job = self.job_storage.get_job_or_revert(job_id);
         let caller = caller();
         if let Err(e) = job.validate_cancel(get_block_time(), caller) {
             revert(e);
         }
 
-        let bid = self
-            .bid_storage
-            .get_bid(&job.bid_id())
-            .unwrap_or_revert_with(Error::BidNotFound);
+        self.cancel_and_slash(job, caller);
+    }
 
-        self.burn_reputation_stake(&bid);
+    /// Settles at most `limit` [Job]s that have not been submitted until the end of the Grace Period,
+    /// the same way as [`cancel_job()`](Self::cancel_job()) does. The `keeper` is rewarded from the `DOS Fee`.
+    ///
+    /// Returns the ids of the expired jobs.
+    pub fn expire_jobs(&mut self, keeper: Address, limit: u32) -> Vec<JobId> {
+        let block_time = get_block_time();
+        let mut expired = vec![];
+        for job_id in self.job_storage.get_active_jobs() {
+            if expired.len() as u32 >= limit {
+                break;
+            }
+            let job = self.job_storage.get_job_or_revert(job_id);
+            if !job.is_expired(block_time) {
+                continue;
+            }
 
-        if !self.refs.va_token().balance_of(&job.worker()).is_zero() {
-            self.slash_worker(&job);
+            let mut job_offer = self
+                .bid_storage
+                .get_job_offer_or_revert(&job.job_offer_id());
+            let keeper_reward = job_offer.take_keeper_reward();
+            self.claims
+                .credit(&keeper, keeper_reward, TransferReason::KeeperReward);
+            self.bid_storage
+                .update_job_offer(&job.job_offer_id(), job_offer);
+
+            self.cancel_and_slash(job, keeper);
+            expired.push(job_id);
         }
+        expired
+    }
 
-        self.raw_cancel_job(job, &bid, caller);
+    /// The `Job Poster` or the `Worker` terminates a recurring Job before the proof for the current period
+    /// is submitted. The payment for the current and the following periods and the `DOS Fee` are returned
+    /// to the `Job Poster`, the `Worker` stake is returned.
+    ///
+    /// # Errors
+    /// * [`Error::CannotTerminateJob`]
+    /// * [`Error::JobCannotBeTerminated`]
+    pub fn terminate_job(&mut self, job_id: JobId) {
+        self.access_control.ensure_not_paused();
+        let mut job = self.job_storage.get_job_or_revert(job_id);
+        let caller = caller();
+        job.terminate(caller);
+
+        self.return_job_poster_payment_and_dos_fee(&job);
+        self.return_external_worker_cspr_stake(&job);
+        if !job.get_stake().is_zero() {
+            self.refs
+                .reputation_token()
+                .unstake(job.worker(), job.get_stake());
+        }
+        self.return_team_members_stakes(&job);
+
+        JobTerminated::new(&job, caller).emit();
+
+        self.job_storage.remove_from_active_jobs(job_id);
+        self.job_storage.store_job(job);
+    }
+
+    /// The `Worker` requests the deadline of the Job to be extended by the given `time`.
+    /// The request awaits the `Job Poster` response.
+    ///
+    /// # Errors
+    /// * [`Error::OnlyWorkerCanRequestJobExtension`]
+    /// * [`Error::JobExtensionNotAllowed`] - if the proof is already submitted or the deadline has passed.
+    /// * [`Error::JobExtensionAlreadyRequested`]
+    /// * [`Error::JobExtensionsLimitReached`]
+    pub fn request_job_extension(&mut self, job_id: JobId, time: BlockTime, reason: DocumentHash) {
+        self.access_control.ensure_not_paused();
+        let mut job = self.job_storage.get_job_or_revert(job_id);
+        let configuration = self.bid_storage.get_job_offer_configuration(&job);
+
+        job.request_extension(RequestJobExtensionRequest {
+            caller: caller(),
+            time,
+            reason: reason.clone(),
+            block_time: get_block_time(),
+            max_extensions: configuration.max_deadline_extensions(),
+        });
+
+        JobExtensionRequested::new(&job, time, reason).emit();
+        self.job_storage.store_job(job);
+    }
+
+    /// The `Job Poster` accepts the pending deadline extension request.
+    ///
+    /// # Errors
+    /// * [`Error::OnlyJobPosterCanRespondToJobExtension`]
+    /// * [`Error::JobExtensionNotRequested`]
+    /// * [`Error::JobExtensionNotAllowed`] - if the proof is already submitted or the deadline has passed.
+    pub fn accept_job_extension(&mut self, job_id: JobId) {
+        self.access_control.ensure_not_paused();
+        let mut job = self.job_storage.get_job_or_revert(job_id);
+        job.accept_extension(caller(), get_block_time());
+
+        JobExtensionAccepted::new(&job).emit();
+        self.job_storage.store_job(job);
+    }
+
+    /// The `Job Poster` rejects the pending deadline extension request.
+    ///
+    /// # Errors
+    /// * [`Error::OnlyJobPosterCanRespondToJobExtension`]
+    /// * [`Error::JobExtensionNotRequested`]
+    pub fn reject_job_extension(&mut self, job_id: JobId) {
+        self.access_control.ensure_not_paused();
+        let mut job = self.job_storage.get_job_or_revert(job_id);
+        job.reject_extension(caller());
+
+        JobExtensionRejected::new(&job).emit();
+        self.job_storage.store_job(job);
     }
 
     /// Records vote in [Voting](crate::voting::voting_engine::voting_state_machine::VotingStateMachine).
@@ -245,10 +367,12 @@ impl JobEngine {
         choice: Choice,
         stake: Balance,
     ) {
+        self.access_control.ensure_not_paused();
         let caller = caller();
         let job = self.job_storage.get_job_by_voting_id(voting_id);
+        let bid = self.bid_storage.get_bid_or_revert(&job.bid_id());
 
-        if caller == job.poster() || caller == job.worker() {
+        if caller == job.poster() || caller == job.worker() || bid.is_team_member(&caller) {
             revert(Error::CannotVoteOnOwnJob);
         }
         self.voting_engine
@@ -260,7 +384,8 @@ impl JobEngine {
     /// Interacts with [`Reputation Token Contract`](crate::core_contracts::ReputationContract) to
     /// redistribute reputation.
     pub fn finish_voting(&mut self, voting_id: VotingId, voting_type: VotingType) -> VotingSummary {
-        let job = self.job_storage.get_job_by_voting_id(voting_id);
+        self.access_control.ensure_not_paused();
+        let mut job = self.job_storage.get_job_by_voting_id(voting_id);
         let job_offer = self
             .bid_storage
             .get_job_offer_or_revert(&job.job_offer_id());
@@ -275,12 +400,13 @@ impl JobEngine {
                             .unwrap_or_revert_with(Error::BidNotFound);
                         self.refs
                             .reputation_token()
-                            .unstake(bid.worker, bid.reputation_stake);
+                            .unstake(bid.worker, bid.reputation_stake_of(&bid.worker));
                     }
                 }
                 VotingResult::QuorumNotReached => {
                     self.return_job_poster_payment_and_dos_fee(&job);
                     self.return_external_worker_cspr_stake(&job);
+                    self.return_team_members_stakes(&job);
                 }
                 VotingResult::Canceled => revert(Error::VotingAlreadyCanceled),
             },
@@ -294,11 +420,12 @@ impl JobEngine {
                                     &job,
                                     job_offer.configuration(),
                                 );
-                                self.return_job_poster_dos_fee(&job);
                             }
                             WorkerType::ExternalToVA => {
                                 // Make user VA.
-                                self.refs.va_token().mint(job.worker());
+                                self.refs
+                                    .va_token()
+                                    .mint_with_voting(job.worker(), voting_id);
 
                                 self.return_external_worker_cspr_stake(&job);
                                 self.burn_external_worker_reputation(&job);
@@ -307,7 +434,6 @@ impl JobEngine {
                                     &job,
                                     job_offer.configuration(),
                                 );
-                                self.return_job_poster_dos_fee(&job);
                             }
                             WorkerType::External => {
                                 self.mint_and_redistribute_reputation_for_external_worker(&job);
@@ -315,11 +441,24 @@ impl JobEngine {
                                     &job,
                                     job_offer.configuration(),
                                 );
-                                self.return_job_poster_dos_fee(&job);
-                                self.return_external_worker_cspr_stake(&job);
                             }
                         };
+                        let expected_timeframe = job_offer.expected_timeframe;
+                        self.update_worker_stats(&job, |stats, paid| {
+                            stats.record_completed(&job, expected_timeframe, paid)
+                        });
+                        job.complete();
                         JobDone::new(&job, caller()).emit();
+
+                        if job.is_last_period() {
+                            self.return_job_poster_dos_fee(&job);
+                            if job.worker_type() == &WorkerType::External {
+                                self.return_external_worker_cspr_stake(&job);
+                            }
+                            self.return_team_members_stakes(&job);
+                        } else {
+                            self.start_next_period(&mut job);
+                        }
                     }
                     VotingResult::Against => {
                         match job.worker_type() {
@@ -335,11 +474,15 @@ impl JobEngine {
                                 );
                             }
                         }
+                        let bid = self.bid_storage.get_bid_or_revert(&job.bid_id());
+                        self.slash_team_members(&bid, job_offer.configuration());
+                        self.update_worker_stats(&job, |stats, _| stats.record_rejected());
                         JobRejected::new(&job, caller()).emit();
                     }
                     VotingResult::QuorumNotReached => {
                         self.return_job_poster_payment_and_dos_fee(&job);
                         self.return_external_worker_cspr_stake(&job);
+                        self.return_team_members_stakes(&job);
                     }
                     VotingResult::Canceled => revert(Error::VotingAlreadyCanceled),
                 }
@@ -369,6 +512,39 @@ impl JobEngine {
             slashed_votings.affected_votings,
         )
     }
+
+    /// Moves the active Jobs, the Bids the Jobs were created from, the ballots and the stats
+    /// of the `from` address to the `to` address, so the payouts, the unstakes and the slashes
+    /// of the Jobs in progress target the `to` address.
+    /// Returns the lists of migrated jobs, migrated bids and affected votings.
+    pub fn migrate_account(
+        &mut self,
+        from: Address,
+        to: Address,
+    ) -> (Vec<JobId>, Vec<BidId>, Vec<VotingId>) {
+        let migrated_votings = self.voting_engine.migrate_voter(from, to);
+        let mut migrated_jobs = vec![];
+        let mut migrated_bids = vec![];
+        for job_id in self.job_storage.get_active_jobs() {
+            let mut job = self.job_storage.get_job_or_revert(job_id);
+            let mut bid = self.bid_storage.get_bid_or_revert(&job.bid_id());
+            if bid.migrate_account(from, to) {
+                self.bid_storage.store_bid(bid);
+                migrated_bids.push(job.bid_id());
+            }
+            if job.migrate_account(from, to) {
+                self.job_storage.store_job(job);
+                migrated_jobs.push(job_id);
+            }
+        }
+
+        let stats = self.job_storage.get_worker_stats(from);
+        self.job_storage.store_worker_stats(to, stats);
+        self.job_storage
+            .store_worker_stats(from, WorkerStats::default());
+
+        (migrated_jobs, migrated_bids, migrated_votings)
+    }
 }
 
 impl JobEngine {
@@ -386,13 +562,14 @@ impl JobEngine {
     }
 
     fn burn_reputation_stake(&self, bid: &Bid) {
-        if bid.reputation_stake > Balance::zero() {
+        let reputation_stake = bid.reputation_stake_of(&bid.worker);
+        if reputation_stake > Balance::zero() {
             self.refs
                 .reputation_token()
-                .unstake(bid.worker, bid.reputation_stake);
+                .unstake(bid.worker, reputation_stake);
             self.refs
                 .reputation_token()
-                .burn(bid.worker, bid.reputation_stake);
+                .burn(bid.worker, reputation_stake);
         }
     }
 
@@ -419,19 +596,82 @@ impl JobEngine {
             .burn(job.worker(), amount_to_burn);
     }
 
+    /// Takes the stakes of the team members other than the `Worker`, who is slashed separately.
+    /// The reputation stakes are burnt, the CSPR stakes are redistributed.
+    fn slash_team_members(&mut self, bid: &Bid, configuration: &Configuration) {
+        for (member, stake) in bid.team_members_stakes() {
+            match bid.cspr_stake {
+                None => {
+                    let mut reputation_token = self.refs.reputation_token();
+                    reputation_token.unstake(member, stake);
+                    reputation_token.burn(member, stake);
+                }
+                Some(_) => self.redistribute_forfeited_cspr_stake(stake, configuration),
+            }
+        }
+    }
+
+    /// Returns the stakes of the team members other than the `Worker`.
+    fn return_team_members_stakes(&mut self, job: &Job) {
+        let bid = self.bid_storage.get_bid_or_revert(&job.bid_id());
+        let mut unstakes: Vec<(Address, Balance)> = Vec::new();
+        for (member, stake) in bid.team_members_stakes() {
+            match bid.cspr_stake {
+                None => unstakes.push((member, stake)),
+                Some(_) => {
+                    self.claims
+                        .credit(&member, stake, TransferReason::BidStakeReturn);
+                }
+            }
+        }
+        if !unstakes.is_empty() {
+            self.refs.reputation_token().bulk_unstake(unstakes);
+        }
+    }
+
+    /// Splits a forfeited CSPR stake between the Insurance Pool, the Bid Escrow Wallet and all the VAs.
+    fn redistribute_forfeited_cspr_stake(&mut self, stake: Balance, configuration: &Configuration) {
+        let stake =
+            redistribute_to_insurance_pool(stake, configuration, &self.refs, &mut self.claims);
+        let left = redistribute_to_governance(stake, configuration, &mut self.claims);
+        redistribute_cspr_to_all_vas(left, &self.refs, &mut self.claims);
+    }
+
+    fn is_va(&self, address: &Address) -> bool {
+        !self.refs.va_token().balance_of(address).is_zero()
+    }
+
     pub fn return_job_poster_payment_and_dos_fee(&mut self, job: &Job) {
         let job_offer = self
             .bid_storage
             .get_job_offer_or_revert(&job.job_offer_id());
-        withdraw(
-            &job.poster(),
-            job.payment() + job_offer.dos_fee,
-            TransferReason::JobPaymentAndDOSFeeReturn,
+        let dos_fee = redistribute_to_insurance_pool(
+            job_offer.dos_fee,
+            &job_offer.configuration,
+            &self.refs,
+            &mut self.claims,
         );
+        match job.payment_token() {
+            None => self.claims.credit(
+                &job.poster(),
+                job.remaining_payment() + dos_fee,
+                TransferReason::JobPaymentAndDOSFeeReturn,
+            ),
+            Some(_) => {
+                self.claims.credit_payment(
+                    &job.poster(),
+                    job.remaining_payment(),
+                    job.payment_token(),
+                    TransferReason::JobPaymentReturn,
+                );
+                self.claims
+                    .credit(&job.poster(), dos_fee, TransferReason::DOSFeeReturn);
+            }
+        }
     }
 
     pub fn return_external_worker_cspr_stake(&mut self, job: &Job) {
-        withdraw(
+        self.claims.credit(
             &job.worker(),
             job.external_worker_cspr_stake(),
             TransferReason::BidStakeReturn,
@@ -439,38 +679,63 @@ impl JobEngine {
     }
 
     fn mint_and_redistribute_reputation_for_internal_worker(&mut self, job: &Job) {
-        let configuration = self.bid_storage.get_job_offer_configuration(job);
+        let job_offer = self
+            .bid_storage
+            .get_job_offer_or_revert(&job.job_offer_id());
+        let configuration = job_offer.configuration();
 
-        let reputation_to_mint = configuration.apply_reputation_conversion_rate_to(job.payment());
+        let reputation_to_mint = configuration
+            .apply_reputation_conversion_rate_to(job_offer.cspr_value_of(job.payment()));
         let reputation_to_redistribute =
             configuration.apply_default_policing_rate_to(reputation_to_mint);
 
         // Worker
-        self.refs.reputation_token().mint(
-            job.worker(),
-            reputation_to_mint - reputation_to_redistribute,
-        );
+        self.mint_reputation_for_worker(job, reputation_to_mint - reputation_to_redistribute, true);
 
         // Voters
         self.mint_reputation_for_voters(job, reputation_to_redistribute);
     }
 
     fn mint_and_redistribute_reputation_for_external_worker(&mut self, job: &Job) {
-        let configuration = self.bid_storage.get_job_offer_configuration(job);
-        let reputation_to_mint = configuration.apply_reputation_conversion_rate_to(job.payment());
+        let job_offer = self
+            .bid_storage
+            .get_job_offer_or_revert(&job.job_offer_id());
+        let configuration = job_offer.configuration();
+        let reputation_to_mint = configuration
+            .apply_reputation_conversion_rate_to(job_offer.cspr_value_of(job.payment()));
         let reputation_to_redistribute =
             configuration.apply_default_policing_rate_to(reputation_to_mint);
 
         // Worker
-        self.refs.reputation_token().mint_passive(
-            job.worker(),
+        self.mint_reputation_for_worker(
+            job,
             reputation_to_mint - reputation_to_redistribute,
+            false,
         );
 
         // Voters
         self.mint_reputation_for_voters(job, reputation_to_redistribute);
     }
 
+    /// Mints reputation for the `Worker`, or splits it between the team members if the Bid was
+    /// submitted by a team. The `Worker` gets active reputation if `worker_is_va`,
+    /// other team members - depending on whether they are VAs.
+    fn mint_reputation_for_worker(&mut self, job: &Job, amount: Balance, worker_is_va: bool) {
+        let bid = self.bid_storage.get_bid_or_revert(&job.bid_id());
+        for (address, part) in bid.split_between_team(amount) {
+            let is_va = if address == job.worker() {
+                worker_is_va
+            } else {
+                self.is_va(&address)
+            };
+            if is_va {
+                self.refs.reputation_token().mint(address, part);
+            } else {
+                self.refs.reputation_token().mint_passive(address, part);
+            }
+        }
+    }
+
     fn mint_reputation_for_voters(&mut self, job: &Job, amount: Balance) {
         let voting = self
             .voting_engine
@@ -512,31 +777,59 @@ impl JobEngine {
     }
 
     fn redistribute_cspr_internal_worker(&mut self, job: &Job, configuration: &Configuration) {
-        let to_redistribute = redistribute_to_governance(job.payment(), configuration);
+        let to_redistribute = redistribute_payment_to_governance(
+            job.payment(),
+            job.payment_token(),
+            configuration,
+            &mut self.claims,
+        );
         let redistribute_to_all_vas = configuration.distribute_payment_to_non_voters();
 
         // For VA's
         if redistribute_to_all_vas {
-            redistribute_cspr_to_all_vas(to_redistribute, &self.refs);
+            redistribute_payment_to_all_vas(
+                to_redistribute,
+                job.payment_token(),
+                &self.refs,
+                &mut self.claims,
+            );
         } else {
             self.redistribute_cspr_to_voters(job, to_redistribute);
         }
     }
 
     fn redistribute_cspr_external_worker(&mut self, job: &Job, configuration: &Configuration) {
-        let total_left = redistribute_to_governance(job.payment(), configuration);
+        let total_left = redistribute_payment_to_governance(
+            job.payment(),
+            job.payment_token(),
+            configuration,
+            &mut self.claims,
+        );
         let config = self.bid_storage.get_job_offer_configuration(job);
         let to_redistribute = config.apply_default_policing_rate_to(total_left);
         let to_worker = total_left - to_redistribute;
 
         // For External Worker
-        withdraw(&job.worker(), to_worker, TransferReason::Redistribution);
+        let bid = self.bid_storage.get_bid_or_revert(&job.bid_id());
+        for (address, part) in bid.split_between_team(to_worker) {
+            self.claims.credit_payment(
+                &address,
+                part,
+                job.payment_token(),
+                TransferReason::Redistribution,
+            );
+        }
 
         let redistribute_to_all_vas = configuration.distribute_payment_to_non_voters();
 
         // For VA's
         if redistribute_to_all_vas {
-            redistribute_cspr_to_all_vas(to_redistribute, &self.refs);
+            redistribute_payment_to_all_vas(
+                to_redistribute,
+                job.payment_token(),
+                &self.refs,
+                &mut self.claims,
+            );
         } else {
             self.redistribute_cspr_to_voters(job, to_redistribute);
         }
@@ -547,8 +840,13 @@ impl JobEngine {
         job: &Job,
         configuration: &Configuration,
     ) {
-        let total_left =
-            redistribute_to_governance(job.external_worker_cspr_stake(), configuration);
+        let stake = redistribute_to_insurance_pool(
+            job.external_worker_cspr_stake(),
+            configuration,
+            &self.refs,
+            &mut self.claims,
+        );
+        let total_left = redistribute_to_governance(stake, configuration, &mut self.claims);
 
         // For VA's
         let all_balances = self.refs.reputation_token().all_balances();
@@ -556,7 +854,8 @@ impl JobEngine {
 
         for (address, balance) in all_balances.balances() {
             let amount = total_left * *balance / total_supply;
-            withdraw(address, amount, TransferReason::Redistribution);
+            self.claims
+                .credit(address, amount, TransferReason::Redistribution);
         }
     }
 
@@ -564,11 +863,14 @@ impl JobEngine {
         let job_offer = self
             .bid_storage
             .get_job_offer_or_revert(&job.job_offer_id());
-        withdraw(
-            &job.poster(),
+        let dos_fee = redistribute_to_insurance_pool(
             job_offer.dos_fee,
-            TransferReason::DOSFeeReturn,
+            &job_offer.configuration,
+            &self.refs,
+            &mut self.claims,
         );
+        self.claims
+            .credit(&job.poster(), dos_fee, TransferReason::DOSFeeReturn);
     }
 
     fn redistribute_cspr_to_voters(&mut self, job: &Job, to_redistribute: Balance) {
@@ -581,8 +883,79 @@ impl JobEngine {
         let partial_supply = balances.total_supply();
         for (address, balance) in balances.balances() {
             let amount = to_redistribute * *balance / partial_supply;
-            withdraw(address, amount, TransferReason::Redistribution)
+            self.claims.credit_payment(
+                address,
+                amount,
+                job.payment_token(),
+                TransferReason::Redistribution,
+            )
+        }
+    }
+
+    /// Creates the job for the next period of a recurring Job. The `Worker` stakes the same amount
+    /// of reputation as in the finished period, a CSPR stake is kept until the last period.
+    ///
+    /// If the `Worker` can no longer afford the stake, the next period is terminated right away,
+    /// so the finished period is still settled. The remaining periods, the `DOS Fee`
+    /// and the CSPR stake are returned.
+    fn start_next_period(&mut self, job: &mut Job) {
+        let mut next_job = job.next_period(self.job_storage.next_job_id(), get_block_time());
+        self.job_storage.remove_from_active_jobs(job.job_id());
+
+        let stake = next_job.get_stake();
+        if !stake.is_zero() && self.available_reputation(next_job.worker()) < stake {
+            next_job.terminate_without_validation();
+            self.return_job_poster_payment_and_dos_fee(&next_job);
+            self.return_external_worker_cspr_stake(&next_job);
+            self.return_team_members_stakes(&next_job);
+            JobTerminated::new(&next_job, caller()).emit();
+            self.job_storage.store_job(next_job);
+            return;
         }
+
+        if !stake.is_zero() {
+            self.refs.reputation_token().stake(next_job.worker(), stake);
+        }
+
+        JobCreated::new(&next_job).emit();
+
+        self.job_storage.add_to_active_jobs(next_job.job_id());
+        self.job_storage.store_job(next_job);
+    }
+
+    /// Returns the reputation of the `address` that is not staked.
+    fn available_reputation(&self, address: Address) -> Balance {
+        let reputation_token = self.refs.reputation_token();
+        reputation_token
+            .balance_of(address)
+            .saturating_sub(reputation_token.get_stake(address))
+    }
+
+    /// Updates the track record of the `Worker`, or of every team member if the Bid was submitted by a team.
+    /// `update` gets the member's part of the Job payment, split the same way as the payout.
+    fn update_worker_stats<F: Fn(&mut WorkerStats, Balance)>(&mut self, job: &Job, update: F) {
+        let bid = self.bid_storage.get_bid_or_revert(&job.bid_id());
+        for (address, paid) in bid.split_between_team(job.payment()) {
+            let mut stats = self.job_storage.get_worker_stats(address);
+            update(&mut stats, paid);
+            self.job_storage.store_worker_stats(address, stats);
+        }
+    }
+
+    /// Burns the `Worker` stake, slashes the `Worker` if is a VA and cancels the Job.
+    fn cancel_and_slash(&mut self, job: Job, caller: Address) {
+        let bid = self
+            .bid_storage
+            .get_bid(&job.bid_id())
+            .unwrap_or_revert_with(Error::BidNotFound);
+
+        self.burn_reputation_stake(&bid);
+
+        if self.is_va(&job.worker()) {
+            self.slash_worker(&job);
+        }
+
+        self.raw_cancel_job(job, &bid, caller);
     }
 
     fn raw_cancel_job(&mut self, mut job: Job, bid: &Bid, caller: Address) {
@@ -591,12 +964,15 @@ impl JobEngine {
         self.return_job_poster_payment_and_dos_fee(&job);
 
         // redistribute cspr stake
-        if let Some(cspr_stake) = bid.cspr_stake {
-            let left = redistribute_to_governance(cspr_stake, &configuration);
-            redistribute_cspr_to_all_vas(left, &self.refs);
+        if let Some(cspr_stake) = bid.cspr_stake_of(&bid.worker) {
+            self.redistribute_forfeited_cspr_stake(cspr_stake, &configuration);
         }
 
+        // the team loses its stakes
+        self.slash_team_members(bid, &configuration);
+
         job.cancel();
+        self.update_worker_stats(&job, |stats, _| stats.record_cancelled());
         JobCancelled::new(&job, caller).emit();
 
         self.job_storage.remove_from_active_jobs(job.job_id());
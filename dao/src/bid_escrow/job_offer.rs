@@ -6,16 +6,20 @@ use crate::bid_escrow::job::PickBidRequest;
 use crate::bid_escrow::types::JobOfferId;
 use crate::configuration::Configuration;
 use crate::rules::validation::bid_escrow::{
-    CanJobOfferBeCancelled, CanProgressJobOffer, HasPermissionsToCancelJobOffer, IsDosFeeEnough,
+    AreJobPeriodsValid, AreTagsAllowed, CanJobOfferBeCancelled, CanJobOfferBeModified,
+    CanProgressJobOffer, HasPermissionsToCancelJobOffer, IsDosFeeEnough,
 };
 use crate::rules::validation::IsUserKyced;
 use crate::rules::RulesBuilder;
+use crate::utils::types::{DocumentHash, KycLevel};
+use crate::utils::Error;
 use alloc::rc::Rc;
+use odra::prelude::{string::String, vec::Vec};
 use odra::types::{Address, Balance, BlockTime};
-use odra::OdraType;
+use odra::{OdraType, UnwrapOrRevert};
 
 /// Serializable JobOffer status representation.
-#[derive(OdraType, PartialEq)]
+#[derive(OdraType, Copy, PartialEq, Debug)]
 pub enum JobOfferStatus {
     /// Created, Bidders can place bids.
     Created,
@@ -23,6 +27,8 @@ pub enum JobOfferStatus {
     InProgress,
     /// Offer canceled, is no longer valid.
     Cancelled,
+    /// No Bid picked in time, is no longer valid.
+    Expired,
 }
 
 /// Auction state representation.
@@ -36,14 +42,66 @@ pub enum AuctionState {
     Public,
 }
 
+/// A whitelisted CEP-18 token a Job Offer is paid in.
+#[derive(OdraType, Copy, PartialEq, Eq, Debug)]
+pub struct PaymentToken {
+    /// The token contract address.
+    pub address: Address,
+    /// Token:Fiat rate at the time the offer was posted.
+    pub fiat_rate: Balance,
+}
+
+/// Parameters of a new job offer passed by the Job Poster.
+///
+/// The optional parameters default to a one-off Job paid in CSPR, open to public Bids,
+/// not assigned to any category and requiring the basic KYC level.
+#[derive(OdraType, PartialEq, Debug)]
+pub struct JobOfferParams {
+    /// The time the Job should be completed, per period for a recurring Job.
+    pub expected_timeframe: BlockTime,
+    /// Max amount the Job Poster can pay for the Job, per period for a recurring Job.
+    pub budget: Balance,
+    /// CSPR amount attached to Post Job query.
+    pub dos_fee: Balance,
+    /// The whitelisted CEP-18 token the Job is paid in, `None` if paid in CSPR.
+    pub payment_token: Option<Address>,
+    /// If Bids are sealed until the auction ends.
+    pub sealed: bool,
+    /// The category of the Job.
+    pub category: Option<String>,
+    /// Skills required to do the Job.
+    pub skills: Vec<String>,
+    /// The number of periods the Job is repeated, `None` for a one-off Job.
+    pub periods: Option<u32>,
+    /// The KYC level required from the Job Poster and Workers, `None` for the basic level.
+    pub required_kyc_level: Option<KycLevel>,
+}
+
+impl JobOfferParams {
+    /// Creates the parameters of a one-off Job paid in CSPR.
+    pub fn new(expected_timeframe: BlockTime, budget: Balance, dos_fee: Balance) -> Self {
+        Self {
+            expected_timeframe,
+            budget,
+            dos_fee,
+            payment_token: None,
+            sealed: false,
+            category: None,
+            skills: Vec::new(),
+            periods: None,
+            required_kyc_level: None,
+        }
+    }
+}
+
 /// Data required to post a job offer.
 pub struct PostJobOfferRequest {
     /// New offer id.
     pub job_offer_id: JobOfferId,
     /// The offer creator.
     pub job_poster: Address,
-    /// Is the creator passed the KYC process.
-    pub job_poster_kyced: bool,
+    /// The KYC level of the creator.
+    pub job_poster_kyc_level: KycLevel,
     /// Max amount the Job Poster can pay for the Job.
     pub max_budget: Balance,
     /// The time the Job should be completed.
@@ -52,10 +110,34 @@ pub struct PostJobOfferRequest {
     pub dos_fee: Balance,
     /// The time since the offer is available for Bidders.
     pub start_time: BlockTime,
+    /// The token the Job is paid in, `None` if paid in CSPR.
+    pub payment_token: Option<PaymentToken>,
+    /// If Bids are sealed until the auction ends.
+    pub sealed: bool,
+    /// The category of the Job.
+    pub category: Option<String>,
+    /// Skills required to do the Job.
+    pub skills: Vec<String>,
+    /// The number of periods the Job is repeated, 1 for a one-off Job.
+    pub periods: u32,
+    /// The KYC level required from the Job Poster and Workers.
+    pub required_kyc_level: KycLevel,
     /// Job configuration.
     pub configuration: Rc<Configuration>,
 }
 
+/// Data required to update a job offer.
+pub struct UpdateJobOfferRequest {
+    /// The request caller.
+    pub caller: Address,
+    /// New max amount the Job Poster can pay for the Job.
+    pub max_budget: Balance,
+    /// New time the Job should be completed.
+    pub expected_timeframe: BlockTime,
+    /// New hash of the Job description.
+    pub description: DocumentHash,
+}
+
 /// Data required to cancel a job offer.
 pub struct CancelJobOfferRequest {
     /// The request caller.
@@ -81,6 +163,21 @@ pub struct JobOffer {
     pub status: JobOfferStatus,
     /// The time since the offer is available for Bidders.
     pub start_time: BlockTime,
+    /// The token the Job is paid in, `None` if paid in CSPR.
+    pub payment_token: Option<PaymentToken>,
+    /// The hash of the Job description, if attached.
+    pub description: Option<DocumentHash>,
+    /// If Bids are sealed until the auction ends.
+    pub sealed: bool,
+    /// The category of the Job, one of `JobCategories`.
+    pub category: Option<String>,
+    /// Skills required to do the Job, a subset of `SkillTags`.
+    pub skills: Vec<String>,
+    /// The number of periods the Job is repeated, 1 for a one-off Job.
+    /// The budget and the timeframe are defined per period.
+    pub periods: u32,
+    /// The KYC level required from the Job Poster and Workers.
+    pub required_kyc_level: KycLevel,
     /// Job configuration.
     pub configuration: Configuration,
 }
@@ -91,14 +188,25 @@ impl JobOffer {
     /// Runs validation:
     /// * [`IsUserKyced`]
     /// * [`IsDosFeeEnough`]
+    /// * [`AreTagsAllowed`]
+    /// * [`AreJobPeriodsValid`]
     /// Stops contract execution if any validation fails.
     pub fn new(request: PostJobOfferRequest) -> JobOffer {
         RulesBuilder::new()
-            .add_validation(IsUserKyced::create(request.job_poster_kyced))
+            .add_validation(IsUserKyced::create(
+                request.job_poster_kyc_level,
+                request.required_kyc_level,
+            ))
             .add_validation(IsDosFeeEnough::create(
                 request.configuration.clone(),
                 request.dos_fee,
             ))
+            .add_validation(AreTagsAllowed::create(
+                request.configuration.clone(),
+                request.category.clone(),
+                request.skills.clone(),
+            ))
+            .add_validation(AreJobPeriodsValid::create(request.periods))
             .build()
             .validate_generic_validations();
 
@@ -110,6 +218,13 @@ impl JobOffer {
             dos_fee: request.dos_fee,
             status: JobOfferStatus::Created,
             start_time: request.start_time,
+            payment_token: request.payment_token,
+            description: None,
+            sealed: request.sealed,
+            category: request.category,
+            skills: request.skills,
+            periods: request.periods,
+            required_kyc_level: request.required_kyc_level,
             configuration: (*request.configuration).clone(),
         }
     }
@@ -129,6 +244,25 @@ impl JobOffer {
         self.status = JobOfferStatus::InProgress;
     }
 
+    /// Conditionally updates the budget, the timeframe and the description of the offer.
+    ///
+    /// Runs validation:
+    /// * [`CanProgressJobOffer`]
+    /// * [`CanJobOfferBeModified`]
+    ///
+    /// Stops contract execution if any validation fails.
+    pub fn update(&mut self, request: UpdateJobOfferRequest) {
+        RulesBuilder::new()
+            .add_validation(CanProgressJobOffer::create(request.caller, self.job_poster))
+            .add_validation(CanJobOfferBeModified::create(self.status))
+            .build()
+            .validate_generic_validations();
+
+        self.max_budget = request.max_budget;
+        self.expected_timeframe = request.expected_timeframe;
+        self.description = Some(request.description);
+    }
+
     /// Conditionally changes the status to [Cancelled](JobOfferStatus::Cancelled).
     ///
     /// Runs validation:
@@ -151,6 +285,30 @@ impl JobOffer {
         self.status = JobOfferStatus::Cancelled;
     }
 
+    /// Checks if no Bid has been picked until `VABidAcceptanceTimeout` passed after the auction end.
+    pub fn is_expired(&self, block_time: BlockTime) -> bool {
+        let expiration_time = self.start_time
+            + self.configuration.internal_auction_time()
+            + self.configuration.public_auction_time()
+            + self.configuration.va_bid_acceptance_timeout();
+        self.status == JobOfferStatus::Created && block_time >= expiration_time
+    }
+
+    /// Unconditionally changes the status to [Expired](JobOfferStatus::Expired).
+    pub fn expire(&mut self) {
+        self.status = JobOfferStatus::Expired;
+    }
+
+    /// Deducts the reward for settling an expired offer or Job from the `DOS Fee`.
+    /// Returns the reward defined by the `KeeperRewardRatio` variable.
+    pub fn take_keeper_reward(&mut self) -> Balance {
+        let reward = self
+            .configuration
+            .apply_keeper_reward_ratio_to(self.dos_fee);
+        self.dos_fee -= reward;
+        reward
+    }
+
     /// Gets the auction state in a given time.
     pub fn auction_state(&self, block_time: BlockTime) -> AuctionState {
         let public_auction_start_time =
@@ -166,11 +324,51 @@ impl JobOffer {
         }
     }
 
+    /// Checks if any of given skills is required by the offer.
+    /// An offer that requires no skills matches any skills.
+    pub fn matches_skills(&self, skills: &[String]) -> bool {
+        self.skills.is_empty() || self.skills.iter().any(|skill| skills.contains(skill))
+    }
+
+    /// Checks if the Job is repeated in more than one period.
+    pub fn is_recurring(&self) -> bool {
+        self.periods > 1
+    }
+
     /// Gets a reference to the job configuration.
     pub fn configuration(&self) -> &Configuration {
         &self.configuration
     }
 
+    /// Gets the address of the token the Job is paid in, `None` if paid in CSPR.
+    pub fn payment_token_address(&self) -> Option<Address> {
+        self.payment_token.map(|token| token.address)
+    }
+
+    /// Expresses a payment in CSPR.
+    ///
+    /// A token payment is converted to its fiat value and then to CSPR using the rates
+    /// captured when the offer was posted.
+    ///
+    /// # Errors
+    /// * [`Error::FiatRateNotSet`]
+    /// * [`Error::ArithmeticOverflow`]
+    pub fn cspr_value_of(&self, payment: Balance) -> Balance {
+        match self.payment_token {
+            None => payment,
+            Some(token) => {
+                let cspr_fiat_rate = self
+                    .configuration
+                    .fiat_rate()
+                    .unwrap_or_revert_with(Error::FiatRateNotSet);
+                payment
+                    .checked_mul(cspr_fiat_rate)
+                    .and_then(|value| value.checked_div(token.fiat_rate))
+                    .unwrap_or_revert_with(Error::ArithmeticOverflow)
+            }
+        }
+    }
+
     pub fn slash(&mut self) {
         self.status = JobOfferStatus::Cancelled;
     }
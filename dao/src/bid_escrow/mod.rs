@@ -8,3 +8,4 @@ pub mod job_engine;
 pub mod job_offer;
 pub mod storage;
 pub mod types;
+pub mod worker_stats;
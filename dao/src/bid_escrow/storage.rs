@@ -4,10 +4,11 @@ use crate::bid_escrow::bid::Bid;
 use crate::bid_escrow::job::Job;
 use crate::bid_escrow::job_offer::JobOffer;
 use crate::bid_escrow::types::{BidId, JobId, JobOfferId};
+use crate::bid_escrow::worker_stats::WorkerStats;
 use crate::configuration::Configuration;
 use crate::utils::Error;
 use crate::voting::types::VotingId;
-use odra::prelude::vec::Vec;
+use odra::prelude::{string::String, vec::Vec};
 use odra::types::Address;
 use odra::{List, Mapping, Sequence, UnwrapOrRevert, Variable};
 
@@ -21,6 +22,9 @@ pub struct BidStorage {
     bids_count: Sequence<BidId>,
     active_job_offers_ids: Variable<Vec<JobOfferId>>,
     worker_bids: Mapping<(Address, JobOfferId), Option<BidId>>,
+    payment_tokens: Mapping<Address, Option<Address>>,
+    category_job_offers_ids: Mapping<String, Vec<JobOfferId>>,
+    skills: Mapping<Address, Vec<String>>,
 }
 
 impl BidStorage {
@@ -124,6 +128,24 @@ impl BidStorage {
         self.active_job_offers_ids.get_or_default()
     }
 
+    pub fn add_to_category_offers(&mut self, category: &String, job_offer_id: JobOfferId) {
+        let mut offers = self.category_job_offers_ids.get_or_default(category);
+        offers.push(job_offer_id);
+        self.category_job_offers_ids.set(category, offers);
+    }
+
+    pub fn get_category_offers(&self, category: &String) -> Vec<JobOfferId> {
+        self.category_job_offers_ids.get_or_default(category)
+    }
+
+    pub fn set_skills(&mut self, address: Address, skills: Vec<String>) {
+        self.skills.set(&address, skills);
+    }
+
+    pub fn get_skills(&self, address: &Address) -> Vec<String> {
+        self.skills.get_or_default(address)
+    }
+
     pub fn add_to_active_bids(&mut self, worker: Address, job_offer_id: JobOfferId, bid_id: BidId) {
         self.worker_bids.set(&(worker, job_offer_id), Some(bid_id));
     }
@@ -135,6 +157,20 @@ impl BidStorage {
     pub fn get_active_bid_id(&self, worker: Address, job_offer_id: JobOfferId) -> Option<BidId> {
         self.worker_bids.get_or_default(&(worker, job_offer_id))
     }
+
+    /// Sets the rate provider of a payment token. `None` removes the token from the whitelist.
+    pub fn set_payment_token_rate_provider(
+        &mut self,
+        token: Address,
+        rate_provider: Option<Address>,
+    ) {
+        self.payment_tokens.set(&token, rate_provider);
+    }
+
+    /// Gets the rate provider of a whitelisted payment token or `None` if the token is not whitelisted.
+    pub fn get_payment_token_rate_provider(&self, token: &Address) -> Option<Address> {
+        self.payment_tokens.get_or_default(token)
+    }
 }
 
 /// Stores [Job]-related variables and mappings.
@@ -144,6 +180,7 @@ pub struct JobStorage {
     jobs_for_voting: Mapping<VotingId, JobId>,
     jobs_count: Sequence<JobId>,
     active_jobs: Variable<Vec<JobId>>,
+    worker_stats: Mapping<Address, WorkerStats>,
 }
 
 impl JobStorage {
@@ -207,4 +244,14 @@ impl JobStorage {
     pub fn get_active_jobs(&self) -> Vec<JobId> {
         self.active_jobs.get_or_default()
     }
+
+    /// Gets the [WorkerStats] of a given `Worker`.
+    pub fn get_worker_stats(&self, worker: Address) -> WorkerStats {
+        self.worker_stats.get_or_default(&worker)
+    }
+
+    /// Writes the [WorkerStats] of a given `Worker` to the storage.
+    pub fn store_worker_stats(&mut self, worker: Address, stats: WorkerStats) {
+        self.worker_stats.set(&worker, stats);
+    }
 }
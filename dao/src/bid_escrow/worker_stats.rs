@@ -0,0 +1,50 @@
+//! Worker track record.
+
+use crate::bid_escrow::job::Job;
+use odra::types::{Balance, BlockTime};
+use odra::OdraType;
+
+/// Statistics of all the [Job]s of a `Worker` - helps `Job Posters` to rank the Bids.
+#[derive(OdraType, Default, Debug, PartialEq, Eq)]
+pub struct WorkerStats {
+    /// The number of Jobs accepted in the formal voting.
+    pub jobs_completed: u32,
+    /// The number of Jobs rejected in the formal voting.
+    pub jobs_rejected: u32,
+    /// The number of Jobs cancelled by the `Job Poster`.
+    pub jobs_cancelled: u32,
+    /// The sum of delays of the completed Jobs - the time that exceeded the expected timeframe.
+    pub total_delay: BlockTime,
+    /// The average delay of the completed Jobs.
+    pub average_delay: BlockTime,
+    /// The total payment for the completed Jobs. Jobs paid in CEP-18 tokens are not included.
+    pub total_paid: Balance,
+}
+
+impl WorkerStats {
+    /// Records a completed [Job] and the part of its payment the `Worker` got.
+    /// The delay is measured from the Job start to the proof submission.
+    pub fn record_completed(&mut self, job: &Job, expected_timeframe: BlockTime, paid: Balance) {
+        let time_spent = job
+            .proof_submission_time()
+            .unwrap_or_default()
+            .saturating_sub(job.start_time());
+
+        self.jobs_completed += 1;
+        self.total_delay += time_spent.saturating_sub(expected_timeframe);
+        self.average_delay = self.total_delay / self.jobs_completed as BlockTime;
+        if job.payment_token().is_none() {
+            self.total_paid += paid;
+        }
+    }
+
+    /// Records a rejected [Job].
+    pub fn record_rejected(&mut self) {
+        self.jobs_rejected += 1;
+    }
+
+    /// Records a cancelled [Job].
+    pub fn record_cancelled(&mut self) {
+        self.jobs_cancelled += 1;
+    }
+}
@@ -76,6 +76,43 @@ impl ConfigurationBuilder {
                         CANCEL_FINISHED_VOTING_TIMEOUT,
                         variables,
                     ),
+                    max_deadline_extensions: get_variable(MAX_DEADLINE_EXTENSIONS, variables),
+                    unrevealed_bid_forfeit_ratio: get_variable(
+                        UNREVEALED_BID_FORFEIT_RATIO,
+                        variables,
+                    ),
+                    keeper_reward_ratio: get_variable(KEEPER_REWARD_RATIO, variables),
+                    bid_escrow_fee_split: get_variable(BID_ESCROW_FEE_SPLIT, variables),
+                    insurance_pool_ratio: get_variable(INSURANCE_POOL_RATIO, variables),
+                    job_categories: get_variable(JOB_CATEGORIES, variables),
+                    skill_tags: get_variable(SKILL_TAGS, variables),
+                    internal_auction_skill_match_required: get_variable(
+                        INTERNAL_AUCTION_SKILL_MATCH_REQUIRED,
+                        variables,
+                    ),
+                    offboarding_reputation_to_passive: get_variable(
+                        OFFBOARDING_REPUTATION_TO_PASSIVE,
+                        variables,
+                    ),
+                    sponsored_informal_voting_time: get_variable(
+                        SPONSORED_INFORMAL_VOTING_TIME,
+                        variables,
+                    ),
+                    sponsorship_probation_period: get_variable(
+                        SPONSORSHIP_PROBATION_PERIOD,
+                        variables,
+                    ),
+                    va_probation_period: get_variable(VA_PROBATION_PERIOD, variables),
+                    va_probation_stake_limit: get_variable(VA_PROBATION_STAKE_LIMIT, variables),
+                    kyc_validity_period: get_variable(KYC_VALIDITY_PERIOD, variables),
+                    kyc_revocation_forfeits_deposit: get_variable(
+                        KYC_REVOCATION_FORFEITS_DEPOSIT,
+                        variables,
+                    ),
+                    account_recovery_challenge_period: get_variable(
+                        ACCOUNT_RECOVERY_CHALLENGE_PERIOD,
+                        variables,
+                    ),
                 },
                 VotingConfiguration {
                     is_bid_escrow: false,
@@ -84,6 +121,7 @@ impl ConfigurationBuilder {
                     contract_calls: Vec::new(),
                     only_va_can_create: true,
                     double_time_between_votings: false,
+                    is_sponsored_onboarding: false,
                 },
                 total_onboarded,
             ),
@@ -121,6 +159,12 @@ impl ConfigurationBuilder {
         self
     }
 
+    /// Sets the `is_sponsored_onboarding` field.
+    pub fn sponsored_onboarding(mut self) -> ConfigurationBuilder {
+        self.configuration.set_is_sponsored_onboarding(true);
+        self
+    }
+
     /// Sets the `unbound_ballot_address` field.
     pub fn bind_ballot_for_successful_voting(mut self, address: Address) -> ConfigurationBuilder {
         self.configuration
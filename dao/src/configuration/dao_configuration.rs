@@ -1,3 +1,5 @@
+use crate::configuration::FeeSplit;
+use odra::prelude::{string::String, vec::Vec};
 use odra::types::{Address, Balance, BlockTime};
 use odra::OdraType;
 
@@ -31,4 +33,20 @@ pub struct DaoConfiguration {
     pub bid_escrow_payment_ratio: Balance,
     pub voting_ids_address: Address,
     pub cancel_finished_voting_timeout: BlockTime,
+    pub max_deadline_extensions: Balance,
+    pub unrevealed_bid_forfeit_ratio: Balance,
+    pub keeper_reward_ratio: Balance,
+    pub bid_escrow_fee_split: FeeSplit,
+    pub insurance_pool_ratio: Balance,
+    pub job_categories: Vec<String>,
+    pub skill_tags: Vec<String>,
+    pub internal_auction_skill_match_required: bool,
+    pub offboarding_reputation_to_passive: bool,
+    pub sponsored_informal_voting_time: BlockTime,
+    pub sponsorship_probation_period: BlockTime,
+    pub va_probation_period: BlockTime,
+    pub va_probation_stake_limit: Balance,
+    pub kyc_validity_period: BlockTime,
+    pub kyc_revocation_forfeits_deposit: bool,
+    pub account_recovery_challenge_period: BlockTime,
 }
@@ -0,0 +1,61 @@
+use crate::utils::{per_mil_of, Error};
+use odra::prelude::vec::Vec;
+use odra::types::{Address, Balance};
+use odra::{OdraType, UnwrapOrRevert};
+
+/// The maximum sum of the ratios of a [FeeSplit] - 1000 per mil.
+const MAX_TOTAL_RATIO: u32 = 1000;
+
+/// A recipient of a part of the Job payment taken by the DAO.
+#[derive(OdraType, Debug, PartialEq, Eq)]
+pub struct FeeRecipient {
+    /// The address credited with the fee.
+    pub address: Address,
+    /// The part of the payment in per mils.
+    pub ratio: Balance,
+}
+
+/// The value of `BidEscrowFeeSplit` variable - a list of fee recipients with their ratios.
+///
+/// If empty, the whole fee goes to `BidEscrowWalletAddress`, as defined by `BidEscrowPaymentRatio`.
+#[derive(OdraType, Default, Debug, PartialEq, Eq)]
+pub struct FeeSplit {
+    pub recipients: Vec<FeeRecipient>,
+}
+
+impl FeeSplit {
+    /// Creates a split with a single recipient.
+    pub fn single(address: Address, ratio: Balance) -> Self {
+        FeeSplit {
+            recipients: Vec::from([FeeRecipient { address, ratio }]),
+        }
+    }
+
+    /// Checks if the split has no recipients.
+    pub fn is_empty(&self) -> bool {
+        self.recipients.is_empty()
+    }
+
+    /// Checks if the ratios sum up to at most 1000 per mils.
+    pub fn is_valid(&self) -> bool {
+        self.recipients
+            .iter()
+            .try_fold(Balance::zero(), |total, recipient| {
+                total.checked_add(recipient.ratio)
+            })
+            .map(|total| total <= Balance::from(MAX_TOTAL_RATIO))
+            .unwrap_or_default()
+    }
+
+    /// Splits a given amount between the recipients.
+    pub fn apply_to(&self, amount: Balance) -> Vec<(Address, Balance)> {
+        self.recipients
+            .iter()
+            .map(|recipient| {
+                let share = per_mil_of(amount, recipient.ratio)
+                    .unwrap_or_revert_with(Error::ArithmeticOverflow);
+                (recipient.address, share)
+            })
+            .collect()
+    }
+}
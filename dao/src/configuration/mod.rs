@@ -8,10 +8,12 @@
 //! [`Governance Variables`]: crate::core_contracts::VariableRepositoryContract
 mod builder;
 mod dao_configuration;
+mod fee_split;
 mod voting_configuration;
 
 pub use builder::ConfigurationBuilder;
 pub use dao_configuration::DaoConfiguration;
+pub use fee_split::{FeeRecipient, FeeSplit};
 pub use voting_configuration::VotingConfiguration;
 
 use crate::utils::{per_mil_of, per_mil_of_as_u32, to_per_mils, ContractCall, Error};
@@ -47,6 +49,11 @@ impl Configuration {
         self.voting_configuration.set_is_bid_escrow(is_bid_escrow);
     }
 
+    pub fn set_is_sponsored_onboarding(&mut self, is_sponsored_onboarding: bool) {
+        self.voting_configuration
+            .set_is_sponsored_onboarding(is_sponsored_onboarding);
+    }
+
     pub fn set_only_va_can_create(&mut self, only_va_can_create: bool) {
         self.voting_configuration
             .set_only_va_can_create(only_va_can_create);
@@ -130,6 +137,10 @@ impl Configuration {
     /// See [Variable Repository](crate::core_contracts::VariableRepositoryContract) BidEscrowInformalQuorumRatio/InformalQuorumRatio
     /// ([available keys](crate::core_contracts::VariableRepositoryContract#available-keys)).
     pub fn informal_voting_quorum(&self) -> u32 {
+        // The sponsor's vouch stands in for the informal quorum.
+        if self.voting_configuration.is_sponsored_onboarding {
+            return 0;
+        }
         let ratio = match self.voting_configuration.is_bid_escrow {
             true => self.dao_configuration.bid_escrow_informal_quorum_ratio,
             false => self.dao_configuration.informal_quorum_ratio,
@@ -141,9 +152,12 @@ impl Configuration {
 
     /// Gets informal voting time.
     ///
-    /// See [Variable Repository](crate::core_contracts::VariableRepositoryContract) BidEscrowInformalVotingTime/InformalVotingTime
+    /// See [Variable Repository](crate::core_contracts::VariableRepositoryContract) BidEscrowInformalVotingTime/InformalVotingTime/SponsoredInformalVotingTime
     /// ([available keys](crate::core_contracts::VariableRepositoryContract#available-keys)).
     pub fn informal_voting_time(&self) -> BlockTime {
+        if self.voting_configuration.is_sponsored_onboarding {
+            return self.dao_configuration.sponsored_informal_voting_time;
+        }
         match self.voting_configuration.is_bid_escrow {
             true => self.dao_configuration.bid_escrow_informal_voting_time,
             false => self.dao_configuration.informal_voting_time,
@@ -192,6 +206,30 @@ impl Configuration {
         self.dao_configuration.bid_escrow_wallet_address
     }
 
+    /// Gets the recipients of the part of the Job payment taken by the DAO.
+    ///
+    /// If `BidEscrowFeeSplit` is empty, the only recipient is `BidEscrowWalletAddress`
+    /// with `BidEscrowPaymentRatio`.
+    ///
+    /// See [Variable Repository](crate::core_contracts::VariableRepositoryContract) BidEscrowFeeSplit
+    /// ([available keys](crate::core_contracts::VariableRepositoryContract#available-keys)).
+    ///
+    /// # Errors
+    /// * [`Error::InvalidFeeSplit`] - if the ratios sum up to more than 1000 per mils.
+    pub fn bid_escrow_fee_split(&self) -> FeeSplit {
+        let fee_split = &self.dao_configuration.bid_escrow_fee_split;
+        if fee_split.is_empty() {
+            return FeeSplit::single(
+                self.bid_escrow_wallet_address(),
+                self.dao_configuration.bid_escrow_payment_ratio,
+            );
+        }
+        if !fee_split.is_valid() {
+            revert(Error::InvalidFeeSplit);
+        }
+        fee_split.clone()
+    }
+
     /// Gets the default reputation slash ratio.
     ///
     /// See [Variable Repository](crate::core_contracts::VariableRepositoryContract) DefaultReputationSlash
@@ -200,6 +238,14 @@ impl Configuration {
         self.dao_configuration.default_reputation_slash
     }
 
+    /// Gets the maximum number of Job deadline extensions.
+    ///
+    /// See [Variable Repository](crate::core_contracts::VariableRepositoryContract) MaxDeadlineExtensions
+    /// ([available keys](crate::core_contracts::VariableRepositoryContract#available-keys)).
+    pub fn max_deadline_extensions(&self) -> Balance {
+        self.dao_configuration.max_deadline_extensions
+    }
+
     /// Gets the voting clearness delta.
     ///
     /// See [Variable Repository](crate::core_contracts::VariableRepositoryContract) VotingClearnessDelta
@@ -257,6 +303,92 @@ impl Configuration {
         self.dao_configuration.va_can_bid_on_public_auction
     }
 
+    /// Checks if a given category belongs to the `JobCategories` taxonomy.
+    ///
+    /// See [Variable Repository](crate::core_contracts::VariableRepositoryContract) JobCategories
+    /// ([available keys](crate::core_contracts::VariableRepositoryContract#available-keys)).
+    pub fn is_job_category_allowed(&self, category: &str) -> bool {
+        self.dao_configuration
+            .job_categories
+            .iter()
+            .any(|allowed| allowed == category)
+    }
+
+    /// Checks if a given skill belongs to the `SkillTags` taxonomy.
+    ///
+    /// See [Variable Repository](crate::core_contracts::VariableRepositoryContract) SkillTags
+    /// ([available keys](crate::core_contracts::VariableRepositoryContract#available-keys)).
+    pub fn is_skill_tag_allowed(&self, skill: &str) -> bool {
+        self.dao_configuration
+            .skill_tags
+            .iter()
+            .any(|allowed| allowed == skill)
+    }
+
+    /// Indicates if only VAs with a skill matching the Job Offer can bid in the Internal Auction.
+    ///
+    /// See [Variable Repository](crate::core_contracts::VariableRepositoryContract) InternalAuctionSkillMatchRequired
+    /// ([available keys](crate::core_contracts::VariableRepositoryContract#available-keys)).
+    pub fn internal_auction_skill_match_required(&self) -> bool {
+        self.dao_configuration.internal_auction_skill_match_required
+    }
+
+    /// Indicates if the reputation of an offboarded VA is converted to passive reputation instead of being burned.
+    ///
+    /// See [Variable Repository](crate::core_contracts::VariableRepositoryContract) OffboardingReputationToPassive
+    /// ([available keys](crate::core_contracts::VariableRepositoryContract#available-keys)).
+    pub fn offboarding_reputation_to_passive(&self) -> bool {
+        self.dao_configuration.offboarding_reputation_to_passive
+    }
+
+    /// Gets the time after a sponsored onboarding during which the sponsor is liable for the new VA.
+    ///
+    /// See [Variable Repository](crate::core_contracts::VariableRepositoryContract) SponsorshipProbationPeriod
+    /// ([available keys](crate::core_contracts::VariableRepositoryContract#available-keys)).
+    pub fn sponsorship_probation_period(&self) -> BlockTime {
+        self.dao_configuration.sponsorship_probation_period
+    }
+
+    /// Checks if a VA onboarded at the given time is still on probation.
+    ///
+    /// See [Variable Repository](crate::core_contracts::VariableRepositoryContract) VaProbationPeriod
+    /// ([available keys](crate::core_contracts::VariableRepositoryContract#available-keys)).
+    pub fn is_on_probation(&self, onboarded_at: BlockTime, block_time: BlockTime) -> bool {
+        block_time < onboarded_at + self.dao_configuration.va_probation_period
+    }
+
+    /// Gets the maximum stake of a VA on probation.
+    ///
+    /// See [Variable Repository](crate::core_contracts::VariableRepositoryContract) VaProbationStakeLimit
+    /// ([available keys](crate::core_contracts::VariableRepositoryContract#available-keys)).
+    pub fn va_probation_stake_limit(&self) -> Balance {
+        self.dao_configuration.va_probation_stake_limit
+    }
+
+    /// Gets the time a KYC token is valid for after it has been issued or renewed.
+    ///
+    /// See [Variable Repository](crate::core_contracts::VariableRepositoryContract) KycValidityPeriod
+    /// ([available keys](crate::core_contracts::VariableRepositoryContract#available-keys)).
+    pub fn kyc_validity_period(&self) -> BlockTime {
+        self.dao_configuration.kyc_validity_period
+    }
+
+    /// Indicates if the CSPR deposit of a pending Onboarding Request is forfeited when the KYC of the requestor is revoked.
+    ///
+    /// See [Variable Repository](crate::core_contracts::VariableRepositoryContract) KycRevocationForfeitsDeposit
+    /// ([available keys](crate::core_contracts::VariableRepositoryContract#available-keys)).
+    pub fn kyc_revocation_forfeits_deposit(&self) -> bool {
+        self.dao_configuration.kyc_revocation_forfeits_deposit
+    }
+
+    /// Gets the time after an account recovery voting passes during which the recovered account can veto the recovery.
+    ///
+    /// See [Variable Repository](crate::core_contracts::VariableRepositoryContract) AccountRecoveryChallengePeriod
+    /// ([available keys](crate::core_contracts::VariableRepositoryContract#available-keys)).
+    pub fn account_recovery_challenge_period(&self) -> BlockTime {
+        self.dao_configuration.account_recovery_challenge_period
+    }
+
     /// Indicates if the payment for the job should be distributed between all VA’s or only to those who voted
     ///
     /// See [Variable Repository](crate::core_contracts::VariableRepositoryContract) DistributePaymentToNonVoters.
@@ -313,6 +445,24 @@ impl Configuration {
             .unwrap_or_revert_with(Error::ArithmeticOverflow)
     }
 
+    /// Applies the value of `KeeperRewardRatio` variable to a given amount.
+    pub fn apply_keeper_reward_ratio_to(&self, amount: Balance) -> Balance {
+        per_mil_of(amount, self.dao_configuration.keeper_reward_ratio)
+            .unwrap_or_revert_with(Error::ArithmeticOverflow)
+    }
+
+    /// Applies the value of `InsurancePoolRatio` variable to a given amount.
+    pub fn apply_insurance_pool_ratio_to(&self, amount: Balance) -> Balance {
+        per_mil_of(amount, self.dao_configuration.insurance_pool_ratio)
+            .unwrap_or_revert_with(Error::ArithmeticOverflow)
+    }
+
+    /// Applies the value of `UnrevealedBidForfeitRatio` variable to a given amount.
+    pub fn apply_unrevealed_bid_forfeit_ratio_to(&self, amount: Balance) -> Balance {
+        per_mil_of(amount, self.dao_configuration.unrevealed_bid_forfeit_ratio)
+            .unwrap_or_revert_with(Error::ArithmeticOverflow)
+    }
+
     /// Applies the value of `DefaultReputationSlash` variable to a given amount.
     pub fn apply_default_reputation_slash_to(&self, amount: Balance) -> Balance {
         per_mil_of(amount, self.dao_configuration.default_reputation_slash)
@@ -12,6 +12,7 @@ pub struct VotingConfiguration {
     pub contract_calls: Vec<ContractCall>,
     pub only_va_can_create: bool,
     pub double_time_between_votings: bool,
+    pub is_sponsored_onboarding: bool,
 }
 
 impl VotingConfiguration {
@@ -30,6 +31,10 @@ impl VotingConfiguration {
         self.is_bid_escrow = is_bid_escrow;
     }
 
+    pub fn set_is_sponsored_onboarding(&mut self, is_sponsored_onboarding: bool) {
+        self.is_sponsored_onboarding = is_sponsored_onboarding;
+    }
+
     pub fn set_only_va_can_create(&mut self, only_va_can_create: bool) {
         self.only_va_can_create = only_va_can_create;
     }
@@ -0,0 +1,94 @@
+//! Types describing the CEP-78 compatible interface of the DAO tokens.
+//!
+//! The discriminants match the values the CEP-78 Enhanced NFT standard stores in its named keys,
+//! so the values returned by the token contracts can be interpreted by the standard tooling.
+//! The contracts also write the values to the named keys on installation.
+use odra::prelude::string::String;
+
+/// Describes who can own a token and whether it can be transferred.
+#[repr(u8)]
+pub enum OwnershipMode {
+    /// The minter owns the token.
+    Minter = 0,
+    /// The token is minted to an owner and cannot be transferred.
+    Assigned = 1,
+    /// The token can be transferred.
+    Transferable = 2,
+}
+
+/// Describes whether a token can be burned.
+#[repr(u8)]
+pub enum BurnMode {
+    Burnable = 0,
+    NonBurnable = 1,
+}
+
+/// Describes who can mint a token.
+#[repr(u8)]
+pub enum MintingMode {
+    /// Only the installer can mint.
+    Installer = 0,
+    /// Anyone can mint.
+    Public = 1,
+    /// Only the addresses from the access control list can mint.
+    Acl = 2,
+}
+
+/// Describes the schema of the token metadata.
+#[repr(u8)]
+pub enum NftMetadataKind {
+    Cep78 = 0,
+    Nft721 = 1,
+    Raw = 2,
+    CustomValidated = 3,
+}
+
+/// Describes whether the token metadata can change.
+#[repr(u8)]
+pub enum MetadataMutability {
+    Immutable = 0,
+    Mutable = 1,
+}
+
+/// Describes how a token is identified.
+#[repr(u8)]
+pub enum NftIdentifierMode {
+    Ordinal = 0,
+    Hash = 1,
+}
+
+/// Stores the CEP-78 named keys in the context of the calling contract.
+///
+/// The standard tooling reads the collection details from the named keys instead of calling
+/// the contract. Named keys exist only on the Casper backend, elsewhere the call is a no-op.
+#[cfg(all(feature = "casper", target_arch = "wasm32"))]
+pub(crate) fn put_named_keys(
+    collection_name: String,
+    collection_symbol: String,
+    json_schema: String,
+    modes: &[(&str, u8)],
+) {
+    use odra::casper::casper_contract::contract_api::{runtime, storage};
+
+    runtime::put_key("collection_name", storage::new_uref(collection_name).into());
+    runtime::put_key(
+        "collection_symbol",
+        storage::new_uref(collection_symbol).into(),
+    );
+    runtime::put_key("json_schema", storage::new_uref(json_schema).into());
+    for (name, value) in modes {
+        runtime::put_key(name, storage::new_uref(*value).into());
+    }
+}
+
+/// Stores the CEP-78 named keys in the context of the calling contract.
+///
+/// Named keys exist only on the Casper backend, elsewhere the call is a no-op.
+#[cfg(not(all(feature = "casper", target_arch = "wasm32")))]
+pub(crate) fn put_named_keys(
+    _collection_name: String,
+    _collection_symbol: String,
+    _json_schema: String,
+    _modes: &[(&str, u8)],
+) {
+}
@@ -1,10 +1,17 @@
-use crate::modules::AccessControl;
+use crate::core_contracts::cep78::{
+    self, BurnMode, MetadataMutability, MintingMode, NftIdentifierMode, NftMetadataKind,
+    OwnershipMode,
+};
+use crate::modules::{AccessControl, OwnerAction, OwnerActionArgs, OwnerProposal, Role};
+use crate::utils::types::KycLevel;
 use crate::utils::Error;
+use crate::voting::types::VotingId;
+use core::fmt::Display;
 use odra::{
     contract_env,
-    prelude::{format, string::String},
-    types::{event::OdraEvent, Address, Balance, U256},
-    Mapping, Sequence, Variable,
+    prelude::{format, string::String, vec::Vec},
+    types::{event::OdraEvent, Address, Balance, BlockTime, U256},
+    Mapping, OdraType, Sequence, Variable,
 };
 use odra_modules::erc721::events::Transfer;
 use odra_modules::erc721::{
@@ -13,6 +20,16 @@ use odra_modules::erc721::{
     Erc721,
 };
 
+/// The CEP-78 custom schema of the token metadata.
+const METADATA_JSON_SCHEMA: &str = concat!(
+    "{\"properties\":{",
+    "\"name\":{\"name\":\"name\",\"description\":\"The name of the token\",\"required\":true},",
+    "\"symbol\":{\"name\":\"symbol\",\"description\":\"The symbol of the collection\",\"required\":true},",
+    "\"token_uri\":{\"name\":\"token_uri\",\"description\":\"The URI of the token\",\"required\":true},",
+    "\"attributes\":{\"name\":\"attributes\",\"description\":\"The traits of the token: issued_at, voting_id, kyc_level, expires_at, joined_at and status\",\"required\":true}",
+    "}}"
+);
+
 /// A unique token id.
 pub type TokenId = U256;
 /// A distinct Uniform Resource Identifier (URI) for a token.
@@ -27,6 +44,7 @@ pub struct DaoNft {
     tokens: Mapping<Address, Option<TokenId>>,
     id_gen: Sequence<TokenId>,
     total_supply: Variable<Balance>,
+    issuing_votings: Mapping<TokenId, VotingId>,
 }
 
 #[odra::module]
@@ -40,9 +58,6 @@ impl DaoNft {
             /// Accepts the new owner proposition. This can be called only by the proposed owner.
             /// [`Read more`](AccessControl::accept_new_owner())
             pub fn accept_new_owner(&mut self);
-            /// Adds a new address to the whitelist.
-            /// [`Read more`](AccessControl::add_to_whitelist())
-            pub fn add_to_whitelist(&mut self, address: Address);
             /// Remove address from the whitelist.
             /// [`Read more`](AccessControl::remove_from_whitelist())
             pub fn remove_from_whitelist(&mut self, address: Address);
@@ -52,6 +67,54 @@ impl DaoNft {
             /// Returns the address of the current owner.
             /// [`Read more`](AccessControl::get_owner()).
             pub fn get_owner(&self) -> Option<Address>;
+            /// Grants the `role` to the `address`.
+            /// [`Read more`](AccessControl::grant_role())
+            pub fn grant_role(&mut self, role: Role, address: Address);
+            /// Revokes the `role` from the `address`.
+            /// [`Read more`](AccessControl::revoke_role())
+            pub fn revoke_role(&mut self, role: Role, address: Address);
+            /// Sets the admin role of the `role`, whose holders grant and revoke the `role`.
+            /// [`Read more`](AccessControl::set_role_admin())
+            pub fn set_role_admin(&mut self, role: Role, admin_role: Role);
+            /// Returns the admin role of the `role`, whose holders grant and revoke the `role`.
+            /// [`Read more`](AccessControl::get_role_admin())
+            pub fn get_role_admin(&self, role: Role) -> Role;
+            /// Checks whether the `address` has the `role`.
+            /// [`Read more`](AccessControl::has_role())
+            pub fn has_role(&self, role: Role, address: Address) -> bool;
+            /// Replaces the whitelist entry of the `address` with the `roles`.
+            /// [`Read more`](AccessControl::migrate_to_roles())
+            pub fn migrate_to_roles(&mut self, address: Address, roles: Vec<Role>);
+            /// Hands the ownership over to the `signers`.
+            /// [`Read more`](AccessControl::enable_multisig_ownership())
+            pub fn enable_multisig_ownership(&mut self, signers: Vec<Address>, threshold: u32);
+            /// Proposes an owner-only action.
+            /// [`Read more`](AccessControl::propose_owner_action())
+            pub fn propose_owner_action(&mut self, action: OwnerAction, action_args: OwnerActionArgs) -> u32;
+            /// Approves a proposed owner-only action.
+            /// [`Read more`](AccessControl::approve_owner_action())
+            pub fn approve_owner_action(&mut self, proposal_id: u32);
+            /// Executes a proposed owner-only action approved by the current signers.
+            /// [`Read more`](AccessControl::execute_owner_action())
+            pub fn execute_owner_action(&mut self, proposal_id: u32);
+            /// Returns the signers of the multisig ownership.
+            /// [`Read more`](AccessControl::get_signers())
+            pub fn get_signers(&self) -> Vec<Address>;
+            /// Returns the number of approvals required to execute an owner-only action.
+            /// [`Read more`](AccessControl::get_threshold())
+            pub fn get_threshold(&self) -> u32;
+            /// Returns the proposal of an owner-only action.
+            /// [`Read more`](AccessControl::get_owner_proposal())
+            pub fn get_owner_proposal(&self, proposal_id: u32) -> Option<OwnerProposal>;
+            /// Pauses the contract.
+            /// [`Read more`](AccessControl::pause())
+            pub fn pause(&mut self);
+            /// Unpauses the contract.
+            /// [`Read more`](AccessControl::unpause())
+            pub fn unpause(&mut self);
+            /// Returns true if the contract is paused.
+            /// [`Read more`](AccessControl::is_paused())
+            pub fn is_paused(&self) -> bool;
         }
 
         to self.metadata {
@@ -75,13 +138,26 @@ impl DaoNft {
 
     /// Module constructor.
     ///
-    /// Initializes modules. Sets the deployer as the owner.
+    /// Initializes modules. Sets the deployer as the owner. Writes the CEP-78 named keys.
     ///
     /// See [Erc721MetadataExtension](Erc721MetadataExtension::init()), [AccessControl](AccessControl::init())
     pub fn init(&mut self, name: String, symbol: String, base_uri: TokenUri) {
         let deployer = contract_env::caller();
-        self.metadata.init(name, symbol, base_uri);
+        self.metadata.init(name.clone(), symbol.clone(), base_uri);
         self.access_control.init(deployer);
+        cep78::put_named_keys(
+            name,
+            symbol,
+            self.json_schema(),
+            &[
+                ("ownership_mode", self.ownership_mode()),
+                ("burn_mode", self.burn_mode()),
+                ("minting_mode", self.minting_mode()),
+                ("nft_metadata_kind", self.nft_metadata_kind()),
+                ("metadata_mutability", self.metadata_mutability()),
+                ("identifier_mode", self.identifier_mode()),
+            ],
+        );
     }
 
     /// Returns the total number of tokens.
@@ -104,11 +180,87 @@ impl DaoNft {
         format!("{}{}", self.metadata.base_uri(), token_id)
     }
 
+    /// Returns a descriptive name for a collection of tokens in this contract.
+    ///
+    /// The CEP-78 equivalent of [`name`](Self::name()).
+    pub fn collection_name(&self) -> String {
+        self.metadata.name()
+    }
+
+    /// Gets an abbreviated name for tokens in this contract.
+    ///
+    /// The CEP-78 equivalent of [`symbol`](Self::symbol()).
+    pub fn collection_symbol(&self) -> String {
+        self.metadata.symbol()
+    }
+
+    /// Returns the CEP-78 ownership mode. The tokens are assigned to the owners and cannot be transferred.
+    pub fn ownership_mode(&self) -> u8 {
+        OwnershipMode::Assigned as u8
+    }
+
+    /// Returns the CEP-78 burn mode. The tokens can be burned by the holders of the
+    /// [`Burner`](crate::modules::Role::Burner) role.
+    pub fn burn_mode(&self) -> u8 {
+        BurnMode::Burnable as u8
+    }
+
+    /// Returns the CEP-78 minting mode. The tokens can be minted by the holders of the
+    /// [`Minter`](crate::modules::Role::Minter) role only.
+    pub fn minting_mode(&self) -> u8 {
+        MintingMode::Acl as u8
+    }
+
+    /// Returns the CEP-78 metadata kind. The metadata follows the custom schema
+    /// returned by [`json_schema`](Self::json_schema()).
+    pub fn nft_metadata_kind(&self) -> u8 {
+        NftMetadataKind::CustomValidated as u8
+    }
+
+    /// Returns the CEP-78 JSON schema of the metadata rendered by [`render_metadata`](Self::render_metadata()).
+    pub fn json_schema(&self) -> String {
+        String::from(METADATA_JSON_SCHEMA)
+    }
+
+    /// Returns the CEP-78 metadata mutability. The metadata changes, e.g. when the token expires.
+    pub fn metadata_mutability(&self) -> u8 {
+        MetadataMutability::Mutable as u8
+    }
+
+    /// Returns the CEP-78 identifier mode. The tokens are identified by ordinal ids.
+    pub fn identifier_mode(&self) -> u8 {
+        NftIdentifierMode::Ordinal as u8
+    }
+
+    /// Returns the id of the voting the token has been issued by.
+    ///
+    /// If the token has not been issued by a voting the None value is returned.
+    pub fn issuing_voting(&self, token_id: TokenId) -> Option<VotingId> {
+        self.issuing_votings.get(&token_id)
+    }
+
+    /// Records the voting the token owned by the `owner` has been issued by.
+    ///
+    /// # Errors
+    /// * [`MissingRole`](crate::utils::Error::MissingRole) if the caller
+    /// does not have the [`Minter`](crate::modules::Role::Minter) role.
+    /// * [`TokenDoesNotExist`](crate::utils::Error::TokenDoesNotExist) if the `owner`
+    /// does not own a token.
+    pub fn set_issuing_voting(&mut self, owner: Address, voting_id: VotingId) {
+        self.access_control.ensure_not_paused();
+        self.access_control.ensure_role(Role::Minter);
+        let token_id = match self.token_id(owner) {
+            Some(token_id) => token_id,
+            None => contract_env::revert(Error::TokenDoesNotExist),
+        };
+        self.issuing_votings.set(&token_id, voting_id);
+    }
+
     /// Creates a new token with the next id and transfers it to a new owner.
     /// Increments the total supply and the balance of the `to` address.
     ///
     /// # Note
-    /// Only whitelisted addresses are permitted to call this function.
+    /// Only the holders of the [`Minter`](crate::modules::Role::Minter) role are permitted to call this function.
     ///
     /// Each user is entitled to own only one token.
     ///
@@ -119,8 +271,8 @@ impl DaoNft {
     /// # Events
     /// * [`Transfer`] event when minted successfully.
     pub fn mint(&mut self, to: Address) {
-        // Check if the caller is whitelisted
-        self.access_control.ensure_whitelisted();
+        self.access_control.ensure_not_paused();
+        self.access_control.ensure_role(Role::Minter);
         self.assert_does_not_own_token(&to);
 
         let token_id = self.id_gen.next_value();
@@ -147,13 +299,14 @@ impl DaoNft {
     /// and decrements the total supply.
     ///
     /// # Errors
-    /// * [`NotWhitelisted`](crate::utils::Error::NotWhitelisted) if the caller
-    /// is not whitelisted.
+    /// * [`MissingRole`](crate::utils::Error::MissingRole) if the caller
+    /// does not have the [`Burner`](crate::modules::Role::Burner) role.
     ///
     /// # Events
     /// * [`Transfer`] event when burnt successfully.
     pub fn burn(&mut self, owner: Address) {
-        self.access_control.ensure_whitelisted();
+        self.access_control.ensure_not_paused();
+        self.access_control.ensure_role(Role::Burner);
         let token_id = self.token_id(owner);
 
         if let Some(token_id) = token_id {
@@ -171,12 +324,147 @@ impl DaoNft {
             .emit();
         }
     }
+
+    /// Moves the token owned by the `from` address to the `to` address. The token id does not change.
+    ///
+    /// # Errors
+    /// * [`MissingRole`](crate::utils::Error::MissingRole) if the caller
+    /// does not have the [`Migrator`](crate::modules::Role::Migrator) role.
+    /// * [`TokenDoesNotExist`](crate::utils::Error::TokenDoesNotExist) if the `from` address
+    /// does not own a token.
+    /// * [`UserAlreadyOwnsToken`](crate::utils::Error::UserAlreadyOwnsToken) if the `to` address
+    /// already owns a token.
+    ///
+    /// # Events
+    /// * [`Transfer`] event when migrated successfully.
+    pub fn migrate(&mut self, from: Address, to: Address) {
+        self.access_control.ensure_not_paused();
+        self.access_control.ensure_role(Role::Migrator);
+        let token_id = match self.token_id(from) {
+            Some(token_id) => token_id,
+            None => contract_env::revert(Error::TokenDoesNotExist),
+        };
+        self.assert_does_not_own_token(&to);
+
+        self.core.balances.subtract(&from, U256::one());
+        self.core.balances.add(&to, U256::one());
+        self.core.owners.set(&token_id, Some(to));
+        self.core.clear_approval(&token_id);
+        self.tokens.set(&from, None);
+        self.tokens.set(&to, Some(token_id));
+
+        Transfer {
+            from: Some(from),
+            to: Some(to),
+            token_id,
+        }
+        .emit();
+    }
 }
 
 impl DaoNft {
+    /// Reverts with [`MissingRole`](Error::MissingRole) if the caller does not have the `role`.
+    pub fn ensure_role(&self, role: Role) {
+        self.access_control.ensure_role(role);
+    }
+
+    /// Reverts with [`ContractPaused`](Error::ContractPaused) if the contract is paused.
+    pub fn ensure_not_paused(&self) {
+        self.access_control.ensure_not_paused();
+    }
+
+    /// Returns the address of the owner of the token.
+    ///
+    /// Reverts with [`TokenDoesNotExist`](Error::TokenDoesNotExist) if the token does not exist.
+    pub fn owner_of_or_revert(&self, token_id: &TokenId) -> Address {
+        if !self.core.exists(token_id) {
+            contract_env::revert(Error::TokenDoesNotExist)
+        }
+        self.core.owner_of(token_id)
+    }
+
+    /// Renders the `metadata` as a JSON document following the CEP-78 custom schema
+    /// returned by [`json_schema`](Self::json_schema()).
+    ///
+    /// The `token_uri` field points to the off-chain asset, the DAO specific data is listed
+    /// in the `attributes` array.
+    pub fn render_metadata(&self, metadata: &TokenMetadata) -> String {
+        let mut attributes = Vec::new();
+        if let Some(issued_at) = metadata.issued_at {
+            attributes.push(json_attribute("issued_at", issued_at));
+        }
+        if let Some(voting_id) = metadata.voting_id {
+            attributes.push(json_attribute("voting_id", voting_id));
+        }
+        if let Some(kyc_level) = metadata.kyc_level {
+            attributes.push(json_attribute("kyc_level", kyc_level));
+        }
+        if let Some(expires_at) = metadata.expires_at {
+            attributes.push(json_attribute("expires_at", expires_at));
+        }
+        if let Some(joined_at) = metadata.joined_at {
+            attributes.push(json_attribute("joined_at", joined_at));
+        }
+        attributes.push(json_attribute(
+            "status",
+            format!("\"{}\"", metadata.status.as_str()),
+        ));
+
+        format!(
+            "{{\"name\":\"{} #{}\",\"symbol\":\"{}\",\"token_uri\":\"{}\",\"attributes\":[{}]}}",
+            escape_json(&self.metadata.name()),
+            metadata.token_id,
+            escape_json(&self.metadata.symbol()),
+            escape_json(&self.token_uri(metadata.token_id)),
+            attributes.join(",")
+        )
+    }
+
     fn assert_does_not_own_token(&self, address: &Address) {
         if self.tokens.get(address).is_some() {
             contract_env::revert(Error::UserAlreadyOwnsToken)
         }
     }
 }
+
+/// On-chain metadata of a DAO token.
+#[derive(Debug, PartialEq, Eq, OdraType)]
+pub struct TokenMetadata {
+    pub token_id: TokenId,
+    pub owner: Address,
+    /// The time the token has been issued or renewed.
+    pub issued_at: Option<BlockTime>,
+    /// The id of the voting the token has been issued by.
+    pub voting_id: Option<VotingId>,
+    /// The KYC level, set for KYC tokens only.
+    pub kyc_level: Option<KycLevel>,
+    /// The time the token expires at, if the token expires.
+    pub expires_at: Option<BlockTime>,
+    /// The time the owner has joined the DAO, set for VA tokens only.
+    pub joined_at: Option<BlockTime>,
+    pub status: TokenStatus,
+}
+
+/// Status of a DAO token.
+#[derive(Debug, PartialEq, Eq, OdraType)]
+pub enum TokenStatus {
+    Active,
+    Expired,
+}
+
+impl TokenStatus {
+    fn as_str(&self) -> &str {
+        match self {
+            TokenStatus::Active => "active",
+            TokenStatus::Expired => "expired",
+        }
+    }
+}
+
+fn json_attribute<T: Display>(trait_type: &str, value: T) -> String {
+    format!("{{\"trait_type\":\"{}\",\"value\":{}}}", trait_type, value)
+}
+
+fn escape_json(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
@@ -9,20 +9,58 @@
 //!
 //! Each [`Address`] can own only one KYC token.
 //!
+//! # Expiry
+//! A KYC token minted as a result of [`KYC Voting`] is valid for `KycValidityPeriod` from its issue date.
+//! After that time the owner is no longer considered KYC'd, until the token is renewed by a renewal voting.
+//! The renewal extends the validity of the token without burning and reminting it.
+//!
+//! # KYC Levels
+//! Each token has a KYC level. A token minted without the level specified has the basic level.
+//! Higher levels stand for an enhanced verification and may be required by some `Job Offers`.
+//! The level of a token can be upgraded without burning and reminting it.
+//!
+//! # KYC Providers
+//! The contract keeps the registry of KYC providers approved by the governance.
+//! A token can be minted without a voting if a registered provider attests the user is verified.
+//!
+//! # Metadata
+//! Each token exposes on-chain metadata: the issue date, the voting the token has been issued or renewed by,
+//! the KYC level and whether the token has expired.
+//! The metadata is rendered as a CEP-78 compatible JSON document by `token_uri`.
+//!
+//! # CEP-78
+//! The contract exposes the read-only part of the CEP-78 interface, so the tokens are discoverable
+//! by the standard tooling. The tokens are soulbound - there is no way to transfer a token,
+//! minting and burning is restricted to the DAO contracts granted the
+//! [`Minter`](crate::modules::Role::Minter) and [`Burner`](crate::modules::Role::Burner) roles.
+//!
 //! [`KYC Voting`]: crate::voting_contracts::KycVoterContract
-use crate::core_contracts::dao_nft::{DaoNft, TokenId, TokenUri};
+use crate::core_contracts::dao_nft::{DaoNft, TokenId, TokenMetadata, TokenStatus, TokenUri};
+use crate::modules::{OwnerAction, OwnerActionArgs, OwnerProposal, Role};
+use crate::utils::consts::BASIC_KYC_LEVEL;
+use crate::utils::types::{copy_public_key, KycLevel};
+use crate::utils::Error;
+use crate::voting::types::VotingId;
 use odra::{
-    prelude::string::String,
-    types::{Address, Balance, U256},
+    contract_env::{self, get_block_time},
+    prelude::{string::String, vec::Vec},
+    types::{event::OdraEvent, Address, Balance, BlockTime, PublicKey, U256},
+    Event, List, Mapping,
 };
 
 /// NFT contract that tells the system if user is KYC'd.
 /// Kyc Owned Nft contract acts like an erc-721 token and derives most of erc-721 standard.
 ///
 /// Kyc Owned Nft token is mintable and burnable but the caller needs to have permissions to perform those actions.
-#[odra::module]
+#[odra::module(events = [KycRenewed, KycLevelUpgraded, KycProviderAdded, KycProviderRemoved])]
 pub struct KycNftContract {
     token: DaoNft,
+    issued_at: Mapping<Address, BlockTime>,
+    validity_periods: Mapping<Address, BlockTime>,
+    holders: List<Address>,
+    levels: Mapping<Address, KycLevel>,
+    kyc_providers: Mapping<PublicKey, bool>,
+    kyc_provider_keys: List<PublicKey>,
 }
 
 #[odra::module]
@@ -44,18 +82,92 @@ impl KycNftContract {
             /// Accepts the new owner proposition. This can be called only by the proposed owner.
             /// [`Read more`](crate::modules::access_control::AccessControl::accept_new_owner())
             pub fn accept_new_owner(&mut self);
-            /// Adds a new address to the whitelist.
-            pub fn add_to_whitelist(&mut self, address: Address);
             /// Remove address from the whitelist.
             pub fn remove_from_whitelist(&mut self, address: Address);
             /// Checks whether the given address is added to the whitelist.
             pub fn is_whitelisted(&self, address: Address) -> bool;
             /// Returns the address of the current owner.
             pub fn get_owner(&self) -> Option<Address>;
+            /// Grants the `role` to the `address`.
+            /// [`Read more`](crate::modules::access_control::AccessControl::grant_role())
+            pub fn grant_role(&mut self, role: Role, address: Address);
+            /// Revokes the `role` from the `address`.
+            /// [`Read more`](crate::modules::access_control::AccessControl::revoke_role())
+            pub fn revoke_role(&mut self, role: Role, address: Address);
+            /// Sets the admin role of the `role`, whose holders grant and revoke the `role`.
+            /// [`Read more`](crate::modules::access_control::AccessControl::set_role_admin())
+            pub fn set_role_admin(&mut self, role: Role, admin_role: Role);
+            /// Returns the admin role of the `role`, whose holders grant and revoke the `role`.
+            /// [`Read more`](crate::modules::access_control::AccessControl::get_role_admin())
+            pub fn get_role_admin(&self, role: Role) -> Role;
+            /// Checks whether the `address` has the `role`.
+            /// [`Read more`](crate::modules::access_control::AccessControl::has_role())
+            pub fn has_role(&self, role: Role, address: Address) -> bool;
+            /// Replaces the whitelist entry of the `address` with the `roles`.
+            /// [`Read more`](crate::modules::access_control::AccessControl::migrate_to_roles())
+            pub fn migrate_to_roles(&mut self, address: Address, roles: Vec<Role>);
+            /// Hands the ownership over to the `signers`.
+            /// [`Read more`](crate::modules::access_control::AccessControl::enable_multisig_ownership())
+            pub fn enable_multisig_ownership(&mut self, signers: Vec<Address>, threshold: u32);
+            /// Proposes an owner-only action.
+            /// [`Read more`](crate::modules::access_control::AccessControl::propose_owner_action())
+            pub fn propose_owner_action(&mut self, action: OwnerAction, action_args: OwnerActionArgs) -> u32;
+            /// Approves a proposed owner-only action.
+            /// [`Read more`](crate::modules::access_control::AccessControl::approve_owner_action())
+            pub fn approve_owner_action(&mut self, proposal_id: u32);
+            /// Executes a proposed owner-only action approved by the current signers.
+            /// [`Read more`](crate::modules::access_control::AccessControl::execute_owner_action())
+            pub fn execute_owner_action(&mut self, proposal_id: u32);
+            /// Returns the signers of the multisig ownership.
+            /// [`Read more`](crate::modules::access_control::AccessControl::get_signers())
+            pub fn get_signers(&self) -> Vec<Address>;
+            /// Returns the number of approvals required to execute an owner-only action.
+            /// [`Read more`](crate::modules::access_control::AccessControl::get_threshold())
+            pub fn get_threshold(&self) -> u32;
+            /// Returns the proposal of an owner-only action.
+            /// [`Read more`](crate::modules::access_control::AccessControl::get_owner_proposal())
+            pub fn get_owner_proposal(&self, proposal_id: u32) -> Option<OwnerProposal>;
+            /// Pauses the contract.
+            /// [`Read more`](crate::modules::access_control::AccessControl::pause())
+            pub fn pause(&mut self);
+            /// Unpauses the contract.
+            /// [`Read more`](crate::modules::access_control::AccessControl::unpause())
+            pub fn unpause(&mut self);
+            /// Returns true if the contract is paused.
+            /// [`Read more`](crate::modules::access_control::AccessControl::is_paused())
+            pub fn is_paused(&self) -> bool;
             /// Returns a descriptive name for a collection of tokens in this contract.
             pub fn name(&self) -> String;
             /// Gets an abbreviated name for tokens in this contract.
             pub fn symbol(&self) -> String;
+            /// Returns a descriptive name for a collection of tokens in this contract.
+            ///
+            /// The CEP-78 equivalent of `name`.
+            pub fn collection_name(&self) -> String;
+            /// Gets an abbreviated name for tokens in this contract.
+            ///
+            /// The CEP-78 equivalent of `symbol`.
+            pub fn collection_symbol(&self) -> String;
+            /// Returns the CEP-78 ownership mode. The tokens cannot be transferred.
+            /// [`Read more`](crate::core_contracts::cep78::OwnershipMode)
+            pub fn ownership_mode(&self) -> u8;
+            /// Returns the CEP-78 burn mode.
+            /// [`Read more`](crate::core_contracts::cep78::BurnMode)
+            pub fn burn_mode(&self) -> u8;
+            /// Returns the CEP-78 minting mode. Only the holders of the [`Minter`](crate::modules::Role::Minter) role can mint.
+            /// [`Read more`](crate::core_contracts::cep78::MintingMode)
+            pub fn minting_mode(&self) -> u8;
+            /// Returns the CEP-78 metadata kind. The metadata follows the custom schema.
+            /// [`Read more`](crate::core_contracts::cep78::NftMetadataKind)
+            pub fn nft_metadata_kind(&self) -> u8;
+            /// Returns the CEP-78 JSON schema of the token metadata.
+            pub fn json_schema(&self) -> String;
+            /// Returns the CEP-78 metadata mutability.
+            /// [`Read more`](crate::core_contracts::cep78::MetadataMutability)
+            pub fn metadata_mutability(&self) -> u8;
+            /// Returns the CEP-78 identifier mode.
+            /// [`Read more`](crate::core_contracts::cep78::NftIdentifierMode)
+            pub fn identifier_mode(&self) -> u8;
             /// Returns the address of the owner of the token.
             ///
             /// If the given `token_id` does not exist the None value is returned.
@@ -68,35 +180,370 @@ impl KycNftContract {
             pub fn balance_of(&self, owner: &Address) -> U256;
             /// Returns the total number of tokens.
             pub fn total_supply(&self) -> Balance;
-            /// Returns a distinct Uniform Resource Identifier (URI) for a given asset.
-            pub fn token_uri(&self, token_id: TokenId) -> TokenUri;
             /// Returns a URI prefix that is used by all the assets.
             pub fn base_uri(&self) -> TokenUri;
-            /// Creates a new token with the next id and transfers it to a new owner.
-            /// Increments the total supply and the balance of the `to` address.
-            ///
-            /// # Note
-            /// Only whitelisted addresses are permitted to call this function.
-            ///
-            /// Each user is entitled to own only one token.
-            ///
-            /// # Errors
-            /// * [`UserAlreadyOwnsToken`](crate::utils::Error::UserAlreadyOwnsToken) if the `to` address
-            /// already owns a token.
-            ///
-            /// # Events
-            /// * [`Transfer`](odra_modules::erc721::events::Transfer) event when minted successfully.
-            pub fn mint(&mut self, to: Address);
             /// Burns a token with the given id. Decrements the balance of the token owner
             /// and decrements the total supply.
             ///
             /// # Errors
-            /// * [`NotWhitelisted`](crate::utils::Error::NotWhitelisted) if the caller
-            /// is not whitelisted.
+            /// * [`MissingRole`](crate::utils::Error::MissingRole) if the caller
+            /// does not have the [`Burner`](crate::modules::Role::Burner) role.
             ///
             /// # Events
             /// * [`Transfer`](odra_modules::erc721::events::Transfer) event when burnt successfully.
             pub fn burn(&mut self, owner: Address);
+            /// Returns the id of the voting the token has been issued or renewed by.
+            ///
+            /// If the token has not been issued by a voting the None value is returned.
+            pub fn issuing_voting(&self, token_id: TokenId) -> Option<VotingId>;
+            /// Records the voting the token owned by the `owner` has been issued or renewed by.
+            ///
+            /// # Errors
+            /// * [`MissingRole`](crate::utils::Error::MissingRole) if the caller
+            /// does not have the [`Minter`](crate::modules::Role::Minter) role.
+            /// * [`TokenDoesNotExist`](crate::utils::Error::TokenDoesNotExist) if the `owner`
+            /// does not own a token.
+            pub fn set_issuing_voting(&mut self, owner: Address, voting_id: VotingId);
+        }
+    }
+
+    /// Creates a new token with the next id and transfers it to a new owner.
+    /// Increments the total supply and the balance of the `to` address.
+    ///
+    /// The token does not expire.
+    ///
+    /// # Note
+    /// Only the holders of the [`Minter`](crate::modules::Role::Minter) role are permitted to call this function.
+    ///
+    /// Each user is entitled to own only one token.
+    ///
+    /// # Errors
+    /// * [`UserAlreadyOwnsToken`](crate::utils::Error::UserAlreadyOwnsToken) if the `to` address
+    /// already owns a token.
+    ///
+    /// # Events
+    /// * [`Transfer`](odra_modules::erc721::events::Transfer) event when minted successfully.
+    pub fn mint(&mut self, to: Address) {
+        self.mint_with_validity(to, 0);
+    }
+
+    /// Creates a new token valid for the `validity_period` and transfers it to a new owner.
+    /// If the `validity_period` is 0, the token does not expire.
+    ///
+    /// # Note
+    /// Only the holders of the [`Minter`](crate::modules::Role::Minter) role are permitted to call this function.
+    ///
+    /// # Errors
+    /// * [`UserAlreadyOwnsToken`](crate::utils::Error::UserAlreadyOwnsToken) if the `to` address
+    /// already owns a token.
+    ///
+    /// # Events
+    /// * [`Transfer`](odra_modules::erc721::events::Transfer) event when minted successfully.
+    pub fn mint_with_validity(&mut self, to: Address, validity_period: BlockTime) {
+        self.mint_with_level(to, validity_period, BASIC_KYC_LEVEL);
+    }
+
+    /// Creates a new token of the given KYC `level` valid for the `validity_period` and transfers it to a new owner.
+    /// If the `validity_period` is 0, the token does not expire.
+    ///
+    /// # Note
+    /// Only the holders of the [`Minter`](crate::modules::Role::Minter) role are permitted to call this function.
+    ///
+    /// # Errors
+    /// * [`UserAlreadyOwnsToken`](crate::utils::Error::UserAlreadyOwnsToken) if the `to` address
+    /// already owns a token.
+    /// * [`InvalidKycLevel`](crate::utils::Error::InvalidKycLevel) if the `level` is 0.
+    ///
+    /// # Events
+    /// * [`Transfer`](odra_modules::erc721::events::Transfer) event when minted successfully.
+    pub fn mint_with_level(&mut self, to: Address, validity_period: BlockTime, level: KycLevel) {
+        if level < BASIC_KYC_LEVEL {
+            contract_env::revert(Error::InvalidKycLevel);
+        }
+        self.token.mint(to);
+        if self.issued_at.get(&to).is_none() {
+            self.holders.push(to);
+        }
+        self.issued_at.set(&to, get_block_time());
+        self.validity_periods.set(&to, validity_period);
+        self.levels.set(&to, level);
+    }
+
+    /// Upgrades the KYC level of the token owned by the `owner`.
+    ///
+    /// # Note
+    /// Only the holders of the [`Minter`](crate::modules::Role::Minter) role are permitted to call this function.
+    ///
+    /// # Errors
+    /// * [`MissingRole`](crate::utils::Error::MissingRole) if the caller
+    /// does not have the [`Minter`](crate::modules::Role::Minter) role.
+    /// * [`TokenDoesNotExist`](crate::utils::Error::TokenDoesNotExist) if the `owner` does not own a token.
+    /// * [`InvalidKycLevel`](crate::utils::Error::InvalidKycLevel) if the `level` is not higher than the current one.
+    ///
+    /// # Events
+    /// * [`KycLevelUpgraded`] event when upgraded successfully.
+    pub fn upgrade_level(&mut self, owner: Address, level: KycLevel) {
+        self.token.ensure_not_paused();
+        self.token.ensure_role(Role::Minter);
+        if self.token.token_id(owner).is_none() {
+            contract_env::revert(Error::TokenDoesNotExist);
+        }
+        if level <= self.level_of(owner) {
+            contract_env::revert(Error::InvalidKycLevel);
+        }
+
+        self.levels.set(&owner, level);
+
+        KycLevelUpgraded { owner, level }.emit();
+    }
+
+    /// Extends the validity of the token owned by the `owner`. The token is valid for the `validity_period`
+    /// from now on.
+    ///
+    /// # Note
+    /// Only the holders of the [`Minter`](crate::modules::Role::Minter) role are permitted to call this function.
+    ///
+    /// # Errors
+    /// * [`MissingRole`](crate::utils::Error::MissingRole) if the caller
+    /// does not have the [`Minter`](crate::modules::Role::Minter) role.
+    /// * [`TokenDoesNotExist`](crate::utils::Error::TokenDoesNotExist) if the `owner` does not own a token.
+    ///
+    /// # Events
+    /// * [`KycRenewed`] event when renewed successfully.
+    pub fn renew(&mut self, owner: Address, validity_period: BlockTime) {
+        self.token.ensure_not_paused();
+        self.token.ensure_role(Role::Minter);
+        if self.token.token_id(owner).is_none() {
+            contract_env::revert(Error::TokenDoesNotExist);
+        }
+
+        let issued_at = get_block_time();
+        self.issued_at.set(&owner, issued_at);
+        self.validity_periods.set(&owner, validity_period);
+
+        KycRenewed {
+            owner,
+            issued_at,
+            validity_period,
+        }
+        .emit();
+    }
+
+    /// Moves the token owned by the `from` address to the `to` address.
+    /// The issue date, the validity period and the KYC level of the token are preserved.
+    ///
+    /// # Note
+    /// Only the holders of the [`Migrator`](crate::modules::Role::Migrator) role are permitted to call this function.
+    ///
+    /// # Errors
+    /// * [`MissingRole`](crate::utils::Error::MissingRole) if the caller
+    /// does not have the [`Migrator`](crate::modules::Role::Migrator) role.
+    /// * [`TokenDoesNotExist`](crate::utils::Error::TokenDoesNotExist) if the `from` address does not own a token.
+    /// * [`UserAlreadyOwnsToken`](crate::utils::Error::UserAlreadyOwnsToken) if the `to` address
+    /// already owns a token.
+    ///
+    /// # Events
+    /// * [`Transfer`](odra_modules::erc721::events::Transfer) event when migrated successfully.
+    pub fn migrate(&mut self, from: Address, to: Address) {
+        self.token.migrate(from, to);
+        if self.issued_at.get(&to).is_none() {
+            self.holders.push(to);
+        }
+        if let Some(issued_at) = self.issued_at.get(&from) {
+            self.issued_at.set(&to, issued_at);
+        }
+        self.validity_periods
+            .set(&to, self.validity_periods.get(&from).unwrap_or_default());
+        self.levels.set(&to, self.level_of(from));
+    }
+
+    /// Adds the `provider` to the registry of KYC providers.
+    ///
+    /// # Note
+    /// Only the holders of the [`Operator`](crate::modules::Role::Operator) role are permitted to call this function.
+    ///
+    /// # Errors
+    /// * [`MissingRole`](crate::utils::Error::MissingRole) if the caller
+    /// does not have the [`Operator`](crate::modules::Role::Operator) role.
+    ///
+    /// # Events
+    /// * [`KycProviderAdded`] event when added successfully.
+    pub fn add_kyc_provider(&mut self, provider: PublicKey) {
+        self.token.ensure_role(Role::Operator);
+        let is_new = self.kyc_providers.get(&provider).is_none();
+        self.kyc_providers.set(&provider, true);
+        if is_new {
+            self.kyc_provider_keys.push(copy_public_key(&provider));
+        }
+
+        KycProviderAdded { provider }.emit();
+    }
+
+    /// Removes the `provider` from the registry of KYC providers.
+    ///
+    /// # Note
+    /// Only the holders of the [`Operator`](crate::modules::Role::Operator) role are permitted to call this function.
+    ///
+    /// # Errors
+    /// * [`MissingRole`](crate::utils::Error::MissingRole) if the caller
+    /// does not have the [`Operator`](crate::modules::Role::Operator) role.
+    /// * [`KycProviderNotRegistered`](crate::utils::Error::KycProviderNotRegistered) if the `provider`
+    /// is not registered.
+    ///
+    /// # Events
+    /// * [`KycProviderRemoved`] event when removed successfully.
+    pub fn remove_kyc_provider(&mut self, provider: PublicKey) {
+        self.token.ensure_role(Role::Operator);
+        if !self.is_kyc_provider(copy_public_key(&provider)) {
+            contract_env::revert(Error::KycProviderNotRegistered);
+        }
+        self.kyc_providers.set(&provider, false);
+
+        KycProviderRemoved { provider }.emit();
+    }
+
+    /// Returns true if the `provider` is a registered KYC provider, false otherwise.
+    pub fn is_kyc_provider(&self, provider: PublicKey) -> bool {
+        self.kyc_providers.get(&provider).unwrap_or_default()
+    }
+
+    /// Returns the registered KYC providers.
+    pub fn get_kyc_providers(&self) -> Vec<PublicKey> {
+        self.kyc_provider_keys
+            .iter()
+            .filter(|provider| self.kyc_providers.get(provider).unwrap_or_default())
+            .collect()
+    }
+
+    /// Returns true if the `address` owns a token that has not expired, false otherwise.
+    pub fn is_kycd(&self, address: Address) -> bool {
+        if self.token.balance_of(&address).is_zero() {
+            return false;
+        }
+        match self.expires_at(address) {
+            Some(expires_at) => get_block_time() < expires_at,
+            None => true,
+        }
+    }
+
+    /// Returns the KYC level of the `address`. If the `address` is not KYC'd, 0 is returned.
+    pub fn kyc_level(&self, address: Address) -> KycLevel {
+        if !self.is_kycd(address) {
+            return 0;
+        }
+        self.level_of(address)
+    }
+
+    /// Returns the time the token of the `address` has been issued or renewed.
+    pub fn issued_at(&self, address: Address) -> Option<BlockTime> {
+        self.token.token_id(address)?;
+        self.issued_at.get(&address)
+    }
+
+    /// Returns the time the token of the `address` expires at.
+    ///
+    /// If the `address` does not own a token or the token does not expire, the None value is returned.
+    pub fn expires_at(&self, address: Address) -> Option<BlockTime> {
+        let issued_at = self.issued_at(address)?;
+        let validity_period = self.validity_periods.get(&address).unwrap_or_default();
+        if validity_period == 0 {
+            return None;
         }
+        Some(issued_at + validity_period)
+    }
+
+    /// Returns the addresses which tokens have not expired yet but expire within the given time.
+    ///
+    /// Checks at most `limit` token holders, starting at the `offset`.
+    /// See [`get_holders_count()`](Self::get_holders_count()).
+    pub fn get_expiring_kycs(&self, within: BlockTime, offset: u32, limit: u32) -> Vec<Address> {
+        let now = get_block_time();
+        let end = offset.saturating_add(limit).min(self.holders.len());
+        (offset..end)
+            .filter_map(|index| self.holders.get(index))
+            .filter(|address| {
+                self.expires_at(*address)
+                    .map(|expires_at| now < expires_at && expires_at <= now + within)
+                    .unwrap_or_default()
+            })
+            .collect()
+    }
+
+    /// Returns the number of addresses a token has ever been minted to.
+    pub fn get_holders_count(&self) -> u32 {
+        self.holders.len()
+    }
+
+    /// Returns the on-chain metadata of the token.
+    ///
+    /// # Errors
+    /// * [`TokenDoesNotExist`](crate::utils::Error::TokenDoesNotExist) if the token does not exist.
+    pub fn token_metadata(&self, token_id: TokenId) -> TokenMetadata {
+        let owner = self.token.owner_of_or_revert(&token_id);
+        let status = if self.is_kycd(owner) {
+            TokenStatus::Active
+        } else {
+            TokenStatus::Expired
+        };
+        TokenMetadata {
+            token_id,
+            owner,
+            issued_at: self.issued_at(owner),
+            voting_id: self.token.issuing_voting(token_id),
+            kyc_level: Some(self.level_of(owner)),
+            expires_at: self.expires_at(owner),
+            joined_at: None,
+            status,
+        }
+    }
+
+    /// Returns the metadata of the token rendered as a CEP-78 compatible JSON document.
+    ///
+    /// # Errors
+    /// * [`TokenDoesNotExist`](crate::utils::Error::TokenDoesNotExist) if the token does not exist.
+    pub fn token_uri(&self, token_id: TokenId) -> TokenUri {
+        self.token.render_metadata(&self.token_metadata(token_id))
+    }
+
+    /// Returns the metadata of the token rendered as a CEP-78 compatible JSON document.
+    ///
+    /// The CEP-78 equivalent of [`token_uri`](Self::token_uri()).
+    ///
+    /// # Errors
+    /// * [`TokenDoesNotExist`](crate::utils::Error::TokenDoesNotExist) if the token does not exist.
+    pub fn metadata(&self, token_id: TokenId) -> String {
+        self.token_uri(token_id)
+    }
+
+    fn level_of(&self, address: Address) -> KycLevel {
+        // Tokens minted before the levels were introduced have the basic level.
+        self.levels.get(&address).unwrap_or(BASIC_KYC_LEVEL)
     }
 }
+
+/// Event emitted when the validity of a KYC token has been extended.
+#[derive(Debug, PartialEq, Eq, Event)]
+pub struct KycRenewed {
+    pub owner: Address,
+    pub issued_at: BlockTime,
+    pub validity_period: BlockTime,
+}
+
+/// Event emitted when the KYC level of a token has been upgraded.
+#[derive(Debug, PartialEq, Eq, Event)]
+pub struct KycLevelUpgraded {
+    pub owner: Address,
+    pub level: KycLevel,
+}
+
+/// Event emitted when a KYC provider has been added to the registry.
+#[derive(PartialEq, Eq, Event)]
+pub struct KycProviderAdded {
+    pub provider: PublicKey,
+}
+
+/// Event emitted when a KYC provider has been removed from the registry.
+#[derive(PartialEq, Eq, Event)]
+pub struct KycProviderRemoved {
+    pub provider: PublicKey,
+}
@@ -1,12 +1,18 @@
 //! Module containing core contracts of the DAO.
+pub mod cep78;
 mod dao_nft;
 mod kyc_ntf;
 mod reputation;
 mod va_nft;
 mod variable_repository;
 
-pub use dao_nft::{DaoNft, DaoNftDeployer, DaoNftRef, TokenId, TokenUri};
-pub use kyc_ntf::{KycNftContract, KycNftContractDeployer, KycNftContractRef};
+pub use dao_nft::{
+    DaoNft, DaoNftDeployer, DaoNftRef, TokenId, TokenMetadata, TokenStatus, TokenUri,
+};
+pub use kyc_ntf::{
+    KycLevelUpgraded, KycNftContract, KycNftContractDeployer, KycNftContractRef, KycProviderAdded,
+    KycProviderRemoved, KycRenewed,
+};
 pub use reputation::token::{
     events::*, ReputationContract, ReputationContractDeployer, ReputationContractRef,
 };
@@ -1,4 +1,4 @@
-use crate::modules::AccessControl;
+use crate::modules::{AccessControl, Role};
 use crate::utils::Error;
 use core::ops::{AddAssign, SubAssign};
 use odra::{
@@ -37,9 +37,10 @@ impl BalanceStorage {
     ///
     /// # Errors
     ///
-    /// [`NotWhitelisted`](crate::utils::Error::NotWhitelisted) if called by a not whitelisted account.
+    /// [`MissingRole`](crate::utils::Error::MissingRole) if the caller does not have the [`Minter`](crate::modules::Role::Minter) role.
     pub fn mint(&mut self, recipient: Address, amount: Balance) {
-        self.access_control.ensure_whitelisted();
+        self.access_control.ensure_not_paused();
+        self.access_control.ensure_role(Role::Minter);
         self.inc_balance(&recipient, amount);
         self.total_supply += amount;
 
@@ -62,9 +63,10 @@ impl BalanceStorage {
     ///
     /// # Errors
     ///
-    /// [`NotWhitelisted`](crate::utils::Error::NotWhitelisted) if called by a not whitelisted account.
+    /// [`MissingRole`](crate::utils::Error::MissingRole) if the caller does not have the [`Burner`](crate::modules::Role::Burner) role.
     pub fn burn(&mut self, owner: Address, amount: Balance) {
-        self.access_control.ensure_whitelisted();
+        self.access_control.ensure_not_paused();
+        self.access_control.ensure_role(Role::Burner);
 
         let decreased = self.dec_balance(&owner, amount);
 
@@ -87,13 +89,16 @@ impl BalanceStorage {
     ///
     /// # Errors
     ///
-    /// [`NotWhitelisted`](crate::utils::Error::NotWhitelisted) if called by a not whitelisted account.
+    /// [`MissingRole`](crate::utils::Error::MissingRole) if the caller does not have the [`Minter`](crate::modules::Role::Minter)
+    /// and the [`Burner`](crate::modules::Role::Burner) roles.
     pub fn bulk_mint_burn(
         &mut self,
         mints: BTreeMap<Address, Balance>,
         burns: BTreeMap<Address, Balance>,
     ) {
-        self.access_control.ensure_whitelisted();
+        self.access_control.ensure_not_paused();
+        self.access_control.ensure_role(Role::Minter);
+        self.access_control.ensure_role(Role::Burner);
 
         let mut total_supply = self.total_supply();
         for (address, amount) in mints {
@@ -116,14 +121,46 @@ impl BalanceStorage {
     ///
     /// # Errors
     ///
-    /// [`NotWhitelisted`](crate::utils::Error::NotWhitelisted) if called by a not whitelisted account.
+    /// [`MissingRole`](crate::utils::Error::MissingRole) if the caller does not have the [`Burner`](crate::modules::Role::Burner) role.
     pub fn burn_all(&mut self, owner: Address) {
-        self.access_control.ensure_whitelisted();
+        self.access_control.ensure_not_paused();
+        self.access_control.ensure_role(Role::Burner);
 
         let balance = self.balance_of(owner);
         self.burn(owner, balance);
     }
 
+    /// Moves all tokens of the `from` account to the `to` account.
+    ///
+    /// Returns the amount of tokens moved.
+    ///
+    /// # Errors
+    ///
+    /// [`MissingRole`](crate::utils::Error::MissingRole) if the caller does not have the [`Migrator`](crate::modules::Role::Migrator) role.
+    pub fn migrate(&mut self, from: Address, to: Address) -> Balance {
+        self.access_control.ensure_not_paused();
+        self.access_control.ensure_role(Role::Migrator);
+
+        let amount = self.balance_of(from);
+        self.set_balance(&from, Balance::zero());
+        self.inc_balance(&to, amount);
+
+        self.holders.push(to);
+
+        Burn {
+            address: from,
+            amount,
+        }
+        .emit();
+        Mint {
+            address: to,
+            amount,
+        }
+        .emit();
+
+        amount
+    }
+
     /// Returns an iterator of token holders.
     pub fn holders(&self) -> Iter<Address> {
         self.holders.iter()
@@ -1,6 +1,6 @@
 use core::hash::Hash;
 
-use crate::modules::AccessControl;
+use crate::modules::{AccessControl, Role};
 use crate::utils::Error;
 
 use odra::{
@@ -23,7 +23,8 @@ pub struct StakesStorage {
 impl StakesStorage {
     /// Increases the voter's stake and total stake.
     pub fn stake(&mut self, voter: Address, stake: Balance) {
-        self.access_control.ensure_whitelisted();
+        self.access_control.ensure_not_paused();
+        self.access_control.ensure_role(Role::Staker);
         self.assert_stake(stake);
         self.assert_balance(voter, stake);
         self.inc_stake(voter, stake);
@@ -34,7 +35,7 @@ impl StakesStorage {
     }
 
     pub fn bulk_unstake(&mut self, stakes: Vec<(Address, Balance)>) {
-        self.access_control.ensure_whitelisted();
+        self.access_control.ensure_role(Role::Staker);
 
         for (voter, stake) in stakes {
             self.assert_stake(stake);
@@ -43,6 +44,16 @@ impl StakesStorage {
     }
 
     /// Returns the total stake of the given account.
+    pub fn migrate(&mut self, from: Address, to: Address) -> Balance {
+        self.access_control.ensure_not_paused();
+        self.access_control.ensure_role(Role::Migrator);
+
+        let stake = self.get_stake(from);
+        self.stake.set(&from, Balance::zero());
+        self.inc_stake(to, stake);
+        stake
+    }
+
     pub fn get_stake(&self, address: Address) -> Balance {
         self.stake.get(&address).unwrap_or_default()
     }
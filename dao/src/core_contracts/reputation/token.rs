@@ -1,6 +1,6 @@
 use odra::prelude::collections::BTreeMap;
 
-use crate::modules::AccessControl;
+use crate::modules::{AccessControl, OwnerAction, OwnerActionArgs, OwnerProposal, Role};
 use odra::{
     contract_env,
     prelude::vec::Vec,
@@ -39,10 +39,6 @@ impl ReputationContract {
             /// Accepts the new owner proposition. This can be called only by the proposed owner.
             /// See [AccessControl](AccessControl::accept_new_owner())
             pub fn accept_new_owner(&mut self);
-            /// Adds a  new address to the whitelist.
-            ///
-            /// See [AccessControl](AccessControl::add_to_whitelist())
-            pub fn add_to_whitelist(&mut self, address: Address);
             /// Removes address from the whitelist.
             ///
             /// See [AccessControl](AccessControl::remove_from_whitelist())
@@ -51,26 +47,73 @@ impl ReputationContract {
             pub fn is_whitelisted(&self, address: Address) -> bool;
             /// Returns the address of the current owner.
             pub fn get_owner(&self) -> Option<Address>;
+            /// Grants the `role` to the `address`.
+            /// [`Read more`](AccessControl::grant_role())
+            pub fn grant_role(&mut self, role: Role, address: Address);
+            /// Revokes the `role` from the `address`.
+            /// [`Read more`](AccessControl::revoke_role())
+            pub fn revoke_role(&mut self, role: Role, address: Address);
+            /// Sets the admin role of the `role`, whose holders grant and revoke the `role`.
+            /// [`Read more`](AccessControl::set_role_admin())
+            pub fn set_role_admin(&mut self, role: Role, admin_role: Role);
+            /// Returns the admin role of the `role`, whose holders grant and revoke the `role`.
+            /// [`Read more`](AccessControl::get_role_admin())
+            pub fn get_role_admin(&self, role: Role) -> Role;
+            /// Checks whether the `address` has the `role`.
+            /// [`Read more`](AccessControl::has_role())
+            pub fn has_role(&self, role: Role, address: Address) -> bool;
+            /// Replaces the whitelist entry of the `address` with the `roles`.
+            /// [`Read more`](AccessControl::migrate_to_roles())
+            pub fn migrate_to_roles(&mut self, address: Address, roles: Vec<Role>);
+            /// Hands the ownership over to the `signers`.
+            /// [`Read more`](AccessControl::enable_multisig_ownership())
+            pub fn enable_multisig_ownership(&mut self, signers: Vec<Address>, threshold: u32);
+            /// Proposes an owner-only action.
+            /// [`Read more`](AccessControl::propose_owner_action())
+            pub fn propose_owner_action(&mut self, action: OwnerAction, action_args: OwnerActionArgs) -> u32;
+            /// Approves a proposed owner-only action.
+            /// [`Read more`](AccessControl::approve_owner_action())
+            pub fn approve_owner_action(&mut self, proposal_id: u32);
+            /// Executes a proposed owner-only action approved by the current signers.
+            /// [`Read more`](AccessControl::execute_owner_action())
+            pub fn execute_owner_action(&mut self, proposal_id: u32);
+            /// Returns the signers of the multisig ownership.
+            /// [`Read more`](AccessControl::get_signers())
+            pub fn get_signers(&self) -> Vec<Address>;
+            /// Returns the number of approvals required to execute an owner-only action.
+            /// [`Read more`](AccessControl::get_threshold())
+            pub fn get_threshold(&self) -> u32;
+            /// Returns the proposal of an owner-only action.
+            /// [`Read more`](AccessControl::get_owner_proposal())
+            pub fn get_owner_proposal(&self, proposal_id: u32) -> Option<OwnerProposal>;
+            /// Pauses the contract.
+            /// [`Read more`](AccessControl::pause())
+            pub fn pause(&mut self);
+            /// Unpauses the contract.
+            /// [`Read more`](AccessControl::unpause())
+            pub fn unpause(&mut self);
+            /// Returns true if the contract is paused.
+            /// [`Read more`](AccessControl::is_paused())
+            pub fn is_paused(&self) -> bool;
         }
 
         to self.reputation_storage {
             /// Mints new tokens. Adds `amount` of new tokens to the balance of the `recipient` and
-            /// increments the total supply. Only whitelisted addresses are permitted to call this method.
+            /// increments the total supply.
             ///
             /// # Errors
-            /// * [`NotWhitelisted`](crate::utils::Error::NotWhitelisted) if caller
-            /// is not whitelisted.
+            /// * [`MissingRole`](crate::utils::Error::MissingRole) if the caller
+            /// does not have the [`Minter`](crate::modules::Role::Minter) role.
             ///
             /// # Events
             /// * [`Mint`](events::Mint).
             pub fn mint(&mut self, recipient: Address, amount: Balance);
             /// Burns existing tokens. Removes `amount` of existing tokens from the balance of the `owner`
-            /// and decrements the total supply. Only whitelisted addresses are permitted to call this
-            /// method.
+            /// and decrements the total supply.
             ///
             /// # Errors
-            /// * [`NotWhitelisted`](crate::utils::Error::NotWhitelisted) if caller
-            /// is not whitelisted.
+            /// * [`MissingRole`](crate::utils::Error::MissingRole) if the caller
+            /// does not have the [`Burner`](crate::modules::Role::Burner) role.
             ///
             /// # Events
             /// * [`Burn`](events::Burn) event.
@@ -109,11 +152,11 @@ impl ReputationContract {
     /// * Events dictionary.
     /// * Named keys of [`AccessControl`].
     /// * Set `caller` as the owner of the contract.
-    /// * Add `caller` to the whitelist.
+    /// * Grant `caller` the [`Admin`](crate::modules::Role::Admin) role.
     ///
     /// # Events
     /// * [`OwnerChanged`](crate::modules::owner::events::OwnerChanged),
-    /// * [`AddedToWhitelist`](crate::modules::whitelist::events::AddedToWhitelist).
+    /// * [`RoleGranted`](crate::modules::roles::events::RoleGranted).
     #[odra(init)]
     pub fn init(&mut self) {
         let deployer = contract_env::caller();
@@ -123,8 +166,8 @@ impl ReputationContract {
     /// Increases the balance of the passive reputation of the given address.
     ///
     /// # Errors
-    /// * [`NotWhitelisted`](crate::utils::Error::NotWhitelisted) if caller
-    /// is not whitelisted.
+    /// * [`MissingRole`](crate::utils::Error::MissingRole) if the caller
+    /// does not have the [`Minter`](crate::modules::Role::Minter) role.
     pub fn mint_passive(&mut self, recipient: Address, amount: Balance) {
         self.passive_reputation_storage.mint(recipient, amount);
     }
@@ -132,8 +175,8 @@ impl ReputationContract {
     /// Decreases the balance of the passive reputation of the given address.
     ///
     /// # Errors
-    /// * [`NotWhitelisted`](crate::utils::Error::NotWhitelisted) if caller
-    /// is not whitelisted.
+    /// * [`MissingRole`](crate::utils::Error::MissingRole) if the caller
+    /// does not have the [`Burner`](crate::modules::Role::Burner) role.
     /// * [`InsufficientBalance`](crate::utils::Error::InsufficientBalance) if the passed
     /// amount exceeds the balance of the passive reputation of the given address.
     pub fn burn_passive(&mut self, owner: Address, amount: Balance) {
@@ -144,6 +187,22 @@ impl ReputationContract {
     pub fn passive_balance_of(&self, address: Address) -> Balance {
         self.passive_reputation_storage.balance_of(address)
     }
+
+    /// Moves the reputation, the passive reputation and the staked reputation of the `from` address
+    /// to the `to` address. The total supply does not change.
+    ///
+    /// # Errors
+    /// * [`MissingRole`](crate::utils::Error::MissingRole) if the caller
+    /// does not have the [`Migrator`](crate::modules::Role::Migrator) role.
+    ///
+    /// # Events
+    /// * [`Burn`](events::Burn) and [`Mint`](events::Mint) events for the reputation
+    /// and the passive reputation.
+    pub fn migrate(&mut self, from: Address, to: Address) {
+        self.reputation_storage.migrate(from, to);
+        self.passive_reputation_storage.migrate(from, to);
+        self.stakes_storage.migrate(from, to);
+    }
 }
 
 pub mod events {
@@ -11,18 +11,36 @@
 //!
 //! Each [`Address`] can own only one VA token.
 //!
+//! The contract records when a VA token has been minted, so the other contracts can tell
+//! if the VA is still on probation.
+//!
+//! # Metadata
+//! Each token exposes on-chain metadata: the join date and the voting the token has been issued by.
+//! The metadata is rendered as a CEP-78 compatible JSON document by `token_uri`.
+//!
+//! # CEP-78
+//! The contract exposes the read-only part of the CEP-78 interface, so the tokens are discoverable
+//! by the standard tooling. The tokens are soulbound - there is no way to transfer a token,
+//! minting and burning is restricted to the DAO contracts granted the
+//! [`Minter`](crate::modules::Role::Minter) and [`Burner`](crate::modules::Role::Burner) roles.
+//!
 //! [`Bid Escrow`]: crate::bid_escrow::BidEscrowContractInterface.
 use odra::{
-    prelude::string::String,
-    types::{Address, Balance, U256},
+    contract_env::get_block_time,
+    prelude::{string::String, vec::Vec},
+    types::{Address, Balance, BlockTime, U256},
+    Mapping,
 };
 
-use crate::core_contracts::dao_nft::{DaoNft, TokenId, TokenUri};
+use crate::core_contracts::dao_nft::{DaoNft, TokenId, TokenMetadata, TokenStatus, TokenUri};
+use crate::modules::{OwnerAction, OwnerActionArgs, OwnerProposal, Role};
+use crate::voting::types::VotingId;
 
 /// NFT contract holding information about members of the DAO.
 #[odra::module]
 pub struct VaNftContract {
     token: DaoNft,
+    onboarded_at: Mapping<Address, BlockTime>,
 }
 
 #[odra::module]
@@ -44,9 +62,6 @@ impl VaNftContract {
             /// Accepts the new owner proposition. This can be called only by the proposed owner.
             /// [`Read more`](crate::modules::access_control::AccessControl::accept_new_owner())
             pub fn accept_new_owner(&mut self);
-            /// Adds a new address to the whitelist.
-            /// [`Read more`](crate::modules::access_control::AccessControl::add_to_whitelist())
-            pub fn add_to_whitelist(&mut self, address: Address);
             /// Remove address from the whitelist.
             /// [`Read more`](crate::modules::access_control::AccessControl::remove_from_whitelist())
             pub fn remove_from_whitelist(&mut self, address: Address);
@@ -56,10 +71,86 @@ impl VaNftContract {
             /// Returns the address of the current owner.
             /// [`Read more`](crate::modules::access_control::AccessControl::get_owner()).
             pub fn get_owner(&self) -> Option<Address>;
+            /// Grants the `role` to the `address`.
+            /// [`Read more`](crate::modules::access_control::AccessControl::grant_role())
+            pub fn grant_role(&mut self, role: Role, address: Address);
+            /// Revokes the `role` from the `address`.
+            /// [`Read more`](crate::modules::access_control::AccessControl::revoke_role())
+            pub fn revoke_role(&mut self, role: Role, address: Address);
+            /// Sets the admin role of the `role`, whose holders grant and revoke the `role`.
+            /// [`Read more`](crate::modules::access_control::AccessControl::set_role_admin())
+            pub fn set_role_admin(&mut self, role: Role, admin_role: Role);
+            /// Returns the admin role of the `role`, whose holders grant and revoke the `role`.
+            /// [`Read more`](crate::modules::access_control::AccessControl::get_role_admin())
+            pub fn get_role_admin(&self, role: Role) -> Role;
+            /// Checks whether the `address` has the `role`.
+            /// [`Read more`](crate::modules::access_control::AccessControl::has_role())
+            pub fn has_role(&self, role: Role, address: Address) -> bool;
+            /// Replaces the whitelist entry of the `address` with the `roles`.
+            /// [`Read more`](crate::modules::access_control::AccessControl::migrate_to_roles())
+            pub fn migrate_to_roles(&mut self, address: Address, roles: Vec<Role>);
+            /// Hands the ownership over to the `signers`.
+            /// [`Read more`](crate::modules::access_control::AccessControl::enable_multisig_ownership())
+            pub fn enable_multisig_ownership(&mut self, signers: Vec<Address>, threshold: u32);
+            /// Proposes an owner-only action.
+            /// [`Read more`](crate::modules::access_control::AccessControl::propose_owner_action())
+            pub fn propose_owner_action(&mut self, action: OwnerAction, action_args: OwnerActionArgs) -> u32;
+            /// Approves a proposed owner-only action.
+            /// [`Read more`](crate::modules::access_control::AccessControl::approve_owner_action())
+            pub fn approve_owner_action(&mut self, proposal_id: u32);
+            /// Executes a proposed owner-only action approved by the current signers.
+            /// [`Read more`](crate::modules::access_control::AccessControl::execute_owner_action())
+            pub fn execute_owner_action(&mut self, proposal_id: u32);
+            /// Returns the signers of the multisig ownership.
+            /// [`Read more`](crate::modules::access_control::AccessControl::get_signers())
+            pub fn get_signers(&self) -> Vec<Address>;
+            /// Returns the number of approvals required to execute an owner-only action.
+            /// [`Read more`](crate::modules::access_control::AccessControl::get_threshold())
+            pub fn get_threshold(&self) -> u32;
+            /// Returns the proposal of an owner-only action.
+            /// [`Read more`](crate::modules::access_control::AccessControl::get_owner_proposal())
+            pub fn get_owner_proposal(&self, proposal_id: u32) -> Option<OwnerProposal>;
+            /// Pauses the contract.
+            /// [`Read more`](crate::modules::access_control::AccessControl::pause())
+            pub fn pause(&mut self);
+            /// Unpauses the contract.
+            /// [`Read more`](crate::modules::access_control::AccessControl::unpause())
+            pub fn unpause(&mut self);
+            /// Returns true if the contract is paused.
+            /// [`Read more`](crate::modules::access_control::AccessControl::is_paused())
+            pub fn is_paused(&self) -> bool;
             /// Returns a descriptive name for a collection of tokens in this contract.
             pub fn name(&self) -> String;
             /// Gets an abbreviated name for tokens in this contract.
             pub fn symbol(&self) -> String;
+            /// Returns a descriptive name for a collection of tokens in this contract.
+            ///
+            /// The CEP-78 equivalent of `name`.
+            pub fn collection_name(&self) -> String;
+            /// Gets an abbreviated name for tokens in this contract.
+            ///
+            /// The CEP-78 equivalent of `symbol`.
+            pub fn collection_symbol(&self) -> String;
+            /// Returns the CEP-78 ownership mode. The tokens cannot be transferred.
+            /// [`Read more`](crate::core_contracts::cep78::OwnershipMode)
+            pub fn ownership_mode(&self) -> u8;
+            /// Returns the CEP-78 burn mode.
+            /// [`Read more`](crate::core_contracts::cep78::BurnMode)
+            pub fn burn_mode(&self) -> u8;
+            /// Returns the CEP-78 minting mode. Only the holders of the [`Minter`](crate::modules::Role::Minter) role can mint.
+            /// [`Read more`](crate::core_contracts::cep78::MintingMode)
+            pub fn minting_mode(&self) -> u8;
+            /// Returns the CEP-78 metadata kind. The metadata follows the custom schema.
+            /// [`Read more`](crate::core_contracts::cep78::NftMetadataKind)
+            pub fn nft_metadata_kind(&self) -> u8;
+            /// Returns the CEP-78 JSON schema of the token metadata.
+            pub fn json_schema(&self) -> String;
+            /// Returns the CEP-78 metadata mutability.
+            /// [`Read more`](crate::core_contracts::cep78::MetadataMutability)
+            pub fn metadata_mutability(&self) -> u8;
+            /// Returns the CEP-78 identifier mode.
+            /// [`Read more`](crate::core_contracts::cep78::NftIdentifierMode)
+            pub fn identifier_mode(&self) -> u8;
             /// Returns the address of the owner of the token.
             ///
             /// If the given `token_id` does not exist the None value is returned.
@@ -72,36 +163,124 @@ impl VaNftContract {
             pub fn balance_of(&self, owner: &Address) -> U256;
             /// Returns the total number of tokens.
             pub fn total_supply(&self) -> Balance;
-            /// Returns a distinct Uniform Resource Identifier (URI) for a given asset.
-            pub fn token_uri(&self, token_id: TokenId) -> TokenUri;
             /// Returns a URI prefix that is used by all the assets.
             pub fn base_uri(&self) -> TokenUri;
-            /// Creates a new token with the next id and transfers it to a new owner.
-            /// Increments the total supply and the balance of the `to` address.
-            ///
-            /// # Note
-            /// Only whitelisted addresses are permitted to call this
-            /// method.
-            ///
-            /// Each user is entitled to own only one token.
-            ///
-            /// # Errors
-            /// * [`UserAlreadyOwnsToken`](crate::utils::Error::UserAlreadyOwnsToken) if the `to` address
-            /// already owns a token.
-            ///
-            /// # Events
-            /// * [`Transfer`](odra_modules::erc721::events::Transfer) when minted successfully.
-            pub fn mint(&mut self, to: Address);
             /// Burns a token with a given id. Decrements the balance of the token owner
             /// and decrements the total supply.
             ///
             /// # Errors
-            /// * [`NotWhitelisted`](crate::utils::Error::NotWhitelisted) if caller
-            /// is not whitelisted.
+            /// * [`MissingRole`](crate::utils::Error::MissingRole) if the caller
+            /// does not have the [`Burner`](crate::modules::Role::Burner) role.
             ///
             /// # Events
             /// * [`Transfer`](odra_modules::erc721::events::Transfer) when burnt successfully.
             pub fn burn(&mut self, owner: Address);
+            /// Returns the id of the voting the token has been issued by.
+            ///
+            /// If the token has not been issued by a voting the None value is returned.
+            pub fn issuing_voting(&self, token_id: TokenId) -> Option<VotingId>;
+        }
+    }
+
+    /// Creates a new token with the next id and transfers it to a new owner.
+    /// Increments the total supply and the balance of the `to` address.
+    /// Records the time of the onboarding.
+    ///
+    /// # Note
+    /// Only the holders of the [`Minter`](crate::modules::Role::Minter) role are permitted to call this
+    /// method.
+    ///
+    /// Each user is entitled to own only one token.
+    ///
+    /// # Errors
+    /// * [`UserAlreadyOwnsToken`](crate::utils::Error::UserAlreadyOwnsToken) if the `to` address
+    /// already owns a token.
+    ///
+    /// # Events
+    /// * [`Transfer`](odra_modules::erc721::events::Transfer) when minted successfully.
+    pub fn mint(&mut self, to: Address) {
+        self.token.mint(to);
+        self.onboarded_at.set(&to, get_block_time());
+    }
+
+    /// Mints a token like [`mint`](Self::mint()) and records the voting the token has been issued by.
+    ///
+    /// # Note
+    /// Only the holders of the [`Minter`](crate::modules::Role::Minter) role are permitted to call this
+    /// method.
+    ///
+    /// # Errors
+    /// * [`UserAlreadyOwnsToken`](crate::utils::Error::UserAlreadyOwnsToken) if the `to` address
+    /// already owns a token.
+    ///
+    /// # Events
+    /// * [`Transfer`](odra_modules::erc721::events::Transfer) when minted successfully.
+    pub fn mint_with_voting(&mut self, to: Address, voting_id: VotingId) {
+        self.mint(to);
+        self.token.set_issuing_voting(to, voting_id);
+    }
+
+    /// Moves the token owned by the `from` address to the `to` address.
+    /// The time of the onboarding is preserved, so the probation period is not restarted.
+    ///
+    /// # Note
+    /// Only the holders of the [`Migrator`](crate::modules::Role::Migrator) role are permitted to call this
+    /// method.
+    ///
+    /// # Errors
+    /// * [`TokenDoesNotExist`](crate::utils::Error::TokenDoesNotExist) if the `from` address
+    /// does not own a token.
+    /// * [`UserAlreadyOwnsToken`](crate::utils::Error::UserAlreadyOwnsToken) if the `to` address
+    /// already owns a token.
+    ///
+    /// # Events
+    /// * [`Transfer`](odra_modules::erc721::events::Transfer) when migrated successfully.
+    pub fn migrate(&mut self, from: Address, to: Address) {
+        self.token.migrate(from, to);
+        if let Some(onboarded_at) = self.onboarded_at.get(&from) {
+            self.onboarded_at.set(&to, onboarded_at);
+        }
+    }
+
+    /// Returns the time the VA token of the given `address` has been minted.
+    pub fn onboarded_at(&self, address: Address) -> Option<BlockTime> {
+        self.onboarded_at.get(&address)
+    }
+
+    /// Returns the on-chain metadata of the token.
+    ///
+    /// # Errors
+    /// * [`TokenDoesNotExist`](crate::utils::Error::TokenDoesNotExist) if the token does not exist.
+    pub fn token_metadata(&self, token_id: TokenId) -> TokenMetadata {
+        let owner = self.token.owner_of_or_revert(&token_id);
+        let onboarded_at = self.onboarded_at.get(&owner);
+        TokenMetadata {
+            token_id,
+            owner,
+            issued_at: onboarded_at,
+            voting_id: self.token.issuing_voting(token_id),
+            kyc_level: None,
+            expires_at: None,
+            joined_at: onboarded_at,
+            status: TokenStatus::Active,
         }
     }
+
+    /// Returns the metadata of the token rendered as a CEP-78 compatible JSON document.
+    ///
+    /// # Errors
+    /// * [`TokenDoesNotExist`](crate::utils::Error::TokenDoesNotExist) if the token does not exist.
+    pub fn token_uri(&self, token_id: TokenId) -> TokenUri {
+        self.token.render_metadata(&self.token_metadata(token_id))
+    }
+
+    /// Returns the metadata of the token rendered as a CEP-78 compatible JSON document.
+    ///
+    /// The CEP-78 equivalent of [`token_uri`](Self::token_uri()).
+    ///
+    /// # Errors
+    /// * [`TokenDoesNotExist`](crate::utils::Error::TokenDoesNotExist) if the token does not exist.
+    pub fn metadata(&self, token_id: TokenId) -> String {
+        self.token_uri(token_id)
+    }
 }
@@ -31,14 +31,32 @@
 //! | VotingClearnessDelta               | 8             | 8000         | int     | If the difference between 50/50 and result of the Informal Voting is bigger than the value, the time between votings should be doubled.                                                                                         |
 //! | VotingStartAfterJobWorkerSubmisson | 3 days        | 259200       | seconds | Time between the worker job submission and the internal voting start.                                                                                                                                                           |
 //! | BidEscrowPaymentRatio              | 0.1           | 100          | float   | How much CSPR is sent to GovernanceWallet after the Job is finished                                                                                                                                                             |
+//! | MaxDeadlineExtensions              | 2             | 2            | int     | How many times a Job deadline can be extended at the Worker's request                                                                                                                                                           |
+//! | UnrevealedBidForfeitRatio          | 0.5           | 500          | float   | How much of the stake of a sealed Bid that has not been revealed is forfeited                                                                                                                                                   |
+//! | KeeperRewardRatio                  | 0             | 0            | float   | How much of the DOS Fee of an expired Job Offer or Job is paid to the caller who settles it                                                                                                                                     |
+//! | BidEscrowFeeSplit                  |               |              | list    | Recipients of the DAO fee with their ratios. The ratios sum up to at most 1000. If empty, BidEscrowPaymentRatio is sent to BidEscrowWalletAddress                                                                               |
+//! | InsurancePoolRatio                 | 0             | 0            | float   | How much of the forfeited stakes and DOS Fees is deposited to the Insurance Pool                                                                                                                                                |
+//! | JobCategories                      |               |              | list    | Categories a Job Offer can be assigned to                                                                                                                                                                                       |
+//! | SkillTags                          |               |              | list    | Skill tags Job Offers and VAs' profiles can be labeled with                                                                                                                                                                     |
+//! | InternalAuctionSkillMatchRequired  | false         | false        | bool    | If true, only VAs with a skill matching the Job Offer can bid in the Internal Auction                                                                                                                                           |
+//! | OffboardingReputationToPassive     | false         | false        | bool    | If true, the reputation of an offboarded VA is converted to passive reputation, otherwise it is burned                                                                                                                          |
+//! | SponsoredInformalVotingTime        | 1 day         | 86400        | seconds | Time of the Informal Voting of an Onboarding Request sponsored by a VA. The informal quorum is not required                                                                                                                     |
+//! | SponsorshipProbationPeriod         | 30 days       | 2592000      | seconds | Time after a sponsored onboarding during which the sponsor's stake is burned if the new VA is fully slashed                                                                                                                     |
+//! | VaProbationPeriod                  | 0             | 0            | seconds | Time after the onboarding during which a new VA can stake at most VaProbationStakeLimit and cannot create Admin and Repo votings                                                                                                |
+//! | VaProbationStakeLimit              | 100           | 100000000000 | int     | The maximum stake of a VA on probation                                                                                                                                                                                          |
+//! | KycValidityPeriod                  | 365 days      | 31536000     | seconds | Time a KYC token is valid for after it has been issued or renewed. If 0, the KYC token does not expire                                                                                                                          |
+//! | KycRevocationForfeitsDeposit       | false         | false        | bool    | If true, the CSPR deposit of a pending Onboarding Request is forfeited when the KYC of the requestor is revoked, otherwise it is returned                                                                                       |
+//! | AccountRecoveryChallengePeriod     | 7 days        | 604800       | seconds | Time after an account recovery voting passes during which the recovered account can veto the recovery                                                                                                                           |
 //! | BidEscrowWalletAddress             |               |              | address | An address of a multisig wallet of the DAO.
 //!
 //!  [Repo Voting]: crate::voting_contracts::RepoVoterContract
 
-use crate::modules::{AccessControl, Record, Repository};
+use crate::modules::{
+    AccessControl, OwnerAction, OwnerActionArgs, OwnerProposal, Record, Repository, Role,
+};
 use crate::utils::Error;
 use odra::contract_env::caller;
-use odra::prelude::{collections::BTreeMap, string::String};
+use odra::prelude::{collections::BTreeMap, string::String, vec::Vec};
 use odra::types::{Address, Bytes};
 use odra::UnwrapOrRevert;
 
@@ -59,6 +77,54 @@ impl VariableRepositoryContract {
             /// Returns the address of the current owner.
             /// [`Read more`](AccessControl::get_owner()).
             pub fn get_owner(&self) -> Option<Address>;
+            /// Grants the `role` to the `address`.
+            /// [`Read more`](AccessControl::grant_role())
+            pub fn grant_role(&mut self, role: Role, address: Address);
+            /// Revokes the `role` from the `address`.
+            /// [`Read more`](AccessControl::revoke_role())
+            pub fn revoke_role(&mut self, role: Role, address: Address);
+            /// Sets the admin role of the `role`, whose holders grant and revoke the `role`.
+            /// [`Read more`](AccessControl::set_role_admin())
+            pub fn set_role_admin(&mut self, role: Role, admin_role: Role);
+            /// Returns the admin role of the `role`, whose holders grant and revoke the `role`.
+            /// [`Read more`](AccessControl::get_role_admin())
+            pub fn get_role_admin(&self, role: Role) -> Role;
+            /// Checks whether the `address` has the `role`.
+            /// [`Read more`](AccessControl::has_role())
+            pub fn has_role(&self, role: Role, address: Address) -> bool;
+            /// Replaces the whitelist entry of the `address` with the `roles`.
+            /// [`Read more`](AccessControl::migrate_to_roles())
+            pub fn migrate_to_roles(&mut self, address: Address, roles: Vec<Role>);
+            /// Hands the ownership over to the `signers`.
+            /// [`Read more`](AccessControl::enable_multisig_ownership())
+            pub fn enable_multisig_ownership(&mut self, signers: Vec<Address>, threshold: u32);
+            /// Proposes an owner-only action.
+            /// [`Read more`](AccessControl::propose_owner_action())
+            pub fn propose_owner_action(&mut self, action: OwnerAction, action_args: OwnerActionArgs) -> u32;
+            /// Approves a proposed owner-only action.
+            /// [`Read more`](AccessControl::approve_owner_action())
+            pub fn approve_owner_action(&mut self, proposal_id: u32);
+            /// Executes a proposed owner-only action approved by the current signers.
+            /// [`Read more`](AccessControl::execute_owner_action())
+            pub fn execute_owner_action(&mut self, proposal_id: u32);
+            /// Returns the signers of the multisig ownership.
+            /// [`Read more`](AccessControl::get_signers())
+            pub fn get_signers(&self) -> Vec<Address>;
+            /// Returns the number of approvals required to execute an owner-only action.
+            /// [`Read more`](AccessControl::get_threshold())
+            pub fn get_threshold(&self) -> u32;
+            /// Returns the proposal of an owner-only action.
+            /// [`Read more`](AccessControl::get_owner_proposal())
+            pub fn get_owner_proposal(&self, proposal_id: u32) -> Option<OwnerProposal>;
+            /// Pauses the contract.
+            /// [`Read more`](AccessControl::pause())
+            pub fn pause(&mut self);
+            /// Unpauses the contract.
+            /// [`Read more`](AccessControl::unpause())
+            pub fn unpause(&mut self);
+            /// Returns true if the contract is paused.
+            /// [`Read more`](AccessControl::is_paused())
+            pub fn is_paused(&self) -> bool;
             /// Changes the ownership of the contract. Transfers ownership to the `owner`.
             /// Only the current owner is permitted to call this method.
             /// [`Read more`](AccessControl::propose_new_owner())
@@ -66,9 +132,6 @@ impl VariableRepositoryContract {
             /// Accepts the new owner proposition. This can be called only by the proposed owner.
             /// [`Read more`](AccessControl::accept_new_owner())
             pub fn accept_new_owner(&mut self);
-            /// Adds a new address to the whitelist.
-            /// [`Read more`](AccessControl::add_to_whitelist())
-            pub fn add_to_whitelist(&mut self, address: Address);
             /// Remove address from the whitelist.
             /// [`Read more`](AccessControl::remove_from_whitelist())
             pub fn remove_from_whitelist(&mut self, address: Address);
@@ -81,11 +144,11 @@ impl VariableRepositoryContract {
     /// Initializes contract elements:
     /// * Sets the default configuration of the [`Repository`](crate::modules::repository::Repository)
     /// * Sets [`caller`] as the owner of the contract.
-    /// * Adds [`caller`] to the whitelist.
+    /// * Grants [`caller`] the [`Admin`](crate::modules::Role::Admin) role.
     ///
     /// # Events
     /// * [`OwnerChanged`](crate::modules::owner::events::OwnerChanged),
-    /// * [`AddedToWhitelist`](crate::modules::whitelist::events::AddedToWhitelist),
+    /// * [`RoleGranted`](crate::modules::roles::events::RoleGranted),
     /// * multiple [`ValueUpdated`](crate::modules::repository::events::ValueUpdated) events,
     /// one per value of the default repository configuration.
     #[odra(init)]
@@ -113,12 +176,13 @@ impl VariableRepositoryContract {
     /// * [`ValueUpdated`](crate::modules::repository::events::ValueUpdated).
     ///
     /// # Errors
-    /// * [`NotWhitelisted`](crate::utils::Error::NotWhitelisted) if the caller
-    /// is not a whitelisted user.
+    /// * [`MissingRole`](crate::utils::Error::MissingRole) if the caller
+    /// does not have the [`VariableEditor`](crate::modules::Role::VariableEditor) role.
     /// * [`ActivationTimeInPast`](crate::utils::Error::ActivationTimeInPast) if
     /// the activation time has passed already.
     pub fn update_at(&mut self, key: String, value: Bytes, activation_time: Option<u64>) {
-        self.access_control.ensure_whitelisted();
+        self.access_control.ensure_not_paused();
+        self.access_control.ensure_role(Role::VariableEditor);
         self.repository.update_at(key, value, activation_time);
     }
 
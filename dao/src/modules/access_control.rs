@@ -1,6 +1,37 @@
 //! AccessControl module.
-use crate::modules::{Owner, Whitelist};
-use odra::contract_env::caller;
+//!
+//! Protected entry points require the caller to have a specific [`Role`].
+//! Each role is administered by another role, by default the [`Admin`](Role::Admin) role.
+//!
+//! # Whitelist
+//! The whitelist predates the roles and does not grant any of them. No address can be added to it,
+//! a whitelisted address is moved to the roles it actually needs with
+//! [`migrate_to_roles`](AccessControl::migrate_to_roles()) or removed from the whitelist.
+//!
+//! # Multisig ownership
+//! The owner can hand the ownership over to a set of signers with
+//! [`enable_multisig_ownership`](AccessControl::enable_multisig_ownership()).
+//! From then on the contract owns itself and each owner-only action is proposed, approved
+//! by the signers and executed once the number of approvals reaches the threshold.
+//! Accepting a new single owner proposed this way disables the multisig ownership
+//! and discards the pending proposals, so they cannot be executed once it is enabled again.
+//!
+//! # Emergency pause
+//! The owner or a [`Guardian`](Role::Guardian) can [`pause`](AccessControl::pause()) the contract.
+//! A paused contract rejects calls to its state-changing entry points with
+//! [`ContractPaused`](crate::utils::Error::ContractPaused), except for the allowlisted ones,
+//! which let users withdraw and claim their funds: `claim`, `release_sponsorship`, `cancel_bid`,
+//! `cancel_job_offer`, `cancel_finished_voting`, `unstake` and `bulk_unstake`.
+//! The access control entry points and the configuration entry points guarded by
+//! the [`Operator`](Role::Operator) role remain callable, so the contract can be unpaused
+//! and fixed.
+use crate::modules::{
+    MultisigOwner, Owner, OwnerAction, OwnerActionArgs, OwnerProposal, Pausable, Role, Roles,
+    Whitelist,
+};
+use crate::utils::Error;
+use odra::contract_env::{caller, revert, self_address};
+use odra::prelude::vec::Vec;
 use odra::types::Address;
 
 /// A AccessControl module storage definition.
@@ -8,6 +39,9 @@ use odra::types::Address;
 pub struct AccessControl {
     pub owner: Owner,
     pub whitelist: Whitelist,
+    pub roles: Roles,
+    pub multisig: MultisigOwner,
+    pub pausable: Pausable,
 }
 
 /// Module entrypoints implementation.
@@ -15,13 +49,14 @@ pub struct AccessControl {
 impl AccessControl {
     /// Module constructor.
     ///
-    /// Initializes submodules.
+    /// Initializes submodules. The `address` becomes the owner and is granted
+    /// the [`Admin`](Role::Admin) role only, any other role must be granted explicitly.
     ///
-    /// See [`Owner`] and [`Whitelist`].
+    /// See [`Owner`] and [`Roles`].
     #[odra(init)]
     pub fn init(&mut self, address: Address) {
         self.owner.init(address);
-        self.whitelist.add_to_whitelist(address);
+        self.roles.grant_role(Role::Admin, address);
     }
 
     /// Proposes a change of ownership of the contract. Owner will be changed if accepted by propsed
@@ -38,26 +73,132 @@ impl AccessControl {
 
     /// Accepts the new owner proposition. This can be called only by the proposed owner.
     ///
+    /// The [`Admin`](Role::Admin) role passes from the previous owner to the new one.
+    /// If the multisig ownership is enabled, it gets disabled.
+    ///
     /// # Events
     /// Emits [`OwnerChanged`](crate::modules::owner::events::OwnerChanged),
-    /// [`AddedToWhitelist`](crate::modules::whitelist::events::AddedToWhitelist) events.
+    /// [`RoleRevoked`](crate::modules::roles::events::RoleRevoked),
+    /// [`RoleGranted`](crate::modules::roles::events::RoleGranted) events.
     pub fn accept_new_owner(&mut self) {
         let caller = caller();
+        let previous_owner = self.owner.get_owner();
         self.owner.accept_owner(caller);
-        self.whitelist.add_to_whitelist(caller);
+        if let Some(previous_owner) = previous_owner {
+            self.roles.revoke_role(Role::Admin, previous_owner);
+        }
+        self.roles.grant_role(Role::Admin, caller);
+        if self.multisig.is_enabled() {
+            self.multisig.disable();
+        }
     }
 
-    /// Adds a new address to the whitelist.
+    /// Hands the ownership over to the `signers`. Owner-only actions must be approved
+    /// by `threshold` signers from then on. The current owner loses all its roles
+    /// and is removed from the whitelist.
     ///
     /// # Errors
     /// Throws [`NotAnOwner`](crate::utils::Error::NotAnOwner) if the caller
     /// is not the current owner.
+    /// Throws [`InvalidThreshold`](crate::utils::Error::InvalidThreshold) if the `threshold`
+    /// is zero or exceeds the number of the `signers`.
+    /// Throws [`SignerAlreadyExists`](crate::utils::Error::SignerAlreadyExists) if
+    /// the `signers` contain duplicates.
     ///
     /// # Events
-    /// Emits [`AddedToWhitelist`](crate::modules::whitelist::events::AddedToWhitelist) event.
-    pub fn add_to_whitelist(&mut self, address: Address) {
+    /// Emits [`SignersChanged`](crate::modules::multisig_owner::events::SignersChanged),
+    /// [`OwnerChanged`](crate::modules::owner::events::OwnerChanged),
+    /// [`RoleRevoked`](crate::modules::roles::events::RoleRevoked),
+    /// [`RemovedFromWhitelist`](crate::modules::whitelist::events::RemovedFromWhitelist) events.
+    pub fn enable_multisig_ownership(&mut self, signers: Vec<Address>, threshold: u32) {
         self.owner.ensure_owner();
-        self.whitelist.add_to_whitelist(address);
+        let caller = caller();
+        self.multisig.enable(signers, threshold);
+        self.owner.change_ownership(self_address());
+        self.roles.revoke_all_roles(caller);
+        if self.whitelist.is_whitelisted(caller) {
+            self.whitelist.remove_from_whitelist(caller);
+        }
+    }
+
+    /// Proposes an owner-only action. The proposal is approved by the proposer.
+    ///
+    /// # Errors
+    /// Throws [`NotASigner`](crate::utils::Error::NotASigner) if the caller is not a signer.
+    ///
+    /// # Events
+    /// Emits [`OwnerActionProposed`](crate::modules::multisig_owner::events::OwnerActionProposed) event.
+    pub fn propose_owner_action(
+        &mut self,
+        action: OwnerAction,
+        action_args: OwnerActionArgs,
+    ) -> u32 {
+        self.multisig.propose(action, action_args)
+    }
+
+    /// Approves a proposed owner-only action.
+    ///
+    /// # Errors
+    /// Throws [`NotASigner`](crate::utils::Error::NotASigner) if the caller is not a signer.
+    /// Throws [`OwnerActionDoesNotExist`](crate::utils::Error::OwnerActionDoesNotExist) if
+    /// the proposal does not exist or has been discarded by disabling the multisig ownership.
+    /// Throws [`OwnerActionAlreadyExecuted`](crate::utils::Error::OwnerActionAlreadyExecuted) if
+    /// the proposal has been executed.
+    /// Throws [`OwnerActionAlreadyApproved`](crate::utils::Error::OwnerActionAlreadyApproved) if
+    /// the caller has already approved the proposal.
+    ///
+    /// # Events
+    /// Emits [`OwnerActionApproved`](crate::modules::multisig_owner::events::OwnerActionApproved) event.
+    pub fn approve_owner_action(&mut self, proposal_id: u32) {
+        self.multisig.approve(proposal_id);
+    }
+
+    /// Executes a proposed owner-only action approved by the current signers.
+    ///
+    /// # Errors
+    /// Throws [`NotASigner`](crate::utils::Error::NotASigner) if the caller is not a signer.
+    /// Throws [`OwnerActionDoesNotExist`](crate::utils::Error::OwnerActionDoesNotExist) if
+    /// the proposal does not exist or has been discarded by disabling the multisig ownership.
+    /// Throws [`OwnerActionAlreadyExecuted`](crate::utils::Error::OwnerActionAlreadyExecuted) if
+    /// the proposal has been executed.
+    /// Throws [`OwnerActionThresholdNotReached`](crate::utils::Error::OwnerActionThresholdNotReached)
+    /// if the proposal has not been approved by enough signers.
+    /// Throws [`InvalidOwnerActionArgs`](crate::utils::Error::InvalidOwnerActionArgs) if
+    /// an argument required by the action is missing.
+    ///
+    /// # Events
+    /// Emits [`OwnerActionExecuted`](crate::modules::multisig_owner::events::OwnerActionExecuted)
+    /// event and the events of the executed action.
+    pub fn execute_owner_action(&mut self, proposal_id: u32) {
+        let proposal = self.multisig.execute(proposal_id);
+        let args = proposal.args;
+        match proposal.action {
+            OwnerAction::RemoveFromWhitelist => {
+                self.whitelist.remove_from_whitelist(args.address())
+            }
+            OwnerAction::ProposeNewOwner => self.owner.propose_owner(args.address()),
+            OwnerAction::GrantRole => self.roles.grant_role(args.role(), args.address()),
+            OwnerAction::RevokeRole => self.roles.revoke_role(args.role(), args.address()),
+            OwnerAction::SetRoleAdmin => self.roles.set_role_admin(args.role(), args.admin_role()),
+            OwnerAction::AddSigner => self.multisig.add_signer(args.address()),
+            OwnerAction::RemoveSigner => self.multisig.remove_signer(args.address()),
+            OwnerAction::ChangeThreshold => self.multisig.change_threshold(args.threshold()),
+        }
+    }
+
+    /// Returns the signers of the multisig ownership.
+    pub fn get_signers(&self) -> Vec<Address> {
+        self.multisig.get_signers()
+    }
+
+    /// Returns the number of approvals required to execute an owner-only action.
+    pub fn get_threshold(&self) -> u32 {
+        self.multisig.get_threshold()
+    }
+
+    /// Returns the proposal of an owner-only action.
+    pub fn get_owner_proposal(&self, proposal_id: u32) -> Option<OwnerProposal> {
+        self.multisig.get_proposal(proposal_id)
     }
 
     /// Removes the `address` from the whitelist.
@@ -79,15 +220,150 @@ impl AccessControl {
         self.whitelist.is_whitelisted(address)
     }
 
-    /// Verifies whether the current caller address is added to the whitelist.
-    pub fn ensure_whitelisted(&self) {
-        self.whitelist.ensure_whitelisted();
-    }
-
     /// Returns the address of the current owner.
     ///
     /// See [`Owner`].
     pub fn get_owner(&self) -> Option<Address> {
         self.owner.get_owner()
     }
+
+    /// Grants the `role` to the `address`.
+    ///
+    /// # Errors
+    /// Throws [`MissingRole`](crate::utils::Error::MissingRole) if the caller
+    /// is neither the owner nor has the admin role of the `role`.
+    ///
+    /// # Events
+    /// Emits [`RoleGranted`](crate::modules::roles::events::RoleGranted) event.
+    pub fn grant_role(&mut self, role: Role, address: Address) {
+        self.ensure_role_admin(role);
+        self.roles.grant_role(role, address);
+    }
+
+    /// Revokes the `role` from the `address`.
+    ///
+    /// # Errors
+    /// Throws [`MissingRole`](crate::utils::Error::MissingRole) if the caller
+    /// is neither the owner nor has the admin role of the `role`.
+    ///
+    /// # Events
+    /// Emits [`RoleRevoked`](crate::modules::roles::events::RoleRevoked) event.
+    pub fn revoke_role(&mut self, role: Role, address: Address) {
+        self.ensure_role_admin(role);
+        self.roles.revoke_role(role, address);
+    }
+
+    /// Sets the admin role of the `role`, whose holders grant and revoke the `role`.
+    ///
+    /// # Errors
+    /// Throws [`NotAnOwner`](crate::utils::Error::NotAnOwner) if the caller
+    /// is not the current owner.
+    ///
+    /// # Events
+    /// Emits [`RoleAdminChanged`](crate::modules::roles::events::RoleAdminChanged) event.
+    pub fn set_role_admin(&mut self, role: Role, admin_role: Role) {
+        self.owner.ensure_owner();
+        self.roles.set_role_admin(role, admin_role);
+    }
+
+    /// Returns the admin role of the `role`, whose holders grant and revoke the `role`.
+    pub fn get_role_admin(&self, role: Role) -> Role {
+        self.roles.get_role_admin(role)
+    }
+
+    /// Checks whether the `address` has been granted the `role`.
+    pub fn has_role(&self, role: Role, address: Address) -> bool {
+        self.roles.has_role(role, address)
+    }
+
+    /// Replaces the whitelist entry of the `address` with the `roles`.
+    ///
+    /// # Errors
+    /// Throws [`MissingRole`](crate::utils::Error::MissingRole) if the caller
+    /// is neither the owner nor has the admin role of any of the `roles`.
+    /// Throws [`NotWhitelisted`](crate::utils::Error::NotWhitelisted) if the `address`
+    /// is not whitelisted.
+    ///
+    /// # Events
+    /// Emits [`RemovedFromWhitelist`](crate::modules::whitelist::events::RemovedFromWhitelist),
+    /// [`RoleGranted`](crate::modules::roles::events::RoleGranted) events.
+    pub fn migrate_to_roles(&mut self, address: Address, roles: Vec<Role>) {
+        for role in &roles {
+            self.ensure_role_admin(*role);
+        }
+        if !self.whitelist.is_whitelisted(address) {
+            revert(Error::NotWhitelisted);
+        }
+        self.whitelist.remove_from_whitelist(address);
+        for role in roles {
+            self.roles.grant_role(role, address);
+        }
+    }
+
+    /// Pauses the contract.
+    ///
+    /// # Errors
+    /// Throws [`MissingRole`](crate::utils::Error::MissingRole) if the caller
+    /// is neither the owner nor has the [`Guardian`](Role::Guardian) role.
+    /// Throws [`ContractPaused`](crate::utils::Error::ContractPaused) if the contract
+    /// is already paused.
+    ///
+    /// # Events
+    /// Emits [`Paused`](crate::modules::pausable::events::Paused) event.
+    pub fn pause(&mut self) {
+        self.ensure_guardian();
+        self.pausable.pause();
+    }
+
+    /// Unpauses the contract.
+    ///
+    /// # Errors
+    /// Throws [`MissingRole`](crate::utils::Error::MissingRole) if the caller
+    /// is neither the owner nor has the [`Guardian`](Role::Guardian) role.
+    /// Throws [`ContractNotPaused`](crate::utils::Error::ContractNotPaused) if the contract
+    /// is not paused.
+    ///
+    /// # Events
+    /// Emits [`Unpaused`](crate::modules::pausable::events::Unpaused) event.
+    pub fn unpause(&mut self) {
+        self.ensure_guardian();
+        self.pausable.unpause();
+    }
+
+    /// Returns true if the contract is paused.
+    pub fn is_paused(&self) -> bool {
+        self.pausable.is_paused()
+    }
+
+    /// Verifies the contract is not paused.
+    ///
+    /// # Errors
+    /// Throws [`ContractPaused`](crate::utils::Error::ContractPaused) if the contract is paused.
+    pub fn ensure_not_paused(&self) {
+        self.pausable.ensure_not_paused();
+    }
+
+    /// Verifies whether the current caller has the `role`.
+    pub fn ensure_role(&self, role: Role) {
+        if !self.has_role(role, caller()) {
+            revert(Error::MissingRole);
+        }
+    }
+
+    fn ensure_guardian(&self) {
+        let caller = caller();
+        if self.owner.get_owner() != Some(caller) && !self.roles.has_role(Role::Guardian, caller) {
+            revert(Error::MissingRole);
+        }
+    }
+
+    fn ensure_role_admin(&self, role: Role) {
+        let caller = caller();
+        if self.owner.get_owner() == Some(caller) {
+            return;
+        }
+        if !self.roles.has_role(self.roles.get_role_admin(role), caller) {
+            revert(Error::MissingRole);
+        }
+    }
 }
@@ -0,0 +1,248 @@
+//! Claimable balances module.
+use crate::bid_escrow::events::{CSPRTransfer, TokenTransfer, TransferReason};
+use crate::modules::claims::events::{ClaimCredited, TokenClaimCredited};
+use crate::utils::{withdraw_tokens, Error};
+use crate::voting_contracts::InsurancePoolContractRef;
+use odra::contract_env::{caller, revert, self_address, transfer_tokens};
+use odra::prelude::{string::String, string::ToString, vec::Vec};
+use odra::types::event::OdraEvent;
+use odra::types::{Address, Balance};
+use odra::{Mapping, OdraType, Variable};
+
+/// CSPR owed to an account for a given reason.
+#[derive(OdraType, Debug, PartialEq, Eq)]
+pub struct PendingClaim {
+    /// The reason of the transfer - see [`TransferReason`].
+    pub reason: String,
+    /// The amount of CSPR to claim.
+    pub amount: Balance,
+}
+
+/// CEP-18 tokens owed to an account.
+#[derive(OdraType, Debug, PartialEq, Eq)]
+pub struct PendingTokenClaim {
+    /// The address of the token.
+    pub token: Address,
+    /// The amount of tokens to claim.
+    pub amount: Balance,
+}
+
+/// A ledger of CSPR and CEP-18 tokens owed by the contract.
+///
+/// Instead of pushing the funds to the recipients, settlements credit their balances,
+/// and the recipients pull the funds calling [`claim()`](Claims::claim()) or
+/// [`claim_token()`](Claims::claim_token()).
+#[odra::module(events = [ClaimCredited, TokenClaimCredited, CSPRTransfer, TokenTransfer])]
+pub struct Claims {
+    pending: Mapping<Address, Vec<PendingClaim>>,
+    pending_tokens: Mapping<Address, Vec<PendingTokenClaim>>,
+    total_pending: Variable<Balance>,
+}
+
+impl Claims {
+    /// Credits the balance of `to` with a given amount of CSPR.
+    pub fn credit(&mut self, to: &Address, amount: Balance, reason: TransferReason) {
+        if amount.is_zero() {
+            return;
+        }
+
+        let reason = reason.to_string();
+        let mut claims = self.pending.get_or_default(to);
+        match claims.iter_mut().find(|claim| claim.reason == reason) {
+            Some(claim) => claim.amount += amount,
+            None => claims.push(PendingClaim {
+                reason: reason.clone(),
+                amount,
+            }),
+        }
+        self.pending.set(to, claims);
+        self.total_pending.add(amount);
+
+        ClaimCredited {
+            to: *to,
+            amount,
+            reason,
+        }
+        .emit();
+    }
+
+    /// Credits the balance of `to` with a given amount of CEP-18 tokens.
+    pub fn credit_token(
+        &mut self,
+        token: &Address,
+        to: &Address,
+        amount: Balance,
+        reason: TransferReason,
+    ) {
+        if amount.is_zero() {
+            return;
+        }
+
+        let mut claims = self.pending_tokens.get_or_default(to);
+        match claims.iter_mut().find(|claim| claim.token == *token) {
+            Some(claim) => claim.amount += amount,
+            None => claims.push(PendingTokenClaim {
+                token: *token,
+                amount,
+            }),
+        }
+        self.pending_tokens.set(to, claims);
+
+        TokenClaimCredited {
+            token: *token,
+            to: *to,
+            amount,
+            reason: reason.to_string(),
+        }
+        .emit();
+    }
+
+    /// Credits a payment - CSPR if `payment_token` is `None`, CEP-18 tokens otherwise.
+    pub fn credit_payment(
+        &mut self,
+        to: &Address,
+        amount: Balance,
+        payment_token: Option<Address>,
+        reason: TransferReason,
+    ) {
+        match payment_token {
+            None => self.credit(to, amount, reason),
+            Some(token) => self.credit_token(&token, to, amount, reason),
+        }
+    }
+
+    /// Transfers all the CSPR owed to the caller.
+    ///
+    /// # Errors
+    /// * [`Error::NothingToClaim`]
+    pub fn claim(&mut self) {
+        let caller = caller();
+        let amount = self.take_pending(&caller);
+        transfer_tokens(&caller, amount);
+
+        CSPRTransfer {
+            from: self_address(),
+            to: caller,
+            amount,
+            reason: TransferReason::Claim.to_string(),
+        }
+        .emit();
+    }
+
+    /// Transfers all the CSPR owed to the calling [Insurance Pool](crate::voting_contracts::InsurancePoolContract).
+    ///
+    /// A contract cannot receive a plain transfer, so the CSPR is attached to
+    /// a [`deposit()`](crate::voting_contracts::InsurancePoolContract::deposit()) call.
+    ///
+    /// # Errors
+    /// * [`Error::NothingToClaim`]
+    pub fn claim_insurance_pool_deposits(&mut self) {
+        let caller = caller();
+        let amount = self.take_pending(&caller);
+        InsurancePoolContractRef::at(&caller)
+            .with_tokens(amount)
+            .deposit();
+
+        CSPRTransfer {
+            from: self_address(),
+            to: caller,
+            amount,
+            reason: TransferReason::InsurancePoolDeposit.to_string(),
+        }
+        .emit();
+    }
+
+    /// Transfers all the tokens of the given CEP-18 `token` owed to the caller.
+    ///
+    /// # Errors
+    /// * [`Error::NothingToClaim`]
+    pub fn claim_token(&mut self, token: Address) {
+        let caller = caller();
+        let mut claims = self.get_pending_token_claims(caller);
+        let amount = match claims.iter().position(|claim| claim.token == token) {
+            Some(position) => claims.remove(position).amount,
+            None => Balance::zero(),
+        };
+        if amount.is_zero() {
+            revert(Error::NothingToClaim);
+        }
+
+        self.pending_tokens.set(&caller, claims);
+        withdraw_tokens(&token, &caller, amount, TransferReason::Claim);
+    }
+
+    /// Moves all the CSPR and tokens owed to the `from` address to the `to` address.
+    pub fn migrate(&mut self, from: Address, to: Address) {
+        let mut claims = self.pending.get_or_default(&to);
+        for claim in self.pending.get_or_default(&from) {
+            match claims.iter_mut().find(|c| c.reason == claim.reason) {
+                Some(c) => c.amount += claim.amount,
+                None => claims.push(claim),
+            }
+        }
+        self.pending.set(&to, claims);
+        self.pending.set(&from, Vec::new());
+
+        let mut token_claims = self.pending_tokens.get_or_default(&to);
+        for claim in self.pending_tokens.get_or_default(&from) {
+            match token_claims.iter_mut().find(|c| c.token == claim.token) {
+                Some(c) => c.amount += claim.amount,
+                None => token_claims.push(claim),
+            }
+        }
+        self.pending_tokens.set(&to, token_claims);
+        self.pending_tokens.set(&from, Vec::new());
+    }
+
+    /// Returns the CSPR owed to a given address, grouped by the reason.
+    pub fn get_pending_claims(&self, address: Address) -> Vec<PendingClaim> {
+        self.pending.get_or_default(&address)
+    }
+
+    /// Returns the CEP-18 tokens owed to a given address, grouped by the token.
+    pub fn get_pending_token_claims(&self, address: Address) -> Vec<PendingTokenClaim> {
+        self.pending_tokens.get_or_default(&address)
+    }
+
+    /// Returns the total amount of CSPR credited and not claimed yet.
+    pub fn total_pending(&self) -> Balance {
+        self.total_pending.get_or_default()
+    }
+    fn take_pending(&mut self, address: &Address) -> Balance {
+        let amount = self
+            .get_pending_claims(*address)
+            .iter()
+            .fold(Balance::zero(), |total, claim| total + claim.amount);
+        if amount.is_zero() {
+            revert(Error::NothingToClaim);
+        }
+
+        self.pending.set(address, Vec::new());
+        self.total_pending.subtract(amount);
+        amount
+    }
+}
+
+pub mod events {
+    //! Events definitions.
+    use odra::prelude::string::String;
+    use odra::types::{Address, Balance};
+    use odra::Event;
+
+    /// Event emitted when CSPR has been credited to an account and awaits to be claimed.
+    #[derive(Debug, PartialEq, Eq, Event)]
+    pub struct ClaimCredited {
+        pub to: Address,
+        pub amount: Balance,
+        pub reason: String,
+    }
+
+    /// Event emitted when CEP-18 tokens have been credited to an account and await to be claimed.
+    #[derive(Debug, PartialEq, Eq, Event)]
+    pub struct TokenClaimCredited {
+        pub token: Address,
+        pub to: Address,
+        pub amount: Balance,
+        pub reason: String,
+    }
+}
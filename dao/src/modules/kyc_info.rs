@@ -14,8 +14,13 @@ pub struct KycInfo {
 }
 
 impl KycInfo {
-    /// Returns true if the `address` has a non-zero balance of kyc token, false otherwise.
+    /// Returns true if the `address` owns a kyc token that has not expired, false otherwise.
     pub fn is_kycd(&self, address: &Address) -> bool {
+        self.refs.kyc_token().is_kycd(*address)
+    }
+
+    /// Returns true if the `address` has a non-zero balance of kyc token, false otherwise.
+    pub fn owns_kyc_token(&self, address: &Address) -> bool {
         !self.refs.kyc_token().balance_of(address).is_zero()
     }
 
@@ -31,8 +36,12 @@ impl KycInfo {
     }
 
     /// Indicates whether there is ongoing voting for the given `address`.
+    pub fn get_ongoing_voting(&self, address: &Address) -> Option<VotingId> {
+        self.votings.get(address).flatten()
+    }
+
     pub fn exists_ongoing_voting(&self, address: &Address) -> bool {
-        self.votings.get(address).is_some()
+        self.votings.get(address).flatten().is_some()
     }
 
     /// Gets the address of the voting subject.
@@ -41,4 +50,9 @@ impl KycInfo {
             .get(&voting_id)
             .unwrap_or_revert_with(Error::VotingAddressNotFound)
     }
+
+    /// Gets the address of the voting subject if the voting has one.
+    pub fn find_voting_subject(&self, voting_id: VotingId) -> Option<Address> {
+        self.addresses.get(&voting_id)
+    }
 }
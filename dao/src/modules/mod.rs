@@ -5,7 +5,17 @@ pub mod owner;
 pub use owner::{Owner, OwnerRef};
 pub mod whitelist;
 pub use whitelist::{Whitelist, WhitelistRef};
+pub mod roles;
+pub use roles::{Role, Roles, RolesRef};
+pub mod multisig_owner;
+pub use multisig_owner::{
+    MultisigOwner, MultisigOwnerRef, OwnerAction, OwnerActionArgs, OwnerProposal,
+};
+pub mod pausable;
+pub use pausable::{Pausable, PausableRef};
 pub mod repository;
 pub use repository::{Record, Repository, RepositoryRef};
+pub mod claims;
+pub use claims::Claims;
 pub mod kyc_info;
 pub mod refs;
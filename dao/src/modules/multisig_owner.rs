@@ -0,0 +1,306 @@
+//! The MultisigOwner module.
+use crate::modules::multisig_owner::events::{
+    OwnerActionApproved, OwnerActionExecuted, OwnerActionProposed, SignersChanged,
+};
+use crate::modules::Role;
+use crate::utils::Error;
+use odra::contract_env::{caller, revert};
+use odra::prelude::{vec, vec::Vec};
+use odra::types::event::OdraEvent;
+use odra::types::Address;
+use odra::{Mapping, OdraType, Sequence, UnwrapOrRevert, Variable};
+
+/// An owner-only action executed once approved by the signers.
+///
+/// - `RemoveFromWhitelist` - removes `address` from the whitelist
+/// - `ProposeNewOwner` - proposes `address` as a new single owner of the contract
+/// - `GrantRole` - grants `role` to `address`
+/// - `RevokeRole` - revokes `role` from `address`
+/// - `SetRoleAdmin` - sets `admin_role` as the admin role of `role`
+/// - `AddSigner` - adds `address` to the signers
+/// - `RemoveSigner` - removes `address` from the signers
+/// - `ChangeThreshold` - changes the number of approvals required to `threshold`
+#[derive(OdraType, Copy, PartialEq, Eq, Debug)]
+pub enum OwnerAction {
+    RemoveFromWhitelist,
+    ProposeNewOwner,
+    GrantRole,
+    RevokeRole,
+    SetRoleAdmin,
+    AddSigner,
+    RemoveSigner,
+    ChangeThreshold,
+}
+
+/// Arguments of an [`OwnerAction`]. Only the arguments the action uses must be set.
+#[derive(OdraType, PartialEq, Eq, Debug, Default)]
+pub struct OwnerActionArgs {
+    pub address: Option<Address>,
+    pub role: Option<Role>,
+    pub admin_role: Option<Role>,
+    pub threshold: Option<u32>,
+}
+
+impl OwnerActionArgs {
+    /// Returns the `address` argument or reverts.
+    pub fn address(&self) -> Address {
+        self.address
+            .unwrap_or_revert_with(Error::InvalidOwnerActionArgs)
+    }
+
+    /// Returns the `role` argument or reverts.
+    pub fn role(&self) -> Role {
+        self.role
+            .unwrap_or_revert_with(Error::InvalidOwnerActionArgs)
+    }
+
+    /// Returns the `admin_role` argument or reverts.
+    pub fn admin_role(&self) -> Role {
+        self.admin_role
+            .unwrap_or_revert_with(Error::InvalidOwnerActionArgs)
+    }
+
+    /// Returns the `threshold` argument or reverts.
+    pub fn threshold(&self) -> u32 {
+        self.threshold
+            .unwrap_or_revert_with(Error::InvalidOwnerActionArgs)
+    }
+}
+
+/// A proposal of an owner-only action.
+#[derive(OdraType, PartialEq, Eq, Debug)]
+pub struct OwnerProposal {
+    pub action: OwnerAction,
+    pub args: OwnerActionArgs,
+    pub proposer: Address,
+    pub approvals: Vec<Address>,
+    pub executed: bool,
+}
+
+/// The MultisigOwner module.
+///
+/// Replaces a single owner with a set of signers. An owner-only action is proposed by a signer,
+/// approved by the signers and executed once the number of approvals reaches the threshold.
+#[odra::module]
+pub struct MultisigOwner {
+    pub signers: Variable<Vec<Address>>,
+    pub threshold: Variable<u32>,
+    pub proposals: Mapping<u32, OwnerProposal>,
+    pub pending_proposals: Variable<Vec<u32>>,
+    pub proposal_id_seq: Sequence<u32>,
+}
+
+#[odra::module]
+impl MultisigOwner {
+    /// Sets the initial signers and the threshold.
+    pub fn enable(&mut self, signers: Vec<Address>, threshold: u32) {
+        for (i, signer) in signers.iter().enumerate() {
+            if signers[..i].contains(signer) {
+                revert(Error::SignerAlreadyExists);
+            }
+        }
+        self.set_signers(signers, threshold);
+    }
+
+    /// Removes the signers and discards the pending proposals. The module is not enabled anymore.
+    pub fn disable(&mut self) {
+        self.set_signers(Vec::new(), 0);
+        self.pending_proposals.set(Vec::new());
+    }
+
+    /// Returns true if the owner-only actions require approvals of the signers.
+    pub fn is_enabled(&self) -> bool {
+        self.threshold.get_or_default() > 0
+    }
+
+    /// Creates a new proposal approved by the proposer.
+    pub fn propose(&mut self, action: OwnerAction, action_args: OwnerActionArgs) -> u32 {
+        let proposer = caller();
+        self.ensure_signer(proposer);
+
+        let proposal_id = self.proposal_id_seq.next_value();
+        self.proposals.set(
+            &proposal_id,
+            OwnerProposal {
+                action,
+                args: action_args,
+                proposer,
+                approvals: vec![proposer],
+                executed: false,
+            },
+        );
+        let mut pending_proposals = self.pending_proposals.get_or_default();
+        pending_proposals.push(proposal_id);
+        self.pending_proposals.set(pending_proposals);
+
+        OwnerActionProposed {
+            proposal_id,
+            action,
+            proposer,
+        }
+        .emit();
+        proposal_id
+    }
+
+    /// Adds the approval of the caller to the proposal.
+    pub fn approve(&mut self, proposal_id: u32) {
+        let signer = caller();
+        self.ensure_signer(signer);
+
+        let mut proposal = self.pending_proposal(proposal_id);
+        if proposal.approvals.contains(&signer) {
+            revert(Error::OwnerActionAlreadyApproved);
+        }
+        proposal.approvals.push(signer);
+        self.proposals.set(&proposal_id, proposal);
+
+        OwnerActionApproved {
+            proposal_id,
+            signer,
+        }
+        .emit();
+    }
+
+    /// Marks the proposal executed and returns it, if the approvals of the current signers
+    /// reached the threshold.
+    pub fn execute(&mut self, proposal_id: u32) -> OwnerProposal {
+        self.ensure_signer(caller());
+
+        let mut proposal = self.pending_proposal(proposal_id);
+        let signers = self.get_signers();
+        let approvals = proposal
+            .approvals
+            .iter()
+            .filter(|signer| signers.contains(signer))
+            .count() as u32;
+        if approvals < self.get_threshold() {
+            revert(Error::OwnerActionThresholdNotReached);
+        }
+        proposal.executed = true;
+        self.proposals.set(&proposal_id, proposal.clone());
+        let mut pending_proposals = self.pending_proposals.get_or_default();
+        pending_proposals.retain(|id| *id != proposal_id);
+        self.pending_proposals.set(pending_proposals);
+
+        OwnerActionExecuted {
+            proposal_id,
+            action: proposal.action,
+        }
+        .emit();
+        proposal
+    }
+
+    /// Adds the `signer` to the signers.
+    pub fn add_signer(&mut self, signer: Address) {
+        let mut signers = self.get_signers();
+        if signers.contains(&signer) {
+            revert(Error::SignerAlreadyExists);
+        }
+        signers.push(signer);
+        self.set_signers(signers, self.get_threshold());
+    }
+
+    /// Removes the `signer` from the signers.
+    pub fn remove_signer(&mut self, signer: Address) {
+        let mut signers = self.get_signers();
+        if !signers.contains(&signer) {
+            revert(Error::NotASigner);
+        }
+        signers.retain(|s| s != &signer);
+        self.set_signers(signers, self.get_threshold());
+    }
+
+    /// Changes the number of approvals required to execute a proposal.
+    pub fn change_threshold(&mut self, threshold: u32) {
+        self.set_signers(self.get_signers(), threshold);
+    }
+
+    /// Returns the current signers.
+    pub fn get_signers(&self) -> Vec<Address> {
+        self.signers.get_or_default()
+    }
+
+    /// Returns the number of approvals required to execute a proposal.
+    pub fn get_threshold(&self) -> u32 {
+        self.threshold.get_or_default()
+    }
+
+    /// Returns the proposal with the given id.
+    pub fn get_proposal(&self, proposal_id: u32) -> Option<OwnerProposal> {
+        self.proposals.get(&proposal_id)
+    }
+}
+
+impl MultisigOwner {
+    fn set_signers(&mut self, signers: Vec<Address>, threshold: u32) {
+        let enabled = !signers.is_empty() || threshold > 0;
+        if enabled && (threshold == 0 || threshold as usize > signers.len()) {
+            revert(Error::InvalidThreshold);
+        }
+        self.signers.set(signers.clone());
+        self.threshold.set(threshold);
+
+        SignersChanged { signers, threshold }.emit();
+    }
+
+    fn ensure_signer(&self, address: Address) {
+        if !self.get_signers().contains(&address) {
+            revert(Error::NotASigner);
+        }
+    }
+
+    fn pending_proposal(&self, proposal_id: u32) -> OwnerProposal {
+        let proposal = self
+            .proposals
+            .get(&proposal_id)
+            .unwrap_or_revert_with(Error::OwnerActionDoesNotExist);
+        if proposal.executed {
+            revert(Error::OwnerActionAlreadyExecuted);
+        }
+        if !self
+            .pending_proposals
+            .get_or_default()
+            .contains(&proposal_id)
+        {
+            revert(Error::OwnerActionDoesNotExist);
+        }
+        proposal
+    }
+}
+
+pub mod events {
+    //! Events definitions.
+    use super::OwnerAction;
+    use odra::prelude::vec::Vec;
+    use odra::types::Address;
+    use odra::Event;
+
+    /// Event emitted when an owner-only action has been proposed.
+    #[derive(Debug, PartialEq, Eq, Event)]
+    pub struct OwnerActionProposed {
+        pub proposal_id: u32,
+        pub action: OwnerAction,
+        pub proposer: Address,
+    }
+
+    /// Event emitted when a signer has approved an owner-only action.
+    #[derive(Debug, PartialEq, Eq, Event)]
+    pub struct OwnerActionApproved {
+        pub proposal_id: u32,
+        pub signer: Address,
+    }
+
+    /// Event emitted when an owner-only action has been executed.
+    #[derive(Debug, PartialEq, Eq, Event)]
+    pub struct OwnerActionExecuted {
+        pub proposal_id: u32,
+        pub action: OwnerAction,
+    }
+
+    /// Event emitted when the signers or the threshold have changed.
+    #[derive(Debug, PartialEq, Eq, Event)]
+    pub struct SignersChanged {
+        pub signers: Vec<Address>,
+        pub threshold: u32,
+    }
+}
@@ -0,0 +1,63 @@
+//! The Pausable module.
+use crate::modules::pausable::events::{Paused, Unpaused};
+use crate::utils::Error;
+use odra::contract_env::{caller, revert};
+use odra::types::event::OdraEvent;
+use odra::Variable;
+
+/// The Pausable module.
+///
+/// Stores the paused state of a single contract.
+#[odra::module]
+pub struct Pausable {
+    pub paused: Variable<bool>,
+}
+
+#[odra::module]
+impl Pausable {
+    /// Pauses the contract.
+    pub fn pause(&mut self) {
+        self.ensure_not_paused();
+        self.paused.set(true);
+        Paused { account: caller() }.emit();
+    }
+
+    /// Unpauses the contract.
+    pub fn unpause(&mut self) {
+        if !self.is_paused() {
+            revert(Error::ContractNotPaused);
+        }
+        self.paused.set(false);
+        Unpaused { account: caller() }.emit();
+    }
+
+    /// Returns true if the contract is paused.
+    pub fn is_paused(&self) -> bool {
+        self.paused.get_or_default()
+    }
+
+    /// Reverts if the contract is paused.
+    pub fn ensure_not_paused(&self) {
+        if self.is_paused() {
+            revert(Error::ContractPaused);
+        }
+    }
+}
+
+pub mod events {
+    //! Events definitions.
+    use odra::types::Address;
+    use odra::Event;
+
+    /// Event emitted when the contract has been paused.
+    #[derive(Debug, PartialEq, Eq, Event)]
+    pub struct Paused {
+        pub account: Address,
+    }
+
+    /// Event emitted when the contract has been unpaused.
+    #[derive(Debug, PartialEq, Eq, Event)]
+    pub struct Unpaused {
+        pub account: Address,
+    }
+}
@@ -6,6 +6,7 @@ use crate::core_contracts::{
     KycNftContractRef, ReputationContractRef, VaNftContractRef, VariableRepositoryContractRef,
 };
 use crate::utils::Error;
+use crate::voting_contracts::InsurancePoolContractRef;
 
 // /// A module that stores addresses to common voting_contracts that are used by most of the voting voting_contracts.
 // #[odra::module]
@@ -135,6 +136,7 @@ pub struct ContractRefs {
     reputation_token: Variable<Address>,
     va_token: Variable<Address>,
     kyc_token: Variable<Address>,
+    insurance_pool: Variable<Address>,
 }
 
 impl ContractRefs {
@@ -154,6 +156,10 @@ impl ContractRefs {
         self.kyc_token.set(kyc_token);
     }
 
+    pub fn set_insurance_pool(&mut self, insurance_pool: Address) {
+        self.insurance_pool.set(insurance_pool);
+    }
+
     pub fn variable_repository_address(&self) -> Address {
         self.variable_repository
             .get()
@@ -178,6 +184,10 @@ impl ContractRefs {
             .unwrap_or_revert_with(Error::VariableValueNotSet)
     }
 
+    pub fn insurance_pool_address(&self) -> Option<Address> {
+        self.insurance_pool.get()
+    }
+
     pub fn variable_repository(&self) -> VariableRepositoryContractRef {
         VariableRepositoryContractRef::at(&self.variable_repository_address())
     }
@@ -193,4 +203,9 @@ impl ContractRefs {
     pub fn kyc_token(&self) -> KycNftContractRef {
         KycNftContractRef::at(&self.kyc_token_address())
     }
+
+    pub fn insurance_pool(&self) -> Option<InsurancePoolContractRef> {
+        self.insurance_pool_address()
+            .map(|address| InsurancePoolContractRef::at(&address))
+    }
 }
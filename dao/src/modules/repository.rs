@@ -1,4 +1,5 @@
 //! Repository module.
+use crate::configuration::FeeSplit;
 use crate::modules::repository::events::ValueUpdated;
 use crate::utils::consts;
 use crate::utils::Error::{ActivationTimeInPast, KeyValueStorageError};
@@ -174,6 +175,25 @@ impl Default for RepositoryDefaults {
         );
         items.push(consts::BID_ESCROW_PAYMENT_RATIO, Balance::from(100));
         items.push(consts::CANCEL_FINISHED_VOTING_TIMEOUT, 2592000000u64);
+        items.push(consts::MAX_DEADLINE_EXTENSIONS, Balance::from(2));
+        items.push(consts::UNREVEALED_BID_FORFEIT_RATIO, Balance::from(500));
+        items.push(consts::KEEPER_REWARD_RATIO, Balance::zero());
+        items.push(consts::BID_ESCROW_FEE_SPLIT, FeeSplit::default());
+        items.push(consts::INSURANCE_POOL_RATIO, Balance::zero());
+        items.push(consts::JOB_CATEGORIES, Vec::<String>::new());
+        items.push(consts::SKILL_TAGS, Vec::<String>::new());
+        items.push(consts::INTERNAL_AUCTION_SKILL_MATCH_REQUIRED, false);
+        items.push(consts::OFFBOARDING_REPUTATION_TO_PASSIVE, false);
+        items.push(consts::SPONSORED_INFORMAL_VOTING_TIME, 86400000u64);
+        items.push(consts::SPONSORSHIP_PROBATION_PERIOD, 2592000000u64);
+        items.push(consts::VA_PROBATION_PERIOD, 0u64);
+        items.push(
+            consts::VA_PROBATION_STAKE_LIMIT,
+            Balance::from(100_000_000_000u64),
+        );
+        items.push(consts::KYC_VALIDITY_PERIOD, 31536000000u64);
+        items.push(consts::KYC_REVOCATION_FORFEITS_DEPOSIT, false);
+        items.push(consts::ACCOUNT_RECOVERY_CHALLENGE_PERIOD, 604800000u64);
         items
     }
 }
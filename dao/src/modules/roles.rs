@@ -0,0 +1,145 @@
+//! Roles module.
+use crate::modules::roles::events::{RoleAdminChanged, RoleGranted, RoleRevoked};
+use odra::contract_env::caller;
+use odra::types::event::OdraEvent;
+use odra::types::Address;
+use odra::{Mapping, OdraType};
+
+/// A named permission required to call a protected entry point.
+#[derive(OdraType, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Role {
+    /// Grants and revokes the roles it administers. By default it administers all the roles.
+    Admin,
+    /// Mints tokens.
+    Minter,
+    /// Burns tokens.
+    Burner,
+    /// Stakes and unstakes reputation.
+    Staker,
+    /// Updates variables in the variable repository.
+    VariableEditor,
+    /// Slashes voters and cancels their activity.
+    Slasher,
+    /// Moves the assets and the activity of an account to another account.
+    Migrator,
+    /// Configures the contract, e.g. sets the related contracts.
+    Operator,
+    /// Creates votings.
+    VotingCreator,
+    /// Pauses and unpauses the contract.
+    Guardian,
+}
+
+impl Role {
+    /// All the roles.
+    pub const ALL: [Role; 10] = [
+        Role::Admin,
+        Role::Minter,
+        Role::Burner,
+        Role::Staker,
+        Role::VariableEditor,
+        Role::Slasher,
+        Role::Migrator,
+        Role::Operator,
+        Role::VotingCreator,
+        Role::Guardian,
+    ];
+}
+
+/// The Roles module.
+#[odra::module]
+pub struct Roles {
+    pub roles: Mapping<(Role, Address), bool>,
+    pub admin_roles: Mapping<Role, Role>,
+}
+
+#[odra::module]
+impl Roles {
+    /// Grants the `role` to the `address`.
+    pub fn grant_role(&mut self, role: Role, address: Address) {
+        if self.has_role(role, address) {
+            return;
+        }
+        self.roles.set(&(role, address), true);
+        RoleGranted {
+            role,
+            address,
+            sender: caller(),
+        }
+        .emit();
+    }
+
+    /// Revokes the `role` from the `address`.
+    pub fn revoke_role(&mut self, role: Role, address: Address) {
+        if !self.has_role(role, address) {
+            return;
+        }
+        self.roles.set(&(role, address), false);
+        RoleRevoked {
+            role,
+            address,
+            sender: caller(),
+        }
+        .emit();
+    }
+
+    /// Revokes all the roles from the `address`.
+    pub fn revoke_all_roles(&mut self, address: Address) {
+        for role in Role::ALL {
+            self.revoke_role(role, address);
+        }
+    }
+
+    /// Returns true if the `address` has been granted the `role`.
+    pub fn has_role(&self, role: Role, address: Address) -> bool {
+        self.roles.get(&(role, address)).unwrap_or(false)
+    }
+
+    /// Returns the admin role of the `role`, whose holders grant and revoke the `role`.
+    pub fn get_role_admin(&self, role: Role) -> Role {
+        self.admin_roles.get(&role).unwrap_or(Role::Admin)
+    }
+
+    /// Sets the admin role of the `role`, whose holders grant and revoke the `role`.
+    pub fn set_role_admin(&mut self, role: Role, admin_role: Role) {
+        let previous_admin_role = self.get_role_admin(role);
+        self.admin_roles.set(&role, admin_role);
+        RoleAdminChanged {
+            role,
+            previous_admin_role,
+            new_admin_role: admin_role,
+        }
+        .emit();
+    }
+}
+
+pub mod events {
+    //! Events definitions.
+    use super::Role;
+    use odra::types::Address;
+    use odra::Event;
+
+    /// Event emitted when a role has been granted to an address.
+    #[derive(Debug, PartialEq, Eq, Event)]
+    pub struct RoleGranted {
+        pub role: Role,
+        pub address: Address,
+        pub sender: Address,
+    }
+
+    /// Event emitted when a role has been revoked from an address.
+    #[derive(Debug, PartialEq, Eq, Event)]
+    pub struct RoleRevoked {
+        pub role: Role,
+        pub address: Address,
+        pub sender: Address,
+    }
+
+    /// Event emitted when the admin role of a role has changed.
+    #[derive(Debug, PartialEq, Eq, Event)]
+    pub struct RoleAdminChanged {
+        pub role: Role,
+        pub previous_admin_role: Role,
+        pub new_admin_role: Role,
+    }
+}
@@ -2,4 +2,5 @@
 mod request;
 mod voting;
 
-pub use voting::Onboarding;
+pub use request::Sponsorship;
+pub use voting::{Onboarding, OnboardingRequestClosed, SponsorSlashed, SponsorshipReleased};
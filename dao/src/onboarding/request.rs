@@ -2,8 +2,9 @@
 use crate::rules::validation::bid_escrow::{ExistsOngoingVoting, IsNotVa};
 use crate::rules::validation::IsUserKyced;
 use crate::rules::RulesBuilder;
-use crate::utils::types::DocumentHash;
-use odra::types::{Address, Balance};
+use crate::utils::consts::BASIC_KYC_LEVEL;
+use crate::utils::types::{DocumentHash, KycLevel};
+use odra::types::{Address, Balance, BlockTime};
 use odra::OdraType;
 
 /// Onboarding request
@@ -12,9 +13,10 @@ pub struct OnboardingRequest {
     pub reason: DocumentHash,
     pub rep_stake: Balance,
     pub cspr_deposit: Balance,
+    pub sponsor: Option<Address>,
     pub is_va: bool,
     pub exists_ongoing_voting: bool,
-    pub is_kyced: bool,
+    pub kyc_level: KycLevel,
 }
 
 /// Onboarding request saved in the state
@@ -24,13 +26,14 @@ pub struct Request {
     reason: DocumentHash,
     rep_stake: Balance,
     cspr_deposit: Balance,
+    sponsor: Option<Address>,
 }
 
 impl Request {
     /// Creates new onboarding request
     pub fn new(request: OnboardingRequest) -> Self {
         RulesBuilder::new()
-            .add_validation(IsUserKyced::create(request.is_kyced))
+            .add_validation(IsUserKyced::create(request.kyc_level, BASIC_KYC_LEVEL))
             .add_validation(IsNotVa::create(request.is_va))
             .add_validation(ExistsOngoingVoting::create(request.exists_ongoing_voting))
             .build()
@@ -41,6 +44,7 @@ impl Request {
             reason: request.reason,
             rep_stake: request.rep_stake,
             cspr_deposit: request.cspr_deposit,
+            sponsor: request.sponsor,
         }
     }
 
@@ -63,4 +67,53 @@ impl Request {
     pub fn cspr_deposit(&self) -> Balance {
         self.cspr_deposit
     }
+
+    /// Returns the VA who sponsors the request, if any
+    pub fn sponsor(&self) -> Option<Address> {
+        self.sponsor
+    }
+
+    /// Replaces the VA who sponsors the request, after the sponsor's account has been migrated
+    pub fn set_sponsor(&mut self, sponsor: Address) {
+        self.sponsor = Some(sponsor);
+    }
+}
+
+/// A VA's reputation staked as a guarantee for a VA onboarded with their sponsorship.
+#[derive(OdraType, Debug)]
+pub struct Sponsorship {
+    sponsor: Address,
+    stake: Balance,
+    probation_ends_at: BlockTime,
+}
+
+impl Sponsorship {
+    /// Creates new sponsorship
+    pub fn new(sponsor: Address, stake: Balance, probation_ends_at: BlockTime) -> Self {
+        Sponsorship {
+            sponsor,
+            stake,
+            probation_ends_at,
+        }
+    }
+
+    /// Returns the sponsoring VA
+    pub fn sponsor(&self) -> Address {
+        self.sponsor
+    }
+
+    /// Returns the reputation staked by the sponsor
+    pub fn stake(&self) -> Balance {
+        self.stake
+    }
+
+    /// Returns the time the sponsor is no longer liable for the sponsored VA
+    pub fn probation_ends_at(&self) -> BlockTime {
+        self.probation_ends_at
+    }
+
+    /// Checks if the sponsor is still liable for the sponsored VA
+    pub fn is_in_probation(&self, block_time: BlockTime) -> bool {
+        block_time <= self.probation_ends_at
+    }
 }
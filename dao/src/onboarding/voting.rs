@@ -3,12 +3,15 @@
 use crate::bid_escrow::events::{CSPRTransfer, TransferReason};
 use crate::configuration::{Configuration, ConfigurationBuilder};
 use crate::modules::refs::ContractRefs;
-use crate::onboarding::request::{OnboardingRequest, Request};
+use crate::modules::Claims;
+use crate::onboarding::request::{OnboardingRequest, Request, Sponsorship};
+use crate::rules::validation::voting::CanApproveOnboarding;
+use crate::rules::RulesBuilder;
 use crate::utils::types::DocumentHash;
-use crate::utils::{withdraw, Error};
+use crate::utils::Error;
 use crate::voting::ballot::Choice;
 use crate::voting::cspr_redistribution::{
-    redistribute_cspr_to_all_vas, redistribute_to_governance,
+    redistribute_cspr_to_all_vas, redistribute_to_governance, redistribute_to_insurance_pool,
 };
 use crate::voting::types::VotingId;
 use crate::voting::voting_engine::events::VotingCreatedInfo;
@@ -16,19 +19,23 @@ use crate::voting::voting_engine::voting_state_machine::{
     VotingResult, VotingStateMachine, VotingSummary, VotingType,
 };
 use crate::voting::voting_engine::VotingEngine;
-use odra::contract_env::{caller, revert};
-use odra::types::{Address, Balance};
-use odra::{Mapping, UnwrapOrRevert};
+use odra::contract_env::{caller, get_block_time, revert};
+use odra::prelude::{vec, vec::Vec};
+use odra::types::{event::OdraEvent, Address, Balance};
+use odra::{Event, Mapping, UnwrapOrRevert};
 
 /// Onboarding voting module.
-#[odra::module(events = [CSPRTransfer])]
+#[odra::module(events = [CSPRTransfer, SponsorSlashed, SponsorshipReleased, OnboardingRequestClosed])]
 pub struct Onboarding {
     requests: Mapping<VotingId, Request>,
     configurations: Mapping<VotingId, Configuration>,
     ids: Mapping<Address, VotingId>,
+    sponsorships: Mapping<Address, Option<Sponsorship>>,
+    sponsored_vas: Mapping<Address, Vec<Address>>,
     refs: ContractRefs,
     #[odra(using = "refs")]
     voting: VotingEngine,
+    claims: Claims,
 }
 
 impl Onboarding {
@@ -55,9 +62,10 @@ impl Onboarding {
             reason,
             rep_stake,
             cspr_deposit,
+            sponsor: None,
             is_va: !self.refs.va_token().balance_of(&requestor).is_zero(),
             exists_ongoing_voting,
-            is_kyced: !self.refs.kyc_token().balance_of(&requestor).is_zero(),
+            kyc_level: self.refs.kyc_token().kyc_level(requestor),
         };
 
         // Create voting and cast creator's ballot
@@ -70,6 +78,211 @@ impl Onboarding {
         voting_info
     }
 
+    /// Creates an onboarding voting for the `candidate` sponsored by the caller.
+    ///
+    /// The sponsor's `stake` is their ballot in the voting. If the candidate is onboarded,
+    /// the `stake` remains staked until the end of the probation period.
+    pub fn submit_sponsored_request(
+        &mut self,
+        candidate: Address,
+        reason: DocumentHash,
+        stake: Balance,
+    ) -> VotingCreatedInfo {
+        let sponsor = caller();
+
+        let configuration = ConfigurationBuilder::new(
+            self.refs.va_token().total_supply(),
+            &self.refs.variable_repository().all_variables(),
+        )
+        .sponsored_onboarding()
+        .build();
+        // The sponsor's ballot is the first approval, so a sponsor on probation would be the sole approver.
+        RulesBuilder::new()
+            .add_validation(CanApproveOnboarding::create(
+                self.voting.is_on_probation(sponsor, &configuration),
+                false,
+            ))
+            .build()
+            .validate_generic_validations();
+        let exists_ongoing_voting = self
+            .get_user_voting(&candidate)
+            .map(|v| !v.completed())
+            .unwrap_or_default();
+
+        let request = OnboardingRequest {
+            requestor: candidate,
+            reason,
+            rep_stake: stake,
+            cspr_deposit: Balance::zero(),
+            sponsor: Some(sponsor),
+            is_va: !self.refs.va_token().balance_of(&candidate).is_zero(),
+            exists_ongoing_voting,
+            kyc_level: self.refs.kyc_token().kyc_level(candidate),
+        };
+
+        // The sponsor's ballot is cast by the voting engine.
+        let (voting_info, _) = self
+            .voting
+            .create_voting(sponsor, stake, configuration.clone());
+        let voting_id = voting_info.voting_id;
+        self.ids.set(&candidate, voting_id);
+
+        self.store_request(request, voting_id);
+        self.store_configuration(voting_id, configuration);
+
+        voting_info
+    }
+
+    /// Returns the sponsorship of a VA onboarded by a sponsored request.
+    pub fn get_sponsorship(&self, va: Address) -> Option<Sponsorship> {
+        self.sponsorships.get(&va).flatten()
+    }
+
+    /// Returns the sponsor's stake once the probation period of the sponsored VA is over.
+    pub fn release_sponsorship(&mut self, va: Address) {
+        let sponsorship = self
+            .get_sponsorship(va)
+            .unwrap_or_revert_with(Error::SponsorshipNotFound);
+        if sponsorship.is_in_probation(get_block_time()) {
+            revert(Error::SponsorshipProbationNotEnded);
+        }
+
+        self.refs
+            .reputation_token()
+            .unstake(sponsorship.sponsor(), sponsorship.stake());
+        self.sponsorships.set(&va, None);
+
+        SponsorshipReleased {
+            va,
+            sponsor: sponsorship.sponsor(),
+            stake: sponsorship.stake(),
+        }
+        .emit();
+    }
+
+    /// Burns the sponsor's stake if the sponsored VA has been fully slashed within the probation period.
+    pub fn slash_sponsor(&mut self, va: Address) {
+        let sponsorship = match self.get_sponsorship(va) {
+            Some(sponsorship) => sponsorship,
+            None => return,
+        };
+        // A full slash burns the VA Token before the slashable contracts are notified,
+        // while an offboarding VA still holds it, so the sponsor is not liable for leaving the DAO.
+        if !self.refs.va_token().balance_of(&va).is_zero()
+            || !sponsorship.is_in_probation(get_block_time())
+        {
+            return;
+        }
+
+        let mut reputation_token = self.refs.reputation_token();
+        reputation_token.unstake(sponsorship.sponsor(), sponsorship.stake());
+        reputation_token.burn(sponsorship.sponsor(), sponsorship.stake());
+        self.sponsorships.set(&va, None);
+
+        SponsorSlashed {
+            va,
+            sponsor: sponsorship.sponsor(),
+            amount: sponsorship.stake(),
+        }
+        .emit();
+    }
+
+    /// Moves the sponsorships of the `from` address, both as the sponsor and as the sponsored VA,
+    /// and its pending sponsored requests to the `to` address.
+    pub fn migrate_sponsorships(&mut self, from: Address, to: Address) {
+        for voting_id in self.voting.get_active_votings() {
+            if let Some(mut request) = self.requests.get(&voting_id) {
+                if request.sponsor() == Some(from) {
+                    request.set_sponsor(to);
+                    self.requests.set(&voting_id, request);
+                }
+            }
+        }
+
+        if let Some(sponsorship) = self.get_sponsorship(from) {
+            let sponsor = sponsorship.sponsor();
+            self.sponsorships.set(&to, Some(sponsorship));
+            self.sponsorships.set(&from, None);
+
+            let sponsored_vas = self
+                .sponsored_vas
+                .get_or_default(&sponsor)
+                .into_iter()
+                .map(|va| if va == from { to } else { va })
+                .collect();
+            self.sponsored_vas.set(&sponsor, sponsored_vas);
+        }
+
+        let sponsored_vas = self.sponsored_vas.get_or_default(&from);
+        for va in sponsored_vas.iter() {
+            if let Some(sponsorship) = self.get_sponsorship(*va) {
+                if sponsorship.sponsor() == from {
+                    self.sponsorships.set(
+                        va,
+                        Some(Sponsorship::new(
+                            to,
+                            sponsorship.stake(),
+                            sponsorship.probation_ends_at(),
+                        )),
+                    );
+                }
+            }
+        }
+        self.sponsored_vas.set(&to, sponsored_vas);
+        self.sponsored_vas.set(&from, vec![]);
+    }
+
+    /// Closes the pending onboarding request of the `address` which KYC has been revoked.
+    ///
+    /// The voting is cancelled and the stakes are returned. The `CSPR` deposit is returned, or,
+    /// if the `KycRevocationForfeitsDeposit` variable is set, forfeited to the DAO.
+    pub fn revoke_kyc(&mut self, address: Address) {
+        let voting = match self.get_user_voting(&address) {
+            Some(voting) if !voting.completed() => voting,
+            _ => return,
+        };
+        let voting_id = voting.voting_id();
+        let request = self
+            .requests
+            .get(&voting_id)
+            .unwrap_or_revert_with(Error::OnboardingRequestNotFound);
+        self.voting.cancel_ongoing_voting(voting_id);
+
+        let configuration = ConfigurationBuilder::new(
+            self.refs.va_token().total_supply(),
+            &self.refs.variable_repository().all_variables(),
+        )
+        .build();
+        let deposit_forfeited = configuration.kyc_revocation_forfeits_deposit();
+        if deposit_forfeited {
+            let to_wallet = redistribute_to_insurance_pool(
+                request.cspr_deposit(),
+                &configuration,
+                &self.refs,
+                &mut self.claims,
+            );
+            self.claims.credit(
+                &configuration.bid_escrow_wallet_address(),
+                to_wallet,
+                TransferReason::OnboardingDepositForfeit,
+            );
+        } else {
+            self.claims.credit(
+                &request.creator(),
+                request.cspr_deposit(),
+                TransferReason::OnboardingStakeReturn,
+            );
+        }
+
+        OnboardingRequestClosed {
+            requestor: address,
+            voting_id,
+            cspr_deposit: request.cspr_deposit(),
+            deposit_forfeited,
+        }
+        .emit();
+    }
+
     pub fn finish_voting(&mut self, voting_id: VotingId, voting_type: VotingType) -> VotingSummary {
         let request = self
             .requests
@@ -92,8 +305,20 @@ impl Onboarding {
         choice: Choice,
         stake: Balance,
     ) {
+        let voter = caller();
+        if choice.is_in_favor() {
+            let configuration = self.voting.get_configuration_or_revert(voting_id);
+            RulesBuilder::new()
+                .add_validation(CanApproveOnboarding::create(
+                    self.voting.is_on_probation(voter, &configuration),
+                    self.exists_approver_not_on_probation(voting_id, voting_type, &configuration),
+                ))
+                .build()
+                .validate_generic_validations();
+        }
+
         self.voting
-            .vote(caller(), voting_id, voting_type, choice, stake);
+            .vote(voter, voting_id, voting_type, choice, stake);
     }
 
     fn get_user_voting(&self, address: &Address) -> Option<VotingStateMachine> {
@@ -144,6 +369,22 @@ impl Onboarding {
         self.requests.set(&voting_id, request);
     }
 
+    fn exists_approver_not_on_probation(
+        &self,
+        voting_id: VotingId,
+        voting_type: VotingType,
+        configuration: &Configuration,
+    ) -> bool {
+        (0..self.voting.voters_count(voting_id, voting_type))
+            .map(|i| self.voting.get_ballot_at(voting_id, voting_type, i))
+            .any(|ballot| {
+                !ballot.unbound
+                    && !ballot.canceled
+                    && ballot.choice.is_in_favor()
+                    && !self.voting.is_on_probation(ballot.voter, configuration)
+            })
+    }
+
     fn store_configuration(&mut self, voting_id: VotingId, configuration: Configuration) {
         self.configurations.set(&voting_id, configuration);
     }
@@ -175,7 +416,10 @@ impl Onboarding {
         summary: &VotingSummary,
     ) {
         match summary.result() {
-            VotingResult::InFavor => self.on_formal_voting_in_favor(voting_id, request),
+            VotingResult::InFavor => match request.sponsor() {
+                Some(sponsor) => self.on_sponsored_voting_in_favor(voting_id, sponsor, request),
+                None => self.on_formal_voting_in_favor(voting_id, request),
+            },
             VotingResult::Against => self.on_formal_voting_against(voting_id, request),
             VotingResult::QuorumNotReached => self.on_quorum_not_reached(request),
             VotingResult::Canceled => Self::on_voting_canceled(),
@@ -186,8 +430,8 @@ impl Onboarding {
         revert(Error::VotingAlreadyCanceled)
     }
 
-    fn on_quorum_not_reached(&self, request: &Request) {
-        withdraw(
+    fn on_quorum_not_reached(&mut self, request: &Request) {
+        self.claims.credit(
             &request.creator(),
             request.cspr_deposit(),
             TransferReason::OnboardingStakeReturn,
@@ -199,7 +443,7 @@ impl Onboarding {
             .requests
             .get(&voting_id)
             .unwrap_or_revert_with(Error::OnboardingRequestNotFound);
-        withdraw(
+        self.claims.credit(
             &request.creator(),
             request.cspr_deposit(),
             TransferReason::OnboardingStakeReturn,
@@ -215,20 +459,59 @@ impl Onboarding {
             .unwrap_or_revert_with(Error::OnboardingConfigurationNotFound);
         let voting = self.voting.get_voting_or_revert(voting_id);
         // Make the user VA.
-        self.refs.va_token().mint(request.creator());
+        self.refs
+            .va_token()
+            .mint_with_voting(request.creator(), voting_id);
         // Burn temporary reputation.
         self.burn_requestor_reputation(request);
         self.mint_and_redistribute_reputation_for_requestor(&voting, request);
         self.redistribute_cspr(&configuration, request.cspr_deposit());
     }
 
+    fn on_sponsored_voting_in_favor(
+        &mut self,
+        voting_id: VotingId,
+        sponsor: Address,
+        request: &Request,
+    ) {
+        let configuration = self
+            .configurations
+            .get(&voting_id)
+            .unwrap_or_revert_with(Error::OnboardingConfigurationNotFound);
+        // Make the user VA.
+        self.refs
+            .va_token()
+            .mint_with_voting(request.creator(), voting_id);
+        // The sponsor's ballot has been unstaked by the voting engine, it is locked again for the probation period.
+        // A sponsor slashed in the meantime locks whatever is left, the candidate is onboarded anyway.
+        let mut reputation_token = self.refs.reputation_token();
+        let available = reputation_token
+            .balance_of(sponsor)
+            .saturating_sub(reputation_token.get_stake(sponsor));
+        let stake = request.rep_stake().min(available);
+        if stake.is_zero() {
+            return;
+        }
+        reputation_token.stake(sponsor, stake);
+        let probation_ends_at = get_block_time() + configuration.sponsorship_probation_period();
+        let mut sponsored_vas = self.sponsored_vas.get_or_default(&sponsor);
+        sponsored_vas.push(request.creator());
+        self.sponsored_vas.set(&sponsor, sponsored_vas);
+        self.sponsorships.set(
+            &request.creator(),
+            Some(Sponsorship::new(sponsor, stake, probation_ends_at)),
+        );
+    }
+
     fn on_formal_voting_against(&mut self, voting_id: VotingId, request: &Request) {
         let configuration = self
             .configurations
             .get(&voting_id)
             .unwrap_or_revert_with(Error::OnboardingConfigurationNotFound);
-        let amount = redistribute_to_governance(request.cspr_deposit(), &configuration);
-        withdraw(&request.creator(), amount, TransferReason::BidStakeReturn);
+        let amount =
+            redistribute_to_governance(request.cspr_deposit(), &configuration, &mut self.claims);
+        self.claims
+            .credit(&request.creator(), amount, TransferReason::BidStakeReturn);
     }
 }
 
@@ -272,8 +555,8 @@ impl Onboarding {
     }
 
     fn redistribute_cspr(&mut self, configuration: &Configuration, amount: Balance) {
-        let to_redistribute = redistribute_to_governance(amount, configuration);
-        redistribute_cspr_to_all_vas(to_redistribute, &self.refs);
+        let to_redistribute = redistribute_to_governance(amount, configuration, &mut self.claims);
+        redistribute_cspr_to_all_vas(to_redistribute, &self.refs, &mut self.claims);
     }
 
     fn burn_requestor_reputation(&self, request: &Request) {
@@ -282,3 +565,28 @@ impl Onboarding {
             .burn(request.creator(), request.rep_stake());
     }
 }
+
+/// Event emitted when the stake of a sponsor has been burned because the sponsored VA has been fully slashed.
+#[derive(Debug, PartialEq, Eq, Event)]
+pub struct SponsorSlashed {
+    pub va: Address,
+    pub sponsor: Address,
+    pub amount: Balance,
+}
+
+/// Event emitted when the stake of a sponsor has been returned after the probation period.
+#[derive(Debug, PartialEq, Eq, Event)]
+pub struct SponsorshipReleased {
+    pub va: Address,
+    pub sponsor: Address,
+    pub stake: Balance,
+}
+
+/// Event emitted when a pending onboarding request has been closed because the KYC of the requestor has been revoked.
+#[derive(Debug, PartialEq, Eq, Event)]
+pub struct OnboardingRequestClosed {
+    pub requestor: Address,
+    pub voting_id: VotingId,
+    pub cspr_deposit: Balance,
+    pub deposit_forfeited: bool,
+}
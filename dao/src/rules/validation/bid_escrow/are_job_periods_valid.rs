@@ -0,0 +1,19 @@
+use crate::rules::validation::Validation;
+use crate::utils::Error;
+use macros::Rule;
+
+/// Verifies if a [`JobOffer`](crate::bid_escrow::job_offer::JobOffer) lasts at least one period.
+/// May return [Error::InvalidJobPeriods].
+#[derive(Rule)]
+pub struct AreJobPeriodsValid {
+    periods: u32,
+}
+
+impl Validation for AreJobPeriodsValid {
+    fn validate(&self) -> Result<(), Error> {
+        if self.periods == 0 {
+            return Err(Error::InvalidJobPeriods);
+        }
+        Ok(())
+    }
+}
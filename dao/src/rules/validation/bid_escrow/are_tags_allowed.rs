@@ -0,0 +1,38 @@
+extern crate alloc;
+
+use crate::configuration::Configuration;
+use crate::rules::validation::Validation;
+use crate::utils::Error;
+use alloc::rc::Rc;
+use macros::Rule;
+use odra::prelude::{string::String, vec::Vec};
+
+/// Verifies if a category and skill tags belong to the taxonomy defined by the `JobCategories`
+/// and `SkillTags` variables. No category and no skills are always valid.
+/// May return [Error::UnknownJobCategory] or [Error::UnknownSkillTag].
+#[derive(Rule)]
+pub struct AreTagsAllowed {
+    configuration: Rc<Configuration>,
+    category: Option<String>,
+    skills: Vec<String>,
+}
+
+impl Validation for AreTagsAllowed {
+    fn validate(&self) -> Result<(), Error> {
+        if let Some(category) = &self.category {
+            if !self.configuration.is_job_category_allowed(category) {
+                return Err(Error::UnknownJobCategory);
+            }
+        }
+
+        if !self
+            .skills
+            .iter()
+            .all(|skill| self.configuration.is_skill_tag_allowed(skill))
+        {
+            return Err(Error::UnknownSkillTag);
+        }
+
+        Ok(())
+    }
+}
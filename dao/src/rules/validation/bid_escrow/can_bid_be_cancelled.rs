@@ -1,3 +1,4 @@
+use crate::bid_escrow::bid::BidStatus;
 use crate::bid_escrow::job_offer::JobOfferStatus;
 use crate::rules::validation::Validation;
 use crate::utils::Error;
@@ -5,9 +6,11 @@ use macros::Rule;
 use odra::types::BlockTime;
 
 /// Verifies if the [`Bid`](crate::bid_escrow::bid::Bid) can be canceled.
-/// May return [Error::CannotCancelBidOnCompletedJobOffer] or [Error::CannotCancelBidBeforeAcceptanceTimeout].
+/// May return [Error::CannotCancelSealedBid], [Error::CannotCancelBidOnCompletedJobOffer]
+/// or [Error::CannotCancelBidBeforeAcceptanceTimeout].
 #[derive(Rule)]
 pub struct CanBidBeCancelled {
+    bid_status: BidStatus,
     job_offer_status: JobOfferStatus,
     block_time: BlockTime,
     bid_timestamp: BlockTime,
@@ -16,6 +19,11 @@ pub struct CanBidBeCancelled {
 
 impl Validation for CanBidBeCancelled {
     fn validate(&self) -> Result<(), Error> {
+        // An unrevealed Bid is settled with the forfeit when the auction ends.
+        if self.bid_status == BidStatus::Sealed {
+            return Err(Error::CannotCancelSealedBid);
+        }
+
         if self.job_offer_status != JobOfferStatus::Created {
             return Err(Error::CannotCancelBidOnCompletedJobOffer);
         }
@@ -17,6 +17,7 @@ impl Validation for CanBidBePicked {
             BidStatus::Picked => Err(Error::BidAlreadyPicked),
             BidStatus::Canceled => Err(Error::BidCanceled),
             BidStatus::Rejected => Err(Error::BidRejected),
+            BidStatus::Sealed => Err(Error::BidNotRevealed),
         }
     }
 }
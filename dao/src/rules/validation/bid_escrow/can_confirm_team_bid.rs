@@ -0,0 +1,21 @@
+use crate::rules::validation::Validation;
+use crate::utils::Error;
+use macros::Rule;
+
+/// Makes sure a team member has not confirmed a team [`Bid`](crate::bid_escrow::bid::Bid)
+/// yet, so the member's share of the stake is not staked twice.
+/// May return [Error::TeamBidAlreadyConfirmed].
+#[derive(Rule)]
+pub struct CanConfirmTeamBid {
+    confirmed: bool,
+}
+
+impl Validation for CanConfirmTeamBid {
+    fn validate(&self) -> Result<(), Error> {
+        if self.confirmed {
+            Err(Error::TeamBidAlreadyConfirmed)
+        } else {
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,23 @@
+use crate::bid_escrow::job::JobStatus;
+use crate::rules::validation::Validation;
+use crate::utils::Error;
+use macros::Rule;
+use odra::types::BlockTime;
+
+/// Verifies if the [`Job`](crate::bid_escrow::job::Job) is in progress - the proof has not been submitted
+/// and the deadline has not passed. May return [Error::JobExtensionNotAllowed].
+#[derive(Rule)]
+pub struct CanJobBeExtended {
+    job_status: JobStatus,
+    finish_time: BlockTime,
+    block_time: BlockTime,
+}
+
+impl Validation for CanJobBeExtended {
+    fn validate(&self) -> Result<(), Error> {
+        if self.job_status != JobStatus::Created || self.finish_time < self.block_time {
+            return Err(Error::JobExtensionNotAllowed);
+        }
+        Ok(())
+    }
+}
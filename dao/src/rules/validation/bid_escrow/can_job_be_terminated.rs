@@ -0,0 +1,21 @@
+use crate::bid_escrow::job::JobStatus;
+use crate::rules::validation::Validation;
+use crate::utils::Error;
+use macros::Rule;
+
+/// Verifies if the [`Job`](crate::bid_escrow::job::Job) is recurring and the proof for the current period
+/// has not been submitted yet. May return [Error::JobCannotBeTerminated].
+#[derive(Rule)]
+pub struct CanJobBeTerminated {
+    recurring: bool,
+    job_status: JobStatus,
+}
+
+impl Validation for CanJobBeTerminated {
+    fn validate(&self) -> Result<(), Error> {
+        if !self.recurring || self.job_status != JobStatus::Created {
+            return Err(Error::JobCannotBeTerminated);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,21 @@
+use crate::bid_escrow::job_offer::JobOfferStatus;
+use crate::rules::validation::Validation;
+use crate::utils::Error;
+use macros::Rule;
+
+/// Verifies if the [`Job Offer`](crate::bid_escrow::job_offer::JobOffer) can be modified
+/// in the given state. May return [Error::JobOfferCannotBeModified].
+#[derive(Rule)]
+pub struct CanJobOfferBeModified {
+    status: JobOfferStatus,
+}
+
+impl Validation for CanJobOfferBeModified {
+    fn validate(&self) -> Result<(), Error> {
+        if self.status != JobOfferStatus::Created {
+            return Err(Error::JobOfferCannotBeModified);
+        }
+
+        Ok(())
+    }
+}
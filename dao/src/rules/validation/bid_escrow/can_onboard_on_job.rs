@@ -0,0 +1,20 @@
+use crate::rules::validation::Validation;
+use crate::utils::Error;
+use macros::Rule;
+
+/// Verifies if the `Worker` does not request onboarding on a recurring Job.
+/// May return [Error::CannotOnboardOnRecurringJob].
+#[derive(Rule)]
+pub struct CanOnboardOnJob {
+    onboard: bool,
+    recurring: bool,
+}
+
+impl Validation for CanOnboardOnJob {
+    fn validate(&self) -> Result<(), Error> {
+        if self.onboard && self.recurring {
+            return Err(Error::CannotOnboardOnRecurringJob);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,25 @@
+use crate::rules::validation::Validation;
+use crate::utils::Error;
+use macros::Rule;
+use odra::types::Balance;
+
+/// Verifies if there is no pending extension request and the limit of extensions has not been reached.
+/// May return [Error::JobExtensionAlreadyRequested] or [Error::JobExtensionsLimitReached].
+#[derive(Rule)]
+pub struct CanRequestJobExtension {
+    is_extension_pending: bool,
+    extensions: u32,
+    max_extensions: Balance,
+}
+
+impl Validation for CanRequestJobExtension {
+    fn validate(&self) -> Result<(), Error> {
+        if self.is_extension_pending {
+            return Err(Error::JobExtensionAlreadyRequested);
+        }
+        if Balance::from(self.extensions) >= self.max_extensions {
+            return Err(Error::JobExtensionsLimitReached);
+        }
+        Ok(())
+    }
+}
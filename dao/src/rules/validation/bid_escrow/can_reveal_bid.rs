@@ -0,0 +1,36 @@
+use crate::bid_escrow::bid::BidStatus;
+use crate::bid_escrow::job_offer::AuctionState;
+use crate::rules::validation::Validation;
+use crate::utils::Error;
+use macros::Rule;
+use odra::types::Address;
+
+/// Verifies if a sealed [`Bid`](crate::bid_escrow::bid::Bid) can be revealed - only by the `Worker`,
+/// once, after the auction ends.
+/// May return [Error::OnlyBidderCanRevealBid], [Error::BidNotSealed]
+/// or [Error::CannotRevealBidDuringAuction].
+#[derive(Rule)]
+pub struct CanRevealBid {
+    caller: Address,
+    worker: Address,
+    bid_status: BidStatus,
+    auction_state: AuctionState,
+}
+
+impl Validation for CanRevealBid {
+    fn validate(&self) -> Result<(), Error> {
+        if self.caller != self.worker {
+            return Err(Error::OnlyBidderCanRevealBid);
+        }
+
+        if self.bid_status != BidStatus::Sealed {
+            return Err(Error::BidNotSealed);
+        }
+
+        if self.auction_state != AuctionState::None {
+            return Err(Error::CannotRevealBidDuringAuction);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,21 @@
+use crate::rules::validation::Validation;
+use crate::utils::Error;
+use macros::Rule;
+use odra::types::Bytes;
+
+/// Verifies if the revealed terms of a sealed [`Bid`](crate::bid_escrow::bid::Bid)
+/// match the commitment. May return [Error::BidCommitmentMismatch].
+#[derive(Rule)]
+pub struct DoesBidMatchCommitment {
+    commitment: Option<Bytes>,
+    revealed: Bytes,
+}
+
+impl Validation for DoesBidMatchCommitment {
+    fn validate(&self) -> Result<(), Error> {
+        match &self.commitment {
+            Some(commitment) if *commitment == self.revealed => Ok(()),
+            _ => Err(Error::BidCommitmentMismatch),
+        }
+    }
+}
@@ -0,0 +1,20 @@
+use crate::rules::validation::Validation;
+use crate::utils::Error;
+use macros::Rule;
+
+/// Verifies if the `Worker` declared a skill required by the [`Job Offer`](crate::bid_escrow::job_offer::JobOffer),
+/// if the skill match is required. May return [Error::NoMatchingSkill].
+#[derive(Rule)]
+pub struct HasMatchingSkill {
+    skill_match_required: bool,
+    has_matching_skill: bool,
+}
+
+impl Validation for HasMatchingSkill {
+    fn validate(&self) -> Result<(), Error> {
+        if self.skill_match_required && !self.has_matching_skill {
+            return Err(Error::NoMatchingSkill);
+        }
+        Ok(())
+    }
+}
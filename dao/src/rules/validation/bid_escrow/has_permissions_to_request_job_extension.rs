@@ -0,0 +1,21 @@
+use crate::rules::validation::Validation;
+use crate::utils::Error;
+use macros::Rule;
+use odra::types::Address;
+
+/// Makes sure the `Worker` is the one who requests a deadline extension of the [`Job`](crate::bid_escrow::job::Job).
+/// May return [Error::OnlyWorkerCanRequestJobExtension].
+#[derive(Rule)]
+pub struct HasPermissionsToRequestJobExtension {
+    caller: Address,
+    worker: Address,
+}
+
+impl Validation for HasPermissionsToRequestJobExtension {
+    fn validate(&self) -> Result<(), Error> {
+        if self.caller != self.worker {
+            return Err(Error::OnlyWorkerCanRequestJobExtension);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,21 @@
+use crate::rules::validation::Validation;
+use crate::utils::Error;
+use macros::Rule;
+use odra::types::Address;
+
+/// Makes sure the `Job Poster` is the one who accepts or rejects a deadline extension
+/// of the [`Job`](crate::bid_escrow::job::Job). May return [Error::OnlyJobPosterCanRespondToJobExtension].
+#[derive(Rule)]
+pub struct HasPermissionsToRespondToJobExtension {
+    caller: Address,
+    poster: Address,
+}
+
+impl Validation for HasPermissionsToRespondToJobExtension {
+    fn validate(&self) -> Result<(), Error> {
+        if self.caller != self.poster {
+            return Err(Error::OnlyJobPosterCanRespondToJobExtension);
+        }
+        Ok(())
+    }
+}
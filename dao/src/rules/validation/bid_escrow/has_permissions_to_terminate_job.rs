@@ -0,0 +1,22 @@
+use crate::rules::validation::Validation;
+use crate::utils::Error;
+use macros::Rule;
+use odra::types::Address;
+
+/// Makes sure the `Job Poster` or the `Worker` is the one who terminates the [`Job`](crate::bid_escrow::job::Job).
+/// May return [Error::CannotTerminateJob].
+#[derive(Rule)]
+pub struct HasPermissionsToTerminateJob {
+    caller: Address,
+    poster: Address,
+    worker: Address,
+}
+
+impl Validation for HasPermissionsToTerminateJob {
+    fn validate(&self) -> Result<(), Error> {
+        if self.caller != self.poster && self.caller != self.worker {
+            return Err(Error::CannotTerminateJob);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,22 @@
+use crate::rules::validation::Validation;
+use crate::utils::Error;
+use macros::Rule;
+
+/// Makes sure a [`Bid`](crate::bid_escrow::bid::Bid) is sealed if and only if
+/// the [`Job Offer`](crate::bid_escrow::job_offer::JobOffer) requires sealed bids.
+/// May return [Error::SealedBidRequired] or [Error::JobOfferNotSealed].
+#[derive(Rule)]
+pub struct IsBidModeCorrect {
+    sealed_offer: bool,
+    sealed_bid: bool,
+}
+
+impl Validation for IsBidModeCorrect {
+    fn validate(&self) -> Result<(), Error> {
+        match (self.sealed_offer, self.sealed_bid) {
+            (true, false) => Err(Error::SealedBidRequired),
+            (false, true) => Err(Error::JobOfferNotSealed),
+            _ => Ok(()),
+        }
+    }
+}
@@ -1,13 +1,11 @@
 use crate::rules::validation::Validation;
 use crate::utils::Error;
 use macros::Rule;
-use odra::contract_env::attached_value;
 use odra::types::Balance;
 
 /// Verifies if the worker's stake is correct.
 /// May return [Error::NotOnboardedWorkerMustStakeCSPR], [Error::ZeroStake],
-/// [Error::OnboardedWorkerCannotStakeCSPR], [Error::CannotStakeBothCSPRAndReputation],
-/// or [Error::AttachedValueMismatch].
+/// [Error::OnboardedWorkerCannotStakeCSPR] or [Error::CannotStakeBothCSPRAndReputation].
 #[derive(Rule)]
 pub struct IsBidStakeCorrect {
     is_worker_va: bool,
@@ -38,9 +36,6 @@ impl Validation for IsBidStakeCorrect {
                 if cspr_stake == Balance::zero() {
                     return Err(Error::ZeroStake);
                 }
-                if attached_value() != cspr_stake {
-                    return Err(Error::AttachedValueMismatch);
-                }
             }
         }
 
@@ -0,0 +1,22 @@
+use crate::rules::validation::Validation;
+use crate::utils::Error;
+use macros::Rule;
+use odra::contract_env::attached_value;
+use odra::types::Balance;
+
+/// Verifies if the value attached to the call is the CSPR stake of the caller,
+/// or nothing if the caller stakes reputation. May return [Error::AttachedValueMismatch].
+#[derive(Rule)]
+pub struct IsCsprStakeAttached {
+    cspr_stake: Option<Balance>,
+}
+
+impl Validation for IsCsprStakeAttached {
+    fn validate(&self) -> Result<(), Error> {
+        if attached_value() != self.cspr_stake.unwrap_or_default() {
+            return Err(Error::AttachedValueMismatch);
+        }
+
+        Ok(())
+    }
+}
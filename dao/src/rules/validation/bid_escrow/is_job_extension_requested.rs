@@ -0,0 +1,18 @@
+use crate::rules::validation::Validation;
+use crate::utils::Error;
+use macros::Rule;
+
+/// Verifies if there is a pending extension request. May return [Error::JobExtensionNotRequested].
+#[derive(Rule)]
+pub struct IsJobExtensionRequested {
+    is_extension_pending: bool,
+}
+
+impl Validation for IsJobExtensionRequested {
+    fn validate(&self) -> Result<(), Error> {
+        if !self.is_extension_pending {
+            return Err(Error::JobExtensionNotRequested);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,20 @@
+use crate::rules::validation::Validation;
+use crate::utils::Error;
+use macros::Rule;
+
+/// Makes sure all the team members confirmed a team [`Bid`](crate::bid_escrow::bid::Bid)
+/// before it is picked. May return [Error::TeamBidNotConfirmed].
+#[derive(Rule)]
+pub struct IsTeamConfirmed {
+    confirmed: bool,
+}
+
+impl Validation for IsTeamConfirmed {
+    fn validate(&self) -> Result<(), Error> {
+        if self.confirmed {
+            Ok(())
+        } else {
+            Err(Error::TeamBidNotConfirmed)
+        }
+    }
+}
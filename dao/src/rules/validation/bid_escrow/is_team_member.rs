@@ -0,0 +1,20 @@
+use crate::rules::validation::Validation;
+use crate::utils::Error;
+use macros::Rule;
+
+/// Makes sure the caller is declared in the team of a [`Bid`](crate::bid_escrow::bid::Bid).
+/// May return [Error::NotATeamMember].
+#[derive(Rule)]
+pub struct IsTeamMember {
+    is_member: bool,
+}
+
+impl Validation for IsTeamMember {
+    fn validate(&self) -> Result<(), Error> {
+        if self.is_member {
+            Ok(())
+        } else {
+            Err(Error::NotATeamMember)
+        }
+    }
+}
@@ -0,0 +1,54 @@
+use crate::bid_escrow::bid::TEAM_SHARES_TOTAL;
+use crate::rules::validation::Validation;
+use crate::utils::Error;
+use macros::Rule;
+use odra::prelude::vec::Vec;
+use odra::types::Address;
+
+/// Verifies if a team declared in a [`Bid`](crate::bid_escrow::bid::Bid) is well-formed -
+/// includes the `Worker` who submits the Bid, does not include the `Job Poster` nor duplicates,
+/// and the shares sum up to [`TEAM_SHARES_TOTAL`]. An empty team (a solo Bid) is always valid.
+/// May return [Error::InvalidTeamMember] or [Error::InvalidTeamShares].
+#[derive(Rule)]
+pub struct IsTeamValid {
+    worker: Address,
+    job_poster: Address,
+    team: Vec<(Address, u32)>,
+}
+
+impl Validation for IsTeamValid {
+    fn validate(&self) -> Result<(), Error> {
+        if self.team.is_empty() {
+            return Ok(());
+        }
+
+        if self.team.len() < 2 {
+            return Err(Error::InvalidTeamMember);
+        }
+
+        let mut members: Vec<Address> = Vec::new();
+        let mut total_shares: u32 = 0;
+        for (member, share) in self.team.iter() {
+            if *member == self.job_poster || members.contains(member) {
+                return Err(Error::InvalidTeamMember);
+            }
+            if *share == 0 {
+                return Err(Error::InvalidTeamShares);
+            }
+            members.push(*member);
+            total_shares = total_shares
+                .checked_add(*share)
+                .ok_or(Error::InvalidTeamShares)?;
+        }
+
+        if !members.contains(&self.worker) {
+            return Err(Error::InvalidTeamMember);
+        }
+
+        if total_shares != TEAM_SHARES_TOTAL {
+            return Err(Error::InvalidTeamShares);
+        }
+
+        Ok(())
+    }
+}
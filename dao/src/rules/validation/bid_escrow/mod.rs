@@ -1,38 +1,78 @@
 //! Groups [Bid Escrow](crate::bid_escrow)-related validations.
+mod are_job_periods_valid;
+mod are_tags_allowed;
 mod can_be_onboarded;
 mod can_bid_be_cancelled;
 mod can_bid_be_picked;
 mod can_bid_on_auction_state;
 mod can_bid_on_own_job;
+mod can_confirm_team_bid;
+mod can_job_be_extended;
+mod can_job_be_terminated;
 mod can_job_offer_be_cancelled;
+mod can_job_offer_be_modified;
+mod can_onboard_on_job;
 mod can_pick_bid;
 mod can_progress_job_offer;
+mod can_request_job_extension;
+mod can_reveal_bid;
+mod does_bid_match_commitment;
 mod does_proposed_payment_exceed_budget;
 mod does_proposed_payment_match_transferred;
 mod exists_ongoing_voting;
+mod has_matching_skill;
 mod has_permissions_to_cancel_bid;
 mod has_permissions_to_cancel_job_offer;
+mod has_permissions_to_request_job_extension;
+mod has_permissions_to_respond_to_job_extension;
+mod has_permissions_to_terminate_job;
+mod is_bid_mode_correct;
 mod is_bid_stake_correct;
+mod is_cspr_stake_attached;
 mod is_dos_fee_enough;
 mod is_grace_period;
+mod is_job_extension_requested;
 mod is_not_va;
 mod is_stake_non_zero;
+mod is_team_confirmed;
+mod is_team_member;
+mod is_team_valid;
 
+pub use are_job_periods_valid::AreJobPeriodsValid;
+pub use are_tags_allowed::AreTagsAllowed;
 pub use can_be_onboarded::CanBeOnboarded;
 pub use can_bid_be_cancelled::CanBidBeCancelled;
 pub use can_bid_be_picked::CanBidBePicked;
 pub use can_bid_on_auction_state::CanBidOnAuctionState;
 pub use can_bid_on_own_job::CanBidOnOwnJob;
+pub use can_confirm_team_bid::CanConfirmTeamBid;
+pub use can_job_be_extended::CanJobBeExtended;
+pub use can_job_be_terminated::CanJobBeTerminated;
 pub use can_job_offer_be_cancelled::CanJobOfferBeCancelled;
+pub use can_job_offer_be_modified::CanJobOfferBeModified;
+pub use can_onboard_on_job::CanOnboardOnJob;
 pub use can_pick_bid::CanPickBid;
 pub use can_progress_job_offer::CanProgressJobOffer;
+pub use can_request_job_extension::CanRequestJobExtension;
+pub use can_reveal_bid::CanRevealBid;
+pub use does_bid_match_commitment::DoesBidMatchCommitment;
 pub use does_proposed_payment_exceed_budget::DoesProposedPaymentExceedBudget;
 pub use does_proposed_payment_match_transferred::DoesProposedPaymentMatchTransferred;
 pub use exists_ongoing_voting::ExistsOngoingVoting;
+pub use has_matching_skill::HasMatchingSkill;
 pub use has_permissions_to_cancel_bid::HasPermissionsToCancelBid;
 pub use has_permissions_to_cancel_job_offer::HasPermissionsToCancelJobOffer;
+pub use has_permissions_to_request_job_extension::HasPermissionsToRequestJobExtension;
+pub use has_permissions_to_respond_to_job_extension::HasPermissionsToRespondToJobExtension;
+pub use has_permissions_to_terminate_job::HasPermissionsToTerminateJob;
+pub use is_bid_mode_correct::IsBidModeCorrect;
 pub use is_bid_stake_correct::IsBidStakeCorrect;
+pub use is_cspr_stake_attached::IsCsprStakeAttached;
 pub use is_dos_fee_enough::IsDosFeeEnough;
 pub use is_grace_period::IsGracePeriod;
+pub use is_job_extension_requested::IsJobExtensionRequested;
 pub use is_not_va::IsNotVa;
 pub use is_stake_non_zero::IsStakeNonZero;
+pub use is_team_confirmed::IsTeamConfirmed;
+pub use is_team_member::IsTeamMember;
+pub use is_team_valid::IsTeamValid;
@@ -0,0 +1,28 @@
+use crate::rules::validation::Validation;
+use crate::utils::Error;
+use crate::voting_contracts::AccountMigrationStatus;
+use macros::Rule;
+use odra::types::BlockTime;
+
+/// Verifies if an account recovery can be executed - once, after the challenge period ends.
+/// May return [Error::AccountMigrationNotPending] or [Error::AccountRecoveryChallengeNotEnded].
+#[derive(Rule)]
+pub struct CanExecuteAccountRecovery {
+    status: AccountMigrationStatus,
+    challenge_ends_at: BlockTime,
+    block_time: BlockTime,
+}
+
+impl Validation for CanExecuteAccountRecovery {
+    fn validate(&self) -> Result<(), Error> {
+        if self.status != AccountMigrationStatus::Challenge {
+            return Err(Error::AccountMigrationNotPending);
+        }
+
+        if self.block_time < self.challenge_ends_at {
+            return Err(Error::AccountRecoveryChallengeNotEnded);
+        }
+
+        Ok(())
+    }
+}
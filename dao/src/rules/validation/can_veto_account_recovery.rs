@@ -0,0 +1,36 @@
+use crate::rules::validation::Validation;
+use crate::utils::Error;
+use crate::voting_contracts::AccountMigrationStatus;
+use macros::Rule;
+use odra::types::{Address, BlockTime};
+
+/// Verifies if an account recovery can be vetoed - only by the recovered account,
+/// before the challenge period ends.
+/// May return [Error::OnlyRecoveredAccountCanVeto], [Error::AccountMigrationNotPending]
+/// or [Error::AccountRecoveryChallengeEnded].
+#[derive(Rule)]
+pub struct CanVetoAccountRecovery {
+    caller: Address,
+    old_account: Address,
+    status: AccountMigrationStatus,
+    challenge_ends_at: BlockTime,
+    block_time: BlockTime,
+}
+
+impl Validation for CanVetoAccountRecovery {
+    fn validate(&self) -> Result<(), Error> {
+        if self.caller != self.old_account {
+            return Err(Error::OnlyRecoveredAccountCanVeto);
+        }
+
+        if self.status != AccountMigrationStatus::Challenge {
+            return Err(Error::AccountMigrationNotPending);
+        }
+
+        if self.block_time >= self.challenge_ends_at {
+            return Err(Error::AccountRecoveryChallengeEnded);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,19 @@
+use crate::rules::validation::Validation;
+use crate::utils::Error;
+use macros::Rule;
+
+/// Verifies if the account has never been used in the DAO. May return [Error::AccountInUse].
+#[derive(Rule)]
+pub struct IsFreshAccount {
+    is_fresh: bool,
+}
+
+impl Validation for IsFreshAccount {
+    fn validate(&self) -> Result<(), Error> {
+        if !self.is_fresh {
+            return Err(Error::AccountInUse);
+        };
+
+        Ok(())
+    }
+}
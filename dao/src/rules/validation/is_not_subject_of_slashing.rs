@@ -0,0 +1,19 @@
+use crate::rules::validation::Validation;
+use crate::utils::Error;
+use macros::Rule;
+
+/// Verifies if the user is not a subject of an ongoing slashing voting. May return [Error::SubjectOfSlashing].
+#[derive(Rule)]
+pub struct IsNotSubjectOfSlashing {
+    is_subject_of_slashing: bool,
+}
+
+impl Validation for IsNotSubjectOfSlashing {
+    fn validate(&self) -> Result<(), Error> {
+        if self.is_subject_of_slashing {
+            return Err(Error::SubjectOfSlashing);
+        };
+
+        Ok(())
+    }
+}
@@ -1,20 +1,26 @@
 use macros::Rule;
 
 use crate::rules::validation::Validation;
+use crate::utils::consts::BASIC_KYC_LEVEL;
+use crate::utils::types::KycLevel;
 use crate::utils::Error;
 
-/// Validates if a user is kyc'd. May return [Error::NotKyced].
+/// Validates if a user is kyc'd at least at the required level.
+/// May return [Error::NotKyced] or [Error::KycLevelTooLow].
 #[derive(Rule)]
 pub struct IsUserKyced {
-    user_kyced: bool,
+    user_kyc_level: KycLevel,
+    required_kyc_level: KycLevel,
 }
 
 impl Validation for IsUserKyced {
     fn validate(&self) -> Result<(), Error> {
-        if self.user_kyced {
-            Ok(())
-        } else {
+        if self.user_kyc_level < BASIC_KYC_LEVEL {
             Err(Error::NotKyced)
+        } else if self.user_kyc_level < self.required_kyc_level {
+            Err(Error::KycLevelTooLow)
+        } else {
+            Ok(())
         }
     }
 }
@@ -1,4 +1,8 @@
 //! Groups validations.
+mod can_execute_account_recovery;
+mod can_veto_account_recovery;
+mod is_fresh_account;
+mod is_not_subject_of_slashing;
 mod is_user_kyced;
 mod is_va;
 
@@ -8,6 +12,10 @@ pub mod voting;
 use crate::configuration::Configuration;
 use crate::utils::Error;
 use crate::voting::voting_engine::voting_state_machine::VotingStateMachine;
+pub use can_execute_account_recovery::CanExecuteAccountRecovery;
+pub use can_veto_account_recovery::CanVetoAccountRecovery;
+pub use is_fresh_account::IsFreshAccount;
+pub use is_not_subject_of_slashing::IsNotSubjectOfSlashing;
 pub use is_user_kyced::IsUserKyced;
 pub use is_va::IsVa;
 
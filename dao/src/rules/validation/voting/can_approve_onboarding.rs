@@ -0,0 +1,21 @@
+use crate::rules::validation::Validation;
+use crate::utils::Error;
+use macros::Rule;
+
+/// Verifies if a VA on probation is not the sole approver of an onboarding.
+/// May return [Error::ProbationaryVaCannotBeSoleApprover].
+#[derive(Rule)]
+pub struct CanApproveOnboarding {
+    on_probation: bool,
+    exists_approver_not_on_probation: bool,
+}
+
+impl Validation for CanApproveOnboarding {
+    fn validate(&self) -> Result<(), Error> {
+        if self.on_probation && !self.exists_approver_not_on_probation {
+            return Err(Error::ProbationaryVaCannotBeSoleApprover);
+        }
+
+        Ok(())
+    }
+}
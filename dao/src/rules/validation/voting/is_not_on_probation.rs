@@ -0,0 +1,19 @@
+use crate::rules::validation::Validation;
+use crate::utils::Error;
+use macros::Rule;
+
+/// Verifies if the VA is not on probation. May return [Error::VaOnProbation].
+#[derive(Rule)]
+pub struct IsNotOnProbation {
+    on_probation: bool,
+}
+
+impl Validation for IsNotOnProbation {
+    fn validate(&self) -> Result<(), Error> {
+        if self.on_probation {
+            return Err(Error::VaOnProbation);
+        }
+
+        Ok(())
+    }
+}
@@ -1,12 +1,18 @@
 //! Groups voting related validations.
 mod after_formal_voting;
+mod can_approve_onboarding;
 mod can_create_voting;
 mod finished_voting_can_be_cancelled;
+mod is_not_on_probation;
+mod stake_within_probation_limit;
 mod vote_in_time;
 mod voting_not_completed;
 
 pub use after_formal_voting::AfterFormalVoting;
+pub use can_approve_onboarding::CanApproveOnboarding;
 pub use can_create_voting::CanCreateVoting;
 pub use finished_voting_can_be_cancelled::FinishedVotingCanBeCancelled;
+pub use is_not_on_probation::IsNotOnProbation;
+pub use stake_within_probation_limit::StakeWithinProbationLimit;
 pub use vote_in_time::VoteInTime;
 pub use voting_not_completed::VotingNotCompleted;
@@ -0,0 +1,23 @@
+use crate::rules::validation::Validation;
+use crate::utils::Error;
+use macros::Rule;
+use odra::types::Balance;
+
+/// Verifies if the stake of a VA on probation does not exceed the limit.
+/// May return [Error::ProbationStakeLimitExceeded].
+#[derive(Rule)]
+pub struct StakeWithinProbationLimit {
+    on_probation: bool,
+    stake: Balance,
+    limit: Balance,
+}
+
+impl Validation for StakeWithinProbationLimit {
+    fn validate(&self) -> Result<(), Error> {
+        if self.on_probation && self.stake > self.limit {
+            return Err(Error::ProbationStakeLimitExceeded);
+        }
+
+        Ok(())
+    }
+}
@@ -13,8 +13,6 @@ pub const EP_STAKE: &str = "stake";
 pub const EP_UNSTAKE: &str = "unstake";
 /// The name of `remove_from_whitelist` entry point.
 pub const EP_REMOVE_FROM_WHITELIST: &str = "remove_from_whitelist";
-/// The name of `add_to_whitelist` entry point.
-pub const EP_ADD_TO_WHITELIST: &str = "add_to_whitelist";
 /// The name of `change_ownership` entry point.
 pub const EP_CHANGE_OWNERSHIP: &str = "change_ownership";
 /// The name of `set_or_update` entry point.
@@ -23,6 +21,20 @@ pub const EP_SET_OR_UPDATE: &str = "set_or_update";
 pub const EP_GET: &str = "get";
 /// The name of `update_at` entry point.
 pub const EP_UPDATE_AT: &str = "update_at";
+/// The name of `mint_with_validity` entry point.
+pub const EP_MINT_WITH_VALIDITY: &str = "mint_with_validity";
+/// The name of `renew` entry point.
+pub const EP_RENEW: &str = "renew";
+/// The name of `revoke_kyc` entry point.
+pub const EP_REVOKE_KYC: &str = "revoke_kyc";
+/// The name of `add_kyc_provider` entry point.
+pub const EP_ADD_KYC_PROVIDER: &str = "add_kyc_provider";
+/// The name of `remove_kyc_provider` entry point.
+pub const EP_REMOVE_KYC_PROVIDER: &str = "remove_kyc_provider";
+/// The name of `mint_with_level` entry point.
+pub const EP_MINT_WITH_LEVEL: &str = "mint_with_level";
+/// The name of `upgrade_level` entry point.
+pub const EP_UPGRADE_LEVEL: &str = "upgrade_level";
 
 /// The name of `recipient` entry point argument.
 pub const ARG_RECIPIENT: &str = "recipient";
@@ -42,6 +54,15 @@ pub const ARG_TO: &str = "to";
 pub const ARG_TOKEN_ID: &str = "token_id";
 /// The name of `activation_time` entry point argument.
 pub const ARG_ACTIVATION_TIME: &str = "activation_time";
+/// The name of `validity_period` entry point argument.
+pub const ARG_VALIDITY_PERIOD: &str = "validity_period";
+/// The name of `provider` entry point argument.
+pub const ARG_PROVIDER: &str = "provider";
+/// The name of `level` entry point argument.
+pub const ARG_LEVEL: &str = "level";
+
+/// The KYC level granted if no level is specified.
+pub const BASIC_KYC_LEVEL: crate::utils::types::KycLevel = 1;
 
 /// The key the collection length is stored under.
 pub const LENGTH_SUFFIX: &str = "_length";
@@ -104,6 +125,38 @@ pub const DEFAULT_REPUTATION_SLASH: &str = "DefaultReputationSlash";
 pub const VOTING_IDS_ADDRESS: &str = "VotingIdsAddress";
 /// Amount of time after formal voting end, when it can be cancelled
 pub const CANCEL_FINISHED_VOTING_TIMEOUT: &str = "CancelFinishedVotingTimeout";
+/// How many times a Job deadline can be extended at the Worker's request.
+pub const MAX_DEADLINE_EXTENSIONS: &str = "MaxDeadlineExtensions";
+/// How much of the stake of a sealed Bid that has not been revealed is forfeited.
+pub const UNREVEALED_BID_FORFEIT_RATIO: &str = "UnrevealedBidForfeitRatio";
+/// How much of the DOS Fee of an expired Job Offer or Job is paid to the caller who settles it.
+pub const KEEPER_REWARD_RATIO: &str = "KeeperRewardRatio";
+/// How the part of the Job payment taken by the DAO is split between multiple recipients.
+pub const BID_ESCROW_FEE_SPLIT: &str = "BidEscrowFeeSplit";
+/// How much of the forfeited stakes and DOS Fees is deposited to the Insurance Pool.
+pub const INSURANCE_POOL_RATIO: &str = "InsurancePoolRatio";
+/// Categories a Job Offer can be assigned to.
+pub const JOB_CATEGORIES: &str = "JobCategories";
+/// Skill tags Job Offers and VAs' profiles can be labeled with.
+pub const SKILL_TAGS: &str = "SkillTags";
+/// Whether or not only VAs with a skill matching the Job Offer can bid in the `Internal Auction`.
+pub const INTERNAL_AUCTION_SKILL_MATCH_REQUIRED: &str = "InternalAuctionSkillMatchRequired";
+/// Whether the reputation of an offboarded VA is converted to passive reputation instead of being burned.
+pub const OFFBOARDING_REPUTATION_TO_PASSIVE: &str = "OffboardingReputationToPassive";
+/// Time of the Informal Voting of a sponsored Onboarding Request.
+pub const SPONSORED_INFORMAL_VOTING_TIME: &str = "SponsoredInformalVotingTime";
+/// Time after a sponsored onboarding during which the sponsor's stake is slashed if the new VA is fully slashed.
+pub const SPONSORSHIP_PROBATION_PERIOD: &str = "SponsorshipProbationPeriod";
+/// Time after the onboarding during which a new VA is on probation.
+pub const VA_PROBATION_PERIOD: &str = "VaProbationPeriod";
+/// The maximum stake of a VA on probation.
+pub const VA_PROBATION_STAKE_LIMIT: &str = "VaProbationStakeLimit";
+/// Time a KYC token is valid for after it has been issued or renewed.
+pub const KYC_VALIDITY_PERIOD: &str = "KycValidityPeriod";
+/// Whether the CSPR deposit of a pending Onboarding Request is forfeited when the KYC of the requestor is revoked.
+pub const KYC_REVOCATION_FORFEITS_DEPOSIT: &str = "KycRevocationForfeitsDeposit";
+/// Time after an account recovery voting passes during which the recovered account can veto the recovery.
+pub const ACCOUNT_RECOVERY_CHALLENGE_PERIOD: &str = "AccountRecoveryChallengePeriod";
 
 /// Contract keys.
 pub const CONTRACT_MAIN_PURSE: &str = "__contract_main_purse";
@@ -7,6 +7,7 @@ execution_error! {
         InsufficientBalance => 404,
         PurseError => 405,
         InsufficientBalanceForStake => 406,
+        NothingToClaim => 407,
         NotAnOwner => 1000,
         OwnerIsNotInitialized => 1001,
         NotWhitelisted => 1002,
@@ -17,12 +18,25 @@ execution_error! {
         BytesConversionError => 1008,
         MappingItemNotAvailable => 1009,
         TypeMismatch => 1010,
+        MissingRole => 1011,
+        NotASigner => 1012,
+        InvalidThreshold => 1013,
+        SignerAlreadyExists => 1014,
+        OwnerActionDoesNotExist => 1015,
+        OwnerActionAlreadyApproved => 1016,
+        OwnerActionAlreadyExecuted => 1017,
+        OwnerActionThresholdNotReached => 1018,
+        InvalidOwnerActionArgs => 1019,
+        ContractPaused => 1020,
+        ContractNotPaused => 1021,
+        InvalidAdminActionArgs => 1022,
         InvalidContext => 1099,
         Unknown => 1100,
         NoSuchMethod => 1101,
         VariableValueNotSet => 1102,
         BytesDeserializationError => 1103,
         CouldntDeserializeValueToCorrectType => 1104,
+        InvalidFeeSplit => 1105,
         TokenDoesNotExist => 1700,
         TokenAlreadyExists => 1701,
         ApprovalToCurrentOwner => 1702,
@@ -54,6 +68,17 @@ execution_error! {
         KycAlreadyInProgress => 2206,
         UserKycedAlready => 2207,
         UnexpectedKycError => 2208,
+        SponsorshipNotFound => 2209,
+        SponsorshipProbationNotEnded => 2210,
+        VaOnProbation => 2211,
+        ProbationStakeLimitExceeded => 2212,
+        ProbationaryVaCannotBeSoleApprover => 2213,
+        KycProviderNotRegistered => 2214,
+        KycAttestationExpired => 2215,
+        InvalidKycAttestation => 2216,
+        KycAttestationAlreadyUsed => 2217,
+        KycLevelTooLow => 2218,
+        InvalidKycLevel => 2219,
         MappingIndexDoesNotExist => 3404,
         BallotDoesNotExist => 3405,
         VoterDoesNotExist => 3406,
@@ -71,6 +96,12 @@ execution_error! {
         AttachedValueMismatch => 3418,
         NotAProposedOwner => 3419,
         NoProposedOwner => 3420,
+        SubjectOfAccountRecovery => 3421,
+        AccountInUse => 3422,
+        AccountMigrationNotPending => 3423,
+        AccountRecoveryChallengeNotEnded => 3424,
+        AccountRecoveryChallengeEnded => 3425,
+        OnlyRecoveredAccountCanVeto => 3426,
 
         // Bid Escrow Errors.
         CannotPostJobForSelf => 4000,
@@ -115,6 +146,36 @@ execution_error! {
         BidCanceled => 4039,
         BidRejected => 4040,
         JobProofSubmittedAfterFinishTime => 4041,
+        PaymentTokenNotWhitelisted => 4042,
+        UnexpectedCSPRTransfer => 4043,
+        JobOfferCannotBeModified => 4044,
+        InvalidTeamShares => 4045,
+        InvalidTeamMember => 4046,
+        TeamBidNotConfirmed => 4047,
+        NotATeamMember => 4048,
+        OnlyWorkerCanRequestJobExtension => 4049,
+        JobExtensionNotAllowed => 4050,
+        JobExtensionAlreadyRequested => 4051,
+        JobExtensionsLimitReached => 4052,
+        OnlyJobPosterCanRespondToJobExtension => 4053,
+        JobExtensionNotRequested => 4054,
+        SealedBidRequired => 4055,
+        JobOfferNotSealed => 4056,
+        OnlyBidderCanRevealBid => 4057,
+        BidNotSealed => 4058,
+        CannotRevealBidDuringAuction => 4059,
+        BidCommitmentMismatch => 4060,
+        BidNotRevealed => 4061,
+        UnknownJobCategory => 4062,
+        UnknownSkillTag => 4063,
+        OnlyVaCanDeclareSkills => 4064,
+        NoMatchingSkill => 4065,
+        InvalidJobPeriods => 4066,
+        CannotOnboardOnRecurringJob => 4067,
+        CannotTerminateJob => 4068,
+        JobCannotBeTerminated => 4069,
+        CannotCancelSealedBid => 4070,
+        TeamBidAlreadyConfirmed => 4071,
 
         // Reputation Token Errors.
         CannotStakeTwice => 4500,
@@ -122,6 +183,14 @@ execution_error! {
         BidStakeDoesntExists => 4502,
         CannotUnstakeMoreThanStaked => 4503,
 
+        // Insurance Pool Errors.
+        InsuranceClaimAlreadyFiled => 4600,
+        OnlyJobPosterCanFileInsuranceClaim => 4601,
+        JobNotCompleted => 4602,
+        InsufficientInsurancePoolBalance => 4603,
+        InsuranceClaimNotFound => 4604,
+        InsuranceClaimExceedsJobPayment => 4605,
+
         InvalidAddress => 5000,
         RepositoryError => 5001,
         KeyValueStorageError => 5002,
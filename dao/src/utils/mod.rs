@@ -11,4 +11,4 @@ pub use consts::*;
 pub use contract_call::ContractCall;
 pub use errors::Error;
 pub use math::*;
-pub use transfer::withdraw;
+pub use transfer::{deposit_tokens, withdraw_tokens, Cep18, Cep18Ref};
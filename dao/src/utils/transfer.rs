@@ -1,13 +1,25 @@
-use crate::bid_escrow::events::{CSPRTransfer, TransferReason};
-use odra::contract_env::{self_address, transfer_tokens};
+use crate::bid_escrow::events::{TokenTransfer, TransferReason};
+use crate::utils::Error;
+use odra::contract_env::self_address;
 use odra::prelude::string::ToString;
-use odra::types::{event::OdraEvent, Address, Balance};
+use odra::types::{event::OdraEvent, Address, Balance, U256};
+use odra::UnwrapOrRevert;
 
-/// Withdraws CSPR from the contract and emits a corresponding event.
-pub fn withdraw(to: &Address, amount: Balance, reason: TransferReason) {
-    transfer_tokens(to, amount);
+/// A subset of the [CEP-18](https://github.com/casper-ecosystem/cep18) token interface
+/// used to collect and pay out Job payments.
+#[odra::external_contract]
+pub trait Cep18 {
+    fn transfer(&mut self, recipient: &Address, amount: &U256);
+    fn transfer_from(&mut self, owner: &Address, recipient: &Address, amount: &U256);
+    fn balance_of(&self, address: &Address) -> U256;
+}
+
+/// Withdraws CEP-18 tokens from the contract and emits a corresponding event.
+pub fn withdraw_tokens(token: &Address, to: &Address, amount: Balance, reason: TransferReason) {
+    Cep18Ref::at(token).transfer(to, &to_token_amount(amount));
 
-    CSPRTransfer {
+    TokenTransfer {
+        token: *token,
         from: self_address(),
         to: *to,
         amount,
@@ -15,3 +27,27 @@ pub fn withdraw(to: &Address, amount: Balance, reason: TransferReason) {
     }
     .emit();
 }
+
+/// Transfers CEP-18 tokens from the `owner` to the contract, and returns the amount the contract
+/// has actually received.
+///
+/// The contract must have been approved to spend the `owner`'s tokens beforehand.
+pub fn deposit_tokens(token: &Address, owner: &Address, amount: Balance) -> Balance {
+    let mut token = Cep18Ref::at(token);
+    let contract = self_address();
+    let balance_before = token.balance_of(&contract);
+    token.transfer_from(owner, &contract, &to_token_amount(amount));
+    let balance_after = token.balance_of(&contract);
+
+    balance_after
+        .checked_sub(balance_before)
+        .unwrap_or_revert_with(Error::ArithmeticOverflow)
+        .to_balance()
+        .unwrap_or_revert_with(Error::ArithmeticOverflow)
+}
+
+fn to_token_amount(amount: Balance) -> U256 {
+    amount
+        .to_u256()
+        .unwrap_or_revert_with(Error::ArithmeticOverflow)
+}
@@ -1,3 +1,15 @@
 //! Types aliases used in the DAO.
+use odra::types::PublicKey;
+
 /// The hash of a document sent to the system.
 pub type DocumentHash = odra::prelude::string::String;
+/// The level of a KYC. 0 means no KYC, higher levels stand for an enhanced verification.
+pub type KycLevel = u8;
+
+/// Returns a copy of the `key`.
+///
+/// `PublicKey` is `Copy` in the MockVM only, other backends have to clone it.
+#[allow(clippy::clone_on_copy)]
+pub fn copy_public_key(key: &PublicKey) -> PublicKey {
+    key.clone()
+}
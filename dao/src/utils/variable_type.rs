@@ -5,6 +5,8 @@ pub enum VariableType {
     BlockTime,
     Address,
     Bool,
+    FeeSplit,
+    StringList,
     Unknown,
 }
 
@@ -20,7 +22,12 @@ impl VariableType {
             | FORMAL_QUORUM_RATIO
             | DEFAULT_REPUTATION_SLASH
             | VOTING_CLEARNESS_DELTA
-            | BID_ESCROW_PAYMENT_RATIO => VariableType::Balance,
+            | BID_ESCROW_PAYMENT_RATIO
+            | MAX_DEADLINE_EXTENSIONS
+            | UNREVEALED_BID_FORFEIT_RATIO
+            | KEEPER_REWARD_RATIO
+            | INSURANCE_POOL_RATIO
+            | VA_PROBATION_STAKE_LIMIT => VariableType::Balance,
             INTERNAL_AUCTION_TIME
             | PUBLIC_AUCTION_TIME
             | BID_ESCROW_INFORMAL_VOTING_TIME
@@ -30,14 +37,24 @@ impl VariableType {
             | TIME_BETWEEN_INFORMAL_AND_FORMAL_VOTING
             | VA_BID_ACCEPTANCE_TIMEOUT
             | CANCEL_FINISHED_VOTING_TIMEOUT
-            | VOTING_START_AFTER_JOB_WORKER_SUBMISSION => VariableType::BlockTime,
+            | VOTING_START_AFTER_JOB_WORKER_SUBMISSION
+            | SPONSORED_INFORMAL_VOTING_TIME
+            | SPONSORSHIP_PROBATION_PERIOD
+            | VA_PROBATION_PERIOD
+            | KYC_VALIDITY_PERIOD
+            | ACCOUNT_RECOVERY_CHALLENGE_PERIOD => VariableType::BlockTime,
             FIAT_CONVERSION_RATE_ADDRESS | BID_ESCROW_WALLET_ADDRESS | VOTING_IDS_ADDRESS => {
                 VariableType::Address
             }
             FORUM_KYC_REQUIRED
             | INFORMAL_STAKE_REPUTATION
             | VA_CAN_BID_ON_PUBLIC_AUCTION
-            | DISTRIBUTE_PAYMENT_TO_NON_VOTERS => VariableType::Bool,
+            | DISTRIBUTE_PAYMENT_TO_NON_VOTERS
+            | INTERNAL_AUCTION_SKILL_MATCH_REQUIRED
+            | OFFBOARDING_REPUTATION_TO_PASSIVE
+            | KYC_REVOCATION_FORFEITS_DEPOSIT => VariableType::Bool,
+            BID_ESCROW_FEE_SPLIT => VariableType::FeeSplit,
+            JOB_CATEGORIES | SKILL_TAGS => VariableType::StringList,
             _ => VariableType::Unknown,
         }
     }
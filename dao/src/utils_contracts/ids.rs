@@ -3,11 +3,11 @@
 //! There is one continuous indexation of votes in the system.
 //! Each new voting gets a unique across-the-system id generated by the contract.
 
-use crate::modules::AccessControl;
-use odra::{contract_env, types::Address, Sequence};
+use crate::modules::{AccessControl, OwnerAction, OwnerActionArgs, OwnerProposal, Role};
+use odra::{contract_env, prelude::vec::Vec, types::Address, Sequence};
 
 /// Dao Ids contract manages voting ids in the system.
-/// Only a whitelisted account is eligible to generate ids.
+/// Only the holders of the [`VotingCreator`](crate::modules::Role::VotingCreator) role are eligible to generate ids.
 ///
 /// For details see [DaoIdsContract]).
 #[odra::module]
@@ -27,10 +27,6 @@ impl DaoIdsContract {
             /// Accepts the new owner proposition. This can be called only by the proposed owner.
             /// [`Read more`](crate::modules::access_control::AccessControl::accept_new_owner())
             pub fn accept_new_owner(&mut self);
-            /// Adds a new address to the whitelist.
-            ///
-            /// [`Read more`](AccessControl::add_to_whitelist())
-            pub fn add_to_whitelist(&mut self, address: Address);
             /// Remove address from the whitelist.
             ///
             /// [`Read more`](AccessControl::remove_from_whitelist())
@@ -43,6 +39,54 @@ impl DaoIdsContract {
             ///
             /// [`Read more`](AccessControl::get_owner()).
             pub fn get_owner(&self) -> Option<Address>;
+            /// Grants the `role` to the `address`.
+            /// [`Read more`](crate::modules::access_control::AccessControl::grant_role())
+            pub fn grant_role(&mut self, role: Role, address: Address);
+            /// Revokes the `role` from the `address`.
+            /// [`Read more`](crate::modules::access_control::AccessControl::revoke_role())
+            pub fn revoke_role(&mut self, role: Role, address: Address);
+            /// Sets the admin role of the `role`, whose holders grant and revoke the `role`.
+            /// [`Read more`](crate::modules::access_control::AccessControl::set_role_admin())
+            pub fn set_role_admin(&mut self, role: Role, admin_role: Role);
+            /// Returns the admin role of the `role`, whose holders grant and revoke the `role`.
+            /// [`Read more`](crate::modules::access_control::AccessControl::get_role_admin())
+            pub fn get_role_admin(&self, role: Role) -> Role;
+            /// Checks whether the `address` has the `role`.
+            /// [`Read more`](crate::modules::access_control::AccessControl::has_role())
+            pub fn has_role(&self, role: Role, address: Address) -> bool;
+            /// Replaces the whitelist entry of the `address` with the `roles`.
+            /// [`Read more`](crate::modules::access_control::AccessControl::migrate_to_roles())
+            pub fn migrate_to_roles(&mut self, address: Address, roles: Vec<Role>);
+            /// Hands the ownership over to the `signers`.
+            /// [`Read more`](crate::modules::access_control::AccessControl::enable_multisig_ownership())
+            pub fn enable_multisig_ownership(&mut self, signers: Vec<Address>, threshold: u32);
+            /// Proposes an owner-only action.
+            /// [`Read more`](crate::modules::access_control::AccessControl::propose_owner_action())
+            pub fn propose_owner_action(&mut self, action: OwnerAction, action_args: OwnerActionArgs) -> u32;
+            /// Approves a proposed owner-only action.
+            /// [`Read more`](crate::modules::access_control::AccessControl::approve_owner_action())
+            pub fn approve_owner_action(&mut self, proposal_id: u32);
+            /// Executes a proposed owner-only action approved by the current signers.
+            /// [`Read more`](crate::modules::access_control::AccessControl::execute_owner_action())
+            pub fn execute_owner_action(&mut self, proposal_id: u32);
+            /// Returns the signers of the multisig ownership.
+            /// [`Read more`](crate::modules::access_control::AccessControl::get_signers())
+            pub fn get_signers(&self) -> Vec<Address>;
+            /// Returns the number of approvals required to execute an owner-only action.
+            /// [`Read more`](crate::modules::access_control::AccessControl::get_threshold())
+            pub fn get_threshold(&self) -> u32;
+            /// Returns the proposal of an owner-only action.
+            /// [`Read more`](crate::modules::access_control::AccessControl::get_owner_proposal())
+            pub fn get_owner_proposal(&self, proposal_id: u32) -> Option<OwnerProposal>;
+            /// Pauses the contract.
+            /// [`Read more`](crate::modules::access_control::AccessControl::pause())
+            pub fn pause(&mut self);
+            /// Unpauses the contract.
+            /// [`Read more`](crate::modules::access_control::AccessControl::unpause())
+            pub fn unpause(&mut self);
+            /// Returns true if the contract is paused.
+            /// [`Read more`](crate::modules::access_control::AccessControl::is_paused())
+            pub fn is_paused(&self) -> bool;
         }
     }
 
@@ -59,9 +103,11 @@ impl DaoIdsContract {
     /// Returns the next voting id in the system.
     ///
     /// # Errors
-    /// Throws [`NotWhitelisted`](crate::utils::Error::NotWhitelisted) if the caller is not whitelisted.
+    /// Throws [`MissingRole`](crate::utils::Error::MissingRole) if the caller
+    /// does not have the [`VotingCreator`](crate::modules::Role::VotingCreator) role.
     pub fn next_voting_id(&mut self) -> u32 {
-        self.access_control.ensure_whitelisted();
+        self.access_control.ensure_not_paused();
+        self.access_control.ensure_role(Role::VotingCreator);
         self.voting_id_seq.next_value()
     }
 }
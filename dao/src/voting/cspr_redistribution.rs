@@ -1,31 +1,92 @@
 //! CSPR redistribution helper functions.
+use crate::bid_escrow::events::TransferReason;
 use crate::configuration::Configuration;
 use crate::modules::refs::ContractRefs;
-use odra::contract_env::transfer_tokens;
+use crate::modules::Claims;
 use odra::types::{Address, Balance};
 
-/// Transfers CSPRs to all VAs'. Each VA gets the amount of CSPR proportionally to their reputation.
+/// Credits CSPRs to all VAs'. Each VA gets the amount of CSPR proportionally to their reputation.
 ///
 /// Interacts with [`Reputation Token Contract`](crate::core_contracts::ReputationContract) to get balances information.
-pub fn redistribute_cspr_to_all_vas(to_redistribute: Balance, refs: &ContractRefs) {
+pub fn redistribute_cspr_to_all_vas(
+    to_redistribute: Balance,
+    refs: &ContractRefs,
+    claims: &mut Claims,
+) {
+    redistribute_payment_to_all_vas(to_redistribute, None, refs, claims);
+}
+
+/// Credits some part of a given amount to the DAO fee recipients and returns the remaining amount.
+///
+/// See [`Configuration::bid_escrow_fee_split()`](Configuration::bid_escrow_fee_split()).
+pub fn redistribute_to_governance(
+    amount: Balance,
+    configuration: &Configuration,
+    claims: &mut Claims,
+) -> Balance {
+    redistribute_payment_to_governance(amount, None, configuration, claims)
+}
+
+/// Credits some part of a given amount to the Insurance Pool and returns the remaining amount.
+///
+/// The pool pulls the credited CSPR calling
+/// [`claim_insurance_pool_deposits()`](Claims::claim_insurance_pool_deposits()), so a paused pool
+/// does not block the settlement.
+/// If the Insurance Pool is not set, the whole amount is returned.
+/// See [`Configuration::apply_insurance_pool_ratio_to()`](Configuration::apply_insurance_pool_ratio_to()).
+pub fn redistribute_to_insurance_pool(
+    amount: Balance,
+    configuration: &Configuration,
+    refs: &ContractRefs,
+    claims: &mut Claims,
+) -> Balance {
+    let pool = match refs.insurance_pool_address() {
+        Some(pool) => pool,
+        None => return amount,
+    };
+    let share = configuration.apply_insurance_pool_ratio_to(amount);
+    claims.credit(&pool, share, TransferReason::InsurancePoolDeposit);
+    amount - share
+}
+
+/// Works like [`redistribute_cspr_to_all_vas()`], but transfers CEP-18 tokens if `payment_token` is set.
+pub fn redistribute_payment_to_all_vas(
+    to_redistribute: Balance,
+    payment_token: Option<Address>,
+    refs: &ContractRefs,
+    claims: &mut Claims,
+) {
     let all_balances = refs.reputation_token().all_balances();
     let total_supply = all_balances.total_supply();
     for (address, balance) in all_balances.balances() {
         let amount = to_redistribute * *balance / total_supply;
         if !amount.is_zero() {
-            transfer_tokens(address, amount);
+            claims.credit_payment(
+                address,
+                amount,
+                payment_token,
+                TransferReason::Redistribution,
+            );
         }
     }
 }
 
-/// Transfers some part of a given amount to `Bid Escrow Wallet` and returns the remaining amount.
-///
-/// See [`Configuration::bid_escrow_wallet_address()`](Configuration::bid_escrow_wallet_address()).
-pub fn redistribute_to_governance(amount: Balance, configuration: &Configuration) -> Balance {
-    let governance_wallet: Address = configuration.bid_escrow_wallet_address();
-    let governance_wallet_payment = configuration.apply_bid_escrow_payment_ratio_to(amount);
-    if !amount.is_zero() {
-        transfer_tokens(&governance_wallet, governance_wallet_payment);
+/// Works like [`redistribute_to_governance()`], but transfers CEP-18 tokens if `payment_token` is set.
+pub fn redistribute_payment_to_governance(
+    amount: Balance,
+    payment_token: Option<Address>,
+    configuration: &Configuration,
+    claims: &mut Claims,
+) -> Balance {
+    let mut remaining = amount;
+    for (recipient, fee) in configuration.bid_escrow_fee_split().apply_to(amount) {
+        claims.credit_payment(
+            &recipient,
+            fee,
+            payment_token,
+            TransferReason::Redistribution,
+        );
+        remaining -= fee;
     }
-    amount - governance_wallet_payment
+    remaining
 }
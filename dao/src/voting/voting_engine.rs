@@ -1,7 +1,7 @@
 //! Voting Engine.
 use crate::configuration::Configuration;
 use crate::modules::refs::ContractRefs;
-use crate::rules::validation::voting::CanCreateVoting;
+use crate::rules::validation::voting::{CanCreateVoting, StakeWithinProbationLimit};
 use crate::rules::RulesBuilder;
 use crate::utils::Error;
 use crate::voting::ballot::{Ballot, Choice};
@@ -321,6 +321,8 @@ impl VotingEngine {
     /// # Errors
     /// * [`VoteOnCompletedVotingNotAllowed`](Error::VoteOnCompletedVotingNotAllowed) if voting is completed.
     /// * [`CannotVoteTwice`](Error::CannotVoteTwice) if the voter already voted.
+    /// * [`ProbationStakeLimitExceeded`](Error::ProbationStakeLimitExceeded) if the voter is on probation
+    /// and the stake exceeds the limit.
     pub fn vote(
         &mut self,
         voter: Address,
@@ -355,6 +357,14 @@ impl VotingEngine {
         self.assert_voting_type(voting, voting_type);
         voting.guard_vote(get_block_time(), configuration);
         self.assert_vote_doesnt_exist(voting_id, voting.voting_type(), voter);
+        RulesBuilder::new()
+            .add_validation(StakeWithinProbationLimit::create(
+                self.is_on_probation(voter, configuration),
+                stake,
+                configuration.va_probation_stake_limit(),
+            ))
+            .build()
+            .validate_generic_validations();
         self.cast_ballot(voter, choice, stake, false, voting, configuration);
     }
 
@@ -646,6 +656,17 @@ impl VotingEngine {
         !self.refs.va_token().balance_of(address).is_zero()
     }
 
+    /// Checks if the given address is a VA on probation.
+    pub fn is_on_probation(&self, address: Address, configuration: &Configuration) -> bool {
+        self.is_va(&address)
+            && self
+                .refs
+                .va_token()
+                .onboarded_at(address)
+                .map(|onboarded_at| configuration.is_on_probation(onboarded_at, get_block_time()))
+                .unwrap_or_default()
+    }
+
     /// Get the governance voting's voters list.
     pub fn voters(&self, voting_id: VotingId, voting_type: VotingType) -> List<Address> {
         self.voters.get_instance(&(voting_id, voting_type))
@@ -710,6 +731,44 @@ impl VotingEngine {
         }
     }
 
+    /// Moves the votings created by the `from` address and its ballots in all active votings
+    /// to the `to` address. The staked reputation is not moved.
+    ///
+    /// Returns a vector of affected votings.
+    pub fn migrate_voter(&mut self, from: Address, to: Address) -> Vec<VotingId> {
+        let mut migrated_votings = vec![];
+        for voting_id in self.active_votings.get_or_default() {
+            let mut voting = self.get_voting_or_revert(voting_id);
+            let mut migrated = false;
+            if voting.creator() == &from {
+                voting.set_creator(to);
+                self.set_voting(voting);
+                migrated = true;
+            }
+            for voting_type in [VotingType::Informal, VotingType::Formal] {
+                migrated |= self.migrate_ballot(voting_id, voting_type, from, to);
+            }
+            if migrated {
+                migrated_votings.push(voting_id);
+            }
+        }
+        migrated_votings
+    }
+
+    /// Returns ids of the votings that have not been finished or canceled yet.
+    pub fn get_active_votings(&self) -> Vec<VotingId> {
+        self.active_votings.get_or_default()
+    }
+
+    /// Cancels an ongoing voting and returns the stakes to the voters.
+    ///
+    /// # Events
+    /// * [`VotingCanceled`]
+    pub fn cancel_ongoing_voting(&mut self, voting_id: VotingId) {
+        let voting = self.get_voting_or_revert(voting_id);
+        self.cancel_voting(voting);
+    }
+
     fn cancel_voting(&mut self, mut voting: VotingStateMachine) {
         let voting_id = voting.voting_id();
         let voting_type = voting.voting_type();
@@ -756,6 +815,30 @@ impl VotingEngine {
         true
     }
 
+    /// Moves voter's ballot in the given voting to the `to` address, keeping the voter's position on the voters list.
+    /// Returns true if the voter has voted in the voting.
+    fn migrate_ballot(
+        &mut self,
+        voting_id: VotingId,
+        voting_type: VotingType,
+        from: Address,
+        to: Address,
+    ) -> bool {
+        let mut ballot = match self.ballots.get(&(voting_id, voting_type, from)) {
+            Some(ballot) => ballot,
+            None => return false,
+        };
+        ballot.voter = to;
+        self.ballots.set(&(voting_id, voting_type, to), ballot);
+
+        let mut voters = self.voters(voting_id, voting_type);
+        if let Some(position) = voters.iter().position(|voter| voter == from) {
+            voters.replace(position as u32, to);
+        }
+
+        true
+    }
+
     fn add_to_active_list(&mut self, voting_id: VotingId) {
         let mut active_list = self.active_votings.get_or_default();
         active_list.push(voting_id);
@@ -256,6 +256,11 @@ impl VotingStateMachine {
         &self.creator
     }
 
+    /// Sets the voting creator, after the creator's account has been migrated.
+    pub fn set_creator(&mut self, creator: Address) {
+        self.creator = creator;
+    }
+
     /// Gets the current voting state.
     pub fn state(&self) -> &VotingState {
         &self.state
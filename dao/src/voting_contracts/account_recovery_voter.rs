@@ -0,0 +1,569 @@
+//! Contains Account Recovery Voter Contract definition and related abstractions.
+//!
+//! # Account Recovery
+//! A `VA` who lost the key cannot act on their own, and the `VA Token`, the `KYC Token` and the reputation
+//! are bound to the address. Any `VA` can create an account recovery voting to move the assets of the lost
+//! account (the old account) to a new account controlled by the same person. A `VA` who wants to rotate their key
+//! goes through the same voting.
+//!
+//! The new account must be a fresh one - it must not own a `VA Token` nor a `KYC Token`, nor hold any reputation.
+//! The old account is not allowed to vote, as its key might have been compromised.
+//!
+//! If the formal voting passes, the challenge period of `AccountRecoveryChallengePeriod` starts. Until it ends,
+//! the old account can [veto](AccountRecoveryVoterContract::veto_recovery()) its own recovery, so a `VA` who
+//! has not lost the key can stop a malicious recovery. Once the challenge period ends, anyone can
+//! [execute](AccountRecoveryVoterContract::execute_recovery()) the recovery. The migration is performed atomically:
+//! * the `VA Token` and the `KYC Token` are moved, preserving the onboarding time, the KYC validity and the KYC level,
+//! * the reputation, the passive reputation and the staked reputation are moved,
+//! * the votings created by the old account and its ballots in the ongoing votings of the migratable contracts
+//! are moved,
+//! * the open `Job Offers`, the `Bids` and the `Jobs` in progress in the [`Bid Escrow Contract`] are moved,
+//! * the sponsorships in the [`Onboarding Request Contract`] are moved,
+//! * the unclaimed `CSPR` and `CEP-18` tokens in the migratable contracts are moved.
+//!
+//! Every migration leaves the [`AccountMigrated`] event for auditors. If the old account is no longer a `VA`,
+//! or the new account has been used in the meantime, the migration is aborted and [`AccountMigrationAborted`]
+//! is emitted. A vetoed recovery emits [`AccountRecoveryVetoed`].
+//!
+//! # Voting
+//! The Voting process is managed by [`VotingEngine`].
+//!
+//! [`Bid Escrow Contract`]: crate::bid_escrow::contract::BidEscrowContract
+//! [`Onboarding Request Contract`]: crate::voting_contracts::OnboardingRequestContract
+//! [`VotingEngine`]: VotingEngine
+use odra::{
+    contract_env::{caller, get_block_time, revert},
+    prelude::vec::Vec,
+    types::{event::OdraEvent, Address, Balance, BlockTime},
+    Event, Mapping, OdraType, UnwrapOrRevert, Variable,
+};
+
+use crate::rules::validation::{
+    CanExecuteAccountRecovery, CanVetoAccountRecovery, IsFreshAccount, IsVa,
+};
+use crate::rules::RulesBuilder;
+use crate::voting_contracts::SlashedVotings;
+use crate::{
+    configuration::ConfigurationBuilder,
+    modules::{
+        refs::ContractRefs, AccessControl, OwnerAction, OwnerActionArgs, OwnerProposal, Role,
+    },
+    utils::Error,
+    voting::{
+        ballot::{Ballot, Choice},
+        types::VotingId,
+        voting_engine::{
+            events::VotingCreatedInfo,
+            voting_state_machine::{VotingResult, VotingStateMachine, VotingSummary, VotingType},
+            VotingEngine,
+        },
+    },
+};
+
+/// Account Recovery Voter contract uses [VotingEngine](VotingEngine) to vote on moving a VA's assets to a new account.
+///
+/// Account Recovery Voter contract needs to have permissions to migrate accounts in the token contracts
+/// and in the migratable contracts.
+#[odra::module(events = [AccountRecoveryVotingCreated, AccountRecoveryChallengeStarted, AccountRecoveryVetoed, AccountMigrated, AccountMigrationAborted])]
+pub struct AccountRecoveryVoterContract {
+    refs: ContractRefs,
+    #[odra(using = "refs")]
+    voting_engine: VotingEngine,
+    migrations: Mapping<VotingId, AccountMigration>,
+    migratable_contracts: Variable<Vec<Address>>,
+    access_control: AccessControl,
+}
+
+#[odra::module]
+impl AccountRecoveryVoterContract {
+    delegate! {
+        to self.voting_engine {
+            /// Checks if voting of a given type and id exists.
+            pub fn voting_exists(&self, voting_id: VotingId, voting_type: VotingType) -> bool;
+            /// Returns the address of nth voter who voted on Voting with `voting_id`.
+            pub fn get_voter(&self, voting_id: VotingId, voting_type: VotingType, at: u32) -> Option<Address>;
+            /// Returns [Voting](VotingStateMachine) for given id.
+            pub fn get_voting(
+                &self,
+                voting_id: VotingId,
+            ) -> Option<VotingStateMachine>;
+            /// Returns the Voter's [`Ballot`].
+            pub fn get_ballot(
+                &self,
+                voting_id: VotingId,
+                voting_type: VotingType,
+                voter: Address,
+            ) -> Option<Ballot>;
+            /// Cancels a voting that has not been finished in defined time.
+            pub fn cancel_finished_voting(&mut self, voting_id: VotingId);
+        }
+
+        to self.access_control {
+            /// Changes the ownership of the contract. Transfers ownership to the `owner`.
+            /// Only the current owner is permitted to call this method.
+            /// [`Read more`](AccessControl::propose_new_owner())
+            pub fn propose_new_owner(&mut self, owner: Address);
+            /// Accepts the new owner proposition. This can be called only by the proposed owner.
+            /// [`Read more`](AccessControl::accept_new_owner())
+            pub fn accept_new_owner(&mut self);
+            /// Remove address from the whitelist.
+            /// [`Read more`](AccessControl::remove_from_whitelist())
+            pub fn remove_from_whitelist(&mut self, address: Address);
+            /// Checks whether the given address is added to the whitelist.
+            /// [`Read more`](AccessControl::is_whitelisted()).
+            pub fn is_whitelisted(&self, address: Address) -> bool;
+            /// Returns the address of the current owner.
+            /// [`Read more`](AccessControl::get_owner()).
+            pub fn get_owner(&self) -> Option<Address>;
+            /// Grants the `role` to the `address`.
+            /// [`Read more`](AccessControl::grant_role())
+            pub fn grant_role(&mut self, role: Role, address: Address);
+            /// Revokes the `role` from the `address`.
+            /// [`Read more`](AccessControl::revoke_role())
+            pub fn revoke_role(&mut self, role: Role, address: Address);
+            /// Sets the admin role of the `role`, whose holders grant and revoke the `role`.
+            /// [`Read more`](AccessControl::set_role_admin())
+            pub fn set_role_admin(&mut self, role: Role, admin_role: Role);
+            /// Returns the admin role of the `role`, whose holders grant and revoke the `role`.
+            /// [`Read more`](AccessControl::get_role_admin())
+            pub fn get_role_admin(&self, role: Role) -> Role;
+            /// Checks whether the `address` has the `role`.
+            /// [`Read more`](AccessControl::has_role())
+            pub fn has_role(&self, role: Role, address: Address) -> bool;
+            /// Replaces the whitelist entry of the `address` with the `roles`.
+            /// [`Read more`](AccessControl::migrate_to_roles())
+            pub fn migrate_to_roles(&mut self, address: Address, roles: Vec<Role>);
+            /// Hands the ownership over to the `signers`.
+            /// [`Read more`](AccessControl::enable_multisig_ownership())
+            pub fn enable_multisig_ownership(&mut self, signers: Vec<Address>, threshold: u32);
+            /// Proposes an owner-only action.
+            /// [`Read more`](AccessControl::propose_owner_action())
+            pub fn propose_owner_action(&mut self, action: OwnerAction, action_args: OwnerActionArgs) -> u32;
+            /// Approves a proposed owner-only action.
+            /// [`Read more`](AccessControl::approve_owner_action())
+            pub fn approve_owner_action(&mut self, proposal_id: u32);
+            /// Executes a proposed owner-only action approved by the current signers.
+            /// [`Read more`](AccessControl::execute_owner_action())
+            pub fn execute_owner_action(&mut self, proposal_id: u32);
+            /// Returns the signers of the multisig ownership.
+            /// [`Read more`](AccessControl::get_signers())
+            pub fn get_signers(&self) -> Vec<Address>;
+            /// Returns the number of approvals required to execute an owner-only action.
+            /// [`Read more`](AccessControl::get_threshold())
+            pub fn get_threshold(&self) -> u32;
+            /// Returns the proposal of an owner-only action.
+            /// [`Read more`](AccessControl::get_owner_proposal())
+            pub fn get_owner_proposal(&self, proposal_id: u32) -> Option<OwnerProposal>;
+            /// Pauses the contract.
+            /// [`Read more`](AccessControl::pause())
+            pub fn pause(&mut self);
+            /// Unpauses the contract.
+            /// [`Read more`](AccessControl::unpause())
+            pub fn unpause(&mut self);
+            /// Returns true if the contract is paused.
+            /// [`Read more`](AccessControl::is_paused())
+            pub fn is_paused(&self) -> bool;
+        }
+
+        to self.refs {
+            /// Returns the address of [Variable Repository](crate::core_contracts::VariableRepositoryContract) contract.
+            pub fn variable_repository_address(&self) -> Address;
+            /// Returns the address of [Reputation Token](crate::core_contracts::ReputationContract) contract.
+            pub fn reputation_token_address(&self) -> Address;
+        }
+    }
+
+    /// Contract constructor.
+    ///
+    /// Initializes contract elements:
+    /// * Sets up [`ContractRefs`] by writing addresses of [`Variable Repository`](crate::core_contracts::VariableRepositoryContract),
+    /// [`Reputation Token`](crate::core_contracts::ReputationContract), [`VA Token`](crate::core_contracts::VaNftContract),
+    /// [`KYC Token`](crate::core_contracts::KycNftContract).
+    /// * Sets [`caller`] as the owner of the contract.
+    /// * Grants [`caller`] the [`Admin`](crate::modules::Role::Admin) role.
+    ///
+    /// # Events
+    /// * [`OwnerChanged`](crate::modules::owner::events::OwnerChanged),
+    /// * [`RoleGranted`](crate::modules::roles::events::RoleGranted),
+    #[odra(init)]
+    pub fn init(
+        &mut self,
+        variable_repository: Address,
+        reputation_token: Address,
+        va_token: Address,
+        kyc_token: Address,
+    ) {
+        self.refs.set_variable_repository(variable_repository);
+        self.refs.set_reputation_token(reputation_token);
+        self.refs.set_va_token(va_token);
+        self.refs.set_kyc_token(kyc_token);
+        self.access_control.init(caller());
+    }
+
+    /// Sets the contracts in which the votings, ballots and other activities of a recovered account are migrated.
+    ///
+    /// # Errors
+    /// * [`MissingRole`](crate::utils::Error::MissingRole) if the caller
+    /// does not have the [`Operator`](crate::modules::Role::Operator) role.
+    pub fn update_migratable_contracts(&mut self, migratable_contracts: Vec<Address>) {
+        self.access_control.ensure_role(Role::Operator);
+        self.migratable_contracts.set(migratable_contracts);
+    }
+
+    /// Returns the contracts in which the votings, ballots and other activities of a recovered account are migrated.
+    pub fn get_migratable_contracts(&self) -> Vec<Address> {
+        self.migratable_contracts.get_or_default()
+    }
+
+    /// Creates a new account recovery voting.
+    ///
+    /// # Errors
+    /// * [`NotOnboarded`](crate::utils::Error::NotOnboarded) if the caller or the `old_account` is not a VA.
+    /// * [`AccountInUse`](crate::utils::Error::AccountInUse) if the `new_account` owns a VA or KYC token,
+    /// or holds reputation.
+    ///
+    /// # Events
+    /// * [`AccountRecoveryVotingCreated`]
+    pub fn create_voting(&mut self, old_account: Address, new_account: Address, stake: Balance) {
+        self.access_control.ensure_not_paused();
+        let creator = caller();
+
+        RulesBuilder::new()
+            .add_validation(IsVa::create(self.is_va(&creator)))
+            .add_validation(IsVa::create(self.is_va(&old_account)))
+            .add_validation(IsFreshAccount::create(self.is_fresh_account(&new_account)))
+            .build()
+            .validate_generic_validations();
+
+        let voting_configuration = ConfigurationBuilder::new(
+            self.refs.va_token().total_supply(),
+            &self.refs.variable_repository().all_variables(),
+        )
+        .build();
+
+        let challenge_period = voting_configuration.account_recovery_challenge_period();
+        let (info, _) = self
+            .voting_engine
+            .create_voting(creator, stake, voting_configuration);
+
+        self.migrations.set(
+            &info.voting_id,
+            AccountMigration {
+                old_account,
+                new_account,
+                status: AccountMigrationStatus::Voting,
+                challenge_period,
+                challenge_ends_at: 0,
+            },
+        );
+
+        AccountRecoveryVotingCreated::new(old_account, new_account, info).emit();
+    }
+
+    /// Casts a vote. [Read more](VotingEngine::vote())
+    ///
+    /// # Errors
+    /// * [`SubjectOfAccountRecovery`](crate::utils::Error::SubjectOfAccountRecovery) if the caller
+    /// is the account being recovered.
+    pub fn vote(
+        &mut self,
+        voting_id: VotingId,
+        voting_type: VotingType,
+        choice: Choice,
+        stake: Balance,
+    ) {
+        self.access_control.ensure_not_paused();
+        let migration = self.migrations.get(&voting_id).unwrap_or_revert();
+        if caller() == migration.old_account {
+            revert(Error::SubjectOfAccountRecovery);
+        }
+        self.voting_engine
+            .vote(caller(), voting_id, voting_type, choice, stake);
+    }
+
+    /// Finishes voting. If the formal voting passes, the challenge period of the recovery starts.
+    ///
+    /// # Events
+    /// * [`AccountRecoveryChallengeStarted`] if the formal voting has passed.
+    pub fn finish_voting(&mut self, voting_id: VotingId, voting_type: VotingType) -> VotingSummary {
+        self.access_control.ensure_not_paused();
+        let summary = self.voting_engine.finish_voting(voting_id, voting_type);
+        if summary.is_formal() {
+            let mut migration = self.migrations.get(&voting_id).unwrap_or_revert();
+            if summary.result() == VotingResult::InFavor {
+                migration.status = AccountMigrationStatus::Challenge;
+                migration.challenge_ends_at = get_block_time() + migration.challenge_period;
+                AccountRecoveryChallengeStarted {
+                    voting_id,
+                    old_account: migration.old_account,
+                    new_account: migration.new_account,
+                    challenge_ends_at: migration.challenge_ends_at,
+                }
+                .emit();
+            } else {
+                migration.status = AccountMigrationStatus::Rejected;
+            }
+            self.migrations.set(&voting_id, migration);
+        }
+        summary
+    }
+
+    /// Vetoes the recovery of the caller's account voted in the voting with the given id.
+    /// The recovered account can veto its recovery until the challenge period ends.
+    ///
+    /// # Errors
+    /// * [`OnlyRecoveredAccountCanVeto`](crate::utils::Error::OnlyRecoveredAccountCanVeto) if the caller
+    /// is not the account being recovered.
+    /// * [`AccountMigrationNotPending`](crate::utils::Error::AccountMigrationNotPending) if the voting
+    /// has not passed, or the recovery has been vetoed or executed.
+    /// * [`AccountRecoveryChallengeEnded`](crate::utils::Error::AccountRecoveryChallengeEnded) if
+    /// the challenge period has ended.
+    ///
+    /// # Events
+    /// * [`AccountRecoveryVetoed`]
+    pub fn veto_recovery(&mut self, voting_id: VotingId) {
+        self.access_control.ensure_not_paused();
+        let mut migration = self.migrations.get(&voting_id).unwrap_or_revert();
+
+        RulesBuilder::new()
+            .add_validation(CanVetoAccountRecovery::create(
+                caller(),
+                migration.old_account,
+                migration.status,
+                migration.challenge_ends_at,
+                get_block_time(),
+            ))
+            .build()
+            .validate_generic_validations();
+
+        migration.status = AccountMigrationStatus::Vetoed;
+        AccountRecoveryVetoed {
+            voting_id,
+            old_account: migration.old_account,
+            new_account: migration.new_account,
+        }
+        .emit();
+        self.migrations.set(&voting_id, migration);
+    }
+
+    /// Migrates the old account to the new one, once the challenge period of the recovery voted in the voting
+    /// with the given id has ended. Anyone can call this method.
+    ///
+    /// # Errors
+    /// * [`AccountMigrationNotPending`](crate::utils::Error::AccountMigrationNotPending) if the voting
+    /// has not passed, or the recovery has been vetoed or executed.
+    /// * [`AccountRecoveryChallengeNotEnded`](crate::utils::Error::AccountRecoveryChallengeNotEnded) if
+    /// the challenge period has not ended yet.
+    ///
+    /// # Events
+    /// * [`AccountMigrated`] if the account has been migrated.
+    /// * [`AccountMigrationAborted`] if the migration could not be performed.
+    pub fn execute_recovery(&mut self, voting_id: VotingId) {
+        self.access_control.ensure_not_paused();
+        let migration = self.migrations.get(&voting_id).unwrap_or_revert();
+
+        RulesBuilder::new()
+            .add_validation(CanExecuteAccountRecovery::create(
+                migration.status,
+                migration.challenge_ends_at,
+                get_block_time(),
+            ))
+            .build()
+            .validate_generic_validations();
+
+        self.migrate(voting_id, migration);
+    }
+
+    /// Returns the account migration voted in the voting with the given id.
+    pub fn get_account_migration(&self, voting_id: VotingId) -> Option<AccountMigration> {
+        self.migrations.get(&voting_id)
+    }
+
+    /// Erases the voter from all ongoing votings. [Read more](VotingEngine::slash_voter).
+    pub fn slash_voter(&mut self, voter: Address) -> SlashedVotings {
+        self.access_control.ensure_not_paused();
+        self.access_control.ensure_role(Role::Slasher);
+        self.voting_engine.slash_voter(voter)
+    }
+}
+
+impl AccountRecoveryVoterContract {
+    fn migrate(&mut self, voting_id: VotingId, mut migration: AccountMigration) {
+        let old_account = migration.old_account;
+        let new_account = migration.new_account;
+
+        // Either account might have changed since the voting was created.
+        if !self.is_va(&old_account) || !self.is_fresh_account(&new_account) {
+            migration.status = AccountMigrationStatus::Aborted;
+            self.migrations.set(&voting_id, migration);
+            AccountMigrationAborted {
+                voting_id,
+                old_account,
+                new_account,
+            }
+            .emit();
+            return;
+        }
+
+        migration.status = AccountMigrationStatus::Executed;
+        self.migrations.set(&voting_id, migration);
+
+        // Migrate ballots and activities first, the stakes are settled against the old account.
+        let mut affected_votings = self.voting_engine.migrate_voter(old_account, new_account);
+        for address in self.migratable_contracts.get_or_default() {
+            affected_votings
+                .extend(MigratableRef::at(&address).migrate_account(old_account, new_account));
+        }
+
+        let mut reputation_token = self.refs.reputation_token();
+        let reputation = reputation_token.balance_of(old_account);
+        let passive_reputation = reputation_token.passive_balance_of(old_account);
+        let stake = reputation_token.get_stake(old_account);
+        reputation_token.migrate(old_account, new_account);
+
+        self.refs.va_token().migrate(old_account, new_account);
+
+        let mut kyc_token = self.refs.kyc_token();
+        let kyc_migrated = !kyc_token.balance_of(&old_account).is_zero();
+        if kyc_migrated {
+            kyc_token.migrate(old_account, new_account);
+        }
+
+        AccountMigrated {
+            voting_id,
+            old_account,
+            new_account,
+            reputation,
+            passive_reputation,
+            stake,
+            kyc_migrated,
+            affected_votings,
+        }
+        .emit();
+    }
+
+    fn is_va(&self, address: &Address) -> bool {
+        !self.refs.va_token().balance_of(address).is_zero()
+    }
+
+    fn is_fresh_account(&self, address: &Address) -> bool {
+        let reputation_token = self.refs.reputation_token();
+        self.refs.va_token().balance_of(address).is_zero()
+            && self.refs.kyc_token().balance_of(address).is_zero()
+            && reputation_token.balance_of(*address).is_zero()
+            && reputation_token.passive_balance_of(*address).is_zero()
+    }
+}
+
+/// A contract which activities of an account can be moved to another account.
+#[odra::external_contract]
+pub trait Migratable {
+    /// Moves the activities of the `from` address to the `to` address. Returns ids of the affected votings.
+    fn migrate_account(&mut self, from: Address, to: Address) -> Vec<VotingId>;
+}
+
+/// Accounts of an account recovery voting and the state of the recovery.
+#[derive(Debug, PartialEq, Eq, OdraType)]
+pub struct AccountMigration {
+    pub old_account: Address,
+    pub new_account: Address,
+    pub status: AccountMigrationStatus,
+    /// The time the old account can veto the recovery for, once the voting has passed.
+    pub challenge_period: BlockTime,
+    /// The end of the challenge period, set once the voting has passed.
+    pub challenge_ends_at: BlockTime,
+}
+
+/// The state of an account recovery.
+#[derive(Debug, PartialEq, Eq, Copy, OdraType)]
+pub enum AccountMigrationStatus {
+    /// The recovery voting is in progress.
+    Voting,
+    /// The recovery voting has failed.
+    Rejected,
+    /// The recovery voting has passed, the old account can veto the recovery.
+    Challenge,
+    /// The old account has vetoed the recovery.
+    Vetoed,
+    /// The old account has been migrated to the new one.
+    Executed,
+    /// The migration could not be performed.
+    Aborted,
+}
+
+/// Event emitted when an account has been migrated as a result of an account recovery voting.
+#[derive(Debug, PartialEq, Eq, Event)]
+pub struct AccountMigrated {
+    pub voting_id: VotingId,
+    pub old_account: Address,
+    pub new_account: Address,
+    pub reputation: Balance,
+    pub passive_reputation: Balance,
+    pub stake: Balance,
+    pub kyc_migrated: bool,
+    pub affected_votings: Vec<VotingId>,
+}
+
+/// Event emitted when an account recovery voting has passed and the old account can veto the recovery.
+#[derive(Debug, PartialEq, Eq, Event)]
+pub struct AccountRecoveryChallengeStarted {
+    pub voting_id: VotingId,
+    pub old_account: Address,
+    pub new_account: Address,
+    pub challenge_ends_at: BlockTime,
+}
+
+/// Event emitted when the old account has vetoed its recovery.
+#[derive(Debug, PartialEq, Eq, Event)]
+pub struct AccountRecoveryVetoed {
+    pub voting_id: VotingId,
+    pub old_account: Address,
+    pub new_account: Address,
+}
+
+/// Event emitted when an account recovery voting has passed, but the migration could not be performed.
+#[derive(Debug, PartialEq, Eq, Event)]
+pub struct AccountMigrationAborted {
+    pub voting_id: VotingId,
+    pub old_account: Address,
+    pub new_account: Address,
+}
+
+/// Event emitted when an account recovery voting has been created.
+#[derive(Debug, PartialEq, Eq, Event)]
+pub struct AccountRecoveryVotingCreated {
+    old_account: Address,
+    new_account: Address,
+    creator: Address,
+    stake: Option<Balance>,
+    voting_id: VotingId,
+    config_informal_quorum: u32,
+    config_informal_voting_time: u64,
+    config_formal_quorum: u32,
+    config_formal_voting_time: u64,
+    config_total_onboarded: Balance,
+    config_double_time_between_votings: bool,
+    config_voting_clearness_delta: Balance,
+    config_time_between_informal_and_formal_voting: BlockTime,
+}
+
+impl AccountRecoveryVotingCreated {
+    pub fn new(old_account: Address, new_account: Address, info: VotingCreatedInfo) -> Self {
+        Self {
+            old_account,
+            new_account,
+            creator: info.creator,
+            stake: info.stake,
+            voting_id: info.voting_id,
+            config_informal_quorum: info.config_informal_quorum,
+            config_informal_voting_time: info.config_informal_voting_time,
+            config_formal_quorum: info.config_formal_quorum,
+            config_formal_voting_time: info.config_formal_voting_time,
+            config_total_onboarded: info.config_total_onboarded,
+            config_double_time_between_votings: info.config_double_time_between_votings,
+            config_voting_clearness_delta: info.config_voting_clearness_delta,
+            config_time_between_informal_and_formal_voting: info
+                .config_time_between_informal_and_formal_voting,
+        }
+    }
+}
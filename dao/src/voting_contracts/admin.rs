@@ -1,7 +1,11 @@
 use crate::configuration::ConfigurationBuilder;
 use crate::modules::refs::ContractRefs;
-use crate::modules::{AccessControl, AccessControlRef};
-use crate::utils::ContractCall;
+use crate::modules::{
+    AccessControl, AccessControlRef, OwnerAction, OwnerActionArgs, OwnerProposal, Role,
+};
+use crate::rules::validation::voting::IsNotOnProbation;
+use crate::rules::RulesBuilder;
+use crate::utils::{ContractCall, Error};
 use crate::voting::ballot::{Ballot, Choice};
 use crate::voting::types::VotingId;
 use crate::voting::voting_engine::events::VotingCreatedInfo;
@@ -9,14 +13,20 @@ use crate::voting::voting_engine::voting_state_machine::VotingType;
 use crate::voting::voting_engine::voting_state_machine::{VotingStateMachine, VotingSummary};
 use crate::voting::voting_engine::VotingEngine;
 use crate::voting_contracts::SlashedVotings;
-use odra::contract_env::{caller, emit_event};
+use odra::contract_env::{caller, emit_event, revert};
 use odra::prelude::string::{String, ToString};
+use odra::prelude::vec::Vec;
 use odra::types::{Address, Balance, BlockTime, CallArgs};
-use odra::{Event, OdraType};
+use odra::{Event, OdraType, UnwrapOrRevert};
 
-/// Admin contract uses [VotingEngine](VotingEngine) to vote on changes of ownership and managing whitelists of other contracts.
+/// Admin contract uses [VotingEngine](VotingEngine) to vote on changes of ownership, roles and managing whitelists of other contracts.
+/// It also votes on pausing and unpausing other contracts in an emergency.
 ///
-/// Admin contract needs to have permissions to perform those actions.
+/// Admin contract needs to have permissions to perform those actions. To grant or revoke a role,
+/// it has to be the owner of the contract or have the admin role of the role. To pause a contract,
+/// it has to be the owner of the contract or have the [`Guardian`](crate::modules::Role::Guardian) role.
+///
+/// A VA on probation cannot create a voting.
 #[odra::module(events = [AdminVotingCreated])]
 pub struct AdminContract {
     refs: ContractRefs,
@@ -41,17 +51,31 @@ impl AdminContract {
                 address: Address,
             ) -> Option<Ballot>;
             pub fn get_voter(&self, voting_id: VotingId, voting_type: VotingType, at: u32) -> Option<Address>;
-            pub fn finish_voting(&mut self, voting_id: VotingId, voting_type: VotingType) -> VotingSummary;
             pub fn cancel_finished_voting(&mut self, voting_id: VotingId);
         }
 
         to self.access_control {
             pub fn propose_new_owner(&mut self, owner: Address);
             pub fn accept_new_owner(&mut self);
-            pub fn add_to_whitelist(&mut self, address: Address);
             pub fn remove_from_whitelist(&mut self, address: Address);
             pub fn is_whitelisted(&self, address: Address) -> bool;
             pub fn get_owner(&self) -> Option<Address>;
+            pub fn grant_role(&mut self, role: Role, address: Address);
+            pub fn revoke_role(&mut self, role: Role, address: Address);
+            pub fn set_role_admin(&mut self, role: Role, admin_role: Role);
+            pub fn get_role_admin(&self, role: Role) -> Role;
+            pub fn has_role(&self, role: Role, address: Address) -> bool;
+            pub fn migrate_to_roles(&mut self, address: Address, roles: Vec<Role>);
+            pub fn enable_multisig_ownership(&mut self, signers: Vec<Address>, threshold: u32);
+            pub fn propose_owner_action(&mut self, action: OwnerAction, action_args: OwnerActionArgs) -> u32;
+            pub fn approve_owner_action(&mut self, proposal_id: u32);
+            pub fn execute_owner_action(&mut self, proposal_id: u32);
+            pub fn get_signers(&self) -> Vec<Address>;
+            pub fn get_threshold(&self) -> u32;
+            pub fn get_owner_proposal(&self, proposal_id: u32) -> Option<OwnerProposal>;
+            pub fn pause(&mut self);
+            pub fn unpause(&mut self);
+            pub fn is_paused(&self) -> bool;
         }
     }
 
@@ -68,15 +92,35 @@ impl AdminContract {
         self.access_control.init(caller());
     }
 
+    /// Creates a voting on the `action` applied to the `contract_to_update`.
+    ///
+    /// The `role` is required by the [`GrantRole`](Action::GrantRole) and
+    /// [`RevokeRole`](Action::RevokeRole) actions and ignored by the other ones.
+    /// The `address` is required by all the actions but [`Pause`](Action::Pause)
+    /// and [`Unpause`](Action::Unpause), which do not accept it.
+    ///
+    /// # Errors
+    /// * [`InvalidAdminActionArgs`](crate::utils::Error::InvalidAdminActionArgs) if the `role`
+    /// is required but not set, or the `address` is required but not set or set but not accepted.
     pub fn create_voting(
         &mut self,
         contract_to_update: Address,
         action: Action,
-        address: Address,
+        address: Option<Address>,
+        role: Option<Role>,
         stake: Balance,
     ) {
+        self.access_control.ensure_not_paused();
         let mut call_args = CallArgs::new();
-        call_args.insert(action.get_arg(), address);
+        if action.requires_role() {
+            let role = role.unwrap_or_revert_with(Error::InvalidAdminActionArgs);
+            call_args.insert("role", role);
+        }
+        match (action.get_arg(), address) {
+            (Some(arg), Some(address)) => call_args.insert(arg, address),
+            (None, None) => {}
+            _ => revert(Error::InvalidAdminActionArgs),
+        }
 
         let voting_configuration = ConfigurationBuilder::new(
             self.refs.va_token().total_supply(),
@@ -90,14 +134,24 @@ impl AdminContract {
         })
         .build();
 
+        let creator = caller();
+        RulesBuilder::new()
+            .add_validation(IsNotOnProbation::create(
+                self.voting_engine
+                    .is_on_probation(creator, &voting_configuration),
+            ))
+            .build()
+            .validate_generic_validations();
+
         let (info, _) = self
             .voting_engine
-            .create_voting(caller(), stake, voting_configuration);
+            .create_voting(creator, stake, voting_configuration);
 
         emit_event(AdminVotingCreated::new(
             contract_to_update,
             action,
             address,
+            role,
             info,
         ));
     }
@@ -109,15 +163,28 @@ impl AdminContract {
         choice: Choice,
         stake: Balance,
     ) {
+        self.access_control.ensure_not_paused();
         self.voting_engine
             .vote(caller(), voting_id, voting_type, choice, stake);
     }
 
+    pub fn finish_voting(&mut self, voting_id: VotingId, voting_type: VotingType) -> VotingSummary {
+        self.access_control.ensure_not_paused();
+        self.voting_engine.finish_voting(voting_id, voting_type)
+    }
+
     pub fn slash_voter(&mut self, voter: Address) -> SlashedVotings {
-        self.access_control.ensure_whitelisted();
+        self.access_control.ensure_not_paused();
+        self.access_control.ensure_role(Role::Slasher);
         self.voting_engine.slash_voter(voter)
     }
 
+    pub fn migrate_account(&mut self, from: Address, to: Address) -> Vec<VotingId> {
+        self.access_control.ensure_not_paused();
+        self.access_control.ensure_role(Role::Migrator);
+        self.voting_engine.migrate_voter(from, to)
+    }
+
     /// Accepts ownership of the contract.
     pub fn accept_ownership(&mut self, contract_address: Address) {
         AccessControlRef::at(&contract_address).accept_new_owner();
@@ -129,7 +196,8 @@ impl AdminContract {
 pub struct AdminVotingCreated {
     contract_to_update: Address,
     action: Action,
-    address: Address,
+    address: Option<Address>,
+    role: Option<Role>,
     creator: Address,
     stake: Option<Balance>,
     voting_id: VotingId,
@@ -147,13 +215,15 @@ impl AdminVotingCreated {
     pub fn new(
         contract_to_update: Address,
         action: Action,
-        address: Address,
+        address: Option<Address>,
+        role: Option<Role>,
         info: VotingCreatedInfo,
     ) -> Self {
         Self {
             contract_to_update,
             action,
             address,
+            role,
             creator: info.creator,
             stake: info.stake,
             voting_id: info.voting_id,
@@ -172,31 +242,45 @@ impl AdminVotingCreated {
 
 /// Enum for actions that [AdminContract] can perform
 ///
-/// - `AddToWhitelist` - calls `add_to_whitelist` method
 /// - `RemoveFromWhitelist` - calls `remove_from_whitelist` method
-/// - `ChangeOwner` - calls `change_ownership` method
+/// - `ProposeNewOwner` - calls `propose_new_owner` method
+/// - `Pause` - calls `pause` method, takes no `address`
+/// - `Unpause` - calls `unpause` method, takes no `address`
+/// - `GrantRole` - calls `grant_role` method, grants the `role` to the `address`
+/// - `RevokeRole` - calls `revoke_role` method, revokes the `role` from the `address`
 #[derive(OdraType, Eq, PartialEq, Debug)]
 pub enum Action {
-    AddToWhitelist,
     RemoveFromWhitelist,
     ProposeNewOwner,
+    Pause,
+    Unpause,
+    GrantRole,
+    RevokeRole,
 }
 
 impl Action {
     pub(crate) fn get_entry_point(&self) -> String {
         match self {
-            Action::AddToWhitelist => "add_to_whitelist",
             Action::RemoveFromWhitelist => "remove_from_whitelist",
             Action::ProposeNewOwner => "propose_new_owner",
+            Action::Pause => "pause",
+            Action::Unpause => "unpause",
+            Action::GrantRole => "grant_role",
+            Action::RevokeRole => "revoke_role",
         }
         .to_string()
     }
 
-    pub(crate) fn get_arg(&self) -> &str {
+    pub(crate) fn get_arg(&self) -> Option<&str> {
         match self {
-            Action::AddToWhitelist => "address",
-            Action::RemoveFromWhitelist => "address",
-            Action::ProposeNewOwner => "owner",
+            Action::RemoveFromWhitelist => Some("address"),
+            Action::ProposeNewOwner => Some("owner"),
+            Action::Pause | Action::Unpause => None,
+            Action::GrantRole | Action::RevokeRole => Some("address"),
         }
     }
+
+    pub(crate) fn requires_role(&self) -> bool {
+        matches!(self, Action::GrantRole | Action::RevokeRole)
+    }
 }
@@ -0,0 +1,423 @@
+//! Contains Insurance Pool Contract definition and related abstractions.
+//!
+//! # Insurance Pool
+//! The pool is funded by the [`Bid Escrow Contract`] - a part of every forfeited `CSPR` stake and `DOS Fee`
+//! defined by the `InsurancePoolRatio` variable is credited to the pool, which collects it calling
+//! [`collect_deposits()`](InsurancePoolContract::collect_deposits()).
+//!
+//! A `Job Poster` whose `Job` has been accepted in a successful voting, but then has proven to be defective,
+//! may file an insurance claim - the amount of `CSPR` to be compensated and a `Document Hash` of
+//! a document describing the defect.
+//! Each `Job` can be the subject of a single claim.
+//!
+//! # Voting
+//! Filing a claim creates a voting. The `Job Poster` does not stake any reputation, the claim is voted on
+//! by the `VA`'s. If the formal voting passes, the claimed amount (limited to the pool balance) is credited
+//! to the `Job Poster`, who withdraws it calling [`claim()`](InsurancePoolContract::claim()).
+//!
+//! The Voting process is managed by [`VotingEngine`].
+//!
+//! [`Bid Escrow Contract`]: crate::bid_escrow::contract::BidEscrowContract
+//! [`VotingEngine`]: VotingEngine
+use crate::bid_escrow::contract::BidEscrowContractRef;
+use crate::bid_escrow::events::TransferReason;
+use crate::bid_escrow::job::JobStatus;
+use crate::bid_escrow::types::JobId;
+use crate::configuration::ConfigurationBuilder;
+use crate::modules::claims::PendingClaim;
+use crate::modules::refs::ContractRefs;
+use crate::modules::{AccessControl, Claims, OwnerAction, OwnerActionArgs, OwnerProposal, Role};
+use crate::utils::types::DocumentHash;
+use crate::utils::Error;
+use crate::voting::ballot::{Ballot, Choice};
+use crate::voting::types::VotingId;
+use crate::voting::voting_engine::events::VotingCreatedInfo;
+use crate::voting::voting_engine::voting_state_machine::{
+    VotingResult, VotingStateMachine, VotingSummary, VotingType,
+};
+use crate::voting::voting_engine::VotingEngine;
+use crate::voting_contracts::SlashedVotings;
+use odra::contract_env::{caller, revert, self_balance};
+use odra::prelude::vec::Vec;
+use odra::types::event::OdraEvent;
+use odra::types::{Address, Balance, BlockTime};
+use odra::{Event, Mapping, OdraType, UnwrapOrRevert, Variable};
+
+/// A claim for a compensation of a defective [Job](crate::bid_escrow::job::Job).
+#[derive(OdraType, Debug, PartialEq, Eq)]
+pub struct InsuranceClaim {
+    /// The id of the defective Job.
+    pub job_id: JobId,
+    /// The address of the Job Poster.
+    pub claimant: Address,
+    /// The amount of CSPR to be compensated.
+    pub amount: Balance,
+    /// The hash of a document describing the defect.
+    pub document_hash: DocumentHash,
+}
+
+/// Insurance Pool Contract.
+#[odra::module(events = [InsuranceClaimFiled])]
+pub struct InsurancePoolContract {
+    refs: ContractRefs,
+    #[odra(using = "refs")]
+    voting_engine: VotingEngine,
+    access_control: AccessControl,
+    claims: Claims,
+    bid_escrow: Variable<Address>,
+    insurance_claims: Mapping<VotingId, InsuranceClaim>,
+    claimed_jobs: Mapping<JobId, VotingId>,
+}
+
+#[odra::module]
+impl InsurancePoolContract {
+    delegate! {
+        to self.voting_engine {
+            /// Checks if voting of a given type and id exists.
+            pub fn voting_exists(&self, voting_id: VotingId, voting_type: VotingType) -> bool;
+            /// Returns the Voter's [`Ballot`].
+            pub fn get_ballot(
+                &self,
+                voting_id: VotingId,
+                voting_type: VotingType,
+                address: Address,
+            ) -> Option<Ballot>;
+            /// Gets the address of nth voter who voted on Voting with `voting_id`.
+            pub fn get_voter(&self, voting_id: VotingId, voting_type: VotingType, at: u32) -> Option<Address>;
+            /// Returns [Voting](VotingStateMachine) for given id.
+            pub fn get_voting(&self, voting_id: VotingId) -> Option<VotingStateMachine>;
+            /// Erases the voter from voting with the given id. [Read more](VotingEngine::cancel_finished_voting()).
+            pub fn cancel_finished_voting(&mut self, voting_id: VotingId);
+        }
+
+        to self.access_control {
+            /// Changes the ownership of the contract. Transfers ownership to the `owner`.
+            /// Only the current owner is permitted to call this method.
+            /// [`Read more`](AccessControl::propose_new_owner())
+            pub fn propose_new_owner(&mut self, owner: Address);
+            /// Accepts the new owner proposition. This can be called only by the proposed owner.
+            /// [`Read more`](AccessControl::accept_new_owner())
+            pub fn accept_new_owner(&mut self);
+            /// Remove address from the whitelist.
+            /// [`Read more`](AccessControl::remove_from_whitelist())
+            pub fn remove_from_whitelist(&mut self, address: Address);
+            /// Checks whether the given address is added to the whitelist.
+            /// [`Read more`](AccessControl::is_whitelisted()).
+            pub fn is_whitelisted(&self, address: Address) -> bool;
+            /// Returns the address of the current owner.
+            /// [`Read more`](AccessControl::get_owner()).
+            pub fn get_owner(&self) -> Option<Address>;
+            /// Grants the `role` to the `address`.
+            /// [`Read more`](AccessControl::grant_role())
+            pub fn grant_role(&mut self, role: Role, address: Address);
+            /// Revokes the `role` from the `address`.
+            /// [`Read more`](AccessControl::revoke_role())
+            pub fn revoke_role(&mut self, role: Role, address: Address);
+            /// Sets the admin role of the `role`, whose holders grant and revoke the `role`.
+            /// [`Read more`](AccessControl::set_role_admin())
+            pub fn set_role_admin(&mut self, role: Role, admin_role: Role);
+            /// Returns the admin role of the `role`, whose holders grant and revoke the `role`.
+            /// [`Read more`](AccessControl::get_role_admin())
+            pub fn get_role_admin(&self, role: Role) -> Role;
+            /// Checks whether the `address` has the `role`.
+            /// [`Read more`](AccessControl::has_role())
+            pub fn has_role(&self, role: Role, address: Address) -> bool;
+            /// Replaces the whitelist entry of the `address` with the `roles`.
+            /// [`Read more`](AccessControl::migrate_to_roles())
+            pub fn migrate_to_roles(&mut self, address: Address, roles: Vec<Role>);
+            /// Hands the ownership over to the `signers`.
+            /// [`Read more`](AccessControl::enable_multisig_ownership())
+            pub fn enable_multisig_ownership(&mut self, signers: Vec<Address>, threshold: u32);
+            /// Proposes an owner-only action.
+            /// [`Read more`](AccessControl::propose_owner_action())
+            pub fn propose_owner_action(&mut self, action: OwnerAction, action_args: OwnerActionArgs) -> u32;
+            /// Approves a proposed owner-only action.
+            /// [`Read more`](AccessControl::approve_owner_action())
+            pub fn approve_owner_action(&mut self, proposal_id: u32);
+            /// Executes a proposed owner-only action approved by the current signers.
+            /// [`Read more`](AccessControl::execute_owner_action())
+            pub fn execute_owner_action(&mut self, proposal_id: u32);
+            /// Returns the signers of the multisig ownership.
+            /// [`Read more`](AccessControl::get_signers())
+            pub fn get_signers(&self) -> Vec<Address>;
+            /// Returns the number of approvals required to execute an owner-only action.
+            /// [`Read more`](AccessControl::get_threshold())
+            pub fn get_threshold(&self) -> u32;
+            /// Returns the proposal of an owner-only action.
+            /// [`Read more`](AccessControl::get_owner_proposal())
+            pub fn get_owner_proposal(&self, proposal_id: u32) -> Option<OwnerProposal>;
+            /// Pauses the contract.
+            /// [`Read more`](AccessControl::pause())
+            pub fn pause(&mut self);
+            /// Unpauses the contract.
+            /// [`Read more`](AccessControl::unpause())
+            pub fn unpause(&mut self);
+            /// Returns true if the contract is paused.
+            /// [`Read more`](AccessControl::is_paused())
+            pub fn is_paused(&self) -> bool;
+        }
+
+        to self.claims {
+            /// Transfers all the CSPR credited to the caller.
+            ///
+            /// # Errors
+            /// * [`NothingToClaim`](crate::utils::Error::NothingToClaim) - if there is nothing to claim
+            ///
+            /// # Events
+            /// * [`CSPRTransfer`](crate::bid_escrow::events::CSPRTransfer)
+            pub fn claim(&mut self);
+
+            /// Returns the CSPR credited to a given address and awaiting to be claimed, grouped by the reason.
+            pub fn get_pending_claims(&self, address: Address) -> Vec<PendingClaim>;
+        }
+
+        to self.refs {
+            /// Returns the address of [Variable Repository](crate::core_contracts::VariableRepositoryContract) contract.
+            pub fn variable_repository_address(&self) -> Address;
+            /// Returns the address of [Reputation Token](crate::core_contracts::ReputationContract) contract.
+            pub fn reputation_token_address(&self) -> Address;
+        }
+    }
+
+    /// Constructor function.
+    ///
+    /// # Note
+    /// Initializes contract elements:
+    /// * Sets up the contract by writing addresses of [`Variable Repository`](crate::core_contracts::VariableRepositoryContract),
+    /// [`Reputation Token`](crate::core_contracts::ReputationContract), [`VA Token`](crate::core_contracts::VaNftContract),
+    /// [`Bid Escrow`](crate::bid_escrow::contract::BidEscrowContract).
+    /// * Sets [`caller`] as the owner of the contract.
+    /// * Grants [`caller`] the [`Admin`](crate::modules::Role::Admin) role.
+    ///
+    /// # Events
+    /// * [`OwnerChanged`](crate::modules::owner::events::OwnerChanged),
+    /// * [`RoleGranted`](crate::modules::roles::events::RoleGranted),
+    #[odra(init)]
+    pub fn init(
+        &mut self,
+        variable_repository: Address,
+        reputation_token: Address,
+        va_token: Address,
+        bid_escrow: Address,
+    ) {
+        self.refs.set_variable_repository(variable_repository);
+        self.refs.set_reputation_token(reputation_token);
+        self.refs.set_va_token(va_token);
+        self.bid_escrow.set(bid_escrow);
+        self.access_control.init(caller());
+    }
+
+    /// Deposits the attached CSPR into the pool.
+    #[odra(payable)]
+    pub fn deposit(&mut self) {
+        self.access_control.ensure_not_paused();
+    }
+
+    /// Collects the CSPR credited to the pool by the [`Bid Escrow`](crate::bid_escrow::contract::BidEscrowContract).
+    ///
+    /// # Errors
+    /// * [`NothingToClaim`](Error::NothingToClaim) - if there is nothing to collect.
+    pub fn collect_deposits(&mut self) {
+        self.access_control.ensure_not_paused();
+        self.bid_escrow().claim_insurance_pool_deposits();
+    }
+
+    /// Returns the amount of CSPR that can be paid out - the pool balance
+    /// reduced by the amounts already credited and not claimed yet.
+    pub fn available_balance(&self) -> Balance {
+        self.available()
+    }
+
+    /// Files a claim for a compensation of a defective [Job](crate::bid_escrow::job::Job)
+    /// and creates a voting on it.
+    ///
+    /// # Errors
+    /// * [`JobNotFound`](Error::JobNotFound) - if the Job does not exist.
+    /// * [`OnlyJobPosterCanFileInsuranceClaim`](Error::OnlyJobPosterCanFileInsuranceClaim) - if the caller
+    /// is not the Job Poster.
+    /// * [`JobNotCompleted`](Error::JobNotCompleted) - if the Job has not been accepted in a voting.
+    /// * [`InsuranceClaimAlreadyFiled`](Error::InsuranceClaimAlreadyFiled) - if the Job has already been claimed.
+    /// * [`InsuranceClaimExceedsJobPayment`](Error::InsuranceClaimExceedsJobPayment) - if the amount exceeds
+    /// the Job payment.
+    /// * [`InsufficientInsurancePoolBalance`](Error::InsufficientInsurancePoolBalance) - if the amount exceeds
+    /// the [available balance](Self::available_balance()).
+    ///
+    /// # Events
+    /// * [`InsuranceClaimFiled`]
+    pub fn file_claim(&mut self, job_id: JobId, amount: Balance, document_hash: DocumentHash) {
+        self.access_control.ensure_not_paused();
+        let claimant = caller();
+        let job = self
+            .bid_escrow()
+            .get_job(job_id)
+            .unwrap_or_revert_with(Error::JobNotFound);
+        if job.poster() != claimant {
+            revert(Error::OnlyJobPosterCanFileInsuranceClaim);
+        }
+        if job.status() != JobStatus::Completed || job.voting_id().is_none() {
+            revert(Error::JobNotCompleted);
+        }
+        if self.claimed_jobs.get(&job_id).is_some() {
+            revert(Error::InsuranceClaimAlreadyFiled);
+        }
+        if amount > job.payment() {
+            revert(Error::InsuranceClaimExceedsJobPayment);
+        }
+        if amount > self.available() {
+            revert(Error::InsufficientInsurancePoolBalance);
+        }
+
+        let configuration = ConfigurationBuilder::new(
+            self.refs.va_token().total_supply(),
+            &self.refs.variable_repository().all_variables(),
+        )
+        .only_va_can_create(false)
+        .set_is_bid_escrow(true)
+        .build();
+
+        let (info, mut voting) =
+            self.voting_engine
+                .create_voting(claimant, Balance::zero(), configuration.clone());
+        // passed config disables casting first votes, must be casted manually.
+        self.voting_engine.cast_ballot(
+            claimant,
+            Choice::InFavor,
+            Balance::zero(),
+            true,
+            &mut voting,
+            &configuration,
+        );
+        self.voting_engine.set_voting(voting);
+
+        let claim = InsuranceClaim {
+            job_id,
+            claimant,
+            amount,
+            document_hash,
+        };
+        let voting_id = info.voting_id;
+        InsuranceClaimFiled::new(&claim, info).emit();
+        self.claimed_jobs.set(&job_id, voting_id);
+        self.insurance_claims.set(&voting_id, claim);
+    }
+
+    /// Returns the claim voted on in voting with a given id.
+    pub fn get_insurance_claim(&self, voting_id: VotingId) -> Option<InsuranceClaim> {
+        self.insurance_claims.get(&voting_id)
+    }
+
+    /// Casts a vote. [Read more](VotingEngine::vote())
+    pub fn vote(
+        &mut self,
+        voting_id: VotingId,
+        voting_type: VotingType,
+        choice: Choice,
+        stake: Balance,
+    ) {
+        self.access_control.ensure_not_paused();
+        self.voting_engine
+            .vote(caller(), voting_id, voting_type, choice, stake);
+    }
+
+    /// Finishes voting stage. If the formal voting passes, the claimed amount is credited to the `Job Poster`.
+    ///
+    /// # Errors
+    /// * [`InsuranceClaimNotFound`](Error::InsuranceClaimNotFound) - if there is no claim for the voting.
+    pub fn finish_voting(&mut self, voting_id: VotingId, voting_type: VotingType) -> VotingSummary {
+        self.access_control.ensure_not_paused();
+        let claim = self
+            .insurance_claims
+            .get(&voting_id)
+            .unwrap_or_revert_with(Error::InsuranceClaimNotFound);
+        let summary = self.voting_engine.finish_voting(voting_id, voting_type);
+
+        if summary.is_formal() && summary.result() == VotingResult::InFavor {
+            let amount = claim.amount.min(self.available());
+            self.claims
+                .credit(&claim.claimant, amount, TransferReason::InsurancePayout);
+        }
+        summary
+    }
+
+    /// Erases the voter from all ongoing votings.
+    pub fn slash_voter(&mut self, voter: Address) -> SlashedVotings {
+        self.access_control.ensure_not_paused();
+        self.access_control.ensure_role(Role::Slasher);
+        self.voting_engine.slash_voter(voter)
+    }
+
+    /// Moves the ballots, the pending insurance claims and the unclaimed CSPR of the `from` address
+    /// to the `to` address.
+    ///
+    /// Returns ids of the affected votings.
+    pub fn migrate_account(&mut self, from: Address, to: Address) -> Vec<VotingId> {
+        self.access_control.ensure_not_paused();
+        self.access_control.ensure_role(Role::Migrator);
+        for voting_id in self.voting_engine.get_active_votings() {
+            if let Some(mut claim) = self.insurance_claims.get(&voting_id) {
+                if claim.claimant == from {
+                    claim.claimant = to;
+                    self.insurance_claims.set(&voting_id, claim);
+                }
+            }
+        }
+        self.claims.migrate(from, to);
+        self.voting_engine.migrate_voter(from, to)
+    }
+}
+
+impl InsurancePoolContract {
+    fn bid_escrow(&self) -> BidEscrowContractRef {
+        BidEscrowContractRef::at(
+            &self
+                .bid_escrow
+                .get()
+                .unwrap_or_revert_with(Error::VariableValueNotSet),
+        )
+    }
+
+    fn available(&self) -> Balance {
+        self_balance().saturating_sub(self.claims.total_pending())
+    }
+}
+
+/// Event emitted when an insurance claim has been filed.
+#[derive(Debug, PartialEq, Eq, Event)]
+pub struct InsuranceClaimFiled {
+    job_id: JobId,
+    amount: Balance,
+    document_hash: DocumentHash,
+    creator: Address,
+    stake: Option<Balance>,
+    voting_id: VotingId,
+    config_informal_quorum: u32,
+    config_informal_voting_time: u64,
+    config_formal_quorum: u32,
+    config_formal_voting_time: u64,
+    config_total_onboarded: Balance,
+    config_double_time_between_votings: bool,
+    config_voting_clearness_delta: Balance,
+    config_time_between_informal_and_formal_voting: BlockTime,
+}
+
+impl InsuranceClaimFiled {
+    pub fn new(claim: &InsuranceClaim, info: VotingCreatedInfo) -> Self {
+        Self {
+            job_id: claim.job_id,
+            amount: claim.amount,
+            document_hash: claim.document_hash.clone(),
+            creator: info.creator,
+            stake: info.stake,
+            voting_id: info.voting_id,
+            config_informal_quorum: info.config_informal_quorum,
+            config_informal_voting_time: info.config_informal_voting_time,
+            config_formal_quorum: info.config_formal_quorum,
+            config_formal_voting_time: info.config_formal_voting_time,
+            config_total_onboarded: info.config_total_onboarded,
+            config_double_time_between_votings: info.config_double_time_between_votings,
+            config_voting_clearness_delta: info.config_voting_clearness_delta,
+            config_time_between_informal_and_formal_voting: info
+                .config_time_between_informal_and_formal_voting,
+        }
+    }
+}
@@ -11,36 +11,72 @@
 //! * to `mint` tokens for a user,
 //! * to `burn` users' tokens.
 //!
+//! # KYC Levels
+//! A KYC voting specifies the KYC level being granted. If the user already owns a KYC token of a lower level,
+//! the voting upgrades the level of the token.
+//!
+//! # KYC Revocation
+//! A revocation voting burns the KYC token of a user. The contracts set by
+//! [`update_kyc_revocation_contracts()`](KycVoterContract::update_kyc_revocation_contracts()) are notified
+//! to cancel the user's activity - open `Job Offers`, `Bids` and pending `Onboarding Requests`.
+//!
+//! # KYC Renewal
+//! A KYC token is valid for `KycValidityPeriod`. A renewal voting extends the validity of the token
+//! of a user without burning and reminting it.
+//!
+//! # KYC Providers
+//! A KYC token can be also minted without a voting if a KYC provider attests the user is verified.
+//! The attestation is a signature of the provider over the user address, the document hash and the expiry
+//! of the KYC - see [`get_attestation_message()`](KycVoterContract::get_attestation_message()).
+//! The message is bound to the chain name and the address of the contract, so an attestation cannot be
+//! replayed on another network or another deployment.
+//! The signature is verified on-chain against the registry of providers kept by the [`KYC Token Contract`].
+//! An attestation grants the basic KYC level.
+//!
+//! Providers are added to and removed from the registry by a provider voting.
+//!
 //! # Voting
 //! The Voting process is managed by [`VotingEngine`].
 //!
 //! [`Reputation Token Contract`]: crate::core_contracts::ReputationContract
+//! [`KYC Token Contract`]: crate::core_contracts::KycNftContract
 //! [`VotingEngine`]: VotingEngine
-use crate::configuration::ConfigurationBuilder;
+use crate::configuration::{Configuration, ConfigurationBuilder};
 use crate::modules::kyc_info::KycInfo;
 use crate::modules::refs::ContractRefs;
-use crate::modules::AccessControl;
-use crate::utils::types::DocumentHash;
+use crate::modules::{AccessControl, OwnerAction, OwnerActionArgs, OwnerProposal, Role};
+use crate::utils::consts::BASIC_KYC_LEVEL;
+use crate::utils::types::{copy_public_key, DocumentHash, KycLevel};
 use crate::utils::{consts, ContractCall, Error};
 use crate::voting::ballot::{Ballot, Choice};
 use crate::voting::types::VotingId;
 use crate::voting::voting_engine::events::VotingCreatedInfo;
 use crate::voting::voting_engine::voting_state_machine::VotingType;
-use crate::voting::voting_engine::voting_state_machine::{VotingStateMachine, VotingSummary};
+use crate::voting::voting_engine::voting_state_machine::{
+    VotingResult, VotingStateMachine, VotingSummary,
+};
 use crate::voting::voting_engine::VotingEngine;
 use crate::voting_contracts::SlashedVotings;
-use odra::contract_env::{self, caller};
-use odra::prelude::string::ToString;
+use odra::contract_env::{self, caller, get_block_time};
+use odra::prelude::{
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
 use odra::types::event::OdraEvent;
-use odra::types::{Address, Balance, BlockTime, CallArgs};
-use odra::{Event, UnwrapOrRevert};
+use odra::types::{Address, Balance, BlockTime, Bytes, CallArgs, OdraType as OdraTyped, PublicKey};
+use odra::{Event, Mapping, OdraType, UnwrapOrRevert, Variable};
 
 /// KycVoterContract
 ///
 /// It is responsible for managing variables held in [Variable Repo](crate::core_contracts::VariableRepositoryContract).
 ///
 /// Each change to the variable is being voted on, and when the voting passes, a change is made at given time.
-#[odra::module(events = [KycVotingCreated])]
+#[odra::module(events = [
+    KycVotingCreated,
+    KycProviderVotingCreated,
+    KycAttested
+])]
 pub struct KycVoterContract {
     refs: ContractRefs,
     #[odra(using = "refs")]
@@ -48,6 +84,9 @@ pub struct KycVoterContract {
     access_control: AccessControl,
     #[odra(using = "refs")]
     kyc: KycInfo,
+    kyc_revocation_contracts: Variable<Vec<Address>>,
+    chain_name: Variable<String>,
+    used_attestations: Mapping<Bytes, bool>,
 }
 
 #[odra::module]
@@ -72,10 +111,25 @@ impl KycVoterContract {
         to self.access_control {
             pub fn propose_new_owner(&mut self, owner: Address);
             pub fn accept_new_owner(&mut self);
-            pub fn add_to_whitelist(&mut self, address: Address);
             pub fn remove_from_whitelist(&mut self, address: Address);
             pub fn is_whitelisted(&self, address: Address) -> bool;
             pub fn get_owner(&self) -> Option<Address>;
+            pub fn grant_role(&mut self, role: Role, address: Address);
+            pub fn revoke_role(&mut self, role: Role, address: Address);
+            pub fn set_role_admin(&mut self, role: Role, admin_role: Role);
+            pub fn get_role_admin(&self, role: Role) -> Role;
+            pub fn has_role(&self, role: Role, address: Address) -> bool;
+            pub fn migrate_to_roles(&mut self, address: Address, roles: Vec<Role>);
+            pub fn enable_multisig_ownership(&mut self, signers: Vec<Address>, threshold: u32);
+            pub fn propose_owner_action(&mut self, action: OwnerAction, action_args: OwnerActionArgs) -> u32;
+            pub fn approve_owner_action(&mut self, proposal_id: u32);
+            pub fn execute_owner_action(&mut self, proposal_id: u32);
+            pub fn get_signers(&self) -> Vec<Address>;
+            pub fn get_threshold(&self) -> u32;
+            pub fn get_owner_proposal(&self, proposal_id: u32) -> Option<OwnerProposal>;
+            pub fn pause(&mut self);
+            pub fn unpause(&mut self);
+            pub fn is_paused(&self) -> bool;
         }
     }
 
@@ -86,23 +140,233 @@ impl KycVoterContract {
         reputation_token: Address,
         va_token: Address,
         kyc_token: Address,
+        chain_name: String,
     ) {
         self.refs.set_variable_repository(variable_repository);
         self.refs.set_reputation_token(reputation_token);
         self.refs.set_va_token(va_token);
         self.refs.set_kyc_token(kyc_token);
+        self.chain_name.set(chain_name);
         self.access_control.init(caller());
     }
 
+    /// Creates a voting to grant the `kyc_level` to the `subject_address`.
+    ///
+    /// If the `subject_address` owns a KYC token of a lower level, the voting upgrades the level of the token.
+    ///
+    /// # Errors
+    /// * [`InvalidKycLevel`](Error::InvalidKycLevel) if the `kyc_level` is 0.
+    /// * [`KycAlreadyInProgress`](Error::KycAlreadyInProgress) if there is an ongoing voting for the `subject_address`.
+    /// * [`UserKycedAlready`](Error::UserKycedAlready) if the `subject_address` owns a KYC token of the `kyc_level`
+    /// or higher, or its token has expired.
+    ///
+    /// # Events
+    /// * [`KycVotingCreated`]
     pub fn create_voting(
         &mut self,
         subject_address: Address,
         document_hash: DocumentHash,
+        kyc_level: KycLevel,
         stake: Balance,
     ) {
+        self.access_control.ensure_not_paused();
+        if kyc_level < BASIC_KYC_LEVEL {
+            contract_env::revert(Error::InvalidKycLevel);
+        }
         self.assert_no_ongoing_voting(&subject_address);
-        self.assert_not_kyced(&subject_address);
 
+        let mut voting_configuration = self.voting_configuration();
+        let contract_call = if self.kyc.owns_kyc_token(&subject_address) {
+            // The owner of an expired token has to renew it first.
+            let current_level = self.refs.kyc_token().kyc_level(subject_address);
+            if current_level == 0 || current_level >= kyc_level {
+                contract_env::revert(Error::UserKycedAlready);
+            }
+            ContractCall {
+                address: self.refs.kyc_token_address(),
+                entry_point: consts::EP_UPGRADE_LEVEL.to_string(),
+                call_args: {
+                    let mut args = CallArgs::new();
+                    args.insert(consts::ARG_OWNER.to_string(), subject_address);
+                    args.insert(consts::ARG_LEVEL.to_string(), kyc_level);
+                    args
+                },
+                amount: None,
+            }
+        } else {
+            ContractCall {
+                address: self.refs.kyc_token_address(),
+                entry_point: consts::EP_MINT_WITH_LEVEL.to_string(),
+                call_args: {
+                    let mut args = CallArgs::new();
+                    args.insert(consts::ARG_TO.to_string(), subject_address);
+                    args.insert(
+                        consts::ARG_VALIDITY_PERIOD.to_string(),
+                        voting_configuration.kyc_validity_period(),
+                    );
+                    args.insert(consts::ARG_LEVEL.to_string(), kyc_level);
+                    args
+                },
+                amount: None,
+            }
+        };
+        voting_configuration.set_contract_calls(vec![contract_call]);
+
+        self.create_subject_voting(
+            subject_address,
+            document_hash,
+            KycVotingKind::Grant,
+            Some(kyc_level),
+            voting_configuration,
+            stake,
+        );
+    }
+
+    /// Creates a voting to renew the KYC token of the `subject_address`.
+    ///
+    /// If the voting passes, the token is valid for `KycValidityPeriod` from the end of the voting.
+    ///
+    /// # Errors
+    /// * [`KycAlreadyInProgress`](Error::KycAlreadyInProgress) if there is an ongoing voting for the `subject_address`.
+    /// * [`NotKyced`](Error::NotKyced) if the `subject_address` does not own a KYC token.
+    ///
+    /// # Events
+    /// * [`KycVotingCreated`]
+    pub fn create_renewal_voting(
+        &mut self,
+        subject_address: Address,
+        document_hash: DocumentHash,
+        stake: Balance,
+    ) {
+        self.access_control.ensure_not_paused();
+        self.assert_no_ongoing_voting(&subject_address);
+        if !self.kyc.owns_kyc_token(&subject_address) {
+            contract_env::revert(Error::NotKyced);
+        }
+
+        let mut voting_configuration = self.voting_configuration();
+        voting_configuration.set_contract_calls(vec![ContractCall {
+            address: self.refs.kyc_token_address(),
+            entry_point: consts::EP_RENEW.to_string(),
+            call_args: {
+                let mut args = CallArgs::new();
+                args.insert(consts::ARG_OWNER.to_string(), subject_address);
+                args.insert(
+                    consts::ARG_VALIDITY_PERIOD.to_string(),
+                    voting_configuration.kyc_validity_period(),
+                );
+                args
+            },
+            amount: None,
+        }]);
+
+        self.create_subject_voting(
+            subject_address,
+            document_hash,
+            KycVotingKind::Renewal,
+            None,
+            voting_configuration,
+            stake,
+        );
+    }
+
+    /// Creates a voting to revoke the KYC of the `subject_address`.
+    ///
+    /// If the voting passes, the KYC token is burned and the activity of the `subject_address`
+    /// in the [KYC revocation contracts](Self::get_kyc_revocation_contracts()) is cancelled.
+    ///
+    /// # Errors
+    /// * [`KycAlreadyInProgress`](Error::KycAlreadyInProgress) if there is an ongoing voting for the `subject_address`.
+    /// * [`NotKyced`](Error::NotKyced) if the `subject_address` does not own a KYC token.
+    ///
+    /// # Events
+    /// * [`KycVotingCreated`]
+    pub fn create_revocation_voting(
+        &mut self,
+        subject_address: Address,
+        document_hash: DocumentHash,
+        stake: Balance,
+    ) {
+        self.access_control.ensure_not_paused();
+        self.assert_no_ongoing_voting(&subject_address);
+        if !self.kyc.owns_kyc_token(&subject_address) {
+            contract_env::revert(Error::NotKyced);
+        }
+
+        let mut contract_calls = vec![ContractCall {
+            address: self.refs.kyc_token_address(),
+            entry_point: consts::EP_BURN.to_string(),
+            call_args: {
+                let mut args = CallArgs::new();
+                args.insert(consts::ARG_OWNER.to_string(), subject_address);
+                args
+            },
+            amount: None,
+        }];
+        for address in self.kyc_revocation_contracts.get_or_default() {
+            contract_calls.push(ContractCall {
+                address,
+                entry_point: consts::EP_REVOKE_KYC.to_string(),
+                call_args: {
+                    let mut args = CallArgs::new();
+                    args.insert(consts::ARG_ADDRESS.to_string(), subject_address);
+                    args
+                },
+                amount: None,
+            });
+        }
+
+        let mut voting_configuration = self.voting_configuration();
+        voting_configuration.set_contract_calls(contract_calls);
+
+        self.create_subject_voting(
+            subject_address,
+            document_hash,
+            KycVotingKind::Revocation,
+            None,
+            voting_configuration,
+            stake,
+        );
+    }
+
+    /// Sets the contracts in which the activity of an address is cancelled when its KYC is revoked.
+    ///
+    /// # Errors
+    /// * [`MissingRole`](Error::MissingRole) if the caller
+    /// does not have the [`Operator`](crate::modules::Role::Operator) role.
+    pub fn update_kyc_revocation_contracts(&mut self, kyc_revocation_contracts: Vec<Address>) {
+        self.access_control.ensure_role(Role::Operator);
+        self.kyc_revocation_contracts.set(kyc_revocation_contracts);
+    }
+
+    /// Returns the contracts in which the activity of an address is cancelled when its KYC is revoked.
+    pub fn get_kyc_revocation_contracts(&self) -> Vec<Address> {
+        self.kyc_revocation_contracts.get_or_default()
+    }
+
+    /// Creates a voting to add a KYC provider to or remove it from the registry.
+    ///
+    /// # Errors
+    /// * [`KycProviderNotRegistered`](Error::KycProviderNotRegistered) if the `provider` to remove
+    /// is not registered.
+    ///
+    /// # Events
+    /// * [`KycProviderVotingCreated`]
+    pub fn create_provider_voting(
+        &mut self,
+        provider: PublicKey,
+        action: KycProviderAction,
+        stake: Balance,
+    ) {
+        self.access_control.ensure_not_paused();
+        if action == KycProviderAction::Remove
+            && !self
+                .refs
+                .kyc_token()
+                .is_kyc_provider(copy_public_key(&provider))
+        {
+            contract_env::revert(Error::KycProviderNotRegistered);
+        }
         let creator = caller();
 
         let voting_configuration = ConfigurationBuilder::new(
@@ -111,10 +375,10 @@ impl KycVoterContract {
         )
         .contract_call(ContractCall {
             address: self.refs.kyc_token_address(),
-            entry_point: consts::EP_MINT.to_string(),
+            entry_point: action.get_entry_point(),
             call_args: {
                 let mut args = CallArgs::new();
-                args.insert(consts::ARG_TO.to_string(), subject_address);
+                args.insert(consts::ARG_PROVIDER.to_string(), copy_public_key(&provider));
                 args
             },
             amount: None,
@@ -125,9 +389,94 @@ impl KycVoterContract {
             .voting_engine
             .create_voting(creator, stake, voting_configuration);
 
-        self.kyc.set_voting(subject_address, info.voting_id);
+        KycProviderVotingCreated::new(provider, action, info).emit();
+    }
+
+    /// Mints a KYC token to the `subject_address` attested by a KYC provider.
+    ///
+    /// The `signature` must be made by the `provider` over the
+    /// [attestation message](Self::get_attestation_message()). The token expires at `expires_at`.
+    /// An attestation can be used only once.
+    ///
+    /// # Errors
+    /// * [`KycProviderNotRegistered`](Error::KycProviderNotRegistered) if the `provider` is not registered.
+    /// * [`KycAttestationExpired`](Error::KycAttestationExpired) if the `expires_at` has passed.
+    /// * [`KycAttestationAlreadyUsed`](Error::KycAttestationAlreadyUsed) if the attestation has been already used.
+    /// * [`InvalidKycAttestation`](Error::InvalidKycAttestation) if the `signature` is invalid.
+    /// * [`KycAlreadyInProgress`](Error::KycAlreadyInProgress) if there is an ongoing voting for the `subject_address`.
+    /// * [`UserKycedAlready`](Error::UserKycedAlready) if the `subject_address` owns a KYC token.
+    ///
+    /// # Events
+    /// * [`KycAttested`]
+    pub fn mint_with_attestation(
+        &mut self,
+        subject_address: Address,
+        document_hash: DocumentHash,
+        expires_at: BlockTime,
+        provider: PublicKey,
+        signature: Bytes,
+    ) {
+        self.access_control.ensure_not_paused();
+        let is_kyc_provider = self
+            .refs
+            .kyc_token()
+            .is_kyc_provider(copy_public_key(&provider));
+        if !is_kyc_provider {
+            contract_env::revert(Error::KycProviderNotRegistered);
+        }
+        let now = get_block_time();
+        if expires_at <= now {
+            contract_env::revert(Error::KycAttestationExpired);
+        }
+        let message =
+            self.get_attestation_message(subject_address, document_hash.clone(), expires_at);
+        let message_hash = Bytes::from(contract_env::hash(&message));
+        if self
+            .used_attestations
+            .get(&message_hash)
+            .unwrap_or_default()
+        {
+            contract_env::revert(Error::KycAttestationAlreadyUsed);
+        }
+        if !contract_env::verify_signature(&message, &signature, &provider) {
+            contract_env::revert(Error::InvalidKycAttestation);
+        }
+        self.assert_no_ongoing_voting(&subject_address);
+        self.assert_not_kyced(&subject_address);
 
-        KycVotingCreated::new(subject_address, document_hash, info).emit();
+        self.used_attestations.set(&message_hash, true);
+        self.refs
+            .kyc_token()
+            .mint_with_validity(subject_address, expires_at - now);
+
+        KycAttested {
+            subject_address,
+            document_hash,
+            expires_at,
+            provider,
+        }
+        .emit();
+    }
+
+    /// Returns the message a KYC provider signs to attest the `subject_address` is verified.
+    ///
+    /// The message starts with the chain name and the address of this contract.
+    pub fn get_attestation_message(
+        &self,
+        subject_address: Address,
+        document_hash: DocumentHash,
+        expires_at: BlockTime,
+    ) -> Bytes {
+        let mut message = self
+            .chain_name
+            .get_or_default()
+            .serialize()
+            .unwrap_or_revert();
+        message.extend(contract_env::self_address().serialize().unwrap_or_revert());
+        message.extend(subject_address.serialize().unwrap_or_revert());
+        message.extend(document_hash.serialize().unwrap_or_revert());
+        message.extend(expires_at.serialize().unwrap_or_revert());
+        message.into()
     }
 
     pub fn vote(
@@ -137,11 +486,13 @@ impl KycVoterContract {
         choice: Choice,
         stake: Balance,
     ) {
+        self.access_control.ensure_not_paused();
         self.voting_engine
             .vote(caller(), voting_id, voting_type, choice, stake);
     }
 
     pub fn finish_voting(&mut self, voting_id: VotingId, voting_type: VotingType) -> VotingSummary {
+        self.access_control.ensure_not_paused();
         let summary = self.voting_engine.finish_voting(voting_id, voting_type);
         // The voting is ended when:
         // 1. Informal voting has been rejected.
@@ -151,23 +502,78 @@ impl KycVoterContract {
                 .voting_engine
                 .get_voting(voting_id)
                 .unwrap_or_revert_with(Error::VotingDoesNotExist);
-            let address = self.kyc.get_voting_subject(voting.voting_id());
-            self.kyc.clear_voting(&address);
+            // A provider voting has no subject.
+            if let Some(address) = self.kyc.find_voting_subject(voting.voting_id()) {
+                self.kyc.clear_voting(&address);
+                // A revoked token has been burned.
+                if summary.result() == VotingResult::InFavor && self.kyc.owns_kyc_token(&address) {
+                    self.refs.kyc_token().set_issuing_voting(address, voting_id);
+                }
+            }
         }
         summary
     }
 
     pub fn slash_voter(&mut self, voter: Address) -> SlashedVotings {
-        self.access_control.ensure_whitelisted();
+        self.access_control.ensure_not_paused();
+        self.access_control.ensure_role(Role::Slasher);
         self.voting_engine.slash_voter(voter)
     }
 
+    /// Moves the ballots of the `from` address to the `to` address.
+    ///
+    /// The action of a pending KYC voting of the `from` address targets the old address,
+    /// so the voting is canceled and the stakes are returned.
+    ///
+    /// Returns ids of the affected votings.
+    pub fn migrate_account(&mut self, from: Address, to: Address) -> Vec<VotingId> {
+        self.access_control.ensure_not_paused();
+        self.access_control.ensure_role(Role::Migrator);
+        let mut affected_votings = vec![];
+        if let Some(voting_id) = self.kyc.get_ongoing_voting(&from) {
+            if self.voting_engine.get_active_votings().contains(&voting_id) {
+                self.voting_engine.cancel_ongoing_voting(voting_id);
+                affected_votings.push(voting_id);
+            }
+            self.kyc.clear_voting(&from);
+        }
+        affected_votings.extend(self.voting_engine.migrate_voter(from, to));
+        affected_votings
+    }
+
     fn assert_not_kyced(&self, address: &Address) {
-        if self.kyc.is_kycd(address) {
+        // The owner of an expired token has to renew it.
+        if self.kyc.owns_kyc_token(address) {
             contract_env::revert(Error::UserKycedAlready);
         }
     }
 
+    fn voting_configuration(&self) -> Configuration {
+        ConfigurationBuilder::new(
+            self.refs.va_token().total_supply(),
+            &self.refs.variable_repository().all_variables(),
+        )
+        .build()
+    }
+
+    fn create_subject_voting(
+        &mut self,
+        subject_address: Address,
+        document_hash: DocumentHash,
+        kind: KycVotingKind,
+        kyc_level: Option<KycLevel>,
+        voting_configuration: Configuration,
+        stake: Balance,
+    ) {
+        let (info, _) = self
+            .voting_engine
+            .create_voting(caller(), stake, voting_configuration);
+
+        self.kyc.set_voting(subject_address, info.voting_id);
+
+        KycVotingCreated::new(subject_address, document_hash, kind, kyc_level, info).emit();
+    }
+
     fn assert_no_ongoing_voting(&self, address: &Address) {
         if self.kyc.exists_ongoing_voting(address) {
             contract_env::revert(Error::KycAlreadyInProgress);
@@ -175,11 +581,13 @@ impl KycVoterContract {
     }
 }
 
-/// Event emitted when kyc voting has been created.
+/// Event emitted when a voting concerning the KYC of a user has been created.
 #[derive(Debug, PartialEq, Eq, Event)]
 pub struct KycVotingCreated {
     subject_address: Address,
     document_hash: DocumentHash,
+    kind: KycVotingKind,
+    kyc_level: Option<KycLevel>,
     creator: Address,
     stake: Option<Balance>,
     voting_id: VotingId,
@@ -197,11 +605,54 @@ impl KycVotingCreated {
     pub fn new(
         subject_address: Address,
         document_hash: DocumentHash,
+        kind: KycVotingKind,
+        kyc_level: Option<KycLevel>,
         info: VotingCreatedInfo,
     ) -> Self {
         Self {
             subject_address,
             document_hash,
+            kind,
+            kyc_level,
+            creator: info.creator,
+            stake: info.stake,
+            voting_id: info.voting_id,
+            config_informal_quorum: info.config_informal_quorum,
+            config_informal_voting_time: info.config_informal_voting_time,
+            config_formal_quorum: info.config_formal_quorum,
+            config_formal_voting_time: info.config_formal_voting_time,
+            config_total_onboarded: info.config_total_onboarded,
+            config_double_time_between_votings: info.config_double_time_between_votings,
+            config_voting_clearness_delta: info.config_voting_clearness_delta,
+            config_time_between_informal_and_formal_voting: info
+                .config_time_between_informal_and_formal_voting,
+        }
+    }
+}
+
+/// Event emitted when kyc provider voting has been created.
+#[derive(PartialEq, Eq, Event)]
+pub struct KycProviderVotingCreated {
+    provider: PublicKey,
+    action: KycProviderAction,
+    creator: Address,
+    stake: Option<Balance>,
+    voting_id: VotingId,
+    config_informal_quorum: u32,
+    config_informal_voting_time: u64,
+    config_formal_quorum: u32,
+    config_formal_voting_time: u64,
+    config_total_onboarded: Balance,
+    config_double_time_between_votings: bool,
+    config_voting_clearness_delta: Balance,
+    config_time_between_informal_and_formal_voting: BlockTime,
+}
+
+impl KycProviderVotingCreated {
+    pub fn new(provider: PublicKey, action: KycProviderAction, info: VotingCreatedInfo) -> Self {
+        Self {
+            provider,
+            action,
             creator: info.creator,
             stake: info.stake,
             voting_id: info.voting_id,
@@ -217,3 +668,44 @@ impl KycVotingCreated {
         }
     }
 }
+
+/// Event emitted when a KYC token has been minted on the basis of a provider attestation.
+#[derive(PartialEq, Eq, Event)]
+pub struct KycAttested {
+    subject_address: Address,
+    document_hash: DocumentHash,
+    expires_at: BlockTime,
+    provider: PublicKey,
+}
+
+/// The kind of a voting concerning the KYC of a user.
+///
+/// - `Grant` - grants a KYC level to the user
+/// - `Renewal` - renews the KYC token of the user
+/// - `Revocation` - burns the KYC token of the user
+#[derive(OdraType, Copy, Eq, PartialEq, Debug)]
+pub enum KycVotingKind {
+    Grant,
+    Renewal,
+    Revocation,
+}
+
+/// Enum for actions that a KYC provider voting can perform
+///
+/// - `Add` - calls `add_kyc_provider` method
+/// - `Remove` - calls `remove_kyc_provider` method
+#[derive(OdraType, Eq, PartialEq, Debug)]
+pub enum KycProviderAction {
+    Add,
+    Remove,
+}
+
+impl KycProviderAction {
+    pub(crate) fn get_entry_point(&self) -> String {
+        match self {
+            KycProviderAction::Add => consts::EP_ADD_KYC_PROVIDER,
+            KycProviderAction::Remove => consts::EP_REMOVE_KYC_PROVIDER,
+        }
+        .to_string()
+    }
+}
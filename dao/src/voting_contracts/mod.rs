@@ -1,17 +1,36 @@
 //! Module containg voting contracts.
+mod account_recovery_voter;
 mod admin;
+mod insurance_pool;
 mod kyc_voter;
+mod offboarding_request;
 mod onboarding_request;
 mod repo_voter;
 mod reputation_voter;
 mod simple_voter;
 mod slashing_voter;
 
+pub use account_recovery_voter::{
+    AccountMigrated, AccountMigration, AccountMigrationStatus, AccountRecoveryVoterContract,
+    AccountRecoveryVoterContractDeployer, AccountRecoveryVoterContractRef, Migratable,
+    MigratableRef,
+};
 pub use admin::{Action as AdminAction, AdminContract, AdminContractDeployer, AdminContractRef};
-pub use kyc_voter::{KycVoterContract, KycVoterContractDeployer, KycVoterContractRef};
+pub use insurance_pool::{
+    InsuranceClaim, InsuranceClaimFiled, InsurancePoolContract, InsurancePoolContractDeployer,
+    InsurancePoolContractRef,
+};
+pub use kyc_voter::{
+    KycAttested, KycProviderAction, KycVoterContract, KycVoterContractDeployer,
+    KycVoterContractRef, KycVotingKind,
+};
+pub use offboarding_request::{
+    OffboardingRequestContract, OffboardingRequestContractDeployer, OffboardingRequestContractRef,
+    VaOffboarded,
+};
 pub use onboarding_request::{
     OnboardingRequestContract, OnboardingRequestContractDeployer, OnboardingRequestContractRef,
-    OnboardingVotingCreated,
+    OnboardingVotingCreated, SponsoredOnboardingVotingCreated,
 };
 pub use repo_voter::{RepoVoterContract, RepoVoterContractDeployer, RepoVoterContractRef};
 pub use reputation_voter::{
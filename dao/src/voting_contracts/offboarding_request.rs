@@ -0,0 +1,247 @@
+//! Contains Offboarding Request Contract definition and related abstractions.
+//!
+//! # Offboarding Request
+//! A `VA` who wants to leave the DAO submits an `Offboarding Request`. The request is processed immediately,
+//! no voting is required. A `VA` who is the subject of an ongoing voting in the [`Slashing Voter Contract`]
+//! cannot leave the DAO until the voting is finished.
+//!
+//! All the activities of the `VA` are settled the same way the full slashing does it, but without the penalty:
+//! * votings created by the `VA` are cancelled and all the stakes are returned,
+//! * `VA`'s ballots in ongoing votings are cancelled,
+//! * `Job Offers`, `Bids` and `Jobs` of the `VA` in the [`Bid Escrow Contract`] are cancelled and the funds are returned.
+//!
+//! Then the `VA Token` is burned. The remaining reputation is burned, or, if the `OffboardingReputationToPassive`
+//! variable is set, converted to passive reputation.
+//!
+//! [`Bid Escrow Contract`]: crate::bid_escrow::contract::BidEscrowContract
+//! [`Slashing Voter Contract`]: crate::voting_contracts::SlashingVoterContract
+use crate::configuration::ConfigurationBuilder;
+use crate::modules::refs::ContractRefs;
+use crate::modules::{AccessControl, OwnerAction, OwnerActionArgs, OwnerProposal, Role};
+use crate::rules::validation::{IsNotSubjectOfSlashing, IsVa};
+use crate::rules::RulesBuilder;
+use crate::utils::Error;
+use crate::voting::types::VotingId;
+use crate::voting_contracts::slashing_voter::{Slashable, SlashableRef};
+use crate::voting_contracts::SlashingVoterContractRef;
+use odra::contract_env::caller;
+use odra::prelude::{vec, vec::Vec};
+use odra::types::event::OdraEvent;
+use odra::types::{Address, Balance};
+use odra::{Event, UnwrapOrRevert, Variable};
+
+/// Offboarding Request Contract.
+#[odra::module(events = [VaOffboarded])]
+pub struct OffboardingRequestContract {
+    refs: ContractRefs,
+    slashing_voter: Variable<Address>,
+    slashable_contracts: Variable<Vec<Address>>,
+    access_control: AccessControl,
+}
+
+#[odra::module]
+impl OffboardingRequestContract {
+    delegate! {
+        to self.access_control {
+            /// Changes the ownership of the contract. Transfers ownership to the `owner`.
+            /// Only the current owner is permitted to call this method.
+            /// [`Read more`](AccessControl::propose_new_owner())
+            pub fn propose_new_owner(&mut self, owner: Address);
+            /// Accepts the new owner proposition. This can be called only by the proposed owner.
+            /// [`Read more`](AccessControl::accept_new_owner())
+            pub fn accept_new_owner(&mut self);
+            /// Remove address from the whitelist.
+            /// [`Read more`](AccessControl::remove_from_whitelist())
+            pub fn remove_from_whitelist(&mut self, address: Address);
+            /// Checks whether the given address is added to the whitelist.
+            /// [`Read more`](AccessControl::is_whitelisted()).
+            pub fn is_whitelisted(&self, address: Address) -> bool;
+            /// Returns the address of the current owner.
+            /// [`Read more`](AccessControl::get_owner()).
+            pub fn get_owner(&self) -> Option<Address>;
+            /// Grants the `role` to the `address`.
+            /// [`Read more`](AccessControl::grant_role())
+            pub fn grant_role(&mut self, role: Role, address: Address);
+            /// Revokes the `role` from the `address`.
+            /// [`Read more`](AccessControl::revoke_role())
+            pub fn revoke_role(&mut self, role: Role, address: Address);
+            /// Sets the admin role of the `role`, whose holders grant and revoke the `role`.
+            /// [`Read more`](AccessControl::set_role_admin())
+            pub fn set_role_admin(&mut self, role: Role, admin_role: Role);
+            /// Returns the admin role of the `role`, whose holders grant and revoke the `role`.
+            /// [`Read more`](AccessControl::get_role_admin())
+            pub fn get_role_admin(&self, role: Role) -> Role;
+            /// Checks whether the `address` has the `role`.
+            /// [`Read more`](AccessControl::has_role())
+            pub fn has_role(&self, role: Role, address: Address) -> bool;
+            /// Replaces the whitelist entry of the `address` with the `roles`.
+            /// [`Read more`](AccessControl::migrate_to_roles())
+            pub fn migrate_to_roles(&mut self, address: Address, roles: Vec<Role>);
+            /// Hands the ownership over to the `signers`.
+            /// [`Read more`](AccessControl::enable_multisig_ownership())
+            pub fn enable_multisig_ownership(&mut self, signers: Vec<Address>, threshold: u32);
+            /// Proposes an owner-only action.
+            /// [`Read more`](AccessControl::propose_owner_action())
+            pub fn propose_owner_action(&mut self, action: OwnerAction, action_args: OwnerActionArgs) -> u32;
+            /// Approves a proposed owner-only action.
+            /// [`Read more`](AccessControl::approve_owner_action())
+            pub fn approve_owner_action(&mut self, proposal_id: u32);
+            /// Executes a proposed owner-only action approved by the current signers.
+            /// [`Read more`](AccessControl::execute_owner_action())
+            pub fn execute_owner_action(&mut self, proposal_id: u32);
+            /// Returns the signers of the multisig ownership.
+            /// [`Read more`](AccessControl::get_signers())
+            pub fn get_signers(&self) -> Vec<Address>;
+            /// Returns the number of approvals required to execute an owner-only action.
+            /// [`Read more`](AccessControl::get_threshold())
+            pub fn get_threshold(&self) -> u32;
+            /// Returns the proposal of an owner-only action.
+            /// [`Read more`](AccessControl::get_owner_proposal())
+            pub fn get_owner_proposal(&self, proposal_id: u32) -> Option<OwnerProposal>;
+            /// Pauses the contract.
+            /// [`Read more`](AccessControl::pause())
+            pub fn pause(&mut self);
+            /// Unpauses the contract.
+            /// [`Read more`](AccessControl::unpause())
+            pub fn unpause(&mut self);
+            /// Returns true if the contract is paused.
+            /// [`Read more`](AccessControl::is_paused())
+            pub fn is_paused(&self) -> bool;
+        }
+
+        to self.refs {
+            /// Returns the address of [Variable Repository](crate::core_contracts::VariableRepositoryContract) contract.
+            pub fn variable_repository_address(&self) -> Address;
+            /// Returns the address of [Reputation Token](crate::core_contracts::ReputationContract) contract.
+            pub fn reputation_token_address(&self) -> Address;
+        }
+    }
+
+    /// Contract constructor.
+    ///
+    /// Initializes contract elements:
+    /// * Sets up [`ContractRefs`] by writing addresses of [`Variable Repository`](crate::core_contracts::VariableRepositoryContract),
+    /// [`Reputation Token`](crate::core_contracts::ReputationContract), [`VA Token`](crate::core_contracts::VaNftContract).
+    /// * Sets the address of [`Slashing Voter`](crate::voting_contracts::SlashingVoterContract).
+    /// * Sets [`caller`] as the owner of the contract.
+    /// * Grants [`caller`] the [`Admin`](crate::modules::Role::Admin) role.
+    ///
+    /// # Events
+    /// * [`OwnerChanged`](crate::modules::owner::events::OwnerChanged),
+    /// * [`RoleGranted`](crate::modules::roles::events::RoleGranted),
+    #[odra(init)]
+    pub fn init(
+        &mut self,
+        variable_repository: Address,
+        reputation_token: Address,
+        va_token: Address,
+        slashing_voter: Address,
+    ) {
+        self.refs.set_variable_repository(variable_repository);
+        self.refs.set_reputation_token(reputation_token);
+        self.refs.set_va_token(va_token);
+        self.slashing_voter.set(slashing_voter);
+        self.access_control.init(caller());
+    }
+
+    /// Sets the contracts in which the activities of an offboarded VA are settled.
+    ///
+    /// # Errors
+    /// * [`MissingRole`](crate::utils::Error::MissingRole) if the caller
+    /// does not have the [`Operator`](crate::modules::Role::Operator) role.
+    pub fn update_slashable_contracts(&mut self, slashable_contracts: Vec<Address>) {
+        self.access_control.ensure_role(Role::Operator);
+        self.slashable_contracts.set(slashable_contracts);
+    }
+
+    /// Returns the contracts in which the activities of an offboarded VA are settled.
+    pub fn get_slashable_contracts(&self) -> Vec<Address> {
+        self.slashable_contracts.get_or_default()
+    }
+
+    /// Offboards the caller.
+    ///
+    /// The caller's votings, ballots, `Job Offers`, `Bids` and `Jobs` are cancelled in all the slashable contracts,
+    /// the `VA Token` is burned and the reputation is burned or converted to passive reputation.
+    ///
+    /// # Errors
+    /// * [`NotOnboarded`](crate::utils::Error::NotOnboarded) if the caller is not a VA.
+    /// * [`SubjectOfSlashing`](crate::utils::Error::SubjectOfSlashing) if the caller is the subject
+    /// of an ongoing slashing voting.
+    ///
+    /// # Events
+    /// * [`VaOffboarded`]
+    pub fn request_offboarding(&mut self) {
+        self.access_control.ensure_not_paused();
+        let va = caller();
+        RulesBuilder::new()
+            .add_validation(IsVa::create(
+                !self.refs.va_token().balance_of(&va).is_zero(),
+            ))
+            .add_validation(IsNotSubjectOfSlashing::create(
+                self.slashing_voter().is_subject_of_slashing(va),
+            ))
+            .build()
+            .validate_generic_validations();
+
+        let mut cancelled_votings = vec![];
+        let mut affected_votings = vec![];
+        for address in self.slashable_contracts.get_or_default() {
+            let slashed_votings = SlashableRef::at(&address).slash_voter(va);
+            cancelled_votings.extend(slashed_votings.cancelled_votings);
+            affected_votings.extend(slashed_votings.affected_votings);
+        }
+
+        let mut reputation_token = self.refs.reputation_token();
+        // Cancelled Jobs do not release the Worker's stake.
+        let stake = reputation_token.get_stake(va);
+        if !stake.is_zero() {
+            reputation_token.unstake(va, stake);
+        }
+
+        self.refs.va_token().burn(va);
+
+        let configuration = ConfigurationBuilder::new(
+            self.refs.va_token().total_supply(),
+            &self.refs.variable_repository().all_variables(),
+        )
+        .build();
+
+        let reputation = reputation_token.balance_of(va);
+        reputation_token.burn_all(va);
+        let converted_to_passive = configuration.offboarding_reputation_to_passive();
+        if converted_to_passive && !reputation.is_zero() {
+            reputation_token.mint_passive(va, reputation);
+        }
+
+        VaOffboarded {
+            va,
+            reputation,
+            converted_to_passive,
+            cancelled_votings,
+            affected_votings,
+        }
+        .emit();
+    }
+}
+
+impl OffboardingRequestContract {
+    fn slashing_voter(&self) -> SlashingVoterContractRef {
+        SlashingVoterContractRef::at(
+            &self
+                .slashing_voter
+                .get()
+                .unwrap_or_revert_with(Error::VariableValueNotSet),
+        )
+    }
+}
+
+/// Event emitted when a VA has been offboarded.
+#[derive(Debug, PartialEq, Eq, Event)]
+pub struct VaOffboarded {
+    pub va: Address,
+    pub reputation: Balance,
+    pub converted_to_passive: bool,
+    pub cancelled_votings: Vec<VotingId>,
+    pub affected_votings: Vec<VotingId>,
+}
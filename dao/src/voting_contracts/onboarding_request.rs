@@ -19,15 +19,35 @@
 //! redistribution of `Job Payment` between `VA`’s we redistribute the stake of the `External Worker`.
 //! If the process fails, the `CSPR` stake of the `External Worker` is returned.
 //!
+//! # Sponsored Onboarding
+//! Alternatively, an existing `VA` may sponsor a KYC'd address calling [`create_sponsored_voting()`](OnboardingRequestContract::create_sponsored_voting()).
+//! The sponsor does not deposit any `CSPR`, but stakes their own reputation. The informal quorum is not required
+//! and the `Informal Voting` lasts `SponsoredInformalVotingTime`, so it can be shortened or skipped.
+//!
+//! If the voting passes, the candidate becomes a `VA`, and the sponsor's stake remains staked for the `SponsorshipProbationPeriod`.
+//! If the sponsor has lost reputation during the voting, only the reputation left is staked.
+//! If the new `VA` is fully slashed during the probation period, the sponsor's stake is burned.
+//! Otherwise, after the probation period the stake can be released calling
+//! [`release_sponsorship()`](OnboardingRequestContract::release_sponsorship()).
+//!
+//! # Probation
+//! A new `VA` is on probation for `VaProbationPeriod`. A `VA` on probation cannot sponsor an onboarding
+//! and cannot vote in favor of an onboarding unless a `VA` out of probation has already voted in favor.
+//!
+//! # Claims
+//! Returned stakes and redistributed `CSPR` are not transferred directly. They are credited to a claimable balance,
+//! and the recipient withdraws them calling [`claim()`](OnboardingRequestContract::claim()).
+//!
 //! # Voting
 //! The Voting process is managed by [`VotingEngine`].
 //!
 //! [`Bid Escrow Contract`]: crate::bid_escrow::contract::BidEscrowContract
 //! [`VotingEngine`]: VotingEngine
 //! [submission process]: crate::bid_escrow#submitting-a-job-proof
+use crate::modules::claims::PendingClaim;
 use crate::modules::refs::ContractRefs;
-use crate::modules::AccessControl;
-use crate::onboarding::Onboarding;
+use crate::modules::{AccessControl, Claims, OwnerAction, OwnerActionArgs, OwnerProposal, Role};
+use crate::onboarding::{Onboarding, Sponsorship};
 use crate::utils::types::DocumentHash;
 use crate::voting::ballot::{Ballot, Choice};
 use crate::voting::types::VotingId;
@@ -38,19 +58,21 @@ use crate::voting::voting_engine::voting_state_machine::{
 use crate::voting::voting_engine::VotingEngine;
 use crate::voting_contracts::SlashedVotings;
 use odra::contract_env::{attached_value, caller, self_balance};
+use odra::prelude::vec::Vec;
 use odra::types::event::OdraEvent;
 use odra::types::{Address, Balance, BlockTime};
 use odra::Event;
 
 /// Onboarding Request Contract.
-#[odra::module(events = [OnboardingVotingCreated])]
+#[odra::module(events = [OnboardingVotingCreated, SponsoredOnboardingVotingCreated])]
 pub struct OnboardingRequestContract {
     refs: ContractRefs,
     #[odra(using = "refs")]
     voting: VotingEngine,
     access_control: AccessControl,
-    #[odra(using = "refs, voting")]
+    #[odra(using = "refs, voting, claims")]
     onboarding: Onboarding,
+    claims: Claims,
 }
 
 #[odra::module]
@@ -80,9 +102,6 @@ impl OnboardingRequestContract {
             /// Accepts the new owner proposition. This can be called only by the proposed owner.
             /// [`Read more`](AccessControl::accept_new_owner())
             pub fn accept_new_owner(&mut self);
-            /// Adds a new address to the whitelist.
-            /// [`Read more`](AccessControl::add_to_whitelist())
-            pub fn add_to_whitelist(&mut self, address: Address);
             /// Remove address from the whitelist.
             /// [`Read more`](AccessControl::remove_from_whitelist())
             pub fn remove_from_whitelist(&mut self, address: Address);
@@ -92,14 +111,82 @@ impl OnboardingRequestContract {
             /// Returns the address of the current owner.
             /// [`Read more`](AccessControl::get_owner()).
             pub fn get_owner(&self) -> Option<Address>;
+            /// Grants the `role` to the `address`.
+            /// [`Read more`](AccessControl::grant_role())
+            pub fn grant_role(&mut self, role: Role, address: Address);
+            /// Revokes the `role` from the `address`.
+            /// [`Read more`](AccessControl::revoke_role())
+            pub fn revoke_role(&mut self, role: Role, address: Address);
+            /// Sets the admin role of the `role`, whose holders grant and revoke the `role`.
+            /// [`Read more`](AccessControl::set_role_admin())
+            pub fn set_role_admin(&mut self, role: Role, admin_role: Role);
+            /// Returns the admin role of the `role`, whose holders grant and revoke the `role`.
+            /// [`Read more`](AccessControl::get_role_admin())
+            pub fn get_role_admin(&self, role: Role) -> Role;
+            /// Checks whether the `address` has the `role`.
+            /// [`Read more`](AccessControl::has_role())
+            pub fn has_role(&self, role: Role, address: Address) -> bool;
+            /// Replaces the whitelist entry of the `address` with the `roles`.
+            /// [`Read more`](AccessControl::migrate_to_roles())
+            pub fn migrate_to_roles(&mut self, address: Address, roles: Vec<Role>);
+            /// Hands the ownership over to the `signers`.
+            /// [`Read more`](AccessControl::enable_multisig_ownership())
+            pub fn enable_multisig_ownership(&mut self, signers: Vec<Address>, threshold: u32);
+            /// Proposes an owner-only action.
+            /// [`Read more`](AccessControl::propose_owner_action())
+            pub fn propose_owner_action(&mut self, action: OwnerAction, action_args: OwnerActionArgs) -> u32;
+            /// Approves a proposed owner-only action.
+            /// [`Read more`](AccessControl::approve_owner_action())
+            pub fn approve_owner_action(&mut self, proposal_id: u32);
+            /// Executes a proposed owner-only action approved by the current signers.
+            /// [`Read more`](AccessControl::execute_owner_action())
+            pub fn execute_owner_action(&mut self, proposal_id: u32);
+            /// Returns the signers of the multisig ownership.
+            /// [`Read more`](AccessControl::get_signers())
+            pub fn get_signers(&self) -> Vec<Address>;
+            /// Returns the number of approvals required to execute an owner-only action.
+            /// [`Read more`](AccessControl::get_threshold())
+            pub fn get_threshold(&self) -> u32;
+            /// Returns the proposal of an owner-only action.
+            /// [`Read more`](AccessControl::get_owner_proposal())
+            pub fn get_owner_proposal(&self, proposal_id: u32) -> Option<OwnerProposal>;
+            /// Pauses the contract.
+            /// [`Read more`](AccessControl::pause())
+            pub fn pause(&mut self);
+            /// Unpauses the contract.
+            /// [`Read more`](AccessControl::unpause())
+            pub fn unpause(&mut self);
+            /// Returns true if the contract is paused.
+            /// [`Read more`](AccessControl::is_paused())
+            pub fn is_paused(&self) -> bool;
         }
 
         to self.onboarding {
-            /// Finishes voting stage. Depending on stage, the voting can be converted to a formal one, end
-            /// with a refund or convert the requester to a VA.
-            pub fn finish_voting(&mut self, voting_id: VotingId, voting_type: VotingType) -> VotingSummary;
-            /// Casts a vote. [Read more](VotingEngine::vote())
-            pub fn vote(&mut self, voting_id: VotingId, voting_type: VotingType, choice: Choice, stake: Balance);
+            /// Returns the sponsorship of a VA onboarded by a sponsored request.
+            pub fn get_sponsorship(&self, va: Address) -> Option<Sponsorship>;
+            /// Returns the sponsor's stake once the probation period of the sponsored VA is over.
+            ///
+            /// # Errors
+            /// * [`SponsorshipNotFound`](crate::utils::Error::SponsorshipNotFound)
+            /// * [`SponsorshipProbationNotEnded`](crate::utils::Error::SponsorshipProbationNotEnded)
+            ///
+            /// # Events
+            /// * [`SponsorshipReleased`](crate::onboarding::SponsorshipReleased)
+            pub fn release_sponsorship(&mut self, va: Address);
+        }
+
+        to self.claims {
+            /// Transfers all the CSPR credited to the caller.
+            ///
+            /// # Errors
+            /// * [`NothingToClaim`](crate::utils::Error::NothingToClaim) - if there is nothing to claim
+            ///
+            /// # Events
+            /// * [`CSPRTransfer`](crate::bid_escrow::events::CSPRTransfer)
+            pub fn claim(&mut self);
+
+            /// Returns the CSPR credited to a given address and awaiting to be claimed, grouped by the reason.
+            pub fn get_pending_claims(&self, address: Address) -> Vec<PendingClaim>;
         }
 
         to self.refs {
@@ -117,11 +204,11 @@ impl OnboardingRequestContract {
     /// * Sets up the contract by writing addresses of [`Variable Repository`](crate::core_contracts::VariableRepositoryContract),
     /// [`Reputation Token`](crate::core_contracts::ReputationContract), [`VA Token`](crate::core_contracts::VaNftContract), [`KYC Token`](crate::core_contracts::KycNftContract).
     /// * Sets [`caller`] as the owner of the contract.
-    /// * Adds [`caller`] to the whitelist.
+    /// * Grants [`caller`] the [`Admin`](crate::modules::Role::Admin) role.
     ///
     /// # Events
     /// * [`OwnerChanged`](crate::modules::owner::events::OwnerChanged),
-    /// * [`AddedToWhitelist`](crate::modules::whitelist::events::AddedToWhitelist),
+    /// * [`RoleGranted`](crate::modules::roles::events::RoleGranted),
     #[odra(init)]
     pub fn init(
         &mut self,
@@ -143,22 +230,110 @@ impl OnboardingRequestContract {
     /// * [`OnboardingVotingCreated`]
     #[odra(payable)]
     pub fn create_voting(&mut self, reason: DocumentHash) {
+        self.access_control.ensure_not_paused();
         let cspr_deposit = attached_value();
         let voting_info = self.onboarding.submit_request(reason.clone(), cspr_deposit);
         OnboardingVotingCreated::new(reason, cspr_deposit, voting_info).emit();
     }
 
+    /// Submits an onboarding request of the `candidate` sponsored by the caller, who stakes the given amount of reputation.
+    ///
+    /// # Errors
+    /// * [`NotOnboarded`](crate::utils::Error::NotOnboarded) if the caller is not a VA.
+    /// * [`NotKyced`](crate::utils::Error::NotKyced) if the candidate is not KYC'd.
+    /// * [`VaOnboardedAlready`](crate::utils::Error::VaOnboardedAlready) if the candidate is a VA.
+    /// * [`VotingNotCompleted`](crate::utils::Error::VotingNotCompleted) if the candidate has an ongoing voting.
+    /// * [`ProbationaryVaCannotBeSoleApprover`](crate::utils::Error::ProbationaryVaCannotBeSoleApprover) if the caller
+    /// is on probation.
+    ///
+    /// # Events
+    /// * [`SponsoredOnboardingVotingCreated`]
+    pub fn create_sponsored_voting(
+        &mut self,
+        candidate: Address,
+        reason: DocumentHash,
+        stake: Balance,
+    ) {
+        self.access_control.ensure_not_paused();
+        let voting_info =
+            self.onboarding
+                .submit_sponsored_request(candidate, reason.clone(), stake);
+        SponsoredOnboardingVotingCreated::new(reason, candidate, voting_info).emit();
+    }
+
     /// Gets the CSPR balance of the contract.
     pub fn get_cspr_balance(&self) -> Balance {
         self_balance()
     }
 
+    /// Finishes voting stage. Depending on stage, the voting can be converted to a formal one, end
+    /// with a refund or convert the requester to a VA.
+    pub fn finish_voting(&mut self, voting_id: VotingId, voting_type: VotingType) -> VotingSummary {
+        self.access_control.ensure_not_paused();
+        self.onboarding.finish_voting(voting_id, voting_type)
+    }
+
+    /// Casts a vote. [Read more](VotingEngine::vote())
+    ///
+    /// # Errors
+    /// * [`ProbationaryVaCannotBeSoleApprover`](crate::utils::Error::ProbationaryVaCannotBeSoleApprover)
+    /// if a VA on probation votes in favor and no VA out of probation has voted in favor yet.
+    pub fn vote(
+        &mut self,
+        voting_id: VotingId,
+        voting_type: VotingType,
+        choice: Choice,
+        stake: Balance,
+    ) {
+        self.access_control.ensure_not_paused();
+        self.onboarding.vote(voting_id, voting_type, choice, stake);
+    }
+
     /// Erases the voter from voting with the given id. [Read more](VotingEngine::slash_voter).
+    ///
+    /// If the voter has been fully slashed during the probation period of a sponsored onboarding, the sponsor's stake is burned.
     pub fn slash_voter(&mut self, voter: Address) -> SlashedVotings {
-        self.access_control.ensure_whitelisted();
+        self.access_control.ensure_not_paused();
+        self.access_control.ensure_role(Role::Slasher);
+        self.onboarding.slash_sponsor(voter);
         self.voting.slash_voter(voter)
     }
 
+    /// Moves the ballots, the sponsorships, the pending sponsored requests and the unclaimed CSPR
+    /// of the `from` address to the `to` address. Only the holders of the [`Migrator`](crate::modules::Role::Migrator) role
+    /// are permitted to call this method.
+    ///
+    /// Returns ids of the affected votings.
+    ///
+    /// # Errors
+    /// * [`MissingRole`](crate::utils::Error::MissingRole) if the caller
+    /// does not have the [`Migrator`](crate::modules::Role::Migrator) role.
+    pub fn migrate_account(&mut self, from: Address, to: Address) -> Vec<VotingId> {
+        self.access_control.ensure_not_paused();
+        self.access_control.ensure_role(Role::Migrator);
+        self.onboarding.migrate_sponsorships(from, to);
+        self.claims.migrate(from, to);
+        self.voting.migrate_voter(from, to)
+    }
+
+    /// Closes the pending onboarding request of the `address` which KYC has been revoked.
+    /// Only the holders of the [`Slasher`](crate::modules::Role::Slasher) role are permitted to call this method.
+    ///
+    /// The voting is cancelled and the stakes are returned. The `CSPR` deposit is returned, or,
+    /// if the `KycRevocationForfeitsDeposit` variable is set, forfeited to the DAO.
+    ///
+    /// # Errors
+    /// * [`MissingRole`](crate::utils::Error::MissingRole) if the caller
+    /// does not have the [`Slasher`](crate::modules::Role::Slasher) role.
+    ///
+    /// # Events
+    /// * [`OnboardingRequestClosed`](crate::onboarding::OnboardingRequestClosed)
+    pub fn revoke_kyc(&mut self, address: Address) {
+        self.access_control.ensure_not_paused();
+        self.access_control.ensure_role(Role::Slasher);
+        self.onboarding.revoke_kyc(address);
+    }
+
     /// Cancels a voting that has not been finished in defined time
     ///
     /// # Errors
@@ -215,3 +390,42 @@ impl OnboardingVotingCreated {
         }
     }
 }
+
+/// Event emitted when sponsored onboarding voting has been created.
+#[derive(Debug, PartialEq, Eq, Event)]
+pub struct SponsoredOnboardingVotingCreated {
+    reason: DocumentHash,
+    candidate: Address,
+    creator: Address,
+    stake: Option<Balance>,
+    voting_id: VotingId,
+    config_informal_quorum: u32,
+    config_informal_voting_time: u64,
+    config_formal_quorum: u32,
+    config_formal_voting_time: u64,
+    config_total_onboarded: Balance,
+    config_double_time_between_votings: bool,
+    config_voting_clearness_delta: Balance,
+    config_time_between_informal_and_formal_voting: BlockTime,
+}
+
+impl SponsoredOnboardingVotingCreated {
+    pub fn new(reason: DocumentHash, candidate: Address, info: VotingCreatedInfo) -> Self {
+        Self {
+            reason,
+            candidate,
+            creator: info.creator,
+            stake: info.stake,
+            voting_id: info.voting_id,
+            config_informal_quorum: info.config_informal_quorum,
+            config_informal_voting_time: info.config_informal_voting_time,
+            config_formal_quorum: info.config_formal_quorum,
+            config_formal_voting_time: info.config_formal_voting_time,
+            config_total_onboarded: info.config_total_onboarded,
+            config_double_time_between_votings: info.config_double_time_between_votings,
+            config_voting_clearness_delta: info.config_voting_clearness_delta,
+            config_time_between_informal_and_formal_voting: info
+                .config_time_between_informal_and_formal_voting,
+        }
+    }
+}
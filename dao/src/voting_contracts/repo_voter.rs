@@ -2,17 +2,23 @@ use odra::contract_env::revert;
 use odra::types::OdraType;
 use odra::{
     contract_env::caller,
-    prelude::string::{String, ToString},
+    prelude::{
+        string::{String, ToString},
+        vec::Vec,
+    },
     types::{event::OdraEvent, Address, Balance, BlockTime, Bytes, CallArgs},
     Event,
 };
 
 use crate::utils::variable_type::VariableType;
-use crate::utils::Error::CouldntDeserializeValueToCorrectType;
+use crate::utils::Error::{CouldntDeserializeValueToCorrectType, InvalidFeeSplit};
 use crate::voting_contracts::SlashedVotings;
 use crate::{
-    configuration::ConfigurationBuilder,
-    modules::{refs::ContractRefs, AccessControl},
+    configuration::{ConfigurationBuilder, FeeSplit},
+    modules::{
+        refs::ContractRefs, AccessControl, OwnerAction, OwnerActionArgs, OwnerProposal, Role,
+    },
+    rules::{validation::voting::IsNotOnProbation, RulesBuilder},
     utils::{consts, ContractCall},
     voting::{
         ballot::{Ballot, Choice},
@@ -30,6 +36,8 @@ use crate::{
 /// It is responsible for managing variables held in [Variable Repo](crate::core_contracts::VariableRepositoryContract).
 ///
 /// Each change to the variable is being voted on, and when the voting passes, a change is made at given time.
+///
+/// A VA on probation cannot create a voting.
 #[odra::module(events = [RepoVotingCreated])]
 pub struct RepoVoterContract {
     refs: ContractRefs,
@@ -54,17 +62,31 @@ impl RepoVoterContract {
                 address: Address,
             ) -> Option<Ballot>;
             pub fn get_voter(&self, voting_id: VotingId, voting_type: VotingType, at: u32) -> Option<Address>;
-            pub fn finish_voting(&mut self, voting_id: VotingId, voting_type: VotingType) -> VotingSummary;
             pub fn cancel_finished_voting(&mut self, voting_id: VotingId);
         }
 
         to self.access_control {
             pub fn propose_new_owner(&mut self, owner: Address);
             pub fn accept_new_owner(&mut self);
-            pub fn add_to_whitelist(&mut self, address: Address);
             pub fn remove_from_whitelist(&mut self, address: Address);
             pub fn is_whitelisted(&self, address: Address) -> bool;
             pub fn get_owner(&self) -> Option<Address>;
+            pub fn grant_role(&mut self, role: Role, address: Address);
+            pub fn revoke_role(&mut self, role: Role, address: Address);
+            pub fn set_role_admin(&mut self, role: Role, admin_role: Role);
+            pub fn get_role_admin(&self, role: Role) -> Role;
+            pub fn has_role(&self, role: Role, address: Address) -> bool;
+            pub fn migrate_to_roles(&mut self, address: Address, roles: Vec<Role>);
+            pub fn enable_multisig_ownership(&mut self, signers: Vec<Address>, threshold: u32);
+            pub fn propose_owner_action(&mut self, action: OwnerAction, action_args: OwnerActionArgs) -> u32;
+            pub fn approve_owner_action(&mut self, proposal_id: u32);
+            pub fn execute_owner_action(&mut self, proposal_id: u32);
+            pub fn get_signers(&self) -> Vec<Address>;
+            pub fn get_threshold(&self) -> u32;
+            pub fn get_owner_proposal(&self, proposal_id: u32) -> Option<OwnerProposal>;
+            pub fn pause(&mut self);
+            pub fn unpause(&mut self);
+            pub fn is_paused(&self) -> bool;
         }
 
         to self.refs {
@@ -94,6 +116,7 @@ impl RepoVoterContract {
         activation_time: Option<u64>,
         stake: Balance,
     ) {
+        self.access_control.ensure_not_paused();
         Self::assert_correct_value_type(&key, &value);
 
         let voting_configuration = ConfigurationBuilder::new(
@@ -114,9 +137,18 @@ impl RepoVoterContract {
         })
         .build();
 
+        let creator = caller();
+        RulesBuilder::new()
+            .add_validation(IsNotOnProbation::create(
+                self.voting_engine
+                    .is_on_probation(creator, &voting_configuration),
+            ))
+            .build()
+            .validate_generic_validations();
+
         let (info, _) = self
             .voting_engine
-            .create_voting(caller(), stake, voting_configuration);
+            .create_voting(creator, stake, voting_configuration);
 
         RepoVotingCreated::new(variable_repo_to_edit, key, value, activation_time, info).emit();
     }
@@ -128,14 +160,27 @@ impl RepoVoterContract {
         choice: Choice,
         stake: Balance,
     ) {
+        self.access_control.ensure_not_paused();
         self.voting_engine
             .vote(caller(), voting_id, voting_type, choice, stake);
     }
 
+    pub fn finish_voting(&mut self, voting_id: VotingId, voting_type: VotingType) -> VotingSummary {
+        self.access_control.ensure_not_paused();
+        self.voting_engine.finish_voting(voting_id, voting_type)
+    }
+
     pub fn slash_voter(&mut self, voter: Address) -> SlashedVotings {
-        self.access_control.ensure_whitelisted();
+        self.access_control.ensure_not_paused();
+        self.access_control.ensure_role(Role::Slasher);
         self.voting_engine.slash_voter(voter)
     }
+
+    pub fn migrate_account(&mut self, from: Address, to: Address) -> Vec<VotingId> {
+        self.access_control.ensure_not_paused();
+        self.access_control.ensure_role(Role::Migrator);
+        self.voting_engine.migrate_voter(from, to)
+    }
 }
 
 impl RepoVoterContract {
@@ -145,6 +190,11 @@ impl RepoVoterContract {
             VariableType::BlockTime => BlockTime::deserialize(value).is_some(),
             VariableType::Address => Address::deserialize(value).is_some(),
             VariableType::Bool => bool::deserialize(value).is_some(),
+            VariableType::FeeSplit => match FeeSplit::deserialize(value) {
+                Some(fee_split) if !fee_split.is_valid() => revert(InvalidFeeSplit),
+                fee_split => fee_split.is_some(),
+            },
+            VariableType::StringList => Vec::<String>::deserialize(value).is_some(),
             VariableType::Unknown => true,
         };
 
@@ -14,7 +14,7 @@
 //! [`VotingEngine`]: VotingEngine
 use crate::configuration::ConfigurationBuilder;
 use crate::modules::refs::ContractRefs;
-use crate::modules::AccessControl;
+use crate::modules::{AccessControl, OwnerAction, OwnerActionArgs, OwnerProposal, Role};
 use crate::utils::types::DocumentHash;
 use crate::utils::ContractCall;
 use crate::voting::ballot::{Ballot, Choice};
@@ -26,6 +26,7 @@ use crate::voting::voting_engine::VotingEngine;
 use crate::voting_contracts::SlashedVotings;
 use odra::contract_env::{caller, emit_event};
 use odra::prelude::string::{String, ToString};
+use odra::prelude::vec::Vec;
 use odra::types::{Address, Balance, BlockTime, CallArgs};
 use odra::{Event, OdraType};
 
@@ -58,17 +59,31 @@ impl ReputationVoterContract {
                 address: Address,
             ) -> Option<Ballot>;
             pub fn get_voter(&self, voting_id: VotingId, voting_type: VotingType, at: u32) -> Option<Address>;
-            pub fn finish_voting(&mut self, voting_id: VotingId, voting_type: VotingType) -> VotingSummary;
             pub fn cancel_finished_voting(&mut self, voting_id: VotingId);
         }
 
         to self.access_control {
             pub fn propose_new_owner(&mut self, owner: Address);
             pub fn accept_new_owner(&mut self);
-            pub fn add_to_whitelist(&mut self, address: Address);
             pub fn remove_from_whitelist(&mut self, address: Address);
             pub fn is_whitelisted(&self, address: Address) -> bool;
             pub fn get_owner(&self) -> Option<Address>;
+            pub fn grant_role(&mut self, role: Role, address: Address);
+            pub fn revoke_role(&mut self, role: Role, address: Address);
+            pub fn set_role_admin(&mut self, role: Role, admin_role: Role);
+            pub fn get_role_admin(&self, role: Role) -> Role;
+            pub fn has_role(&self, role: Role, address: Address) -> bool;
+            pub fn migrate_to_roles(&mut self, address: Address, roles: Vec<Role>);
+            pub fn enable_multisig_ownership(&mut self, signers: Vec<Address>, threshold: u32);
+            pub fn propose_owner_action(&mut self, action: OwnerAction, action_args: OwnerActionArgs) -> u32;
+            pub fn approve_owner_action(&mut self, proposal_id: u32);
+            pub fn execute_owner_action(&mut self, proposal_id: u32);
+            pub fn get_signers(&self) -> Vec<Address>;
+            pub fn get_threshold(&self) -> u32;
+            pub fn get_owner_proposal(&self, proposal_id: u32) -> Option<OwnerProposal>;
+            pub fn pause(&mut self);
+            pub fn unpause(&mut self);
+            pub fn is_paused(&self) -> bool;
         }
 
         to self.refs {
@@ -98,6 +113,7 @@ impl ReputationVoterContract {
         document_hash: DocumentHash,
         stake: Balance,
     ) {
+        self.access_control.ensure_not_paused();
         let voting_configuration = ConfigurationBuilder::new(
             self.refs.va_token().total_supply(),
             &self.refs.variable_repository().all_variables(),
@@ -130,14 +146,27 @@ impl ReputationVoterContract {
         choice: Choice,
         stake: Balance,
     ) {
+        self.access_control.ensure_not_paused();
         self.voting_engine
             .vote(caller(), voting_id, voting_type, choice, stake);
     }
 
+    pub fn finish_voting(&mut self, voting_id: VotingId, voting_type: VotingType) -> VotingSummary {
+        self.access_control.ensure_not_paused();
+        self.voting_engine.finish_voting(voting_id, voting_type)
+    }
+
     pub fn slash_voter(&mut self, voter: Address) -> SlashedVotings {
-        self.access_control.ensure_whitelisted();
+        self.access_control.ensure_not_paused();
+        self.access_control.ensure_role(Role::Slasher);
         self.voting_engine.slash_voter(voter)
     }
+
+    pub fn migrate_account(&mut self, from: Address, to: Address) -> Vec<VotingId> {
+        self.access_control.ensure_not_paused();
+        self.access_control.ensure_role(Role::Migrator);
+        self.voting_engine.migrate_voter(from, to)
+    }
 }
 
 /// Event emitted once voting is created.
@@ -1,5 +1,6 @@
 use odra::{
     contract_env::caller,
+    prelude::vec::Vec,
     types::{event::OdraEvent, Address, Balance, BlockTime},
     Event, Mapping,
 };
@@ -7,7 +8,9 @@ use odra::{
 use crate::voting_contracts::SlashedVotings;
 use crate::{
     configuration::ConfigurationBuilder,
-    modules::{refs::ContractRefs, AccessControl},
+    modules::{
+        refs::ContractRefs, AccessControl, OwnerAction, OwnerActionArgs, OwnerProposal, Role,
+    },
     utils::types::DocumentHash,
     voting::{
         ballot::{Ballot, Choice},
@@ -51,16 +54,30 @@ impl SimpleVoterContract {
             ) -> Option<Ballot>;
             pub fn get_voter(&self, voting_id: VotingId, voting_type: VotingType, at: u32) -> Option<Address>;
             pub fn cancel_finished_voting(&mut self, voting_id: VotingId);
-            pub fn finish_voting(&mut self, voting_id: VotingId, voting_type: VotingType) -> VotingSummary;
         }
 
         to self.access_control {
             pub fn propose_new_owner(&mut self, owner: Address);
             pub fn accept_new_owner(&mut self);
-            pub fn add_to_whitelist(&mut self, address: Address);
             pub fn remove_from_whitelist(&mut self, address: Address);
             pub fn is_whitelisted(&self, address: Address) -> bool;
             pub fn get_owner(&self) -> Option<Address>;
+            pub fn grant_role(&mut self, role: Role, address: Address);
+            pub fn revoke_role(&mut self, role: Role, address: Address);
+            pub fn set_role_admin(&mut self, role: Role, admin_role: Role);
+            pub fn get_role_admin(&self, role: Role) -> Role;
+            pub fn has_role(&self, role: Role, address: Address) -> bool;
+            pub fn migrate_to_roles(&mut self, address: Address, roles: Vec<Role>);
+            pub fn enable_multisig_ownership(&mut self, signers: Vec<Address>, threshold: u32);
+            pub fn propose_owner_action(&mut self, action: OwnerAction, action_args: OwnerActionArgs) -> u32;
+            pub fn approve_owner_action(&mut self, proposal_id: u32);
+            pub fn execute_owner_action(&mut self, proposal_id: u32);
+            pub fn get_signers(&self) -> Vec<Address>;
+            pub fn get_threshold(&self) -> u32;
+            pub fn get_owner_proposal(&self, proposal_id: u32) -> Option<OwnerProposal>;
+            pub fn pause(&mut self);
+            pub fn unpause(&mut self);
+            pub fn is_paused(&self) -> bool;
         }
 
         to self.refs {
@@ -83,6 +100,7 @@ impl SimpleVoterContract {
     }
 
     pub fn create_voting(&mut self, document_hash: DocumentHash, stake: Balance) {
+        self.access_control.ensure_not_paused();
         let voting_configuration = ConfigurationBuilder::new(
             self.refs.va_token().total_supply(),
             &self.refs.variable_repository().all_variables(),
@@ -110,14 +128,27 @@ impl SimpleVoterContract {
         choice: Choice,
         stake: Balance,
     ) {
+        self.access_control.ensure_not_paused();
         self.voting_engine
             .vote(caller(), voting_id, voting_type, choice, stake);
     }
 
+    pub fn finish_voting(&mut self, voting_id: VotingId, voting_type: VotingType) -> VotingSummary {
+        self.access_control.ensure_not_paused();
+        self.voting_engine.finish_voting(voting_id, voting_type)
+    }
+
     pub fn slash_voter(&mut self, voter: Address) -> SlashedVotings {
-        self.access_control.ensure_whitelisted();
+        self.access_control.ensure_not_paused();
+        self.access_control.ensure_role(Role::Slasher);
         self.voting_engine.slash_voter(voter)
     }
+
+    pub fn migrate_account(&mut self, from: Address, to: Address) -> Vec<VotingId> {
+        self.access_control.ensure_not_paused();
+        self.access_control.ensure_role(Role::Migrator);
+        self.voting_engine.migrate_voter(from, to)
+    }
 }
 
 /// Event emitted when simple voting has been created.
@@ -9,7 +9,9 @@ use crate::rules::validation::IsVa;
 use crate::rules::RulesBuilder;
 use crate::{
     configuration::ConfigurationBuilder,
-    modules::{refs::ContractRefs, AccessControl},
+    modules::{
+        refs::ContractRefs, AccessControl, OwnerAction, OwnerActionArgs, OwnerProposal, Role,
+    },
     utils::Error,
     voting::{
         ballot::{Ballot, Choice},
@@ -57,10 +59,25 @@ impl SlashingVoterContract {
         to self.access_control {
             pub fn propose_new_owner(&mut self, owner: Address);
             pub fn accept_new_owner(&mut self);
-            pub fn add_to_whitelist(&mut self, address: Address);
             pub fn remove_from_whitelist(&mut self, address: Address);
             pub fn is_whitelisted(&self, address: Address) -> bool;
             pub fn get_owner(&self) -> Option<Address>;
+            pub fn grant_role(&mut self, role: Role, address: Address);
+            pub fn revoke_role(&mut self, role: Role, address: Address);
+            pub fn set_role_admin(&mut self, role: Role, admin_role: Role);
+            pub fn get_role_admin(&self, role: Role) -> Role;
+            pub fn has_role(&self, role: Role, address: Address) -> bool;
+            pub fn migrate_to_roles(&mut self, address: Address, roles: Vec<Role>);
+            pub fn enable_multisig_ownership(&mut self, signers: Vec<Address>, threshold: u32);
+            pub fn propose_owner_action(&mut self, action: OwnerAction, action_args: OwnerActionArgs) -> u32;
+            pub fn approve_owner_action(&mut self, proposal_id: u32);
+            pub fn execute_owner_action(&mut self, proposal_id: u32);
+            pub fn get_signers(&self) -> Vec<Address>;
+            pub fn get_threshold(&self) -> u32;
+            pub fn get_owner_proposal(&self, proposal_id: u32) -> Option<OwnerProposal>;
+            pub fn pause(&mut self);
+            pub fn unpause(&mut self);
+            pub fn is_paused(&self) -> bool;
         }
 
         to self.refs {
@@ -83,11 +100,12 @@ impl SlashingVoterContract {
     }
 
     pub fn update_slashable_contracts(&mut self, slashable_contracts: Vec<Address>) {
-        self.access_control.ensure_whitelisted();
+        self.access_control.ensure_role(Role::Operator);
         self.slashable_contracts.set(slashable_contracts);
     }
 
     pub fn create_voting(&mut self, address_to_slash: Address, slash_ratio: u32, stake: Balance) {
+        self.access_control.ensure_not_paused();
         let creator = caller();
 
         // Both creator and address_to_slash must be VA.
@@ -130,6 +148,7 @@ impl SlashingVoterContract {
         choice: Choice,
         stake: Balance,
     ) {
+        self.access_control.ensure_not_paused();
         // Check if the caller is not a subject for the voting.
         let task = self.tasks.get(&voting_id).unwrap_or_revert();
         if caller() == task.subject {
@@ -140,6 +159,7 @@ impl SlashingVoterContract {
     }
 
     pub fn finish_voting(&mut self, voting_id: VotingId, voting_type: VotingType) -> VotingSummary {
+        self.access_control.ensure_not_paused();
         let summary = self.voting_engine.finish_voting(voting_id, voting_type);
         if summary.is_formal() && summary.result() == VotingResult::InFavor {
             self.slash(voting_id);
@@ -148,9 +168,34 @@ impl SlashingVoterContract {
     }
 
     pub fn slash_voter(&mut self, voter: Address) -> SlashedVotings {
-        self.access_control.ensure_whitelisted();
+        self.access_control.ensure_not_paused();
+        self.access_control.ensure_role(Role::Slasher);
         self.voting_engine.slash_voter(voter)
     }
+
+    /// Checks whether the `address` is the subject of an ongoing slashing voting.
+    pub fn is_subject_of_slashing(&self, address: Address) -> bool {
+        self.voting_engine
+            .get_active_votings()
+            .iter()
+            .filter_map(|voting_id| self.tasks.get(voting_id))
+            .any(|task| task.subject == address)
+    }
+
+    pub fn migrate_account(&mut self, from: Address, to: Address) -> Vec<VotingId> {
+        self.access_control.ensure_not_paused();
+        self.access_control.ensure_role(Role::Migrator);
+        // A pending slash follows the migrated account.
+        for voting_id in self.voting_engine.get_active_votings() {
+            if let Some(mut task) = self.tasks.get(&voting_id) {
+                if task.subject == from {
+                    task.subject = to;
+                    self.tasks.set(&voting_id, task);
+                }
+            }
+        }
+        self.voting_engine.migrate_voter(from, to)
+    }
 }
 
 impl SlashingVoterContract {
@@ -180,8 +225,11 @@ impl SlashingVoterContract {
         }
 
         // We're doing full slash.
-        // Burn VA token.
-        self.refs.va_token().burn(slash_task.subject);
+        // Burn VA token, unless the subject has already left the DAO.
+        let mut va_token = self.refs.va_token();
+        if !va_token.balance_of(&slash_task.subject).is_zero() {
+            va_token.burn(slash_task.subject);
+        }
 
         // Slash subject in all voter contracts.
         let mut cancelled_votings = vec![];
@@ -226,7 +274,7 @@ pub struct SlashedVotings {
 }
 
 #[odra::external_contract]
-trait Slashable {
+pub trait Slashable {
     fn slash_voter(&mut self, voter: Address) -> SlashedVotings;
 }
 
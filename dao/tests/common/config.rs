@@ -7,7 +7,6 @@ use crate::common::params::ReputationBalance;
 #[allow(dead_code)]
 pub struct UserConfiguration {
     account: Account,
-    whitelisted_in: Vec<Account>,
     is_kyced: bool,
     is_va: bool,
     reputation_balance: ReputationBalance,
@@ -18,7 +17,6 @@ pub struct UserConfiguration {
 #[allow(dead_code)]
 impl UserConfiguration {
     pub fn from_labeled_data(labels: &[String], data: &[String]) -> Self {
-        let mut whitelisted_in = vec![];
         let mut is_kyced = false;
         let mut is_va = false;
         let mut reputation_balance = ReputationBalance::zero();
@@ -28,19 +26,6 @@ impl UserConfiguration {
 
         for (idx, label) in labels.iter().enumerate() {
             match label.as_str() {
-                "whitelisted_in" => {
-                    let contracts_string = data.get(idx).map(|s| s.to_owned()).unwrap_or_default();
-                    let contracts_names = contracts_string.split(',');
-                    whitelisted_in = contracts_names
-                        .filter(|s| !s.is_empty())
-                        .map(|name| {
-                            helpers::parse::<Account>(
-                                Some(&name.to_owned()),
-                                "Couldn't parse contract",
-                            )
-                        })
-                        .collect();
-                }
                 "is_kyced" => {
                     is_kyced = helpers::parse_or_default(data.get(idx));
                 }
@@ -70,7 +55,6 @@ impl UserConfiguration {
 
         Self {
             account: account.expect("Invalid config - `user` label is missing"),
-            whitelisted_in,
             is_kyced,
             is_va,
             reputation_balance,
@@ -83,10 +67,6 @@ impl UserConfiguration {
         &self.account
     }
 
-    pub fn get_contracts_to_be_whitelisted_in(&self) -> &Vec<Account> {
-        &self.whitelisted_in
-    }
-
     pub fn is_kyced(&self) -> bool {
         self.is_kyced
     }
@@ -38,6 +38,9 @@ impl DaoWorld {
             Contract::ReputationVoter => self.reputation_voter.address(),
             Contract::BidEscrow => self.bid_escrow.address(),
             Contract::Onboarding => self.onboarding.address(),
+            Contract::InsurancePool => self.insurance_pool.address(),
+            Contract::Offboarding => self.offboarding.address(),
+            Contract::AccountRecovery => self.account_recovery.address(),
             Contract::CSPRRateProvider => self.rate_provider.address(),
         }
     }
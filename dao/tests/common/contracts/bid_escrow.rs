@@ -1,10 +1,12 @@
 use crate::common::params::Account;
 use crate::common::DaoWorld;
-use dao::bid_escrow::bid::Bid;
+use dao::bid_escrow::bid::{Bid, TEAM_SHARES_TOTAL};
+use dao::bid_escrow::job_offer::JobOfferParams;
 use dao::bid_escrow::types::{BidId, JobOfferId};
+use dao::utils::types::KycLevel;
 use dao::utils::Error;
 use odra::test_env;
-use odra::types::{Balance, BlockTime};
+use odra::types::{Address, Balance, BlockTime, Bytes};
 
 impl DaoWorld {
     pub fn get_job_offer_id(&self, job_poster: &Account) -> Option<&JobOfferId> {
@@ -43,6 +45,105 @@ impl DaoWorld {
         self.bids.insert((offer_id, bidder), bid_id);
     }
 
+    #[allow(clippy::too_many_arguments)]
+    pub fn post_team_bid(
+        &mut self,
+        offer_id: JobOfferId,
+        leader: Account,
+        timeframe: BlockTime,
+        budget: Balance,
+        stake: Balance,
+        cspr_stake: Option<Balance>,
+        team: Vec<(Address, u32)>,
+    ) {
+        let leader = self.get_address(&leader);
+
+        test_env::set_caller(leader);
+        match cspr_stake {
+            None => self
+                .bid_escrow
+                .submit_team_bid(offer_id, timeframe, budget, stake, None, team),
+            Some(cspr_stake) => self
+                .bid_escrow
+                .with_tokens(Self::leader_stake_share(leader, cspr_stake, &team))
+                .submit_team_bid(offer_id, timeframe, budget, stake, Some(cspr_stake), team),
+        };
+        let bid_id = self.bid_escrow.bids_count();
+        self.bids.insert((offer_id, leader), bid_id);
+    }
+
+    pub fn confirm_team_bid(&mut self, offer_id: JobOfferId, member: Account, leader: Account) {
+        let bid = self.get_bid(offer_id, leader).unwrap();
+        let member = self.get_address(&member);
+
+        test_env::set_caller(member);
+        match bid.cspr_stake_of(&member) {
+            None => self.bid_escrow.confirm_team_bid(bid.bid_id),
+            Some(cspr_stake) => self
+                .bid_escrow
+                .with_tokens(cspr_stake)
+                .confirm_team_bid(bid.bid_id),
+        }
+    }
+
+    /// The leader stakes what is left after the other members' shares are taken.
+    fn leader_stake_share(leader: Address, stake: Balance, team: &[(Address, u32)]) -> Balance {
+        team.iter()
+            .filter(|(member, _)| *member != leader)
+            .fold(stake, |left, (_, share)| {
+                left - stake * Balance::from(*share) / Balance::from(TEAM_SHARES_TOTAL)
+            })
+    }
+
+    pub fn post_sealed_bid(
+        &mut self,
+        offer_id: JobOfferId,
+        bidder: Account,
+        timeframe: BlockTime,
+        budget: Balance,
+        stake: Balance,
+        cspr_stake: Option<Balance>,
+    ) {
+        let bidder = self.get_address(&bidder);
+        let commitment =
+            Bid::commitment(bidder, offer_id, timeframe, budget, &Self::bid_salt(bidder));
+
+        test_env::set_caller(bidder);
+        match cspr_stake {
+            None => self
+                .bid_escrow
+                .submit_sealed_bid(offer_id, commitment, stake, false, None),
+            Some(cspr_stake) => self.bid_escrow.with_tokens(cspr_stake).submit_sealed_bid(
+                offer_id,
+                commitment,
+                stake,
+                false,
+                Some(cspr_stake),
+            ),
+        };
+        let bid_id = self.bid_escrow.bids_count();
+        self.bids.insert((offer_id, bidder), bid_id);
+    }
+
+    pub fn reveal_bid(
+        &mut self,
+        offer_id: JobOfferId,
+        bidder: Account,
+        timeframe: BlockTime,
+        budget: Balance,
+    ) {
+        let bid = self.get_bid(offer_id, bidder).expect("Bid not found.");
+        let bidder = self.get_address(&bidder);
+
+        test_env::set_caller(bidder);
+        self.bid_escrow
+            .reveal_bid(bid.bid_id, timeframe, budget, Self::bid_salt(bidder));
+    }
+
+    pub fn bid_salt(bidder: Address) -> Bytes {
+        Bytes::from(format!("salt of {:?}", bidder).into_bytes())
+    }
+
     pub fn get_bid(&self, offer_id: JobOfferId, poster: Account) -> Option<Bid> {
         let poster = self.get_address(&poster);
         let bid_id = self.bids.get(&(offer_id, poster))?;
@@ -68,16 +169,86 @@ impl DaoWorld {
         maximum_budget: Balance,
         dos_fee: Balance,
     ) -> Result<JobOfferId, Error> {
+        let params = JobOfferParams::new(timeframe, maximum_budget, dos_fee);
+        Ok(self.post_offer_with_params(poster, params))
+    }
+
+    pub fn post_sealed_offer(
+        &mut self,
+        poster: Account,
+        timeframe: BlockTime,
+        maximum_budget: Balance,
+        dos_fee: Balance,
+    ) -> JobOfferId {
+        let params = JobOfferParams {
+            sealed: true,
+            ..JobOfferParams::new(timeframe, maximum_budget, dos_fee)
+        };
+        self.post_offer_with_params(poster, params)
+    }
+
+    pub fn post_recurring_offer(
+        &mut self,
+        poster: Account,
+        timeframe: BlockTime,
+        maximum_budget: Balance,
+        dos_fee: Balance,
+        periods: u32,
+    ) -> JobOfferId {
+        let params = JobOfferParams {
+            periods: Some(periods),
+            ..JobOfferParams::new(timeframe, maximum_budget, dos_fee)
+        };
+        self.post_offer_with_params(poster, params)
+    }
+
+    pub fn post_offer_with_kyc_level(
+        &mut self,
+        poster: Account,
+        timeframe: BlockTime,
+        maximum_budget: Balance,
+        dos_fee: Balance,
+        required_kyc_level: KycLevel,
+    ) -> JobOfferId {
+        let params = JobOfferParams {
+            required_kyc_level: Some(required_kyc_level),
+            ..JobOfferParams::new(timeframe, maximum_budget, dos_fee)
+        };
+        self.post_offer_with_params(poster, params)
+    }
+
+    pub fn post_categorized_offer(
+        &mut self,
+        poster: Account,
+        timeframe: BlockTime,
+        maximum_budget: Balance,
+        dos_fee: Balance,
+        category: String,
+        skills: Vec<String>,
+    ) -> JobOfferId {
+        let params = JobOfferParams {
+            category: Some(category),
+            skills,
+            ..JobOfferParams::new(timeframe, maximum_budget, dos_fee)
+        };
+        self.post_offer_with_params(poster, params)
+    }
+
+    pub fn post_offer_with_params(
+        &mut self,
+        poster: Account,
+        params: JobOfferParams,
+    ) -> JobOfferId {
         let poster = self.get_address(&poster);
 
         test_env::set_caller(poster);
         self.bid_escrow
-            .with_tokens(dos_fee)
-            .post_job_offer(timeframe, maximum_budget, dos_fee);
+            .with_tokens(params.dos_fee)
+            .post_job_offer(params);
 
         let offer_id = self.bid_escrow.job_offers_count();
         self.offers.insert(poster, offer_id);
-        Ok(offer_id)
+        offer_id
     }
 
     pub fn pick_bid(&mut self, job_poster: Account, worker: Account) {
@@ -89,12 +260,24 @@ impl DaoWorld {
             .get(&(*job_offer_id, worker))
             .expect("Bid id not found.");
         let bid = self.bid_escrow.get_bid(*bid_id).expect("Bid not found.");
+        let job_offer = self
+            .bid_escrow
+            .get_job_offer(*job_offer_id)
+            .expect("Job Offer not found.");
+        // a recurring Job is paid upfront for all the periods
+        let payment = bid.proposed_payment * job_offer.periods;
         test_env::set_caller(job_poster);
-        self.bid_escrow.with_tokens(bid.proposed_payment).pick_bid(
-            *job_offer_id,
-            *bid_id,
-            bid.proposed_payment,
-        );
+        match job_offer.payment_token {
+            None => self
+                .bid_escrow
+                .with_tokens(payment)
+                .pick_bid(*job_offer_id, *bid_id, payment),
+            Some(_) => {
+                self.payment_token()
+                    .approve(self.bid_escrow.address(), &payment);
+                self.bid_escrow.pick_bid(*job_offer_id, *bid_id, payment);
+            }
+        }
     }
 
     pub fn pick_bid_failed(&mut self, job_poster: Account, worker: Account) {
@@ -1,14 +1,20 @@
 use std::collections::HashMap;
 
 use odra::types::address::OdraAddress;
-use odra::{test_env, types::Address};
+use odra::{
+    test_env,
+    types::{Address, Balance},
+};
 
 use crate::common::helpers::is_cspr_balance_close_enough;
 use crate::common::{
-    params::{Account, CsprBalance},
+    params::{Account, Contract, CsprBalance},
     DaoWorld,
 };
 
+// the number of accounts provided by the test env
+const ACCOUNTS_COUNT: usize = 20;
+
 #[derive(Default, Clone)]
 pub struct VirtualBalances {
     current: HashMap<Address, CsprBalance>,
@@ -67,4 +73,55 @@ impl DaoWorld {
             real_cspr_balance
         );
     }
+
+    // gets the amount of CSPR credited to the account and not claimed yet
+    pub fn get_pending_claims(&self, account: &Account, contract: &Contract) -> CsprBalance {
+        let address = self.get_address(account);
+        let pending_claims = match contract {
+            Contract::BidEscrow => self.bid_escrow.get_pending_claims(address),
+            Contract::Onboarding => self.onboarding.get_pending_claims(address),
+            Contract::InsurancePool => self.insurance_pool.get_pending_claims(address),
+            _ => panic!("{:?} does not credit CSPR", contract),
+        };
+        let amount = pending_claims
+            .iter()
+            .fold(Balance::zero(), |total, claim| total + claim.amount);
+        CsprBalance(amount)
+    }
+
+    pub fn claim(&mut self, account: &Account, contract: &Contract) {
+        test_env::set_caller(self.get_address(account));
+        match contract {
+            Contract::BidEscrow => self.bid_escrow.claim(),
+            Contract::Onboarding => self.onboarding.claim(),
+            Contract::InsurancePool => self.insurance_pool.claim(),
+            _ => panic!("{:?} does not credit CSPR", contract),
+        }
+    }
+
+    // claims all the CSPR and tokens credited to the test accounts, so the balances reflect the settlements
+    pub fn claim_all_pending(&mut self) {
+        let token = self.payment_token.as_ref().map(|token| *token.address());
+        for n in 0..ACCOUNTS_COUNT {
+            let address = test_env::get_account(n);
+            if let Some(token) = token {
+                if !self.bid_escrow.get_pending_token_claims(address).is_empty() {
+                    test_env::set_caller(address);
+                    self.bid_escrow.claim_token(token);
+                }
+            }
+            if !self.bid_escrow.get_pending_claims(address).is_empty() {
+                test_env::set_caller(address);
+                self.bid_escrow.claim();
+            }
+            if !self.onboarding.get_pending_claims(address).is_empty() {
+                test_env::set_caller(address);
+                self.onboarding.claim();
+            }
+            if !self.insurance_pool.get_pending_claims(address).is_empty() {
+                test_env::set_caller(address);
+                self.insurance_pool.claim();
+            }
+        }
+    }
 }
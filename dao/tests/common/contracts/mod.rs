@@ -2,6 +2,7 @@ mod account;
 mod bid_escrow;
 pub mod cspr;
 mod ownership;
+mod payment_token;
 mod rate_provider;
 mod reputation;
 pub mod tokens;
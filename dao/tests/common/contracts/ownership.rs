@@ -1,3 +1,4 @@
+use dao::modules::{OwnerAction, OwnerActionArgs, Role};
 use dao::utils::Error::NoProposedOwner;
 use odra::test_env;
 use odra::types::address::OdraAddress;
@@ -11,7 +12,21 @@ trait AccessControl {
     fn accept_new_owner(&mut self);
     fn is_whitelisted(&self, address: Address) -> bool;
     fn remove_from_whitelist(&mut self, address: Address);
-    fn add_to_whitelist(&mut self, address: Address);
+    fn grant_role(&mut self, role: Role, address: Address);
+    fn revoke_role(&mut self, role: Role, address: Address);
+    fn set_role_admin(&mut self, role: Role, admin_role: Role);
+    fn get_role_admin(&self, role: Role) -> Role;
+    fn has_role(&self, role: Role, address: Address) -> bool;
+    fn migrate_to_roles(&mut self, address: Address, roles: Vec<Role>);
+    fn enable_multisig_ownership(&mut self, signers: Vec<Address>, threshold: u32);
+    fn propose_owner_action(&mut self, action: OwnerAction, action_args: OwnerActionArgs) -> u32;
+    fn approve_owner_action(&mut self, proposal_id: u32);
+    fn execute_owner_action(&mut self, proposal_id: u32);
+    fn get_signers(&self) -> Vec<Address>;
+    fn get_threshold(&self) -> u32;
+    fn pause(&mut self);
+    fn unpause(&mut self);
+    fn is_paused(&self) -> bool;
 }
 
 #[odra::external_contract]
@@ -26,14 +41,6 @@ trait Ownable {
 
 #[allow(dead_code)]
 impl DaoWorld {
-    pub fn whitelist_account(&mut self, contract: &Account, caller: &Account, user: &Account) {
-        let user = self.get_address(user);
-        let contract = self.get_address(contract);
-
-        self.set_caller(caller);
-        AccessControlRef::at(&contract).add_to_whitelist(user);
-    }
-
     pub fn remove_from_whitelist(&mut self, contract: &Account, caller: &Account, user: &Account) {
         let user = self.get_address(user);
         let contract = self.get_address(contract);
@@ -89,4 +96,140 @@ impl DaoWorld {
         let contract = self.get_address(contract);
         AccessControlRef::at(&contract).is_whitelisted(account)
     }
+
+    pub fn grant_role(&mut self, contract: &Account, caller: &Account, role: Role, user: &Account) {
+        let user = self.get_address(user);
+        let contract = self.get_address(contract);
+
+        self.set_caller(caller);
+        AccessControlRef::at(&contract).grant_role(role, user);
+    }
+
+    pub fn revoke_role(
+        &mut self,
+        contract: &Account,
+        caller: &Account,
+        role: Role,
+        user: &Account,
+    ) {
+        let user = self.get_address(user);
+        let contract = self.get_address(contract);
+
+        self.set_caller(caller);
+        AccessControlRef::at(&contract).revoke_role(role, user);
+    }
+
+    pub fn set_role_admin(
+        &mut self,
+        contract: &Account,
+        caller: &Account,
+        role: Role,
+        admin_role: Role,
+    ) {
+        let contract = self.get_address(contract);
+
+        self.set_caller(caller);
+        AccessControlRef::at(&contract).set_role_admin(role, admin_role);
+    }
+
+    pub fn get_role_admin(&mut self, contract: &Account, role: Role) -> Role {
+        let contract = self.get_address(contract);
+        AccessControlRef::at(&contract).get_role_admin(role)
+    }
+
+    pub fn has_role(&mut self, contract: &Account, role: Role, account: &Account) -> bool {
+        let account = self.get_address(account);
+        let contract = self.get_address(contract);
+        AccessControlRef::at(&contract).has_role(role, account)
+    }
+
+    pub fn migrate_to_roles(
+        &mut self,
+        contract: &Account,
+        caller: &Account,
+        user: &Account,
+        roles: Vec<Role>,
+    ) {
+        let user = self.get_address(user);
+        let contract = self.get_address(contract);
+
+        self.set_caller(caller);
+        AccessControlRef::at(&contract).migrate_to_roles(user, roles);
+    }
+
+    pub fn enable_multisig_ownership(
+        &mut self,
+        contract: &Account,
+        caller: &Account,
+        signers: Vec<Account>,
+        threshold: u32,
+    ) {
+        let signers = signers
+            .iter()
+            .map(|signer| self.get_address(signer))
+            .collect();
+        let contract = self.get_address(contract);
+
+        self.set_caller(caller);
+        AccessControlRef::at(&contract).enable_multisig_ownership(signers, threshold);
+    }
+
+    pub fn propose_owner_action(
+        &mut self,
+        contract: &Account,
+        caller: &Account,
+        action: OwnerAction,
+        action_args: OwnerActionArgs,
+    ) -> u32 {
+        let contract = self.get_address(contract);
+
+        self.set_caller(caller);
+        AccessControlRef::at(&contract).propose_owner_action(action, action_args)
+    }
+
+    pub fn approve_owner_action(&mut self, contract: &Account, caller: &Account, proposal_id: u32) {
+        let contract = self.get_address(contract);
+
+        self.set_caller(caller);
+        AccessControlRef::at(&contract).approve_owner_action(proposal_id);
+    }
+
+    pub fn execute_owner_action(&mut self, contract: &Account, caller: &Account, proposal_id: u32) {
+        let contract = self.get_address(contract);
+
+        self.set_caller(caller);
+        AccessControlRef::at(&contract).execute_owner_action(proposal_id);
+    }
+
+    pub fn is_signer(&mut self, contract: &Account, account: &Account) -> bool {
+        let account = self.get_address(account);
+        let contract = self.get_address(contract);
+        AccessControlRef::at(&contract)
+            .get_signers()
+            .contains(&account)
+    }
+
+    pub fn get_threshold(&mut self, contract: &Account) -> u32 {
+        let contract = self.get_address(contract);
+        AccessControlRef::at(&contract).get_threshold()
+    }
+
+    pub fn pause(&mut self, contract: &Account, caller: &Account) {
+        let contract = self.get_address(contract);
+
+        self.set_caller(caller);
+        AccessControlRef::at(&contract).pause();
+    }
+
+    pub fn unpause(&mut self, contract: &Account, caller: &Account) {
+        let contract = self.get_address(contract);
+
+        self.set_caller(caller);
+        AccessControlRef::at(&contract).unpause();
+    }
+
+    pub fn is_paused(&mut self, contract: &Account) -> bool {
+        let contract = self.get_address(contract);
+        AccessControlRef::at(&contract).is_paused()
+    }
 }
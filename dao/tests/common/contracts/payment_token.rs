@@ -0,0 +1,82 @@
+use crate::common::helpers::is_cspr_balance_close_enough;
+use crate::common::params::{Account, CsprBalance};
+use crate::common::DaoWorld;
+use dao::bid_escrow::job_offer::JobOfferParams;
+use dao::bid_escrow::types::JobOfferId;
+use dao::utils_contracts::CSPRRateProviderContractDeployer;
+use odra::test_env;
+use odra::types::{Balance, BlockTime, U256};
+use odra_modules::erc20::{Erc20Deployer, Erc20Ref};
+
+impl DaoWorld {
+    pub fn whitelist_payment_token(&mut self, fiat_rate: Balance) {
+        test_env::set_caller(self.get_address(&Account::Owner));
+        let token = Erc20Deployer::init(
+            "USDT".to_string(),
+            "Tether".to_string(),
+            9,
+            &Some(U256::MAX),
+        );
+        let rate_provider = CSPRRateProviderContractDeployer::init(fiat_rate);
+        self.bid_escrow
+            .add_payment_token(*token.address(), *rate_provider.address());
+        self.payment_token = Some(token);
+    }
+
+    pub fn payment_token(&self) -> Erc20Ref {
+        self.payment_token
+            .clone()
+            .expect("Payment token not whitelisted.")
+    }
+
+    pub fn set_payment_token_balance(&mut self, account: &Account, amount: Balance) {
+        let account = self.get_address(account);
+        test_env::set_caller(self.get_address(&Account::Owner));
+        self.payment_token().transfer(&account, &amount);
+    }
+
+    pub fn assert_payment_token_balance(&self, account: &Account, expected_balance: CsprBalance) {
+        let address = self.get_address(account);
+        let real_balance = self.payment_token().balance_of(&address);
+
+        assert!(
+            is_cspr_balance_close_enough(expected_balance, real_balance),
+            "For account {:?} token balance should be {:?} but is {:?}",
+            account,
+            expected_balance,
+            real_balance
+        );
+    }
+
+    // gets the amount of payment tokens credited to the account and not claimed yet
+    pub fn get_pending_payment_token_claims(&self, account: &Account) -> CsprBalance {
+        let token = *self.payment_token().address();
+        let amount = self
+            .bid_escrow
+            .get_pending_token_claims(self.get_address(account))
+            .iter()
+            .filter(|claim| claim.token == token)
+            .fold(Balance::zero(), |total, claim| total + claim.amount);
+        CsprBalance(amount)
+    }
+
+    pub fn claim_payment_token(&mut self, account: &Account) {
+        let token = *self.payment_token().address();
+        test_env::set_caller(self.get_address(account));
+        self.bid_escrow.claim_token(token);
+    }
+
+    pub fn post_offer_with_token(
+        &mut self,
+        poster: Account,
+        timeframe: BlockTime,
+        maximum_budget: Balance,
+        dos_fee: Balance,
+    ) -> JobOfferId {
+        let params = JobOfferParams {
+            payment_token: Some(*self.payment_token().address()),
+            ..JobOfferParams::new(timeframe, maximum_budget, dos_fee)
+        };
+        self.post_offer_with_params(poster, params)
+    }
+}
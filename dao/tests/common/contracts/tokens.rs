@@ -1,7 +1,10 @@
-use dao::core_contracts::TokenId as DaoTokenId;
+use dao::{
+    core_contracts::{TokenId as DaoTokenId, TokenMetadata},
+    utils::types::{DocumentHash, KycLevel},
+};
 use odra::{
     test_env,
-    types::{Address, Balance, U256},
+    types::{Address, Balance, BlockTime, Bytes, OdraType, U256},
 };
 
 use crate::common::{
@@ -21,6 +24,18 @@ pub trait NftToken {
     fn mint(&mut self, to: Address);
     fn burn(&mut self, owner: Address);
     fn token_id(&self, address: Address) -> Option<DaoTokenId>;
+    fn token_metadata(&self, token_id: DaoTokenId) -> TokenMetadata;
+    fn token_uri(&self, token_id: DaoTokenId) -> String;
+    fn metadata(&self, token_id: DaoTokenId) -> String;
+    fn collection_name(&self) -> String;
+    fn collection_symbol(&self) -> String;
+    fn ownership_mode(&self) -> u8;
+    fn burn_mode(&self) -> u8;
+    fn minting_mode(&self) -> u8;
+    fn nft_metadata_kind(&self) -> u8;
+    fn json_schema(&self) -> String;
+    fn metadata_mutability(&self) -> u8;
+    fn identifier_mode(&self) -> u8;
 }
 
 impl DaoWorld {
@@ -66,6 +81,105 @@ impl DaoWorld {
         !NftTokenRef::at(&contract).balance_of(address).is_zero()
     }
 
+    pub fn is_kycd(&self, account: &Account) -> bool {
+        let address = self.get_address(account);
+        self.kyc_token.is_kycd(address)
+    }
+
+    pub fn kyc_validity(&self, account: &Account) -> (BlockTime, Option<BlockTime>) {
+        let address = self.get_address(account);
+        let issued_at = self
+            .kyc_token
+            .issued_at(address)
+            .expect("Account should own a KYC token");
+        (issued_at, self.kyc_token.expires_at(address))
+    }
+
+    pub fn kyc_level(&self, account: &Account) -> KycLevel {
+        let address = self.get_address(account);
+        self.kyc_token.kyc_level(address)
+    }
+
+    pub fn upgrade_kyc_level(&mut self, account: &Account, level: KycLevel) {
+        let address = self.get_address(account);
+        self.set_caller(&Account::Owner);
+        self.kyc_token.upgrade_level(address, level);
+    }
+
+    // collects the expiring KYCs page by page
+    pub fn get_expiring_kycs(&self, within: BlockTime) -> Vec<Address> {
+        const PAGE_SIZE: u32 = 2;
+        let holders_count = self.kyc_token.get_holders_count();
+        (0..holders_count)
+            .step_by(PAGE_SIZE as usize)
+            .flat_map(|offset| self.kyc_token.get_expiring_kycs(within, offset, PAGE_SIZE))
+            .collect()
+    }
+
+    pub fn is_kyc_provider(&self, account: &Account) -> bool {
+        let address = self.get_address(account);
+        self.kyc_token
+            .is_kyc_provider(test_env::public_key(&address))
+    }
+
+    pub fn attest_kyc(
+        &mut self,
+        sender: &Account,
+        subject: &Account,
+        provider: &Account,
+        signer: &Account,
+        expires_at: BlockTime,
+    ) {
+        let subject = self.get_address(subject);
+        let provider = self.get_address(provider);
+        let signer = self.get_address(signer);
+        let document_hash = DocumentHash::default();
+
+        let message =
+            self.kyc_voter
+                .get_attestation_message(subject, document_hash.clone(), expires_at);
+        let signature = test_env::sign_message(&message, &signer);
+
+        self.set_caller(sender);
+        self.kyc_voter.mint_with_attestation(
+            subject,
+            document_hash,
+            expires_at,
+            test_env::public_key(&provider),
+            signature,
+        );
+    }
+
+    // submits an attestation signed over the message built for the given chain
+    pub fn attest_kyc_on_chain(
+        &mut self,
+        sender: &Account,
+        subject: &Account,
+        provider: &Account,
+        chain_name: &str,
+        expires_at: BlockTime,
+    ) {
+        let subject = self.get_address(subject);
+        let provider = self.get_address(provider);
+        let document_hash = DocumentHash::default();
+
+        let mut message = String::from(chain_name).serialize().unwrap();
+        message.extend(self.kyc_voter.address().serialize().unwrap());
+        message.extend(subject.serialize().unwrap());
+        message.extend(document_hash.serialize().unwrap());
+        message.extend(expires_at.serialize().unwrap());
+        let signature = test_env::sign_message(&Bytes::from(message), &provider);
+
+        self.set_caller(sender);
+        self.kyc_voter.mint_with_attestation(
+            subject,
+            document_hash,
+            expires_at,
+            test_env::public_key(&provider),
+            signature,
+        );
+    }
+
     pub fn get_nft_token_id(&self, contract: Contract, holder: &Account) -> TokenId {
         let contract = self.contract_address(contract);
         let holder = self.get_address(holder);
@@ -75,6 +189,40 @@ impl DaoWorld {
         TokenId(id)
     }
 
+    pub fn nft_metadata(&self, contract: Contract, holder: &Account) -> TokenMetadata {
+        let token_id = self.get_nft_token_id(contract, holder);
+        let contract = self.contract_address(contract);
+        NftTokenRef::at(&contract).token_metadata(*token_id)
+    }
+
+    pub fn nft_token_uri(&self, contract: Contract, holder: &Account) -> String {
+        let token_id = self.get_nft_token_id(contract, holder);
+        let contract = self.contract_address(contract);
+        NftTokenRef::at(&contract).token_uri(*token_id)
+    }
+
+    pub fn nft_cep78_metadata(&self, contract: Contract, holder: &Account) -> String {
+        let token_id = self.get_nft_token_id(contract, holder);
+        let contract = self.contract_address(contract);
+        NftTokenRef::at(&contract).metadata(*token_id)
+    }
+
+    pub fn nft_cep78_named_key(&self, contract: Contract, key: &str) -> String {
+        let contract = NftTokenRef::at(&self.contract_address(contract));
+        match key {
+            "collection_name" => contract.collection_name(),
+            "collection_symbol" => contract.collection_symbol(),
+            "ownership_mode" => contract.ownership_mode().to_string(),
+            "burn_mode" => contract.burn_mode().to_string(),
+            "minting_mode" => contract.minting_mode().to_string(),
+            "nft_metadata_kind" => contract.nft_metadata_kind().to_string(),
+            "metadata_mutability" => contract.metadata_mutability().to_string(),
+            "identifier_mode" => contract.identifier_mode().to_string(),
+            "json_schema" => contract.json_schema(),
+            unknown => panic!("Unknown CEP-78 named key {}", unknown),
+        }
+    }
+
     fn contract_address(&self, contract: Contract) -> Address {
         let account = Account::Contract(contract);
         self.get_address(&account)
@@ -1,6 +1,8 @@
-use odra::types::{Bytes, OdraType};
+use dao::configuration::{FeeRecipient, FeeSplit};
+use dao::utils::consts::BID_ESCROW_FEE_SPLIT;
+use odra::types::{Balance, Bytes, OdraType};
 
-use crate::common::DaoWorld;
+use crate::common::{helpers, params::Account, DaoWorld};
 
 impl DaoWorld {
     // sets variable value
@@ -13,4 +15,23 @@ impl DaoWorld {
         let bytes = self.variable_repository.get(name.to_string()).unwrap();
         T::deserialize(bytes.as_slice())
     }
+
+    // converts a string value to bytes, resolving the accounts of a fee split given as `Account:ratio,...`
+    pub fn variable_to_bytes(&self, value: &str, key: &str) -> Bytes {
+        if key != BID_ESCROW_FEE_SPLIT {
+            return helpers::value_to_bytes(value, key);
+        }
+
+        let recipients = value
+            .split(',')
+            .map(|recipient| {
+                let (account, ratio) = recipient.split_once(':').expect("Expected Account:ratio");
+                FeeRecipient {
+                    address: self.get_address(&account.parse::<Account>().unwrap()),
+                    ratio: Balance::from_dec_str(ratio).unwrap(),
+                }
+            })
+            .collect();
+        Bytes::from(FeeSplit { recipients }.serialize().unwrap())
+    }
 }
@@ -1,6 +1,6 @@
 use dao::voting_contracts::SlashedVotings;
 use dao::{
-    utils::{types::DocumentHash, Error},
+    utils::{consts::BASIC_KYC_LEVEL, types::DocumentHash, Error},
     voting::{
         ballot::{Ballot as DaoBallot, Choice},
         types::VotingId,
@@ -54,12 +54,26 @@ impl DaoWorld {
         self.set_caller(&creator);
 
         match builder::build(self, voting) {
-            builder::VotingSetup::Admin(contract_to_update, action, subject) => self
+            builder::VotingSetup::Admin(contract_to_update, action, subject, role) => self
                 .admin
-                .create_voting(contract_to_update, action, subject, *stake),
-            builder::VotingSetup::Kyc(subject, document_hash) => {
-                self.kyc_voter.create_voting(subject, document_hash, *stake)
+                .create_voting(contract_to_update, action, subject, role, *stake),
+            builder::VotingSetup::Kyc(subject, document_hash, level) => {
+                self.kyc_voter.create_voting(
+                    subject,
+                    document_hash,
+                    level.unwrap_or(BASIC_KYC_LEVEL),
+                    *stake,
+                )
             }
+            builder::VotingSetup::KycRenewal(subject, document_hash) => self
+                .kyc_voter
+                .create_renewal_voting(subject, document_hash, *stake),
+            builder::VotingSetup::KycRevocation(subject, document_hash) => self
+                .kyc_voter
+                .create_revocation_voting(subject, document_hash, *stake),
+            builder::VotingSetup::KycProvider(provider, action) => self
+                .kyc_voter
+                .create_provider_voting(test_env::public_key(&provider), action, *stake),
             builder::VotingSetup::Slasher(address_to_slash, slash_ratio) => self
                 .slashing_voter
                 .create_voting(address_to_slash, slash_ratio, *stake),
@@ -87,6 +101,9 @@ impl DaoWorld {
                     *stake,
                 )
             }
+            builder::VotingSetup::AccountRecovery(old_account, new_account) => self
+                .account_recovery
+                .create_voting(old_account, new_account, *stake),
         }
     }
 
@@ -102,7 +119,10 @@ impl DaoWorld {
 
         self.set_caller(&creator);
         match contract {
-            Contract::KycVoter => self.kyc_voter.create_voting(alice, document_hash, *stake),
+            Contract::KycVoter => {
+                self.kyc_voter
+                    .create_voting(alice, document_hash, BASIC_KYC_LEVEL, *stake)
+            }
             Contract::RepoVoter => self.repo_voter.create_voting(
                 *self.variable_repository.address(),
                 String::from("key"),
@@ -119,8 +139,9 @@ impl DaoWorld {
             ),
             Contract::Admin => self.admin.create_voting(
                 alice,
-                dao::voting_contracts::AdminAction::AddToWhitelist,
-                alice,
+                dao::voting_contracts::AdminAction::RemoveFromWhitelist,
+                Some(alice),
+                None,
                 *stake,
             ),
             Contract::SlashingVoter => self.slashing_voter.create_voting(va2, 100, *stake),
@@ -176,6 +197,16 @@ impl DaoWorld {
         VoterRef::at(&contract).cancel_finished_voting(voting_id);
     }
 
+    pub fn veto_recovery(&mut self, caller: &Account, voting_id: u32) {
+        self.set_caller(caller);
+        self.account_recovery.veto_recovery(voting_id);
+    }
+
+    pub fn execute_recovery(&mut self, caller: &Account, voting_id: u32) {
+        self.set_caller(caller);
+        self.account_recovery.execute_recovery(voting_id);
+    }
+
     pub fn voting_exists(
         &self,
         contract: &Account,
@@ -1,10 +1,13 @@
-use dao::voting_contracts::ReputationAction;
-use dao::{utils::types::DocumentHash, voting_contracts::AdminAction};
+use dao::voting_contracts::{KycProviderAction, ReputationAction};
+use dao::{
+    utils::types::{DocumentHash, KycLevel},
+    voting_contracts::AdminAction,
+};
 use odra::types::{Address, BlockTime, Bytes};
 
-use crate::common::params::ReputationBalance;
+use crate::common::params::{ReputationBalance, Role};
 use crate::common::{
-    helpers::{to_milliseconds, value_to_bytes},
+    helpers::to_milliseconds,
     params::{voting::Voting, Account, Contract},
     DaoWorld,
 };
@@ -17,22 +20,45 @@ pub fn build(world: &DaoWorld, voting: Voting) -> VotingSetup {
 
             let action = voting.get_parsed_arg::<String>(1);
             let action = match action.as_str() {
-                "add_to_whitelist" => AdminAction::AddToWhitelist,
                 "remove_from_whitelist" => AdminAction::RemoveFromWhitelist,
                 "propose_new_owner" => AdminAction::ProposeNewOwner,
+                "pause" => AdminAction::Pause,
+                "unpause" => AdminAction::Unpause,
+                "grant_role" => AdminAction::GrantRole,
+                "revoke_role" => AdminAction::RevokeRole,
                 unknown => panic!("{:?} is not a valid action", unknown),
             };
 
-            let address = voting.get_parsed_arg::<Account>(2);
-            let address = world.get_address(&address);
+            let address = voting
+                .get_parsed_arg_or_none::<Account>(2)
+                .map(|address| world.get_address(&address));
+
+            let role = voting.get_parsed_arg_or_none::<Role>(3).map(|role| *role);
 
-            VotingSetup::Admin(contract_to_update, action, address)
+            VotingSetup::Admin(contract_to_update, action, address, role)
         }
         Contract::KycVoter => {
             let subject_address = voting.get_parsed_arg::<Account>(0);
             let subject_address = world.get_address(&subject_address);
 
-            VotingSetup::Kyc(subject_address, DocumentHash::default())
+            match voting.get_parsed_arg_or_none::<String>(1).as_deref() {
+                Some("renew") => VotingSetup::KycRenewal(subject_address, DocumentHash::default()),
+                Some("revoke") => {
+                    VotingSetup::KycRevocation(subject_address, DocumentHash::default())
+                }
+                Some("add_provider") => {
+                    VotingSetup::KycProvider(subject_address, KycProviderAction::Add)
+                }
+                Some("remove_provider") => {
+                    VotingSetup::KycProvider(subject_address, KycProviderAction::Remove)
+                }
+                Some(level) => VotingSetup::Kyc(
+                    subject_address,
+                    DocumentHash::default(),
+                    Some(level.parse().expect("KYC level expected")),
+                ),
+                None => VotingSetup::Kyc(subject_address, DocumentHash::default(), None),
+            }
         }
         Contract::SlashingVoter => {
             let address_to_slash = voting.get_parsed_arg::<Account>(0);
@@ -49,7 +75,7 @@ pub fn build(world: &DaoWorld, voting: Voting) -> VotingSetup {
             let key = voting.get_parsed_arg::<String>(1);
 
             let value = voting.get_parsed_arg::<String>(2);
-            let value = value_to_bytes(&value, &key);
+            let value = world.variable_to_bytes(&value, &key);
 
             let activation_time = voting.get_parsed_arg_or_none::<String>(3).map(|s| {
                 let values = s.split(' ').collect::<Vec<_>>();
@@ -76,16 +102,34 @@ pub fn build(world: &DaoWorld, voting: Voting) -> VotingSetup {
 
             VotingSetup::Reputation(recipient_address, action, amount, Default::default())
         }
+        Contract::AccountRecovery => {
+            let old_account = voting.get_parsed_arg::<Account>(0);
+            let old_account = world.get_address(&old_account);
+
+            let new_account = voting.get_parsed_arg::<Account>(1);
+            let new_account = world.get_address(&new_account);
+
+            VotingSetup::AccountRecovery(old_account, new_account)
+        }
         contract => panic!("{:?} is not a voting contract", contract),
     }
 }
 
 #[derive(Debug)]
 pub enum VotingSetup {
-    Admin(Address, AdminAction, Address),
-    Kyc(Address, DocumentHash),
+    Admin(
+        Address,
+        AdminAction,
+        Option<Address>,
+        Option<dao::modules::Role>,
+    ),
+    Kyc(Address, DocumentHash, Option<KycLevel>),
+    KycRenewal(Address, DocumentHash),
+    KycRevocation(Address, DocumentHash),
+    KycProvider(Address, KycProviderAction),
     Slasher(Address, u32),
     Repository(Address, String, Bytes, Option<BlockTime>),
     Simple(DocumentHash),
     Reputation(Address, ReputationAction, ReputationBalance, DocumentHash),
+    AccountRecovery(Address, Address),
 }
@@ -1,6 +1,11 @@
 use dao::bid_escrow::contract::{BidEscrowContractDeployer, BidEscrowContractRef};
 use dao::bid_escrow::types::{BidId, JobOfferId};
-use dao::voting_contracts::{OnboardingRequestContractDeployer, OnboardingRequestContractRef};
+use dao::modules::Role;
+use dao::voting_contracts::{
+    AccountRecoveryVoterContractDeployer, AccountRecoveryVoterContractRef,
+    InsurancePoolContractDeployer, InsurancePoolContractRef, OffboardingRequestContractDeployer,
+    OffboardingRequestContractRef, OnboardingRequestContractDeployer, OnboardingRequestContractRef,
+};
 use dao::{
     core_contracts::{
         KycNftContractDeployer, KycNftContractRef, ReputationContractDeployer,
@@ -19,6 +24,7 @@ use dao::{
 };
 use odra::test_env;
 use odra::types::Address;
+use odra_modules::erc20::Erc20Ref;
 use std::collections::HashMap;
 use std::fmt::{Debug, Formatter};
 use std::fs::OpenOptions;
@@ -28,11 +34,13 @@ use super::{contracts::cspr::VirtualBalances, params::Account};
 // 1CSPR ~= 0.02924$
 const DEFAULT_CSPR_USD_RATE: u64 = 34_000_000_000;
 
-macro_rules! whitelist {
-    ( $( $source:ident => [$( $target:ident ),+] ),+ ) => {
+macro_rules! grant_roles {
+    ( $( $source:ident => { $( $role:ident => [$( $target:ident ),+] ),+ } ),+ ) => {
         $(
             $(
-                $source.add_to_whitelist(*$target.address());
+                $(
+                    $source.grant_role(Role::$role, *$target.address());
+                )+
             )+
         )+
     }
@@ -54,8 +62,12 @@ pub struct DaoWorld {
     pub slashing_voter: SlashingVoterContractRef,
     pub bid_escrow: BidEscrowContractRef,
     pub onboarding: OnboardingRequestContractRef,
+    pub insurance_pool: InsurancePoolContractRef,
+    pub offboarding: OffboardingRequestContractRef,
+    pub account_recovery: AccountRecoveryVoterContractRef,
     pub bids: HashMap<(u32, Address), BidId>,
     pub offers: HashMap<Address, JobOfferId>,
+    pub payment_token: Option<Erc20Ref>,
 }
 
 impl DaoWorld {
@@ -107,6 +119,7 @@ impl Default for DaoWorld {
             *reputation_token.address(),
             *va_token.address(),
             *kyc_token.address(),
+            String::from("casper-test"),
         );
         let mut repo_voter = RepoVoterContractDeployer::init(
             *variable_repository.address(),
@@ -135,24 +148,76 @@ impl Default for DaoWorld {
             *kyc_token.address(),
             *va_token.address(),
         );
+        let mut insurance_pool = InsurancePoolContractDeployer::init(
+            *variable_repository.address(),
+            *reputation_token.address(),
+            *va_token.address(),
+            *bid_escrow.address(),
+        );
+        let mut offboarding = OffboardingRequestContractDeployer::init(
+            *variable_repository.address(),
+            *reputation_token.address(),
+            *va_token.address(),
+            *slashing_voter.address(),
+        );
+        let mut account_recovery = AccountRecoveryVoterContractDeployer::init(
+            *variable_repository.address(),
+            *reputation_token.address(),
+            *va_token.address(),
+            *kyc_token.address(),
+        );
 
-        whitelist!(
-            ids => [admin, kyc_voter, slashing_voter, repo_voter, reputation_voter, simple_voter, bid_escrow, onboarding],
-            variable_repository => [repo_voter],
-            reputation_token => [admin, repo_voter, reputation_voter, kyc_voter, slashing_voter, simple_voter, bid_escrow, onboarding],
-            va_token => [slashing_voter, bid_escrow, onboarding],
-            kyc_token => [kyc_voter],
-            admin => [slashing_voter],
-            kyc_voter => [slashing_voter],
-            onboarding => [slashing_voter],
-            repo_voter => [slashing_voter, simple_voter],
-            reputation_voter => [slashing_voter],
-            simple_voter => [slashing_voter],
-            slashing_voter => [slashing_voter],
-            bid_escrow => [slashing_voter]
+        grant_roles!(
+            ids => {
+                VotingCreator => [admin, kyc_voter, slashing_voter, repo_voter, reputation_voter, simple_voter, bid_escrow, onboarding, insurance_pool, account_recovery]
+            },
+            variable_repository => {
+                VariableEditor => [repo_voter]
+            },
+            reputation_token => {
+                Minter => [admin, repo_voter, reputation_voter, kyc_voter, slashing_voter, simple_voter, bid_escrow, onboarding, insurance_pool, offboarding, account_recovery],
+                Burner => [admin, repo_voter, reputation_voter, kyc_voter, slashing_voter, simple_voter, bid_escrow, onboarding, insurance_pool, offboarding, account_recovery],
+                Staker => [admin, repo_voter, reputation_voter, kyc_voter, slashing_voter, simple_voter, bid_escrow, onboarding, insurance_pool, offboarding, account_recovery],
+                Migrator => [account_recovery]
+            },
+            va_token => {
+                Minter => [bid_escrow, onboarding],
+                Burner => [slashing_voter, offboarding],
+                Migrator => [account_recovery]
+            },
+            kyc_token => {
+                Minter => [kyc_voter],
+                Burner => [kyc_voter],
+                Operator => [kyc_voter],
+                Migrator => [account_recovery]
+            },
+            admin => { Slasher => [slashing_voter, offboarding], Migrator => [account_recovery] },
+            kyc_voter => { Slasher => [slashing_voter, offboarding], Migrator => [account_recovery] },
+            onboarding => { Slasher => [slashing_voter, offboarding, kyc_voter], Migrator => [account_recovery] },
+            repo_voter => { Slasher => [slashing_voter, offboarding], Migrator => [account_recovery] },
+            reputation_voter => { Slasher => [slashing_voter, offboarding], Migrator => [account_recovery] },
+            simple_voter => { Slasher => [slashing_voter, offboarding], Migrator => [account_recovery] },
+            slashing_voter => { Slasher => [slashing_voter, offboarding], Migrator => [account_recovery] },
+            bid_escrow => { Slasher => [slashing_voter, offboarding, kyc_voter], Migrator => [account_recovery] },
+            insurance_pool => { Slasher => [slashing_voter, offboarding], Migrator => [account_recovery] },
+            account_recovery => { Slasher => [slashing_voter, offboarding] }
         );
 
-        let slashable_contracts: Vec<Address> = vec![
+        // The deployer mints the initial tokens and configures the contracts.
+        for role in [Role::Minter, Role::Burner] {
+            reputation_token.grant_role(role, default_account);
+            va_token.grant_role(role, default_account);
+            kyc_token.grant_role(role, default_account);
+        }
+        variable_repository.grant_role(Role::VariableEditor, default_account);
+        bid_escrow.grant_role(Role::Operator, default_account);
+        kyc_voter.grant_role(Role::Operator, default_account);
+        slashing_voter.grant_role(Role::Operator, default_account);
+        offboarding.grant_role(Role::Operator, default_account);
+        account_recovery.grant_role(Role::Operator, default_account);
+        bid_escrow.set_insurance_pool(*insurance_pool.address());
+
+        let migratable_contracts: Vec<Address> = vec![
             admin.address(),
             kyc_voter.address(),
             onboarding.address(),
@@ -161,13 +226,21 @@ impl Default for DaoWorld {
             simple_voter.address(),
             slashing_voter.address(),
             bid_escrow.address(),
+            insurance_pool.address(),
         ]
         .into_iter()
         .cloned()
         .collect();
 
+        let mut slashable_contracts = migratable_contracts.clone();
+        slashable_contracts.push(*account_recovery.address());
+
         // WON'T DO: Maybe in variable repo?
-        slashing_voter.update_slashable_contracts(slashable_contracts);
+        slashing_voter.update_slashable_contracts(slashable_contracts.clone());
+        offboarding.update_slashable_contracts(slashable_contracts);
+        account_recovery.update_migratable_contracts(migratable_contracts);
+        kyc_voter
+            .update_kyc_revocation_contracts(vec![*bid_escrow.address(), *onboarding.address()]);
 
         Self {
             virtual_balances: Default::default(),
@@ -184,8 +257,12 @@ impl Default for DaoWorld {
             slashing_voter,
             bid_escrow,
             onboarding,
+            insurance_pool,
+            offboarding,
+            account_recovery,
             bids: Default::default(),
             offers: Default::default(),
+            payment_token: None,
         }
     }
 }
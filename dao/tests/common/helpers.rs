@@ -33,10 +33,19 @@ pub fn value_to_bytes(value: &str, key: &str) -> Bytes {
             | INFORMAL_QUORUM_RATIO
             | FORMAL_QUORUM_RATIO
             | DEFAULT_REPUTATION_SLASH
-            | VOTING_CLEARNESS_DELTA => {
+            | VOTING_CLEARNESS_DELTA
+            | MAX_DEADLINE_EXTENSIONS
+            | UNREVEALED_BID_FORFEIT_RATIO
+            | KEEPER_REWARD_RATIO
+            | INSURANCE_POOL_RATIO
+            | VA_PROBATION_STAKE_LIMIT => {
                 let value = odra::types::Balance::from_dec_str(value).unwrap();
                 Bytes::from(value.serialize().unwrap())
             }
+            JOB_CATEGORIES | SKILL_TAGS => {
+                let value: Vec<String> = parse_list(value);
+                Bytes::from(value.serialize().unwrap())
+            }
             POST_JOB_DOS_FEE => {
                 let value = odra::types::Balance::from_dec_str(value).unwrap();
                 Bytes::from(value.serialize().unwrap())
@@ -49,6 +58,17 @@ pub fn value_to_bytes(value: &str, key: &str) -> Bytes {
     }
 }
 
+/// Parses a comma-separated list of values from Gherkin scenario, `none` stands for an empty list.
+pub fn parse_list(value: &str) -> Vec<String> {
+    match value {
+        "none" => vec![],
+        _ => value
+            .split(',')
+            .map(|item| item.trim().to_string())
+            .collect(),
+    }
+}
+
 // TODO: Refactor bo be more generic, or move to common?
 
 pub fn is_cspr_balance_close_enough<A: Into<CsprBalance>, B: Into<CsprBalance>>(
@@ -17,7 +17,7 @@ macro_rules! impl_balance {
             Ord,
             Parameter,
         )]
-        #[param(name = $name, regex = r"\d+")]
+        #[param(name = $name, regex = r"\d+(?:\.\d+)?")]
         pub struct $type(pub OdraBalance);
 
         impl FromStr for $type {
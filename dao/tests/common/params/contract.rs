@@ -17,6 +17,9 @@ pub enum Contract {
     ReputationVoter,
     BidEscrow,
     Onboarding,
+    InsurancePool,
+    Offboarding,
+    AccountRecovery,
     CSPRRateProvider,
 }
 
@@ -37,6 +40,9 @@ impl FromStr for Contract {
             "ReputationVoter" => Self::ReputationVoter,
             "BidEscrow" => Self::BidEscrow,
             "Onboarding" => Self::Onboarding,
+            "InsurancePool" => Self::InsurancePool,
+            "Offboarding" => Self::Offboarding,
+            "AccountRecovery" => Self::AccountRecovery,
             "CSPRRateProvider" => Self::CSPRRateProvider,
             invalid => return Err(format!("Unknown contract {}", invalid)),
         };
@@ -25,6 +25,19 @@ impl FromStr for Error {
             "NotAnOwner" => dao::utils::Error::NotAnOwner,
             "OwnerIsNotInitialized" => dao::utils::Error::OwnerIsNotInitialized,
             "NotWhitelisted" => dao::utils::Error::NotWhitelisted,
+            "MissingRole" => dao::utils::Error::MissingRole,
+            "NotASigner" => dao::utils::Error::NotASigner,
+            "InvalidThreshold" => dao::utils::Error::InvalidThreshold,
+            "SignerAlreadyExists" => dao::utils::Error::SignerAlreadyExists,
+            "OwnerActionDoesNotExist" => dao::utils::Error::OwnerActionDoesNotExist,
+            "OwnerActionAlreadyApproved" => dao::utils::Error::OwnerActionAlreadyApproved,
+            "OwnerActionAlreadyExecuted" => dao::utils::Error::OwnerActionAlreadyExecuted,
+            "OwnerActionThresholdNotReached" => dao::utils::Error::OwnerActionThresholdNotReached,
+            "InvalidOwnerActionArgs" => dao::utils::Error::InvalidOwnerActionArgs,
+            "ContractPaused" => dao::utils::Error::ContractPaused,
+            "ContractNotPaused" => dao::utils::Error::ContractNotPaused,
+            "NothingToClaim" => dao::utils::Error::NothingToClaim,
+            "InvalidAdminActionArgs" => dao::utils::Error::InvalidAdminActionArgs,
             "TotalSupplyOverflow" => dao::utils::Error::TotalSupplyOverflow,
             "ValueNotAvailable" => dao::utils::Error::ValueNotAvailable,
             "ActivationTimeInPast" => dao::utils::Error::ActivationTimeInPast,
@@ -37,6 +50,7 @@ impl FromStr for Error {
             "NoSuchMethod" => dao::utils::Error::NoSuchMethod,
             "VariableValueNotSet" => dao::utils::Error::VariableValueNotSet,
             "BytesDeserializationError" => dao::utils::Error::BytesDeserializationError,
+            "InvalidFeeSplit" => dao::utils::Error::InvalidFeeSplit,
             "TokenDoesNotExist" => dao::utils::Error::TokenDoesNotExist,
             "TokenAlreadyExists" => dao::utils::Error::TokenAlreadyExists,
             "ApprovalToCurrentOwner" => dao::utils::Error::ApprovalToCurrentOwner,
@@ -74,6 +88,19 @@ impl FromStr for Error {
             "KycAlreadyInProgress" => dao::utils::Error::KycAlreadyInProgress,
             "UserKycedAlready" => dao::utils::Error::UserKycedAlready,
             "UnexpectedKycError" => dao::utils::Error::UnexpectedKycError,
+            "SponsorshipNotFound" => dao::utils::Error::SponsorshipNotFound,
+            "SponsorshipProbationNotEnded" => dao::utils::Error::SponsorshipProbationNotEnded,
+            "VaOnProbation" => dao::utils::Error::VaOnProbation,
+            "ProbationStakeLimitExceeded" => dao::utils::Error::ProbationStakeLimitExceeded,
+            "ProbationaryVaCannotBeSoleApprover" => {
+                dao::utils::Error::ProbationaryVaCannotBeSoleApprover
+            }
+            "KycProviderNotRegistered" => dao::utils::Error::KycProviderNotRegistered,
+            "KycAttestationExpired" => dao::utils::Error::KycAttestationExpired,
+            "InvalidKycAttestation" => dao::utils::Error::InvalidKycAttestation,
+            "KycAttestationAlreadyUsed" => dao::utils::Error::KycAttestationAlreadyUsed,
+            "KycLevelTooLow" => dao::utils::Error::KycLevelTooLow,
+            "InvalidKycLevel" => dao::utils::Error::InvalidKycLevel,
             "MappingIndexDoesNotExist" => dao::utils::Error::MappingIndexDoesNotExist,
             "BallotDoesNotExist" => dao::utils::Error::BallotDoesNotExist,
             "VoterDoesNotExist" => dao::utils::Error::VoterDoesNotExist,
@@ -90,6 +117,14 @@ impl FromStr for Error {
             }
             "VotingIdNotFound" => dao::utils::Error::VotingIdNotFound,
             "VotingAddressNotFound" => dao::utils::Error::VotingAddressNotFound,
+            "SubjectOfAccountRecovery" => dao::utils::Error::SubjectOfAccountRecovery,
+            "AccountInUse" => dao::utils::Error::AccountInUse,
+            "AccountMigrationNotPending" => dao::utils::Error::AccountMigrationNotPending,
+            "AccountRecoveryChallengeNotEnded" => {
+                dao::utils::Error::AccountRecoveryChallengeNotEnded
+            }
+            "AccountRecoveryChallengeEnded" => dao::utils::Error::AccountRecoveryChallengeEnded,
+            "OnlyRecoveredAccountCanVeto" => dao::utils::Error::OnlyRecoveredAccountCanVeto,
             "CannotPostJobForSelf" => dao::utils::Error::CannotPostJobForSelf,
             "JobPosterNotKycd" => dao::utils::Error::JobPosterNotKycd,
             "WorkerNotKycd" => dao::utils::Error::WorkerNotKycd,
@@ -109,6 +144,25 @@ impl FromStr for Error {
             "JobOfferNotFound" => dao::utils::Error::JobOfferNotFound,
             "BidNotFound" => dao::utils::Error::BidNotFound,
             "JobNotFound" => dao::utils::Error::JobNotFound,
+            "InsuranceClaimAlreadyFiled" => dao::utils::Error::InsuranceClaimAlreadyFiled,
+            "OnlyJobPosterCanFileInsuranceClaim" => {
+                dao::utils::Error::OnlyJobPosterCanFileInsuranceClaim
+            }
+            "JobNotCompleted" => dao::utils::Error::JobNotCompleted,
+            "InsufficientInsurancePoolBalance" => {
+                dao::utils::Error::InsufficientInsurancePoolBalance
+            }
+            "InsuranceClaimNotFound" => dao::utils::Error::InsuranceClaimNotFound,
+            "InsuranceClaimExceedsJobPayment" => dao::utils::Error::InsuranceClaimExceedsJobPayment,
+            "UnknownJobCategory" => dao::utils::Error::UnknownJobCategory,
+            "UnknownSkillTag" => dao::utils::Error::UnknownSkillTag,
+            "OnlyVaCanDeclareSkills" => dao::utils::Error::OnlyVaCanDeclareSkills,
+            "NoMatchingSkill" => dao::utils::Error::NoMatchingSkill,
+            "CannotOnboardOnRecurringJob" => dao::utils::Error::CannotOnboardOnRecurringJob,
+            "CannotTerminateJob" => dao::utils::Error::CannotTerminateJob,
+            "JobCannotBeTerminated" => dao::utils::Error::JobCannotBeTerminated,
+            "JobExtensionNotAllowed" => dao::utils::Error::JobExtensionNotAllowed,
+            "TeamBidAlreadyConfirmed" => dao::utils::Error::TeamBidAlreadyConfirmed,
             "OnlyJobPosterCanPickABid" => dao::utils::Error::OnlyJobPosterCanPickABid,
             "OnlyWorkerCanSubmitProof" => dao::utils::Error::OnlyWorkerCanSubmitProof,
             "InternalAuctionTimeExpired" => dao::utils::Error::InternalAuctionTimeExpired,
@@ -2,9 +2,11 @@ mod account;
 mod common;
 mod contract;
 mod error;
+mod role;
 pub mod voting;
 
 pub use account::Account;
 pub use common::{CsprBalance, ReputationBalance, Result, TimeUnit, TokenId};
 pub use contract::Contract;
 pub use error::Error;
+pub use role::Role;
@@ -0,0 +1,33 @@
+use std::ops::Deref;
+use std::str::FromStr;
+
+pub struct Role(dao::modules::Role);
+
+impl Deref for Role {
+    type Target = dao::modules::Role;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl FromStr for Role {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let role = match s {
+            "Admin" => dao::modules::Role::Admin,
+            "Minter" => dao::modules::Role::Minter,
+            "Burner" => dao::modules::Role::Burner,
+            "Staker" => dao::modules::Role::Staker,
+            "VariableEditor" => dao::modules::Role::VariableEditor,
+            "Slasher" => dao::modules::Role::Slasher,
+            "Migrator" => dao::modules::Role::Migrator,
+            "Operator" => dao::modules::Role::Operator,
+            "VotingCreator" => dao::modules::Role::VotingCreator,
+            "Guardian" => dao::modules::Role::Guardian,
+            _ => return Err(String::from("Parsing error")),
+        };
+        Ok(Self(role))
+    }
+}
@@ -0,0 +1,43 @@
+Feature: Claims
+  CSPR is not transferred when the Job is settled. It is credited to the recipient, who claims it.
+
+  Background:
+    Given following balances
+      | account          | CSPR balance | REP balance  | REP stake  | is_kyced | is_va |
+      | BidEscrow        | 0            | 0            | 0          | false    | false |
+      | MultisigWallet   | 0            | 0            | 0          | false    | false |
+      | JobPoster        | 1000         | 0            | 0          | true     | false |
+      | InternalWorker   | 0            | 1000         | 0          | true     | true  |
+      | VA1              | 0            | 1000         | 0          | true     | true  |
+      | VA2              | 0            | 1000         | 0          | true     | true  |
+      | VA3              | 0            | 1000         | 0          | true     | true  |
+      | VA4              | 0            | 1000         | 0          | true     | true  |
+      | VA5              | 0            | 1000         | 0          | true     | true  |
+    And following configuration
+      | key                                    | value         |
+      | TimeBetweenInformalAndFormalVoting     | 0             |
+      | VotingStartAfterJobSubmission          | 0             |
+    When JobPoster posted a JobOffer with expected timeframe of 14 days, maximum budget of 1000 CSPR and 400 CSPR DOS Fee
+    And InternalWorker posted the Bid for JobOffer 0 with proposed timeframe of 7 days and 500 CSPR price and 100 REP stake
+    And JobPoster picked the Bid of InternalWorker
+    And InternalWorker submits the JobProof of Job 0
+    And voters vote in BidEscrow informal voting with id 0
+      | account          | REP stake | choice |
+      | VA1              | 500       | Yes    |
+    And 6 days passed
+    And informal voting with id 0 ends in BidEscrow contract
+
+  Scenario: Job Poster claims the returned payment and DOS Fee
+    Then JobPoster has 900 CSPR to claim from BidEscrow
+    When JobPoster claims CSPR from BidEscrow
+    Then JobPoster has 0 CSPR to claim from BidEscrow
+    And balances are
+      | account          | CSPR balance |
+      | BidEscrow        | 0            |
+      | JobPoster        | 1000         |
+
+  Scenario: Nothing to claim
+    Then InternalWorker has 0 CSPR to claim from BidEscrow
+    And InternalWorker cannot claim CSPR from BidEscrow
+    When JobPoster claims CSPR from BidEscrow
+    Then JobPoster cannot claim CSPR from BidEscrow
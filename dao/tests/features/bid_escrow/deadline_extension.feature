@@ -0,0 +1,45 @@
+Feature: Job deadline extension
+  The Worker can request the Job deadline to be extended.
+  The Job Poster accepts or rejects the request.
+
+  Background:
+    Given following balances
+      | account          | CSPR balance | REP balance  | REP stake  | is_kyced | is_va |
+      | BidEscrow        | 0            | 0            | 0          | false    | false |
+      | JobPoster        | 1000         | 0            | 0          | true     | false |
+      | InternalWorker   | 0            | 1000         | 0          | true     | true  |
+      | VA1              | 0            | 1000         | 0          | true     | true  |
+    And following configuration
+      | key                                    | value |
+      | MaxDeadlineExtensions                  | 1     |
+    When JobPoster posted a JobOffer with expected timeframe of 14 days, maximum budget of 1000 CSPR and 400 CSPR DOS Fee
+    And InternalWorker posted the Bid for JobOffer 0 with proposed timeframe of 7 days and 500 CSPR price and 100 REP stake
+    And JobPoster picked the Bid of InternalWorker
+    And 5 days passed
+    And InternalWorker requests extending the Job 0 by 3 days
+
+  Scenario: JobPoster accepts the extension
+    When VA1 accepts the extension of Job 0
+    And JobPoster accepts the extension of Job 0
+    And 4 days passed
+    And InternalWorker submits the JobProof of Job 0
+    Then Job with id 0 has 1 extension
+
+  Scenario: JobPoster rejects the extension
+    When JobPoster rejects the extension of Job 0
+    Then Job with id 0 has 0 extensions
+    When 4 days passed
+    Then InternalWorker fails submit the JobProof of outdated Job 0
+
+  Scenario: Worker cannot extend the Job more times than allowed
+    When JobPoster accepts the extension of Job 0
+    Then InternalWorker cannot request extending the Job 0 any more
+    And Job with id 0 has 1 extension
+
+  Scenario: JobPoster cannot accept the extension once the JobProof is submitted
+    When InternalWorker submits the JobProof of Job 0
+    Then JobPoster cannot accept the extension of Job 0 due to JobExtensionNotAllowed
+
+  Scenario: JobPoster cannot accept the extension after the deadline
+    When 3 days passed
+    Then JobPoster cannot accept the extension of Job 0 due to JobExtensionNotAllowed
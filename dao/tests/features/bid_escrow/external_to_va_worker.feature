@@ -79,4 +79,5 @@ Feature: External Worker who wants to become a VA submits job
       | ExternalWorker   | 511.98       | 81.17        | 0          |
       | BidEscrow        | 0            | 0            | 0          |
     And total reputation is 3050
-    And ExternalWorker is a VA
\ No newline at end of file
+    And ExternalWorker is a VA
+    And VA Token of ExternalWorker has been issued by voting 0
\ No newline at end of file
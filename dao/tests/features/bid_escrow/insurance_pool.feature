@@ -0,0 +1,171 @@
+Feature: Insurance Pool
+  A part of forfeited stakes and DOS Fees is credited to the Insurance Pool, which collects it from the Bid Escrow.
+  Job Poster files a claim for a defective Job, the VAs decide if it is compensated.
+
+  Background:
+    Given following balances
+      | account          | CSPR balance | REP balance  | REP stake  | is_kyced | is_va |
+      | BidEscrow        | 0            | 0            | 0          | false    | false |
+      | InsurancePool    | 0            | 0            | 0          | false    | false |
+      | MultisigWallet   | 0            | 0            | 0          | false    | false |
+      | JobPoster        | 1000         | 0            | 0          | true     | false |
+      | InternalWorker   | 0            | 1000         | 0          | true     | true  |
+      | ExternalWorker   | 500          | 0            | 0          | true     | false |
+      | VA1              | 0            | 1000         | 0          | true     | true  |
+      | VA2              | 0            | 1000         | 0          | true     | true  |
+    And following configuration
+      | key                                    | value         |
+      | TimeBetweenInformalAndFormalVoting     | 0             |
+      | VotingStartAfterJobSubmission          | 0             |
+      | InsurancePoolRatio                     | 100           |
+    When JobPoster posted a JobOffer with expected timeframe of 14 days, maximum budget of 1000 CSPR and 400 CSPR DOS Fee
+
+  Scenario: A part of the forfeited stake and DOS Fee is deposited to the Insurance Pool
+    When 8 days passed
+    And ExternalWorker posted the Bid for JobOffer 0 with proposed timeframe of 7 days and 500 CSPR price and 500 CSPR stake without onboarding
+    And JobPoster picked the Bid of ExternalWorker
+    And 15 days passed
+    And JobPoster cancels the Job with id 0
+    Then Job with id 0 is cancelled
+    And InsurancePool has 90 CSPR to claim from BidEscrow
+    And balances are
+      | account          | CSPR balance |
+      | BidEscrow        | 90           |
+      | InsurancePool    | 0            |
+      | MultisigWallet   | 45           |
+      | JobPoster        | 960          |
+      | ExternalWorker   | 0            |
+    When JobPoster collects the Insurance Pool deposits
+    Then InsurancePool has 0 CSPR to claim from BidEscrow
+    And balances are
+      | account          | CSPR balance |
+      | BidEscrow        | 0            |
+      | InsurancePool    | 90           |
+
+  Scenario: A part of the DOS Fee of a cancelled Job Offer is deposited to the Insurance Pool
+    When 18 days passed
+    And JobPoster cancels the JobOffer with id 0
+    Then JobOffer with id 0 is cancelled
+    When JobPoster collects the Insurance Pool deposits
+    Then balances are
+      | account          | CSPR balance |
+      | BidEscrow        | 0            |
+      | InsurancePool    | 40           |
+      | JobPoster        | 960          |
+    And JobPoster cannot collect the Insurance Pool deposits due to NothingToClaim
+
+  Scenario: A paused Insurance Pool does not block the settlements
+    When Deployer pauses InsurancePool contract
+    And 18 days passed
+    And JobPoster cancels the JobOffer with id 0
+    Then JobOffer with id 0 is cancelled
+    And InsurancePool has 40 CSPR to claim from BidEscrow
+    And JobPoster cannot collect the Insurance Pool deposits due to ContractPaused
+    When Deployer unpauses InsurancePool contract
+    And JobPoster collects the Insurance Pool deposits
+    Then balances are
+      | account          | CSPR balance |
+      | BidEscrow        | 0            |
+      | InsurancePool    | 40           |
+      | JobPoster        | 960          |
+
+  Scenario: Job Poster is compensated for a defective Job
+    When InternalWorker posted the Bid for JobOffer 0 with proposed timeframe of 7 days and 500 CSPR price and 100 REP stake
+    And JobPoster picked the Bid of InternalWorker
+    And InternalWorker submits the JobProof of Job 0
+    And voters vote in BidEscrow informal voting with id 0
+      | account          | REP stake | choice |
+      | VA1              | 500       | Yes    |
+      | VA2              | 500       | Yes    |
+    And 6 days passed
+    And informal voting with id 0 ends in BidEscrow contract
+    And voters vote in BidEscrow formal voting with id 0
+      | account          | REP stake | choice |
+      | VA1              | 500       | Yes    |
+      | VA2              | 500       | Yes    |
+    And 6 days passed
+    And formal voting with id 0 ends in BidEscrow contract
+    And JobPoster collects the Insurance Pool deposits
+    Then balances are
+      | account          | CSPR balance |
+      | BidEscrow        | 0            |
+      | InsurancePool    | 40           |
+      | JobPoster        | 460          |
+    When JobPoster files an insurance claim for Job 0 of 30 CSPR
+    And voters vote in InsurancePool informal voting with id 1
+      | account          | REP stake | choice |
+      | VA1              | 100       | Yes    |
+      | VA2              | 100       | Yes    |
+    And 6 days passed
+    And informal voting with id 1 ends in InsurancePool contract
+    And voters vote in InsurancePool formal voting with id 1
+      | account          | REP stake | choice |
+      | VA1              | 100       | Yes    |
+      | VA2              | 100       | Yes    |
+    And 6 days passed
+    And formal voting with id 1 ends in InsurancePool contract
+    Then JobPoster has 30 CSPR to claim from InsurancePool
+    And balances are
+      | account          | CSPR balance |
+      | InsurancePool    | 10           |
+      | JobPoster        | 490          |
+    And JobPoster cannot file an insurance claim for Job 0 of 10 CSPR due to InsuranceClaimAlreadyFiled
+
+  Scenario: Job Poster is not compensated if the VAs reject the claim
+    When InternalWorker posted the Bid for JobOffer 0 with proposed timeframe of 7 days and 500 CSPR price and 100 REP stake
+    And JobPoster picked the Bid of InternalWorker
+    And InternalWorker submits the JobProof of Job 0
+    And voters vote in BidEscrow informal voting with id 0
+      | account          | REP stake | choice |
+      | VA1              | 500       | Yes    |
+      | VA2              | 500       | Yes    |
+    And 6 days passed
+    And informal voting with id 0 ends in BidEscrow contract
+    And voters vote in BidEscrow formal voting with id 0
+      | account          | REP stake | choice |
+      | VA1              | 500       | Yes    |
+      | VA2              | 500       | Yes    |
+    And 6 days passed
+    And formal voting with id 0 ends in BidEscrow contract
+    And JobPoster collects the Insurance Pool deposits
+    When JobPoster files an insurance claim for Job 0 of 30 CSPR
+    And voters vote in InsurancePool informal voting with id 1
+      | account          | REP stake | choice |
+      | VA1              | 100       | No     |
+      | VA2              | 100       | No     |
+    And 6 days passed
+    And informal voting with id 1 ends in InsurancePool contract
+    And voters vote in InsurancePool formal voting with id 1
+      | account          | REP stake | choice |
+      | VA1              | 100       | No     |
+      | VA2              | 100       | No     |
+    And 6 days passed
+    And formal voting with id 1 ends in InsurancePool contract
+    Then JobPoster has 0 CSPR to claim from InsurancePool
+    And balances are
+      | account          | CSPR balance |
+      | InsurancePool    | 40           |
+      | JobPoster        | 460          |
+
+  Scenario: Insurance claims are validated
+    When InternalWorker posted the Bid for JobOffer 0 with proposed timeframe of 7 days and 500 CSPR price and 100 REP stake
+    And JobPoster picked the Bid of InternalWorker
+    Then JobPoster cannot file an insurance claim for Job 0 of 10 CSPR due to JobNotCompleted
+    And JobPoster cannot file an insurance claim for Job 1 of 10 CSPR due to JobNotFound
+    When InternalWorker submits the JobProof of Job 0
+    And voters vote in BidEscrow informal voting with id 0
+      | account          | REP stake | choice |
+      | VA1              | 500       | Yes    |
+      | VA2              | 500       | Yes    |
+    And 6 days passed
+    And informal voting with id 0 ends in BidEscrow contract
+    And voters vote in BidEscrow formal voting with id 0
+      | account          | REP stake | choice |
+      | VA1              | 500       | Yes    |
+      | VA2              | 500       | Yes    |
+    And 6 days passed
+    And formal voting with id 0 ends in BidEscrow contract
+    And JobPoster collects the Insurance Pool deposits
+    Then InternalWorker cannot file an insurance claim for Job 0 of 10 CSPR due to OnlyJobPosterCanFileInsuranceClaim
+    And JobPoster cannot file an insurance claim for Job 0 of 600 CSPR due to InsuranceClaimExceedsJobPayment
+    And JobPoster cannot file an insurance claim for Job 0 of 50 CSPR due to InsufficientInsurancePoolBalance
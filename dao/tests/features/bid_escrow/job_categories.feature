@@ -0,0 +1,52 @@
+Feature: Job categories and skill tags
+  A JobOffer is assigned to a category and labeled with skill tags from a governed taxonomy.
+  VAs declare their skills. Open JobOffers can be listed by category.
+  Optionally, only VAs with a matching skill can bid in the Internal Auction.
+
+  Background:
+    Given following balances
+      | account          | CSPR balance | REP balance  | REP stake  | is_kyced | is_va |
+      | BidEscrow        | 0            | 0            | 0          | false    | false |
+      | MultisigWallet   | 0            | 0            | 0          | false    | false |
+      | JobPoster        | 2000         | 0            | 0          | true     | false |
+      | InternalWorker   | 0            | 1000         | 0          | true     | true  |
+      | VA1              | 0            | 1000         | 0          | true     | true  |
+      | ExternalWorker   | 500          | 0            | 0          | true     | false |
+    And following configuration
+      | key                                    | value                  |
+      | JobCategories                          | Development,Design     |
+      | SkillTags                              | Rust,Solidity,Figma    |
+      | InternalAuctionSkillMatchRequired      | true                   |
+
+  Scenario: Open JobOffers are listed by category
+    When JobPoster posted a JobOffer in category Development requiring Rust with expected timeframe of 14 days, maximum budget of 500 CSPR and 400 CSPR DOS Fee
+    And JobPoster posted a JobOffer in category Design requiring Figma with expected timeframe of 14 days, maximum budget of 500 CSPR and 400 CSPR DOS Fee
+    And JobPoster posted a JobOffer in category Development requiring none with expected timeframe of 14 days, maximum budget of 500 CSPR and 400 CSPR DOS Fee
+    Then open JobOffers in category Development are 0,2
+    And open JobOffers in category Design are 1
+    When 18 days passed
+    And JobPoster cancels the JobOffer with id 0
+    Then open JobOffers in category Development are 2
+
+  Scenario: JobOffer must use the governed taxonomy
+    Then JobPoster cannot post a JobOffer in category Marketing requiring Rust due to UnknownJobCategory
+    And JobPoster cannot post a JobOffer in category Development requiring Rust,Go due to UnknownSkillTag
+
+  Scenario: Only a VA can declare known skills
+    When InternalWorker declares skills Rust,Solidity
+    Then skills of InternalWorker are Rust,Solidity
+    And InternalWorker cannot declare skills Go due to UnknownSkillTag
+    And ExternalWorker cannot declare skills Rust due to OnlyVaCanDeclareSkills
+
+  Scenario: Only a VA with a matching skill can bid in the Internal Auction
+    When InternalWorker declares skills Rust
+    And VA1 declares skills Figma
+    And JobPoster posted a JobOffer in category Development requiring Rust,Solidity with expected timeframe of 14 days, maximum budget of 1000 CSPR and 400 CSPR DOS Fee
+    Then VA1 cannot post the Bid for JobOffer 0 due to NoMatchingSkill
+    When InternalWorker posted the Bid for JobOffer 0 with proposed timeframe of 7 days and 500 CSPR price and 100 REP stake
+    Then the Bid of InternalWorker for JobOffer 0 is in state Created
+
+  Scenario: A JobOffer without skills is open to every VA
+    When JobPoster posted a JobOffer in category Design requiring none with expected timeframe of 14 days, maximum budget of 1000 CSPR and 400 CSPR DOS Fee
+    And VA1 posted the Bid for JobOffer 0 with proposed timeframe of 7 days and 500 CSPR price and 100 REP stake
+    Then the Bid of VA1 for JobOffer 0 is in state Created
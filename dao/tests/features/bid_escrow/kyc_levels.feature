@@ -0,0 +1,34 @@
+Feature: KYC levels required by Job Offers
+  A Job Offer can require an enhanced KYC level from the Job Poster and the Workers.
+
+  Background:
+    Given following balances
+      | account          | CSPR balance | REP balance  | REP stake  | is_kyced | is_va |
+      | BidEscrow        | 0            | 0            | 0          | false    | false |
+      | JobPoster        | 1000         | 0            | 0          | true     | false |
+      | InternalWorker   | 0            | 1000         | 0          | true     | true  |
+      | VA1              | 0            | 1000         | 0          | true     | true  |
+    And JobPoster has KYC level 2
+
+  Scenario: The Job Poster must have the required KYC level
+    Then KYC level of JobPoster is 2
+    And KYC level of InternalWorker is 1
+    And JobPoster cannot post a JobOffer requiring KYC level 3 due to KycLevelTooLow
+    And JobPoster cannot post a JobOffer requiring KYC level 0 due to InvalidKycLevel
+    And InternalWorker cannot post a JobOffer requiring KYC level 2 due to KycLevelTooLow
+
+  Scenario: The Worker must have the required KYC level
+    When JobPoster posted a JobOffer with expected timeframe of 14 days, maximum budget of 1000 CSPR and 400 CSPR DOS Fee requiring KYC level 2
+    Then InternalWorker cannot post the Bid for JobOffer 0 due to KycLevelTooLow
+    Given InternalWorker has KYC level 2
+    When InternalWorker posted the Bid for JobOffer 0 with proposed timeframe of 7 days and 500 CSPR price and 100 REP stake
+    Then the Bid of InternalWorker for JobOffer 0 is in state Created
+    And balances are
+      | account          | CSPR balance | REP balance  | REP stake  |
+      | BidEscrow        | 400          | 0            | 0          |
+      | InternalWorker   | 0            | 1000         | 100        |
+
+  Scenario: A regular Job Offer requires the basic KYC level
+    When JobPoster posted a JobOffer with expected timeframe of 14 days, maximum budget of 1000 CSPR and 400 CSPR DOS Fee
+    And InternalWorker posted the Bid for JobOffer 0 with proposed timeframe of 7 days and 500 CSPR price and 100 REP stake
+    Then the Bid of InternalWorker for JobOffer 0 is in state Created
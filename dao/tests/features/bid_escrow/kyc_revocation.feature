@@ -0,0 +1,57 @@
+Feature: Revocation of a KYC
+  When a KYC revocation voting passes, the KYC token is burned and the registered contracts
+  close the open activity of the revoked user.
+
+  Background:
+    Given following balances
+      | account          | CSPR balance | REP balance  | REP stake  | is_kyced | is_va |
+      | BidEscrow        | 0            | 0            | 0          | false    | false |
+      | JobPoster        | 1000         | 0            | 0          | true     | false |
+      | InternalWorker   | 0            | 1000         | 0          | true     | true  |
+      | Bob              | 1000         | 0            | 0          | true     | false |
+      | VA1              | 0            | 1000         | 0          | true     | true  |
+      | VA2              | 0            | 1000         | 0          | true     | true  |
+      | VA3              | 0            | 1000         | 0          | true     | true  |
+    And following configuration
+      | key                                    | value |
+      | TimeBetweenInformalAndFormalVoting     | 0     |
+      | VotingStartAfterJobSubmission          | 0     |
+    When JobPoster posted a JobOffer with expected timeframe of 14 days, maximum budget of 1000 CSPR and 400 CSPR DOS Fee
+    And InternalWorker posted the Bid for JobOffer 0 with proposed timeframe of 7 days and 500 CSPR price and 100 REP stake
+
+  Scenario: Revoking the KYC of a JobPoster cancels the JobOffer
+    When KycVoter voting with id 0 created by VA1 passes
+      | voting_contract | stake | arg1      | arg2   |
+      | KycVoter        | 100   | JobPoster | revoke |
+    Then JobPoster is not kyced
+    And JobOffer with id 0 is cancelled
+    And the Bid of InternalWorker for JobOffer 0 is in state Canceled
+    And balances are
+      | account          | CSPR balance | REP balance  | REP stake  |
+      | BidEscrow        | 0            | 0            | 0          |
+      | JobPoster        | 1000         | 0            | 0          |
+      | InternalWorker   | 0            | 1000         | 0          |
+
+  Scenario: Revoking the KYC of a worker rejects the Bid
+    When KycVoter voting with id 0 created by VA1 passes
+      | voting_contract | stake | arg1           | arg2   |
+      | KycVoter        | 100   | InternalWorker | revoke |
+    Then InternalWorker is not kyced
+    And JobOffer with id 0 isn't cancelled
+    And the Bid of InternalWorker for JobOffer 0 is in state Rejected
+    And balances are
+      | account          | CSPR balance | REP balance  | REP stake  |
+      | BidEscrow        | 400          | 0            | 0          |
+      | JobPoster        | 600          | 0            | 0          |
+      | InternalWorker   | 0            | 1000         | 0          |
+
+  Scenario: Revoking the KYC of a candidate closes the onboarding request
+    When Bob submits an onboarding request with the stake of 1000 CSPR
+    And KycVoter voting with id 1 created by VA1 passes
+      | voting_contract | stake | arg1 | arg2   |
+      | KycVoter        | 100   | Bob  | revoke |
+    Then Bob is not kyced
+    And Onboarding voting with id 0 is canceled
+    And balances are
+      | account          | CSPR balance | REP balance  | REP stake  |
+      | Bob              | 1000         | 0            | 0          |
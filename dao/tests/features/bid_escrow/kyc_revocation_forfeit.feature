@@ -0,0 +1,27 @@
+Feature: Revocation of a KYC with the forfeit of the onboarding deposit
+  If KycRevocationForfeitsDeposit is set, the CSPR deposit of a candidate
+  whose KYC is revoked is not returned.
+
+  Background:
+    Given following balances
+      | account          | CSPR balance | REP balance  | REP stake  | is_kyced | is_va |
+      | Bob              | 1000         | 0            | 0          | true     | false |
+      | VA1              | 0            | 1000         | 0          | true     | true  |
+      | VA2              | 0            | 1000         | 0          | true     | true  |
+      | VA3              | 0            | 1000         | 0          | true     | true  |
+    And following configuration
+      | key                                    | value |
+      | TimeBetweenInformalAndFormalVoting     | 0     |
+      | VotingStartAfterJobSubmission          | 0     |
+      | KycRevocationForfeitsDeposit           | true  |
+
+  Scenario: Revoking the KYC of a candidate forfeits the deposit
+    When Bob submits an onboarding request with the stake of 1000 CSPR
+    And KycVoter voting with id 1 created by VA1 passes
+      | voting_contract | stake | arg1 | arg2   |
+      | KycVoter        | 100   | Bob  | revoke |
+    Then Bob is not kyced
+    And Onboarding voting with id 0 is canceled
+    And balances are
+      | account          | CSPR balance | REP balance  | REP stake  |
+      | Bob              | 0            | 0            | 0          |
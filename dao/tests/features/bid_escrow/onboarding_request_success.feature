@@ -62,3 +62,4 @@ Feature: User who wants to become a VA without submitting a job
       | VA2              | 434.41       | 1013.63      | 0          |
     And total reputation is 2100
     And Bob is a VA
+    And VA Token of Bob has been issued by voting 0
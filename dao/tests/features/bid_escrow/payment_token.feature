@@ -0,0 +1,96 @@
+Feature: Job paid in a CEP-18 token
+  Job Poster posts a Job Offer paid in a whitelisted CEP-18 token.
+  The payment and the payouts are made in the token, the DOS Fee is paid in CSPR.
+  The tokens are credited to the recipients, who claim them.
+  Reputation is minted based on the fiat value of the payment.
+
+  Background:
+    Given following balances
+      | account          | CSPR balance | REP balance  | REP stake  | is_kyced | is_va |
+      | BidEscrow        | 0            | 0            | 0          | false    | false |
+      | MultisigWallet   | 0            | 0            | 0          | false    | false |
+      | JobPoster        | 1000         | 0            | 0          | true     | false |
+      | InternalWorker   | 0            | 1000         | 0          | true     | true  |
+      | VA1              | 0            | 1000         | 0          | true     | true  |
+      | VA2              | 0            | 1000         | 0          | true     | true  |
+    And following configuration
+      | key                                    | value         |
+      | TimeBetweenInformalAndFormalVoting     | 0             |
+      | VotingStartAfterJobSubmission          | 0             |
+    And BidEscrow accepts a payment token valued the same as CSPR
+    And JobPoster holds 1000 payment tokens
+    When JobPoster posted a JobOffer paid in payment tokens with expected timeframe of 14 days, maximum budget of 1000 tokens and 400 CSPR DOS Fee
+    And InternalWorker posted the Bid for JobOffer 0 with proposed timeframe of 7 days and 500 CSPR price and 100 REP stake
+    And JobPoster picked the Bid of InternalWorker
+
+  Scenario: Job is done and paid in the token
+    Then balances are
+      | account          | CSPR balance | token balance | REP balance  | REP stake  |
+      | BidEscrow        | 400          | 500           | 0            | 0          |
+      | JobPoster        | 600          | 500           | 0            | 0          |
+      | InternalWorker   | 0            | 0             | 1000         | 100        |
+    When InternalWorker submits the JobProof of Job 0
+    And voters vote in BidEscrow informal voting with id 0
+      | account          | REP stake | choice |
+      | VA1              | 500       | Yes    |
+      | VA2              | 500       | Yes    |
+    And 6 days passed
+    And informal voting with id 0 ends in BidEscrow contract
+    And voters vote in BidEscrow formal voting with id 0
+      | account          | REP stake | choice |
+      | VA1              | 500       | Yes    |
+      | VA2              | 500       | No     |
+    And 6 days passed
+    And formal voting with id 0 ends in BidEscrow contract
+    Then balances are
+      | account          | CSPR balance | token balance | REP balance  | REP stake  |
+      | MultisigWallet   | 0            | 50            | 0            | 0          |
+      | JobPoster        | 1000         | 500           | 0            | 0          |
+      | InternalWorker   | 0            | 165.20        | 1119.69      | 0          |
+      | VA1              | 0            | 210.02        | 1423.48      | 0          |
+      | VA2              | 0            | 74.77         | 506.82       | 0          |
+      | BidEscrow        | 0            | 0             | 0            | 0          |
+    And total reputation is 3050
+
+  Scenario: Payment is returned in the token if the Job is rejected
+    When InternalWorker submits the JobProof of Job 0
+    And voters vote in BidEscrow informal voting with id 0
+      | account          | REP stake | choice |
+      | VA1              | 500       | No     |
+      | VA2              | 500       | No     |
+    And 6 days passed
+    And informal voting with id 0 ends in BidEscrow contract
+    And voters vote in BidEscrow formal voting with id 0
+      | account          | REP stake | choice |
+      | VA1              | 500       | No     |
+      | VA2              | 500       | No     |
+    And 6 days passed
+    And formal voting with id 0 ends in BidEscrow contract
+    Then balances are
+      | account          | CSPR balance | token balance |
+      | JobPoster        | 1000         | 1000          |
+      | BidEscrow        | 0            | 0             |
+
+  Scenario: Job Offer cannot be paid in a token that is not whitelisted
+    When BidEscrow stops accepting the payment token
+    Then JobPoster cannot post a JobOffer paid in payment tokens
+
+  Scenario: Worker claims the payment in the token
+    When InternalWorker submits the JobProof of Job 0
+    And voters vote in BidEscrow informal voting with id 0
+      | account          | REP stake | choice |
+      | VA1              | 500       | Yes    |
+      | VA2              | 500       | Yes    |
+    And 6 days passed
+    And informal voting with id 0 ends in BidEscrow contract
+    And voters vote in BidEscrow formal voting with id 0
+      | account          | REP stake | choice |
+      | VA1              | 500       | Yes    |
+      | VA2              | 500       | No     |
+    And 6 days passed
+    And formal voting with id 0 ends in BidEscrow contract
+    Then InternalWorker has 165.20 payment tokens to claim from BidEscrow
+    And JobPoster cannot claim payment tokens from BidEscrow
+    When InternalWorker claims payment tokens from BidEscrow
+    Then InternalWorker has 0 payment tokens to claim from BidEscrow
+    And InternalWorker cannot claim payment tokens from BidEscrow
@@ -0,0 +1,74 @@
+Feature: Settling expired Job Offers and Jobs
+  Anyone can settle Job Offers with no Bid picked in time and Jobs not submitted until the end of the Grace Period.
+  The caller is rewarded from the DOS Fee.
+
+  Background:
+    Given following balances
+      | account          | CSPR balance | REP balance  | REP stake  | is_kyced | is_va |
+      | BidEscrow        | 0            | 0            | 0          | false    | false |
+      | JobPoster        | 1000         | 0            | 0          | true     | false |
+      | InternalWorker   | 0            | 1000         | 0          | true     | true  |
+      | ExternalWorker   | 500          | 0            | 0          | true     | false |
+      | VA1              | 0            | 1000         | 0          | true     | true  |
+    And following configuration
+      | key                                    | value         |
+      | KeeperRewardRatio                      | 100           |
+    When JobPoster posted a JobOffer with expected timeframe of 14 days, maximum budget of 1000 CSPR and 400 CSPR DOS Fee
+    And InternalWorker posted the Bid for JobOffer 0 with proposed timeframe of 7 days and 500 CSPR price and 100 REP stake
+
+  Scenario: Job Offer with no Bid picked expires after the acceptance timeout
+    When 8 days passed
+    And ExternalWorker posted the Bid for JobOffer 0 with proposed timeframe of 7 days and 500 CSPR price and 100 CSPR stake without onboarding
+    And 10 days passed
+    And VA1 settles expired JobOffers and Jobs with limit 10
+    Then JobOffer with id 0 isn't cancelled
+    And the Bid of InternalWorker for JobOffer 0 is in state Canceled
+    And the Bid of ExternalWorker for JobOffer 0 is in state Canceled
+    And balances are
+      | account          | CSPR balance | REP balance  | REP stake  |
+      | BidEscrow        | 400          | 0            | 0          |
+      | JobPoster        | 600          | 0            | 0          |
+      | InternalWorker   | 0            | 1000         | 0          |
+      | ExternalWorker   | 500          | 0            | 0          |
+    When 2 days passed
+    And VA1 settles expired JobOffers and Jobs with limit 0
+    Then JobOffer with id 0 isn't cancelled
+    When VA1 settles expired JobOffers and Jobs with limit 10
+    Then JobOffer with id 0 is expired
+    And balances are
+      | account          | CSPR balance | REP balance  | REP stake  |
+      | BidEscrow        | 0            | 0            | 0          |
+      | JobPoster        | 960          | 0            | 0          |
+      | VA1              | 40           | 1000         | 0          |
+      | InternalWorker   | 0            | 1000         | 0          |
+      | ExternalWorker   | 500          | 0            | 0          |
+
+  Scenario: Bid not picked within the acceptance timeout is settled while the Job Offer is open
+    When 1 days passed
+    And VA1 settles expired JobOffers and Jobs with limit 10
+    Then the Bid of InternalWorker for JobOffer 0 is in state Created
+    When 1 days passed
+    And VA1 settles expired JobOffers and Jobs with limit 10
+    Then JobOffer with id 0 isn't cancelled
+    And the Bid of InternalWorker for JobOffer 0 is in state Canceled
+    And balances are
+      | account          | CSPR balance | REP balance  | REP stake  |
+      | BidEscrow        | 400          | 0            | 0          |
+      | JobPoster        | 600          | 0            | 0          |
+      | VA1              | 0            | 1000         | 0          |
+      | InternalWorker   | 0            | 1000         | 0          |
+
+  Scenario: Job not submitted until the end of the Grace Period expires
+    When JobPoster picked the Bid of InternalWorker
+    And 10 days passed
+    And VA1 settles expired JobOffers and Jobs with limit 10
+    Then Job with id 0 isn't cancelled
+    When 5 days passed
+    And VA1 settles expired JobOffers and Jobs with limit 10
+    Then Job with id 0 is cancelled
+    And balances are
+      | account          | CSPR balance | REP balance  | REP stake  |
+      | BidEscrow        | 0            | 0            | 0          |
+      | JobPoster        | 960          | 0            | 0          |
+      | VA1              | 40           | 1000         | 0          |
+      | InternalWorker   | 0            | 810          | 0          |
@@ -0,0 +1,133 @@
+Feature: Recurring Jobs
+  JobPoster posts a JobOffer recurring in several periods and pre-funds all of them when picking a Bid.
+  The Worker submits a JobProof for every period, each period is voted and paid separately.
+  Either side can terminate the Job between periods, the unused funds are refunded.
+  The Job also ends when the Worker cannot afford the stake for the next period.
+
+  Background:
+    Given following balances
+      | account          | CSPR balance | REP balance  | REP stake  | is_kyced | is_va |
+      | BidEscrow        | 0            | 0            | 0          | false    | false |
+      | MultisigWallet   | 0            | 0            | 0          | false    | false |
+      | JobPoster        | 1000         | 0            | 0          | true     | false |
+      | InternalWorker   | 0            | 1000         | 0          | true     | true  |
+      | ExternalWorker   | 500          | 0            | 0          | true     | false |
+      | VA1              | 0            | 1000         | 0          | true     | true  |
+      | VA2              | 0            | 1000         | 0          | true     | true  |
+    And following configuration
+      | key                                    | value         |
+      | TimeBetweenInformalAndFormalVoting     | 0             |
+      | VotingStartAfterJobSubmission          | 0             |
+    When JobPoster posted a JobOffer recurring 3 times with expected timeframe of 14 days, maximum budget of 200 CSPR and 400 CSPR DOS Fee
+    And InternalWorker posted the Bid for JobOffer 0 with proposed timeframe of 7 days and 200 CSPR price and 100 REP stake
+
+  Scenario: JobPoster pre-funds all the periods
+    Then JobPoster cannot pick the Bid of InternalWorker paying for a single period
+    When JobPoster picked the Bid of InternalWorker
+    Then Job 0 is in period 1 of 3
+    And balances are
+      | account          | CSPR balance | REP stake  |
+      | BidEscrow        | 1000         | 0          |
+      | JobPoster        | 0            | 0          |
+      | InternalWorker   | 0            | 100        |
+
+  Scenario: Each period is voted and paid separately, the next period starts right away
+    When JobPoster picked the Bid of InternalWorker
+    And InternalWorker submits the JobProof of Job 0
+    And voters vote in BidEscrow informal voting with id 0
+      | account          | REP stake | choice |
+      | VA1              | 500       | Yes    |
+      | VA2              | 500       | Yes    |
+    And 6 days passed
+    And informal voting with id 0 ends in BidEscrow contract
+    And voters vote in BidEscrow formal voting with id 0
+      | account          | REP stake | choice |
+      | VA1              | 500       | Yes    |
+      | VA2              | 500       | Yes    |
+    And 6 days passed
+    And formal voting with id 0 ends in BidEscrow contract
+    Then Job 0 is followed by Job 1
+    And Job 1 is in period 2 of 3
+    And balances are
+      | account          | CSPR balance |
+      | BidEscrow        | 800          |
+      | MultisigWallet   | 20           |
+      | JobPoster        | 0            |
+    And balances are
+      | account          | REP stake  |
+      | InternalWorker   | 100        |
+    And JobPoster cannot terminate the Job 0 due to JobCannotBeTerminated
+
+  Scenario: Either side can terminate the Job, the remaining periods and the DOS Fee are refunded
+    When JobPoster picked the Bid of InternalWorker
+    Then VA1 cannot terminate the Job 0 due to CannotTerminateJob
+    When InternalWorker terminates the Job 0
+    Then balances are
+      | account          | CSPR balance | REP balance  | REP stake  |
+      | BidEscrow        | 0            | 0            | 0          |
+      | JobPoster        | 1000         | 0            | 0          |
+      | InternalWorker   | 0            | 1000         | 0          |
+
+  Scenario: A Job cannot be terminated once the JobProof is submitted
+    When JobPoster picked the Bid of InternalWorker
+    And InternalWorker submits the JobProof of Job 0
+    Then JobPoster cannot terminate the Job 0 due to JobCannotBeTerminated
+
+  Scenario: A failed period ends the Job and refunds the remaining periods
+    When JobPoster picked the Bid of InternalWorker
+    And InternalWorker submits the JobProof of Job 0
+    And voters vote in BidEscrow informal voting with id 0
+      | account          | REP stake | choice |
+      | VA1              | 500       | No     |
+      | VA2              | 500       | No     |
+    And 6 days passed
+    And informal voting with id 0 ends in BidEscrow contract
+    And voters vote in BidEscrow formal voting with id 0
+      | account          | REP stake | choice |
+      | VA1              | 500       | No     |
+      | VA2              | 500       | No     |
+    And 6 days passed
+    And formal voting with id 0 ends in BidEscrow contract
+    Then balances are
+      | account          | CSPR balance | REP stake  |
+      | BidEscrow        | 0            | 0          |
+      | JobPoster        | 1000         | 0          |
+      | InternalWorker   | 0            | 0          |
+
+  Scenario: The Job ends when the Worker cannot afford the stake for the next period
+    When JobPoster picked the Bid of InternalWorker
+    And InternalWorker submits the JobProof of Job 0
+    And VA1 starts voting with the following config
+        | voting_contract       | stake | arg1           | arg2 |
+        | SlashingVoter         | 200   | InternalWorker | 0.99 |
+    And voters vote in SlashingVoter informal voting with id 1
+      | account          | stake | vote |
+      | VA2              | 200   | yes  |
+    And voters vote in BidEscrow informal voting with id 0
+      | account          | REP stake | choice |
+      | VA1              | 500       | Yes    |
+      | VA2              | 500       | Yes    |
+    And 6 days passed
+    And informal voting with id 1 ends in SlashingVoter contract
+    And informal voting with id 0 ends in BidEscrow contract
+    And voters vote in SlashingVoter formal voting with id 1
+      | account          | stake | vote |
+      | VA2              | 200   | yes  |
+    And voters vote in BidEscrow formal voting with id 0
+      | account          | REP stake | choice |
+      | VA1              | 500       | Yes    |
+      | VA2              | 500       | Yes    |
+    And 6 days passed
+    And formal voting with id 1 ends in SlashingVoter contract
+    And formal voting with id 0 ends in BidEscrow contract
+    Then Job 0 is followed by Job 1
+    And InternalWorker cannot terminate the Job 1 due to JobCannotBeTerminated
+    And balances are
+      | account          | CSPR balance | REP stake  |
+      | BidEscrow        | 0            | 0          |
+      | JobPoster        | 800          | 0          |
+      | InternalWorker   | 2.18         | 0          |
+
+  Scenario: A recurring Job does not onboard the Worker
+    When 8 days passed
+    Then ExternalWorker cannot post the Bid for JobOffer 0 with onboarding due to CannotOnboardOnRecurringJob
@@ -0,0 +1,85 @@
+Feature: Sealed Bids
+  Workers commit to the terms of a Bid on a sealed JobOffer and reveal them after the auction ends.
+  Only revealed Bids can be picked. A sealed Bid cannot be canceled.
+  The stakes of unrevealed Bids are partially forfeited, also when the JobOffer is canceled.
+
+  Background:
+    Given following balances
+      | account          | CSPR balance | REP balance  | REP stake  | is_kyced | is_va |
+      | BidEscrow        | 0            | 0            | 0          | false    | false |
+      | MultisigWallet   | 0            | 0            | 0          | false    | false |
+      | JobPoster        | 1000         | 0            | 0          | true     | false |
+      | InternalWorker   | 0            | 1000         | 0          | true     | true  |
+      | ExternalWorker   | 500          | 0            | 0          | true     | false |
+    And following configuration
+      | key                                    | value         |
+      | UnrevealedBidForfeitRatio              | 200           |
+    When JobPoster posted a sealed JobOffer with expected timeframe of 14 days, maximum budget of 1000 CSPR and 400 CSPR DOS Fee
+
+  Scenario: A revealed Bid can be picked
+    Then InternalWorker cannot post a regular Bid for JobOffer 0
+    When InternalWorker posted the sealed Bid for JobOffer 0 with proposed timeframe of 7 days and 500 CSPR price and 100 REP stake
+    Then the Bid of InternalWorker for JobOffer 0 is in state Sealed
+    And InternalWorker cannot reveal the Bid for JobOffer 0 during the auction
+    When 18 days passed
+    Then InternalWorker cannot reveal the Bid for JobOffer 0 with proposed timeframe of 7 days and 400 CSPR price
+    When InternalWorker reveals the Bid for JobOffer 0 with proposed timeframe of 7 days and 500 CSPR price
+    And JobPoster picked the Bid of InternalWorker
+    Then the Bid of InternalWorker for JobOffer 0 is in state Picked
+    And balances are
+      | account          | CSPR balance | REP balance  | REP stake  |
+      | BidEscrow        | 900          | 0            | 0          |
+      | JobPoster        | 100          | 0            | 0          |
+      | InternalWorker   | 0            | 1000         | 100        |
+
+  Scenario: Reputation stake of an unrevealed Bid is partially burnt
+    When InternalWorker posted the sealed Bid for JobOffer 0 with proposed timeframe of 7 days and 500 CSPR price and 100 REP stake
+    And 8 days passed
+    And ExternalWorker posted the sealed Bid for JobOffer 0 with proposed timeframe of 7 days and 500 CSPR price and 100 CSPR stake
+    And 10 days passed
+    And ExternalWorker reveals the Bid for JobOffer 0 with proposed timeframe of 7 days and 500 CSPR price
+    Then JobPoster cannot pick the unrevealed Bid of InternalWorker
+    When JobPoster picked the Bid of ExternalWorker
+    Then the Bid of InternalWorker for JobOffer 0 is in state Rejected
+    And balances are
+      | account          | CSPR balance | REP balance  | REP stake  |
+      | BidEscrow        | 1000         | 0            | 0          |
+      | ExternalWorker   | 400          | 0            | 0          |
+      | InternalWorker   | 0            | 980          | 0          |
+
+  Scenario: CSPR stake of an unrevealed Bid is partially transferred to the Bid Escrow Wallet
+    When InternalWorker posted the sealed Bid for JobOffer 0 with proposed timeframe of 7 days and 500 CSPR price and 100 REP stake
+    And 8 days passed
+    And ExternalWorker posted the sealed Bid for JobOffer 0 with proposed timeframe of 7 days and 500 CSPR price and 100 CSPR stake
+    And 10 days passed
+    And InternalWorker reveals the Bid for JobOffer 0 with proposed timeframe of 7 days and 500 CSPR price
+    And JobPoster picked the Bid of InternalWorker
+    Then the Bid of ExternalWorker for JobOffer 0 is in state Rejected
+    And balances are
+      | account          | CSPR balance | REP balance  | REP stake  |
+      | BidEscrow        | 900          | 0            | 0          |
+      | MultisigWallet   | 20           | 0            | 0          |
+      | ExternalWorker   | 480          | 0            | 0          |
+      | InternalWorker   | 0            | 1000         | 100        |
+
+  Scenario: A sealed Bid cannot be canceled
+    When InternalWorker posted the sealed Bid for JobOffer 0 with proposed timeframe of 7 days and 500 CSPR price and 100 REP stake
+    And 18 days passed
+    Then InternalWorker cannot cancel the sealed Bid for JobOffer 0
+    And the Bid of InternalWorker for JobOffer 0 is in state Sealed
+
+  Scenario: Stakes of unrevealed Bids are partially forfeited when the JobOffer is canceled
+    When InternalWorker posted the sealed Bid for JobOffer 0 with proposed timeframe of 7 days and 500 CSPR price and 100 REP stake
+    And 8 days passed
+    And ExternalWorker posted the sealed Bid for JobOffer 0 with proposed timeframe of 7 days and 500 CSPR price and 100 CSPR stake
+    And 10 days passed
+    And JobPoster cancels the JobOffer with id 0
+    Then JobOffer with id 0 is cancelled
+    And the Bid of InternalWorker for JobOffer 0 is in state Canceled
+    And balances are
+      | account          | CSPR balance | REP balance  | REP stake  |
+      | BidEscrow        | 0            | 0            | 0          |
+      | MultisigWallet   | 20           | 0            | 0          |
+      | JobPoster        | 1000         | 0            | 0          |
+      | ExternalWorker   | 480          | 0            | 0          |
+      | InternalWorker   | 0            | 980          | 0          |
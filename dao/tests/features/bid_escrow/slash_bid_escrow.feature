@@ -19,7 +19,7 @@ Feature: Slash on BidEscrow
     And VA1 posted the Bid for JobOffer 0 with proposed timeframe of 2 days and 100 CSPR price and 200 REP stake
     And 8 days passed
     And ExternalWorker posted the Bid for JobOffer 0 with proposed timeframe of 7 days and 500 CSPR price and 500 CSPR stake with onboarding
-    And Owner adds Alice to whitelist in BidEscrow contract
+    And Owner grants Slasher role to Alice in BidEscrow contract
  
   # Scenario: JobPoster gets slashed during job bidding
   #   When Alice calls BidEscrow to slash JobPoster
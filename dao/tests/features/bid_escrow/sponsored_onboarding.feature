@@ -0,0 +1,117 @@
+Feature: Onboarding sponsored by a VA
+  A VA vouches for a KYC'd user staking their own reputation instead of the user's CSPR deposit.
+  The informal quorum is not required and the informal voting is shortened.
+  The sponsor's stake is burned if the new VA is fully slashed within the probation period.
+
+  Background:
+    Given following balances
+      | account          | CSPR balance | REP balance  | REP stake  | is_kyced | is_va |
+      | Onboarding       | 0            | 0            | 0          | false    | false |
+      | Alice            | 0            | 0            | 0          | false    | false |
+      | Bob              | 0            | 0            | 0          | true     | false |
+      | ExternalWorker   | 0            | 0            | 0          | true     | false |
+      | VA1              | 0            | 1000         | 0          | true     | true  |
+      | VA2              | 0            | 1000         | 0          | true     | true  |
+    And following configuration
+      | key                                    | value         |
+      | TimeBetweenInformalAndFormalVoting     | 0             |
+      | SponsoredInformalVotingTime            | 0             |
+      | SponsorshipProbationPeriod             | 2592000000    |
+
+  Scenario: Only a VA can sponsor a KYC'd user
+    Then ExternalWorker cannot sponsor an onboarding request of Bob due to NotOnboarded
+    And VA1 cannot sponsor an onboarding request of Alice due to NotKyced
+    And VA1 cannot sponsor an onboarding request of VA2 due to VaOnboardedAlready
+    When VA1 sponsors an onboarding request of Bob with 100 REP stake
+    Then VA2 cannot sponsor an onboarding request of Bob due to VotingNotCompleted
+
+  Scenario: The sponsored user becomes a VA, the sponsor's stake is released after the probation period
+    When VA1 sponsors an onboarding request of Bob with 100 REP stake
+    And informal voting with id 0 ends in Onboarding contract
+    And voters vote in Onboarding formal voting with id 0
+      | user    | REP stake  | choice |
+     #| VA1     | 100        | yes    | - automatically voted by the system
+      | VA2     | 500        | yes    |
+    And 6 days passed
+    And formal voting with id 0 ends in Onboarding contract
+    Then Bob is a VA
+    And VA1 sponsors Bob
+    And balances are
+      | account          | REP balance  | REP stake  |
+      | Bob              | 0            | 0          |
+      | VA1              | 1000         | 100        |
+      | VA2              | 1000         | 0          |
+    And Alice cannot release the sponsorship of Bob due to SponsorshipProbationNotEnded
+    When 31 days passed
+    And Alice releases the sponsorship of Bob
+    Then balances are
+      | account          | REP balance  | REP stake  |
+      | VA1              | 1000         | 0          |
+    And Alice cannot release the sponsorship of Bob due to SponsorshipNotFound
+
+  Scenario: The sponsor's stake is burned if the sponsored VA is fully slashed within the probation period
+    When VA1 sponsors an onboarding request of Bob with 100 REP stake
+    And informal voting with id 0 ends in Onboarding contract
+    And voters vote in Onboarding formal voting with id 0
+      | user    | REP stake  | choice |
+      | VA2     | 500        | yes    |
+    And 6 days passed
+    And formal voting with id 0 ends in Onboarding contract
+    And Owner grants Burner role to Alice in VaToken contract
+    And Owner grants Slasher role to Alice in Onboarding contract
+    And Alice burns Bob's VA token
+    And Alice calls Onboarding to slash Bob
+    Then balances are
+      | account          | REP balance  | REP stake  |
+      | VA1              | 900          | 0          |
+      | VA2              | 1000         | 0          |
+    And total reputation is 1900
+
+  Scenario: The sponsor is not liable if the sponsored VA leaves the DAO
+    When VA1 sponsors an onboarding request of Bob with 100 REP stake
+    And informal voting with id 0 ends in Onboarding contract
+    And voters vote in Onboarding formal voting with id 0
+      | user    | REP stake  | choice |
+      | VA2     | 500        | yes    |
+    And 6 days passed
+    And formal voting with id 0 ends in Onboarding contract
+    And Bob requests offboarding
+    Then Bob is not a VA
+    And VA1 sponsors Bob
+    And balances are
+      | account          | REP balance  | REP stake  |
+      | VA1              | 1000         | 100        |
+
+  Scenario: The sponsor loses the stake if the formal voting fails
+    When VA1 sponsors an onboarding request of Bob with 100 REP stake
+    And informal voting with id 0 ends in Onboarding contract
+    And voters vote in Onboarding formal voting with id 0
+      | user    | REP stake  | choice |
+      | VA2     | 500        | no     |
+    And 6 days passed
+    And formal voting with id 0 ends in Onboarding contract
+    Then Bob is not a VA
+    And balances are
+      | account          | REP balance  | REP stake  |
+      | VA1              | 900          | 0          |
+      | VA2              | 1100         | 0          |
+
+  Scenario: A sponsor slashed during the voting locks the reputation that is left
+    When VA1 sponsors an onboarding request of Bob with 100 REP stake
+    And VA2 starts voting with the following config
+        | voting_contract       | stake | arg1  | arg2 |
+        | SlashingVoter         | 200   | VA1   | 0.95 |
+    And informal voting with id 0 ends in Onboarding contract
+    And voters vote in Onboarding formal voting with id 0
+      | user    | REP stake  | choice |
+      | VA2     | 500        | yes    |
+    And 5 days passed
+    And informal voting with id 1 ends in SlashingVoter contract
+    And 6 days passed
+    And formal voting with id 1 ends in SlashingVoter contract
+    And formal voting with id 0 ends in Onboarding contract
+    Then Bob is a VA
+    And VA1 sponsors Bob
+    And balances are
+      | account          | REP balance  | REP stake  |
+      | VA1              | 50           | 50         |
@@ -0,0 +1,145 @@
+Feature: Team Bids
+  Several Workers submit a joint Bid with the payment and reputation split declared upfront.
+  The stake is split the same way - every team member stakes its share when confirming the Bid.
+  All the team members confirm the Bid before it can be picked.
+  The reputation minted for the Worker is split between the team members,
+  and every member loses its stake if the Job fails.
+
+  Background:
+    Given following balances
+      | account          | CSPR balance | REP balance  | REP stake  | is_kyced | is_va |
+      | BidEscrow        | 0            | 0            | 0          | false    | false |
+      | MultisigWallet   | 0            | 0            | 0          | false    | false |
+      | JobPoster        | 1000         | 0            | 0          | true     | false |
+      | InternalWorker   | 0            | 1000         | 0          | true     | true  |
+      | ExternalWorker   | 100          | 0            | 0          | true     | false |
+      | Alice            | 100          | 0            | 0          | true     | false |
+      | VA1              | 0            | 1000         | 0          | true     | true  |
+      | VA2              | 0            | 1000         | 0          | true     | true  |
+      | VA3              | 0            | 1000         | 0          | true     | true  |
+    And following configuration
+      | key                                    | value         |
+      | TimeBetweenInformalAndFormalVoting     | 0             |
+      | VotingStartAfterJobSubmission          | 0             |
+      | DefaultReputationSlash                 | 250           |
+    When JobPoster posted a JobOffer with expected timeframe of 14 days, maximum budget of 1000 CSPR and 400 CSPR DOS Fee
+
+  Scenario: Team Bid can be picked only when all the members confirmed it
+    Then InternalWorker cannot post the team Bid with VA3 for JobOffer 0 split 600:300
+    When InternalWorker posted the team Bid with VA3 for JobOffer 0 with proposed timeframe of 7 days and 500 CSPR price and 100 REP stake split 600:400
+    Then JobPoster cannot pick the unconfirmed team Bid of InternalWorker
+    And balances are
+      | account          | CSPR balance | REP balance  | REP stake  |
+      | InternalWorker   | 0            | 1000         | 60         |
+      | VA3              | 0            | 1000         | 0          |
+    When VA3 confirms the team Bid of InternalWorker for JobOffer 0
+    Then VA3 cannot confirm the team Bid of InternalWorker for JobOffer 0 due to TeamBidAlreadyConfirmed
+    And InternalWorker cannot confirm the team Bid of InternalWorker for JobOffer 0 due to TeamBidAlreadyConfirmed
+    When JobPoster picked the Bid of InternalWorker
+    Then the Bid of InternalWorker for JobOffer 0 is in state Picked
+    And balances are
+      | account          | CSPR balance | REP balance  | REP stake  |
+      | BidEscrow        | 900          | 0            | 0          |
+      | JobPoster        | 100          | 0            | 0          |
+      | InternalWorker   | 0            | 1000         | 60         |
+      | VA3              | 0            | 1000         | 40         |
+
+  Scenario: Team member stakes in the currency of the team leader
+    When InternalWorker posted the team Bid with ExternalWorker for JobOffer 0 with proposed timeframe of 7 days and 500 CSPR price and 100 REP stake split 600:400
+    Then ExternalWorker cannot confirm the team Bid of InternalWorker for JobOffer 0 due to InsufficientBalanceForStake
+
+  Scenario: Team members' CSPR stakes are returned when the Job Offer is cancelled
+    When 8 days passed
+    And ExternalWorker posted the team Bid with Alice for JobOffer 0 with proposed timeframe of 7 days and 500 CSPR price and 100 CSPR stake split 700:300
+    And Alice confirms the team Bid of ExternalWorker for JobOffer 0
+    Then balances are
+      | account          | CSPR balance | REP balance  | REP stake  |
+      | BidEscrow        | 500          | 0            | 0          |
+      | ExternalWorker   | 30           | 0            | 0          |
+      | Alice            | 70           | 0            | 0          |
+    When 10 days passed
+    And JobPoster cancels the JobOffer with id 0
+    Then JobOffer with id 0 is cancelled
+    And balances are
+      | account          | CSPR balance | REP balance  | REP stake  |
+      | BidEscrow        | 0            | 0            | 0          |
+      | JobPoster        | 1000         | 0            | 0          |
+      | ExternalWorker   | 100          | 0            | 0          |
+      | Alice            | 100          | 0            | 0          |
+
+  Scenario: Reputation minted for a successful Job is split between the team members
+    When InternalWorker posted the team Bid with VA3 for JobOffer 0 with proposed timeframe of 7 days and 500 CSPR price and 100 REP stake split 600:400
+    And VA3 confirms the team Bid of InternalWorker for JobOffer 0
+    And JobPoster picked the Bid of InternalWorker
+    And InternalWorker submits the JobProof of Job 0
+    And voters vote in BidEscrow informal voting with id 0
+      | account          | REP stake | choice |
+      | VA1              | 500       | Yes    |
+      | VA2              | 500       | Yes    |
+    And 6 days passed
+    And informal voting with id 0 ends in BidEscrow contract
+    And voters vote in BidEscrow formal voting with id 0
+      | account          | REP stake | choice |
+      | VA1              | 500       | Yes    |
+      | VA2              | 500       | No     |
+    And 6 days passed
+    And formal voting with id 0 ends in BidEscrow contract
+    Then balances are
+      | account          | REP balance  | REP stake  |
+      | InternalWorker   | 1075.42      | 0          |
+      | VA3              | 1014         | 0          |
+    And InternalWorker has 1 completed, 0 rejected and 0 cancelled Jobs
+    And VA3 has 1 completed, 0 rejected and 0 cancelled Jobs
+    And InternalWorker has been paid 300 CSPR with the average delay of 0 days
+    And VA3 has been paid 200 CSPR with the average delay of 0 days
+
+  Scenario: Every team member loses its stake when the Job fails
+    When InternalWorker posted the team Bid with VA3 for JobOffer 0 with proposed timeframe of 7 days and 500 CSPR price and 100 REP stake split 500:500
+    And VA3 confirms the team Bid of InternalWorker for JobOffer 0
+    And JobPoster picked the Bid of InternalWorker
+    And InternalWorker submits the JobProof of Job 0
+    And voters vote in BidEscrow informal voting with id 0
+      | account          | REP stake | choice |
+      | VA1              | 500       | No     |
+      | VA2              | 500       | No     |
+    And 6 days passed
+    And informal voting with id 0 ends in BidEscrow contract
+    And voters vote in BidEscrow formal voting with id 0
+      | account          | REP stake | choice |
+      | VA1              | 500       | No     |
+      | VA2              | 500       | No     |
+    And 6 days passed
+    And formal voting with id 0 ends in BidEscrow contract
+    Then balances are
+      | account          | CSPR balance | REP balance  | REP stake  |
+      | JobPoster        | 1000         | 0            | 0          |
+      | InternalWorker   | 0            | 712.5        | 0          |
+      | VA3              | 0            | 950          | 0          |
+    And VA3 has 0 completed, 1 rejected and 0 cancelled Jobs
+
+  Scenario: Every team member loses its stake when the Job is cancelled
+    When InternalWorker posted the team Bid with VA3 for JobOffer 0 with proposed timeframe of 7 days and 500 CSPR price and 100 REP stake split 500:500
+    And VA3 confirms the team Bid of InternalWorker for JobOffer 0
+    And JobPoster picked the Bid of InternalWorker
+    And 15 days passed
+    And JobPoster cancels the Job with id 0
+    Then Job with id 0 is cancelled
+    And balances are
+      | account          | CSPR balance | REP balance  | REP stake  |
+      | JobPoster        | 1000         | 0            | 0          |
+      | InternalWorker   | 0            | 712.5        | 0          |
+      | VA3              | 0            | 950          | 0          |
+    And InternalWorker has 0 completed, 0 rejected and 1 cancelled Job
+    And VA3 has 0 completed, 0 rejected and 1 cancelled Job
+
+  Scenario: Every team member loses its stake when the Job is reclaimed during the Grace Period
+    When InternalWorker posted the team Bid with VA3 for JobOffer 0 with proposed timeframe of 7 days and 500 CSPR price and 100 REP stake split 500:500
+    And VA3 confirms the team Bid of InternalWorker for JobOffer 0
+    And JobPoster picked the Bid of InternalWorker
+    And 8 days passed
+    And VA1 submits the JobProof of Job 0 with 100 REP stake
+    Then balances are
+      | account          | CSPR balance | REP balance  | REP stake  |
+      | InternalWorker   | 0            | 712.5        | 0          |
+      | VA3              | 0            | 950          | 0          |
+      | VA1              | 0            | 1000         | 100        |
@@ -0,0 +1,35 @@
+Feature: Updating the Job Offer
+  Job Poster can update the Job Offer until a Bid is picked.
+  Bids exceeding the new budget are rejected and their stakes returned.
+
+  Background:
+    Given following balances
+      | account          | CSPR balance | REP balance  | REP stake  | is_kyced | is_va |
+      | BidEscrow        | 0            | 0            | 0          | false    | false |
+      | JobPoster        | 1000         | 0            | 0          | true     | false |
+      | InternalWorker   | 0            | 1000         | 0          | true     | true  |
+      | VA1              | 0            | 1000         | 0          | true     | true  |
+    When JobPoster posted a JobOffer with expected timeframe of 14 days, maximum budget of 1000 CSPR and 400 CSPR DOS Fee
+    And InternalWorker posted the Bid for JobOffer 0 with proposed timeframe of 7 days and 500 CSPR price and 100 REP stake
+    And VA1 posted the Bid for JobOffer 0 with proposed timeframe of 7 days and 800 CSPR price and 200 REP stake
+
+  Scenario: JobPoster lowers the budget
+    When JobPoster updates the JobOffer with id 0 to maximum budget of 600 CSPR and expected timeframe of 10 days
+    Then JobOffer with id 0 has maximum budget of 600 CSPR
+    And the Bid of InternalWorker for JobOffer 0 is in state Created
+    And the Bid of VA1 for JobOffer 0 is in state Rejected
+    And balances are
+      | account          | CSPR balance | REP balance  | REP stake  |
+      | BidEscrow        | 400          | 0            | 0          |
+      | InternalWorker   | 0            | 1000         | 100        |
+      | VA1              | 0            | 1000         | 0          |
+
+  Scenario: Somebody else tries to update the JobOffer
+    When VA1 updates the JobOffer with id 0 to maximum budget of 600 CSPR and expected timeframe of 10 days
+    Then JobOffer with id 0 has maximum budget of 1000 CSPR
+    And the Bid of VA1 for JobOffer 0 is in state Created
+
+  Scenario: JobPoster tries to update the JobOffer after picking a Bid
+    When JobPoster picked the Bid of InternalWorker
+    And JobPoster updates the JobOffer with id 0 to maximum budget of 600 CSPR and expected timeframe of 10 days
+    Then JobOffer with id 0 has maximum budget of 1000 CSPR
@@ -0,0 +1,63 @@
+Feature: Probation of a newly onboarded VA
+  A new VA is on probation for VaProbationPeriod. During the probation the VA's stake is capped,
+  the VA cannot create votings in Admin and RepoVoter contracts,
+  and cannot be the sole approver of an onboarding.
+
+  Background:
+    Given following balances
+      | account          | CSPR balance | REP balance  | REP stake  | is_kyced | is_va |
+      | Onboarding       | 0            | 0            | 0          | false    | false |
+      | Bob              | 0            | 1000         | 0          | true     | false |
+      | ExternalWorker   | 1000         | 0            | 0          | true     | false |
+      | VA1              | 0            | 1000         | 0          | true     | true  |
+      | VA2              | 0            | 1000         | 0          | true     | true  |
+    And following configuration
+      | key                                    | value         |
+      | TimeBetweenInformalAndFormalVoting     | 0             |
+      | VotingStartAfterJobSubmission          | 0             |
+      | SponsoredInformalVotingTime            | 0             |
+      | VaProbationPeriod                      | 2592000000    |
+      | VaProbationStakeLimit                  | 100000000000  |
+    # VA1 and VA2 complete the probation
+    When 31 days passed
+    And VA1 sponsors an onboarding request of Bob with 100 REP stake
+    And informal voting with id 0 ends in Onboarding contract
+    And voters vote in Onboarding formal voting with id 0
+      | user    | REP stake  | choice |
+      | VA2     | 500        | yes    |
+    And 6 days passed
+    And formal voting with id 0 ends in Onboarding contract
+    Then Bob is a VA
+
+  Scenario: A VA on probation cannot create votings in Admin and RepoVoter contracts
+    Then Bob cannot create test voting in Admin with 50 stake due to VaOnProbation
+    And Bob cannot create test voting in RepoVoter with 50 stake due to VaOnProbation
+    When 31 days passed
+    And Bob creates test voting in Admin with 50 stake
+    Then balances are
+      | account          | REP balance  | REP stake  |
+      | Bob              | 1000         | 50         |
+
+  Scenario: The stake of a VA on probation is capped
+    When VA1 creates test voting in Admin with 100 stake
+    Then votes in Admin informal voting with id 1 fail
+      | user    | REP stake  | choice | error                       |
+      | Bob     | 200        | yes    | ProbationStakeLimitExceeded |
+    And Bob yes vote of 100 REP succeeds
+      | Admin | 1 | informal |
+    When 31 days passed
+    And VA2 creates test voting in Admin with 100 stake
+    Then Bob yes vote of 200 REP succeeds
+      | Admin | 2 | informal |
+
+  Scenario: A VA on probation cannot be the sole approver of an onboarding
+    Then Bob cannot sponsor an onboarding request of ExternalWorker due to ProbationaryVaCannotBeSoleApprover
+    When ExternalWorker submits an onboarding request with the stake of 1000 CSPR
+    Then votes in Onboarding informal voting with id 1 fail
+      | user    | REP stake  | choice | error                              |
+      | Bob     | 50         | yes    | ProbationaryVaCannotBeSoleApprover |
+    When voters vote in Onboarding informal voting with id 1
+      | user    | REP stake  | choice |
+      | VA2     | 500        | yes    |
+    Then Bob yes vote of 50 REP succeeds
+      | Onboarding | 1 | informal |
@@ -0,0 +1,62 @@
+Feature: Worker track record
+  BidEscrow keeps the statistics of Jobs done by each Worker, so Job Posters can rank the Bids.
+
+  Background:
+    Given following balances
+      | account          | CSPR balance | REP balance  | REP stake  | is_kyced | is_va |
+      | BidEscrow        | 0            | 0            | 0          | false    | false |
+      | MultisigWallet   | 0            | 0            | 0          | false    | false |
+      | JobPoster        | 1000         | 0            | 0          | true     | false |
+      | InternalWorker   | 0            | 1000         | 0          | true     | true  |
+      | VA1              | 0            | 1000         | 0          | true     | true  |
+      | VA2              | 0            | 1000         | 0          | true     | true  |
+    And following configuration
+      | key                                    | value         |
+      | TimeBetweenInformalAndFormalVoting     | 0             |
+      | VotingStartAfterJobSubmission          | 0             |
+    When JobPoster posted a JobOffer with expected timeframe of 7 days, maximum budget of 1000 CSPR and 400 CSPR DOS Fee
+    And InternalWorker posted the Bid for JobOffer 0 with proposed timeframe of 14 days and 500 CSPR price and 100 REP stake
+    And JobPoster picked the Bid of InternalWorker
+
+  Scenario: A completed Job is recorded with the delay and the payment
+    When 10 days passed
+    And InternalWorker submits the JobProof of Job 0
+    And voters vote in BidEscrow informal voting with id 0
+      | account          | REP stake | choice |
+      | VA1              | 500       | Yes    |
+      | VA2              | 500       | Yes    |
+    And 6 days passed
+    And informal voting with id 0 ends in BidEscrow contract
+    And voters vote in BidEscrow formal voting with id 0
+      | account          | REP stake | choice |
+      | VA1              | 500       | Yes    |
+      | VA2              | 500       | Yes    |
+    And 6 days passed
+    And formal voting with id 0 ends in BidEscrow contract
+    Then InternalWorker has 1 completed, 0 rejected and 0 cancelled Jobs
+    And InternalWorker has been paid 500 CSPR with the average delay of 3 days
+
+  Scenario: A rejected Job is recorded
+    When 10 days passed
+    And InternalWorker submits the JobProof of Job 0
+    And voters vote in BidEscrow informal voting with id 0
+      | account          | REP stake | choice |
+      | VA1              | 500       | No     |
+      | VA2              | 500       | No     |
+    And 6 days passed
+    And informal voting with id 0 ends in BidEscrow contract
+    And voters vote in BidEscrow formal voting with id 0
+      | account          | REP stake | choice |
+      | VA1              | 500       | No     |
+      | VA2              | 500       | No     |
+    And 6 days passed
+    And formal voting with id 0 ends in BidEscrow contract
+    Then InternalWorker has 0 completed, 1 rejected and 0 cancelled Jobs
+    And InternalWorker has been paid 0 CSPR with the average delay of 0 days
+
+  Scenario: A cancelled Job is recorded
+    When 29 days passed
+    And JobPoster cancels the Job with id 0
+    Then Job with id 0 is cancelled
+    And InternalWorker has 0 completed, 0 rejected and 1 cancelled Job
+    And InternalWorker has been paid 0 CSPR with the average delay of 0 days
@@ -0,0 +1,87 @@
+Feature: Multisig ownership
+  Background:
+    Given Deployer enabled multisig ownership in VaToken contract with threshold 2
+      | signer |
+      | Alice  |
+      | Bob    |
+      | Holder |
+
+  Scenario: The contract owns itself
+    Then VaToken is the owner of VaToken contract
+    And the threshold of VaToken contract is 2
+    And Alice is a signer in VaToken contract
+    And Deployer is not a signer in VaToken contract
+    And Deployer does not have Admin role in VaToken contract
+    And Deployer does not have Minter role in VaToken contract
+    And Deployer does not have Burner role in VaToken contract
+
+  Scenario: The former owner cannot call owner-only entry points
+    Then Deployer cannot grant Minter role to Account in VaToken contract due to MissingRole
+
+  Scenario: An action approved by the threshold of signers is executed
+    When Alice proposes to grant Minter role to Account in VaToken contract
+    And Bob approves owner action 0 in VaToken contract
+    And Holder executes owner action 0 in VaToken contract
+    Then Account has Minter role in VaToken contract
+    And Holder cannot execute owner action 0 in VaToken contract due to OwnerActionAlreadyExecuted
+
+  Scenario: An action below the threshold is not executed
+    When Alice proposes to grant Minter role to Account in VaToken contract
+    Then Alice cannot execute owner action 0 in VaToken contract due to OwnerActionThresholdNotReached
+    And Alice cannot approve owner action 0 in VaToken contract due to OwnerActionAlreadyApproved
+    And Account does not have Minter role in VaToken contract
+
+  Scenario: A user out of the signers cannot take part in the process
+    When Alice proposes to grant Minter role to Account in VaToken contract
+    Then Deployer cannot propose to grant Minter role to Account in VaToken contract due to NotASigner
+    And Deployer cannot approve owner action 0 in VaToken contract due to NotASigner
+    And Account cannot approve owner action 1 in VaToken contract due to NotASigner
+
+  Scenario: Signers and the threshold change through the threshold
+    When Alice proposes to add Account to signers in VaToken contract
+    And Bob approves owner action 0 in VaToken contract
+    And Alice executes owner action 0 in VaToken contract
+    And Alice proposes to change the threshold to 3 in VaToken contract
+    And Account approves owner action 1 in VaToken contract
+    And Account executes owner action 1 in VaToken contract
+    Then Account is a signer in VaToken contract
+    And the threshold of VaToken contract is 3
+
+  Scenario: The threshold cannot exceed the number of signers
+    When Alice proposes to change the threshold to 4 in VaToken contract
+    And Bob approves owner action 0 in VaToken contract
+    Then Alice cannot execute owner action 0 in VaToken contract due to InvalidThreshold
+    And the threshold of VaToken contract is 2
+
+  Scenario: The approvals of a removed signer do not count
+    When Alice proposes to grant Minter role to Account in VaToken contract
+    And Bob approves owner action 0 in VaToken contract
+    And Alice proposes to remove Bob from signers in VaToken contract
+    And Holder approves owner action 1 in VaToken contract
+    And Alice executes owner action 1 in VaToken contract
+    Then Bob is not a signer in VaToken contract
+    And Alice cannot execute owner action 0 in VaToken contract due to OwnerActionThresholdNotReached
+
+  Scenario: Signers hand the ownership over to a single owner
+    When Alice proposes Bob as a new owner of VaToken contract
+    And Holder approves owner action 0 in VaToken contract
+    And Alice executes owner action 0 in VaToken contract
+    Then Bob is the proposed owner of VaToken contract
+    And Bob has Admin role in VaToken contract
+    And the threshold of VaToken contract is 0
+    And Alice is not a signer in VaToken contract
+
+  Scenario: Disabling the multisig ownership discards the pending proposals
+    When Alice proposes to grant Minter role to Account in VaToken contract
+    And Bob approves owner action 0 in VaToken contract
+    And Alice proposes Bob as a new owner of VaToken contract
+    And Holder approves owner action 1 in VaToken contract
+    And Alice executes owner action 1 in VaToken contract
+    Then Bob is the proposed owner of VaToken contract
+    When Bob enables multisig ownership in VaToken contract with threshold 2
+      | signer |
+      | Alice  |
+      | Bob    |
+      | Holder |
+    Then Holder cannot execute owner action 0 in VaToken contract due to OwnerActionDoesNotExist
+    And Account does not have Minter role in VaToken contract
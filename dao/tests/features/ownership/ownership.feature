@@ -1,7 +1,7 @@
 Feature: Ownership management
   Scenario Outline: Deploy a new instance
     Then Deployer is the owner of <contract> contract
-    And Deployer is whitelisted in <contract> contract
+    And Deployer is not whitelisted in <contract> contract
 
     Examples: 
       | contract           |
@@ -23,8 +23,8 @@ Feature: Ownership management
     Scenario Outline: The current owner changes ownership
       When Deployer sets Alice as a new owner of <contract> contract
       Then Alice is the owner of <contract> contract
-      And Alice is whitelisted in <contract> contract
-      And Deployer is whitelisted in <contract> contract
+      And Alice has Admin role in <contract> contract
+      And Deployer does not have Admin role in <contract> contract
 
       Examples: 
         | contract           |
@@ -60,31 +60,10 @@ Feature: Ownership management
         | VaToken            |
         | VariableRepository |
 
-    Scenario Outline: A whitelisted user claims ownership
-      When Deployer adds Bob to whitelist in <contract> contract
-      And Bob sets Bob as a new owner of <contract> contract
-      Then Deployer is the owner of <contract> contract
-
-      Examples: 
-        | contract           |
-        | Admin              |
-        | BidEscrow          |
-        | KycToken           |
-        | KycVoter           |
-        | Onboarding         |
-        | RepoVoter          |
-        | ReputationToken    |
-        | ReputationVoter    |
-        | SimpleVoter        |
-        | SlashingVoter      |
-        | VaToken            |
-        | VariableRepository |
-    
   Rule: Only the current owner can remove a user from the whitelist
 
     Scenario Outline: Owners removes from the whitelist
-      When Deployer adds Bob to whitelist in <contract> contract
-      And Deployer removes Bob from whitelist in <contract> contract
+      When Deployer removes Bob from whitelist in <contract> contract
       Then Bob is not whitelisted in <contract> contract
          
       Examples: 
@@ -102,54 +81,8 @@ Feature: Ownership management
         | VaToken            |
         | VariableRepository |
 
-
     Scenario Outline: Non-owner removes from the whitelist
-      When Deployer adds Bob to whitelist in <contract> contract
-      And Bob removes Bob from whitelist in <contract> contract
-      Then Bob is whitelisted in <contract> contract
-
-      Examples: 
-        | contract           |
-        | Admin              |
-        | BidEscrow          |
-        | KycToken           |
-        | KycVoter           |
-        | Onboarding         |
-        | RepoVoter          |
-        | ReputationToken    |
-        | ReputationVoter    |
-        | SimpleVoter        |
-        | SlashingVoter      |
-        | VaToken            |
-        | VariableRepository |
-
-  Rule: Only the current owner can remove a user from the whitelist
-
-    Scenario Outline: Owner adds to the whitelist
-      When Deployer adds Alice to whitelist in <contract> contract
-      Then Alice is whitelisted in <contract> contract
-      
-      Examples: 
-        | contract           |
-        | Admin              |
-        | BidEscrow          |
-        | KycToken           |
-        | KycVoter           |
-        | Onboarding         |
-        | RepoVoter          |
-        | ReputationToken    |
-        | ReputationVoter    |
-        | SimpleVoter        |
-        | SlashingVoter      |
-        | VaToken            |
-        | VariableRepository |
-
-
-    Scenario Outline: A user adds to the whitelist
-      When Deployer adds Bob to whitelist in <contract> contract
-      And Bob adds Alice to whitelist in <contract> contract
-      Then Alice is not whitelisted in <contract> contract
-      
+      Then Bob cannot remove Bob from whitelist in <contract> contract due to NotAnOwner
 
       Examples: 
         | contract           |
@@ -165,4 +98,3 @@ Feature: Ownership management
         | SlashingVoter      |
         | VaToken            |
         | VariableRepository |
-  
\ No newline at end of file
@@ -0,0 +1,41 @@
+Feature: Emergency pause
+  Background:
+    Given users
+      | user   | is_va |
+      | Alice  | false |
+      | Bob    | false |
+      | Holder | true  |
+    And Deployer granted Minter role to Bob in VaToken contract
+
+  Scenario: The owner pauses the contract
+    When Deployer pauses VaToken contract
+    Then VaToken contract is paused
+    And Deployer cannot mint a VA Token to Alice due to ContractPaused
+    And Bob cannot mint a VA Token to Alice due to ContractPaused
+    And Deployer cannot burn Holder's VA token due to ContractPaused
+    And Deployer cannot pause VaToken contract due to ContractPaused
+
+  Scenario: A Guardian pauses and unpauses the contract
+    Given Deployer granted Guardian role to Alice in VaToken contract
+    When Alice pauses VaToken contract
+    And Alice unpauses VaToken contract
+    And Bob mints a VA Token to Alice
+    Then VaToken contract is not paused
+    And the Alice's balance of VA Token is 1
+    And Alice cannot unpause VaToken contract due to ContractNotPaused
+
+  Scenario: A minter cannot pause the contract
+    Then Bob does not have Guardian role in VaToken contract
+    And Bob cannot pause VaToken contract due to MissingRole
+    And Alice cannot pause VaToken contract due to MissingRole
+    And VaToken contract is not paused
+
+  Scenario: A user without the Guardian role cannot unpause the contract
+    Given Deployer paused VaToken contract
+    Then Bob cannot unpause VaToken contract due to MissingRole
+    And VaToken contract is paused
+
+  Scenario: Pausing a contract does not affect other contracts
+    When Deployer pauses VaToken contract
+    Then KycToken contract is not paused
+    And ReputationToken contract is not paused
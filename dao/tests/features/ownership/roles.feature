@@ -0,0 +1,68 @@
+Feature: Role-based access control
+  Scenario Outline: Deploy a new instance
+    Then Deployer has Admin role in <contract> contract
+    And Admin is the admin role of Minter in <contract> contract
+
+    Examples:
+      | contract           |
+      | Admin              |
+      | BidEscrow          |
+      | KycToken           |
+      | KycVoter           |
+      | Onboarding         |
+      | RepoVoter          |
+      | ReputationToken    |
+      | ReputationVoter    |
+      | SimpleVoter        |
+      | SlashingVoter      |
+      | VaToken            |
+      | VariableRepository |
+
+  Rule: A role grants access to the protected entry points it guards
+    Background:
+      Given users
+        | user   | is_va |
+        | Alice  | false |
+        | Bob    | false |
+        | Holder | true  |
+
+    Scenario: A Minter mints a VA Token but cannot burn it
+      When Deployer grants Minter role to Alice in VaToken contract
+      And Alice mints a VA Token to Bob
+      Then the Bob's balance of VA Token is 1
+      And Alice cannot burn Holder's VA token due to MissingRole
+      And Alice does not have Burner role in VaToken contract
+
+    Scenario: A user without a role cannot mint
+      Then Alice cannot mint a VA Token to Bob due to MissingRole
+
+    Scenario: A revoked Minter cannot mint
+      Given Deployer granted Minter role to Alice in VaToken contract
+      When Deployer revokes Minter role from Alice in VaToken contract
+      Then Alice does not have Minter role in VaToken contract
+      And Alice cannot mint a VA Token to Bob due to MissingRole
+
+  Rule: A role is granted and revoked by the holders of its admin role
+    Scenario: A user without the admin role grants a role
+      Then Alice cannot grant Minter role to Bob in VaToken contract due to MissingRole
+      And Bob does not have Minter role in VaToken contract
+
+    Scenario: The holder of the admin role grants a role
+      When Deployer sets Operator as the admin role of Minter in VaToken contract
+      And Deployer grants Operator role to Alice in VaToken contract
+      And Alice grants Minter role to Bob in VaToken contract
+      Then Operator is the admin role of Minter in VaToken contract
+      And Bob has Minter role in VaToken contract
+      And Alice cannot grant Burner role to Bob in VaToken contract due to MissingRole
+
+    Scenario: Only the owner changes the admin role
+      When Alice sets Operator as the admin role of Minter in VaToken contract
+      Then Admin is the admin role of Minter in VaToken contract
+
+  Rule: Only a whitelisted user is migrated to the roles
+    Scenario: A user out of the whitelist cannot be migrated
+      Then Deployer cannot migrate Alice from whitelist to Minter role in ReputationToken contract due to NotWhitelisted
+      And Alice does not have Minter role in ReputationToken contract
+
+    Scenario: A user without the admin role cannot migrate a user
+      Then Alice cannot migrate Alice from whitelist to Minter role in ReputationToken contract due to MissingRole
@@ -0,0 +1,164 @@
+Feature: Account recovery
+  A VA who lost access to the account may move the VA membership to a new account. Once a recovery voting passes,
+  the old account may veto the recovery until the challenge period ends. Then anyone executes the recovery and
+  the VA Token, the KYC Token, the reputation, the stakes, the ballots, the JobOffers, the Bids, the Jobs in progress
+  and the unclaimed CSPR of the old account belong to the new account.
+
+  Background:
+    Given following balances
+      | account          | CSPR balance | REP balance  | REP stake  | is_kyced | is_va |
+      | BidEscrow        | 0            | 0            | 0          | false    | false |
+      | JobPoster        | 1000         | 0            | 0          | true     | false |
+      | VA1              | 0            | 1000         | 0          | true     | true  |
+      | VA2              | 0            | 2000         | 0          | true     | true  |
+      | VA3              | 0            | 2000         | 0          | true     | true  |
+      | Alice            | 0            | 0            | 0          | false    | false |
+
+  Scenario: VA1 recovers the account to Alice
+    When VA2 creates test voting in RepoVoter with 300 stake
+    And voters vote in RepoVoter informal voting with id 0
+        | account | stake | vote |
+        | VA1     | 200   | yes  |
+      # | VA2     | 300   | yes  | - automatically voted by the system
+    And JobPoster posted a JobOffer with expected timeframe of 14 days, maximum budget of 500 CSPR and 400 CSPR DOS Fee
+    And VA1 posted the Bid for JobOffer 0 with proposed timeframe of 7 days and 500 CSPR price and 100 REP stake
+    Then balances are
+      | account          | CSPR balance | REP balance  | REP stake  |
+      | VA1              | 0            | 1000         | 300        |
+      | VA2              | 0            | 2000         | 300        |
+      | Alice            | 0            | 0            | 0          |
+    When VA2 starts voting with the following config
+        | voting_contract       | stake | arg1  | arg2  |
+        | AccountRecovery       | 500   | VA1   | Alice |
+    And voters vote in AccountRecovery informal voting with id 1
+        | account | stake | vote |
+      # | VA2     | 500   | yes  | - automatically voted by the system
+        | VA3     | 500   | yes  |
+    And 5 days passed
+    And informal voting with id 1 ends in AccountRecovery contract
+    And 2 days passed
+    And voters vote in AccountRecovery formal voting with id 1
+        | account | stake | vote |
+      # | VA2     | 500   | yes  | - automatically voted by the system
+        | VA3     | 500   | yes  |
+    And 5 days passed
+    And formal voting with id 1 ends in AccountRecovery contract
+    Then Alice is not a VA
+    And VA1 is a VA
+    When 7 days passed
+    And VA2 executes the account recovery with id 1
+    Then Alice is a VA
+    And Alice is kyced
+    And VA1 is not a VA
+    And VA1 is not kyced
+    And balances are
+      | account          | CSPR balance | REP balance  | REP stake  |
+      | VA1              | 0            | 0            | 0          |
+      | VA2              | 0            | 2000         | 300        |
+      | VA3              | 0            | 2000         | 0          |
+      | Alice            | 0            | 1000         | 300        |
+    And total reputation is 5000
+
+  Scenario: VA1 vetoes the recovery within the challenge period
+    When VA2 starts voting with the following config
+        | voting_contract       | stake | arg1  | arg2  |
+        | AccountRecovery       | 500   | VA1   | Alice |
+    And voters vote in AccountRecovery informal voting with id 0
+        | account | stake | vote |
+        | VA3     | 500   | yes  |
+    And 5 days passed
+    And informal voting with id 0 ends in AccountRecovery contract
+    And 2 days passed
+    And voters vote in AccountRecovery formal voting with id 0
+        | account | stake | vote |
+        | VA3     | 500   | yes  |
+    And 5 days passed
+    And formal voting with id 0 ends in AccountRecovery contract
+    Then VA2 cannot veto the account recovery with id 0 due to OnlyRecoveredAccountCanVeto
+    And VA2 cannot execute the account recovery with id 0 due to AccountRecoveryChallengeNotEnded
+    When VA1 vetoes the account recovery with id 0
+    And 7 days passed
+    Then VA2 cannot execute the account recovery with id 0 due to AccountMigrationNotPending
+    And VA1 is a VA
+    And Alice is not a VA
+    And balances are
+      | account          | CSPR balance | REP balance  | REP stake  |
+      | VA1              | 0            | 1000         | 0          |
+      | Alice            | 0            | 0            | 0          |
+
+  Scenario: VA1 cannot veto the recovery after the challenge period
+    When VA2 starts voting with the following config
+        | voting_contract       | stake | arg1  | arg2  |
+        | AccountRecovery       | 500   | VA1   | Alice |
+    And voters vote in AccountRecovery informal voting with id 0
+        | account | stake | vote |
+        | VA3     | 500   | yes  |
+    And 5 days passed
+    And informal voting with id 0 ends in AccountRecovery contract
+    And 2 days passed
+    And voters vote in AccountRecovery formal voting with id 0
+        | account | stake | vote |
+        | VA3     | 500   | yes  |
+    And 5 days passed
+    And formal voting with id 0 ends in AccountRecovery contract
+    And 7 days passed
+    Then VA1 cannot veto the account recovery with id 0 due to AccountRecoveryChallengeEnded
+
+  Scenario: The new account finishes the Job in progress of the old account
+    Given following configuration
+      | key                                    | value         |
+      | VotingStartAfterJobSubmission          | 0             |
+    When JobPoster posted a JobOffer with expected timeframe of 30 days, maximum budget of 500 CSPR and 400 CSPR DOS Fee
+    And VA1 posted the Bid for JobOffer 0 with proposed timeframe of 21 days and 500 CSPR price and 100 REP stake
+    And JobPoster picked the Bid of VA1
+    And VA2 starts voting with the following config
+        | voting_contract       | stake | arg1  | arg2  |
+        | AccountRecovery       | 500   | VA1   | Alice |
+    And voters vote in AccountRecovery informal voting with id 0
+        | account | stake | vote |
+        | VA3     | 500   | yes  |
+    And 5 days passed
+    And informal voting with id 0 ends in AccountRecovery contract
+    And 2 days passed
+    And voters vote in AccountRecovery formal voting with id 0
+        | account | stake | vote |
+        | VA3     | 500   | yes  |
+    And 5 days passed
+    And formal voting with id 0 ends in AccountRecovery contract
+    And 7 days passed
+    And VA2 executes the account recovery with id 0
+    Then balances are
+      | account          | CSPR balance | REP balance  | REP stake  |
+      | VA1              | 0            | 0            | 0          |
+      | Alice            | 0            | 1000         | 100        |
+    When Alice submits the JobProof of Job 0
+    And voters vote in BidEscrow informal voting with id 1
+      | account          | REP stake | choice |
+      | VA2              | 500       | Yes    |
+      | VA3              | 500       | Yes    |
+    And 5 days passed
+    And informal voting with id 1 ends in BidEscrow contract
+    And 2 days passed
+    And voters vote in BidEscrow formal voting with id 1
+      | account          | REP stake | choice |
+      | VA2              | 500       | Yes    |
+      | VA3              | 500       | Yes    |
+    And 5 days passed
+    And formal voting with id 1 ends in BidEscrow contract
+    Then balances are
+      | account          | CSPR balance | REP balance  | REP stake  |
+      | VA1              | 0            | 0            | 0          |
+      | Alice            | 92.35        | 1036.36      | 0          |
+
+  Scenario: VA1 cannot vote in own recovery
+    When VA2 starts voting with the following config
+        | voting_contract       | stake | arg1  | arg2  |
+        | AccountRecovery       | 500   | VA1   | Alice |
+    Then votes in AccountRecovery informal voting with id 0 fail
+        | account | stake | vote | error                    |
+        | VA1     | 500   | yes  | SubjectOfAccountRecovery |
+
+  Scenario: Account can be recovered only to an unused account
+    Then VA2 can't start voting with the following config
+        | voting_contract       | stake | arg1  | arg2  | error        |
+        | AccountRecovery       | 500   | VA1   | VA3   | AccountInUse |
@@ -0,0 +1,74 @@
+Feature: Voluntary VA offboarding
+  A VA may leave the DAO. All the VA's votings, ballots, JobOffers, Bids and Jobs are cancelled without any penalty,
+  the VA Token is burned and the reputation is burned or converted to passive reputation.
+
+  Background:
+    Given following balances
+      | account          | CSPR balance | REP balance  | REP stake  | is_kyced | is_va |
+      | BidEscrow        | 0            | 0            | 0          | false    | false |
+      | JobPoster        | 1000         | 0            | 0          | true     | false |
+      | VA1              | 1000         | 1000         | 0          | true     | true  |
+      | VA2              | 0            | 2000         | 0          | true     | true  |
+      | ExternalWorker   | 500          | 0            | 0          | true     | false |
+
+  Scenario: VA1 leaves the DAO, all the stakes are returned
+    When VA1 creates test voting in KycVoter with 100 stake
+    And voters vote in KycVoter informal voting with id 0
+        | account | stake | vote |
+      # | VA1     | 100   | yes  | - automatically voted by the system
+        | VA2     | 200   | yes  |
+    And VA2 creates test voting in RepoVoter with 300 stake
+    And voters vote in RepoVoter informal voting with id 1
+        | account | stake | vote |
+        | VA1     | 200   | yes  |
+      # | VA2     | 300   | yes  | - automatically voted by the system
+    And VA1 posted a JobOffer with expected timeframe of 14 days, maximum budget of 500 CSPR and 400 CSPR DOS Fee
+    And JobPoster posted a JobOffer with expected timeframe of 14 days, maximum budget of 500 CSPR and 400 CSPR DOS Fee
+    And VA1 posted the Bid for JobOffer 1 with proposed timeframe of 7 days and 500 CSPR price and 100 REP stake
+    And JobPoster picked the Bid of VA1
+    Then balances are
+      | account          | CSPR balance | REP balance  | REP stake  |
+      | BidEscrow        | 1300         | 0            | 0          |
+      | JobPoster        | 100          | 0            | 0          |
+      | VA1              | 600          | 1000         | 400        |
+      | VA2              | 0            | 2000         | 500        |
+    When VA1 requests offboarding
+    Then VA1 is not a VA
+    And balances are
+      | account          | CSPR balance | REP balance  | REP stake  |
+      | BidEscrow        | 0            | 0            | 0          |
+      | JobPoster        | 1000         | 0            | 0          |
+      | VA1              | 1000         | 0            | 0          |
+      | VA2              | 0            | 2000         | 300        |
+    And passive REP of VA1 is 0
+    And total reputation is 2000
+
+  Scenario: The reputation of VA1 is converted to passive reputation
+    Given following configuration
+      | key                                    | value         |
+      | OffboardingReputationToPassive         | true          |
+    When VA1 requests offboarding
+    Then VA1 is not a VA
+    And balances are
+      | account          | REP balance  | REP stake  |
+      | VA1              | 0            | 0          |
+    And passive REP of VA1 is 1000
+    And total reputation is 2000
+
+  Scenario: Only a VA can leave the DAO
+    Then ExternalWorker cannot request offboarding due to NotOnboarded
+    When VA1 requests offboarding
+    Then VA1 cannot request offboarding due to NotOnboarded
+
+  Scenario: VA1 cannot leave the DAO while a slashing voting targets VA1
+    When VA2 starts voting with the following config
+        | voting_contract       | stake | arg1  | arg2 |
+        | SlashingVoter         | 500   | VA1   | 0.5  |
+    Then VA1 cannot request offboarding due to SubjectOfSlashing
+    When 5 days passed
+    And informal voting with id 0 ends in SlashingVoter contract
+    Then VA1 cannot request offboarding due to SubjectOfSlashing
+    When 7 days passed
+    And formal voting with id 0 ends in SlashingVoter contract
+    And VA1 requests offboarding
+    Then VA1 is not a VA
@@ -16,7 +16,7 @@ Feature: Slashing in Bid Escrow contract
       | VotingStartAfterJobSubmission          | 0             |
 
   Scenario: VA1 gets slashed while being a worker in active job
-    When Owner adds Alice to whitelist in BidEscrow contract
+    When Owner grants Slasher role to Alice in BidEscrow contract
     And JobPoster posted a JobOffer with expected timeframe of 14 days, maximum budget of 1000 CSPR and 400 CSPR DOS Fee
     And VA1 posted the Bid for JobOffer 0 with proposed timeframe of 7 days and 500 CSPR price and 100 REP stake
     And 8 days passed
@@ -41,7 +41,7 @@ Feature: Slashing in Bid Escrow contract
     And total reputation is 4000
 
   Scenario: VA1 gets slashed while being a JobPoster in active job
-    When Owner adds Alice to whitelist in BidEscrow contract
+    When Owner grants Slasher role to Alice in BidEscrow contract
     And VA1 posted a JobOffer with expected timeframe of 14 days, maximum budget of 1000 CSPR and 400 CSPR DOS Fee
     And VA2 posted the Bid for JobOffer 0 with proposed timeframe of 7 days and 500 CSPR price and 100 REP stake
     And 8 days passed
@@ -71,8 +71,8 @@ Feature: Slashing in Bid Escrow contract
     And total reputation is 4050
 
   Scenario: VA1 votes in formal job acceptance voting
-    When Owner adds Alice to whitelist in BidEscrow contract
-    And Owner adds Alice to whitelist in ReputationToken contract
+    When Owner grants Slasher role to Alice in BidEscrow contract
+    And Owner grants Burner role to Alice in ReputationToken contract
     And JobPoster posted a JobOffer with expected timeframe of 14 days, maximum budget of 1000 CSPR and 400 CSPR DOS Fee
     And VA2 posted the Bid for JobOffer 0 with proposed timeframe of 7 days and 500 CSPR price and 100 REP stake
     And 8 days passed
@@ -9,7 +9,7 @@ Feature: Slashing in voter contract
       | VA3     | 2000        | true     | true  |
 
     Scenario Outline: Voting creator gets slashed.
-      When Owner adds Alice to whitelist in <contract> contract
+      When Owner grants Slasher role to Alice in <contract> contract
       And VA1 creates test voting in <contract> with 500 stake
       And voters vote in <contract> informal voting with id 0
         | account | stake | vote | 
@@ -34,7 +34,7 @@ Feature: Slashing in voter contract
         | Admin           |
 
     Scenario Outline: Voting participant gets slashed.
-      When Owner adds Alice to whitelist in <contract> contract
+      When Owner grants Slasher role to Alice in <contract> contract
       And VA1 creates test voting in <contract> with 500 stake
       Then balances are
         | account  | REP balance  | REP stake  |
@@ -6,11 +6,13 @@ Feature: VA Token
   Rule: A contract is set up
     Background:
       Given users
-        | user    | whitelisted_in | is_va |
-        | Alice   |                | false |
-        | Bob     | VaToken        | false |
-        | Holder  |                | true  |
-        | Account |                | false |
+        | user    | is_va |
+        | Alice   | false |
+        | Bob     | false |
+        | Holder  | true  |
+        | Account | false |
+      And Deployer granted Minter role to Bob in VaToken contract
+      And Deployer granted Burner role to Bob in VaToken contract
 
     Scenario Outline: If <minter> mints a Va Token, the balance is updated
       When <minter> mints a VA Token to Account
@@ -28,7 +30,7 @@ Feature: VA Token
       And VA Token with id 1 belongs to Bob
       And total supply of VA Token is 2 tokens
       
-    Scenario: If a not whitelisted user VA Token to Bob, he's balance remains the same
+    Scenario: If a user without the Minter role mints a VA Token to Bob, he's balance remains the same
       When Alice mints a VA Token to Bob
       Then the Bob's balance of VA Token is 0
       And total supply of VA Token is 1 token
@@ -38,12 +40,32 @@ Feature: VA Token
       Then the Holder's balance of VA Token is 1
       And total supply of VA Token is 1 token  
       
-    Scenario: If a whitelisted user burns token, Holder's balance and total supply remain the same
+    Scenario: If a user with the Burner role burns token, Holder's balance and total supply remain the same
       When Bob burns Holder's VA token
       Then the Holder's balance of VA Token is 0
       And total supply of VA Token is 0 tokens
 
-    Scenario: If not whitelisted user burns token, Holder's balance and total supply remain the same
+    Scenario: If a user without the Burner role burns token, Holder's balance and total supply remain the same
       When Alice burns Holder's VA token
       Then the Holder's balance of VA Token is 1
-      And total supply of VA Token is 1 token
\ No newline at end of file
+      And total supply of VA Token is 1 token
+
+    Scenario: VA Token exposes the CEP-78 interface
+      Then VA Token exposes the CEP-78 configuration
+        | key                 | value    |
+        | collection_name     | va_token |
+        | collection_symbol   | VAT      |
+        | ownership_mode      | 1        |
+        | burn_mode           | 0        |
+        | minting_mode        | 2        |
+        | nft_metadata_kind   | 3        |
+        | metadata_mutability | 1        |
+        | identifier_mode     | 0        |
+      And VA Token publishes the CEP-78 metadata schema
+        """
+        {"properties":{"name":{"name":"name","description":"The name of the token","required":true},"symbol":{"name":"symbol","description":"The symbol of the collection","required":true},"token_uri":{"name":"token_uri","description":"The URI of the token","required":true},"attributes":{"name":"attributes","description":"The traits of the token: issued_at, voting_id, kyc_level, expires_at, joined_at and status","required":true}}}
+        """
+      And the CEP-78 metadata of VA Token of Holder is
+        """
+        {"name":"va_token #0","symbol":"VAT","token_uri":"0","attributes":[{"trait_type":"issued_at","value":0},{"trait_type":"joined_at","value":0},{"trait_type":"status","value":"active"}]}
+        """
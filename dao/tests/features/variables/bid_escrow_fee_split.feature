@@ -0,0 +1,81 @@
+Feature: BidEscrowFeeSplit variable
+  The part of the Job payment taken by the DAO is split between multiple recipients.
+
+  Background:
+    Given following balances
+      | account          | CSPR balance | REP balance  | REP stake  | is_kyced | is_va |
+      | BidEscrow        | 0            | 0            | 0          | false    | false |
+      | MultisigWallet   | 0            | 0            | 0          | false    | false |
+      | Alice            | 0            | 0            | 0          | false    | false |
+      | Bob              | 0            | 0            | 0          | false    | false |
+      | JobPoster        | 1000         | 0            | 0          | true     | false |
+      | ExternalWorker   | 500          | 0            | 0          | true     | false |
+      | InternalWorker   | 0            | 1000         | 0          | true     | true  |
+      | VA1              | 0            | 1000         | 0          | true     | true  |
+      | VA2              | 0            | 1000         | 0          | true     | true  |
+      | VA3              | 0            | 1000         | 0          | true     | true  |
+    And following configuration
+      | key                                    | value                              |
+      | BidEscrowFeeSplit                      | MultisigWallet:60,Alice:30,Bob:10  |
+      | DistributePaymentToNonVoters           | false                              |
+      | TimeBetweenInformalAndFormalVoting     | 0                                  |
+      | VotingStartAfterJobSubmission          | 0                                  |
+
+  Scenario: Internal Worker payment is split between the fee recipients
+    When JobPoster posted a JobOffer with expected timeframe of 14 days, maximum budget of 1000 CSPR and 400 CSPR DOS Fee
+    And InternalWorker posted the Bid for JobOffer 0 with proposed timeframe of 7 days and 500 CSPR price and 100 REP stake
+    And JobPoster picked the Bid of InternalWorker
+    And InternalWorker submits the JobProof of Job 0
+    And voters vote in BidEscrow informal voting with id 0
+      | account          | REP stake | choice |
+      | VA1              | 500       | Yes    |
+      | VA2              | 500       | Yes    |
+    And 6 days passed
+    And informal voting with id 0 ends in BidEscrow contract
+    And voters vote in BidEscrow formal voting with id 0
+      | account          | REP stake | choice |
+      | VA1              | 500       | Yes    |
+      | VA2              | 500       | No     |
+    And 6 days passed
+    And formal voting with id 0 ends in BidEscrow contract
+    Then balances are
+      | account          | CSPR balance | REP balance  | REP stake  |
+      | MultisigWallet   | 30           | 0            | 0          |
+      | Alice            | 15           | 0            | 0          |
+      | Bob              | 5            | 0            | 0          |
+      | JobPoster        | 500          | 0            | 0          |
+      | InternalWorker   | 165.20       | 1119.69      | 0          |
+      | VA1              | 210.02       | 1423.48      | 0          |
+      | VA2              | 74.77        | 506.82       | 0          |
+      | VA3              | 0            | 1000         | 0          |
+      | BidEscrow        | 0            | 0            | 0          |
+
+  Scenario: External Worker payment is split between the fee recipients
+    When JobPoster posted a JobOffer with expected timeframe of 14 days, maximum budget of 1000 CSPR and 400 CSPR DOS Fee
+    And 8 days passed
+    And ExternalWorker posted the Bid for JobOffer 0 with proposed timeframe of 7 days and 500 CSPR price and 100 CSPR stake without onboarding
+    And JobPoster picked the Bid of ExternalWorker
+    And ExternalWorker submits the JobProof of Job 0
+    And voters vote in BidEscrow informal voting with id 0
+      | account          | REP stake | choice |
+      | VA1              | 500       | Yes    |
+      | VA2              | 500       | Yes    |
+    And 6 days passed
+    And informal voting with id 0 ends in BidEscrow contract
+    And voters vote in BidEscrow formal voting with id 0
+      | account          | REP stake | choice |
+      | VA1              | 500       | Yes    |
+      | VA2              | 500       | Yes    |
+    And 6 days passed
+    And formal voting with id 0 ends in BidEscrow contract
+    Then balances are
+      | account          | CSPR balance |
+      | MultisigWallet   | 30           |
+      | Alice            | 15           |
+      | Bob              | 5            |
+      | BidEscrow        | 0            |
+
+  Scenario: Fee split exceeding 1000 per mils is rejected
+    Then VA1 can't start voting with the following config
+      | voting_contract | stake | arg1               | arg2              | arg3                | error           |
+      | RepoVoter       | 100   | VariableRepository | BidEscrowFeeSplit | Alice:600,Bob:500   | InvalidFeeSplit |
@@ -1,18 +1,16 @@
 Feature: Admin Contract manages other contracts ownership
-  Admin contract is capable of add/remove to whitelist and change the current owner of contract.
+  Admin contract is capable of removing from the whitelist, granting/revoking roles, pausing and changing the current owner of contract.
 
 Background:
   Given users
-    | user    | is_va | REP balance | whitelisted_in  |
-    | Alice   | false | 0           |                 |
-    | Bob     | false | 0           | ReputationToken |
-    | VA1     | true  | 1000        |                 |
-    | VA2     | true  | 1000        |                 |
-    | VA3     | true  | 1000        |                 |
+    | user    | is_va | REP balance |
+    | Alice   | false | 0           |
+    | Bob     | false | 0           |
+    | VA1     | true  | 1000        |
+    | VA2     | true  | 1000        |
+    | VA3     | true  | 1000        |
   And Admin is the owner of all contracts
-  Then Alice is not whitelisted in ReputationToken contract
-  And Bob is whitelisted in ReputationToken contract
-  And Bob is not the owner of ReputationToken contract
+  Then Bob is not the owner of ReputationToken contract
 
 Scenario Outline: Voting passed, action applied
   When Admin voting with id 0 created by VA1 passes
@@ -21,7 +19,6 @@ Scenario Outline: Voting passed, action applied
   Then <subject> <result>
   Examples:
     | action                | subject | result                                         |
-    | add_to_whitelist      | Alice   | is whitelisted in ReputationToken contract     |
     | remove_from_whitelist | Bob     | is not whitelisted in ReputationToken contract |
     | propose_new_owner     | Bob     | is the proposed owner of ReputationToken contract       |
 
@@ -32,6 +29,32 @@ Scenario Outline: Voting rejected, action not applied
   Then <subject> <result>
   Examples:
     | action                | subject | result                                         |
-    | add_to_whitelist      | Alice   | is not whitelisted in ReputationToken contract |
-    | remove_from_whitelist | Bob     | is whitelisted in ReputationToken contract     |
     | propose_new_owner     | Bob     | is not the proposed owner of ReputationToken contract   |
+
+Scenario: Voting passed, contract paused and unpaused
+  When Admin voting with id 0 created by VA1 passes
+    | voting_contract | stake | arg1            | arg2  |
+    | Admin           | 100   | VaToken         | pause |
+  Then VaToken contract is paused
+  When Admin voting with id 1 created by VA1 passes
+    | voting_contract | stake | arg1            | arg2    |
+    | Admin           | 100   | VaToken         | unpause |
+  Then VaToken contract is not paused
+
+Scenario: Voting passed, role granted and revoked
+  When Admin voting with id 0 created by VA1 passes
+    | voting_contract | stake | arg1            | arg2       | arg3  | arg4   |
+    | Admin           | 100   | ReputationToken | grant_role | Alice | Minter |
+  Then Alice has Minter role in ReputationToken contract
+  When Admin voting with id 1 created by VA1 passes
+    | voting_contract | stake | arg1            | arg2        | arg3  | arg4   |
+    | Admin           | 100   | ReputationToken | revoke_role | Alice | Minter |
+  Then Alice does not have Minter role in ReputationToken contract
+
+Scenario: Voting with invalid arguments cannot be created
+  Then VA1 can't start voting with the following config
+    | voting_contract | stake | arg1            | arg2                  | arg3  | arg4   | error                  |
+    | Admin           | 100   | VaToken         | pause                 | Alice |        | InvalidAdminActionArgs |
+    | Admin           | 100   | VaToken         | unpause               | Alice |        | InvalidAdminActionArgs |
+    | Admin           | 100   | ReputationToken | remove_from_whitelist |       |        | InvalidAdminActionArgs |
+    | Admin           | 100   | ReputationToken | grant_role            | Alice |        | InvalidAdminActionArgs |
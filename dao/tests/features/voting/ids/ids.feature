@@ -17,8 +17,8 @@ Feature: Voting id generation
       | SimpleVoter     | 100   |
     Then voting with id 0 in SimpleVoter contract starts
     When VA1 starts voting with the following config
-      | voting_contract | stake | arg1            | arg2             | arg3  |
-      | Admin           | 100   | ReputationToken | add_to_whitelist | Alice |
+      | voting_contract | stake | arg1            | arg2                  | arg3  |
+      | Admin           | 100   | ReputationToken | remove_from_whitelist | Alice |
     Then voting with id 1 in Admin contract starts
     When VA1 starts voting with the following config
       | voting_contract | stake | arg1               | arg2          | arg3  |
@@ -0,0 +1,58 @@
+Feature: KYC Providers
+  A registered KYC provider can attest a user is verified. The attestation mints a KYC token
+  without a KYC voting. Providers are added and removed by a voting.
+
+  Background:
+    Given users
+      | user     | is_va | REP balance |
+      | Alice    | false | 0           |
+      | Bob      | false | 0           |
+      | Holder   | false | 0           |
+      | VA1      | true  | 1000        |
+      | VA2      | true  | 1000        |
+      | VA3      | true  | 1000        |
+      | VA4      | true  | 1000        |
+    When KycVoter voting with id 0 created by VA1 passes
+      | voting_contract | stake | arg1     | arg2         |
+      | KycVoter        | 100   | Holder   | add_provider |
+    Then Holder is a registered KYC provider
+
+  Scenario: A registered provider attests a user
+    When Alice submits a KYC attestation of Alice by Holder expiring on day 60
+    Then Alice is kyced
+    And the Alice's balance of KYC Token is 1
+    And KYC of Alice expires within 60 days
+    When 60 days passed
+    Then Alice is not kyced
+
+  Scenario: An attestation cannot be used twice
+    When Bob submits a KYC attestation of Alice by Holder expiring on day 60
+    And KycVoter voting with id 1 created by VA1 passes
+      | voting_contract | stake | arg1  | arg2   |
+      | KycVoter        | 100   | Alice | revoke |
+    Then Alice is not kyced
+    And Alice cannot submit a KYC attestation of Alice by Holder expiring on day 60 due to KycAttestationAlreadyUsed
+
+  Scenario: Invalid attestations are rejected
+    Then Alice cannot submit a KYC attestation of Alice by Bob expiring on day 60 due to KycProviderNotRegistered
+    And Alice cannot submit a KYC attestation of Alice by Holder signed by Bob due to InvalidKycAttestation
+    And Alice cannot submit a KYC attestation of Alice by Holder made for casper-mainnet due to InvalidKycAttestation
+    When 61 days passed
+    Then Alice cannot submit a KYC attestation of Alice by Holder expiring on day 60 due to KycAttestationExpired
+    And Alice is not kyced
+
+  Scenario: A removed provider cannot attest users
+    When KycVoter voting with id 1 created by VA1 passes
+      | voting_contract | stake | arg1     | arg2            |
+      | KycVoter        | 100   | Holder   | remove_provider |
+    Then Holder is not a registered KYC provider
+    And Alice cannot submit a KYC attestation of Alice by Holder expiring on day 60 due to KycProviderNotRegistered
+
+  Scenario: An attestation bound to the chain is accepted
+    When Alice submits a KYC attestation of Alice by Holder made for casper-test
+    Then Alice is kyced
+
+  Scenario: An unregistered provider cannot be removed
+    Then VA1 can't start voting with the following config
+      | voting_contract | stake | arg1  | arg2            | error                    |
+      | KycVoter        | 100   | Bob   | remove_provider | KycProviderNotRegistered |
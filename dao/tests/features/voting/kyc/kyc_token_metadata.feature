@@ -0,0 +1,44 @@
+Feature: KYC Token metadata
+  Background:
+    Given users
+      | user    | is_va | REP balance |
+      | Alice   | false | 0           |
+      | VA1     | true  | 1000        |
+      | VA2     | true  | 1000        |
+      | VA3     | true  | 1000        |
+      | VA4     | true  | 1000        |
+    And following configuration
+      | key               | value      |
+      | KycValidityPeriod | 2592000000 |
+
+  Scenario: KYC Token issued by a voting exposes the metadata
+    When KycVoter voting with id 0 created by VA1 passes
+      | voting_contract | stake | arg1  |
+      | KycVoter        | 100   | Alice |
+    Then KYC Token of Alice has been issued by voting 0
+    And the token uri of KYC Token of Alice is
+      """
+      {"name":"kyc_token #0","symbol":"KYC","token_uri":"0","attributes":[{"trait_type":"issued_at","value":1036800000},{"trait_type":"voting_id","value":0},{"trait_type":"kyc_level","value":1},{"trait_type":"expires_at","value":3628800000},{"trait_type":"status","value":"active"}]}
+      """
+
+  Scenario: Metadata reflects the expiry and the renewal of the KYC Token
+    When KycVoter voting with id 0 created by VA1 passes
+      | voting_contract | stake | arg1  |
+      | KycVoter        | 100   | Alice |
+    And 30 days passed
+    Then the token uri of KYC Token of Alice is
+      """
+      {"name":"kyc_token #0","symbol":"KYC","token_uri":"0","attributes":[{"trait_type":"issued_at","value":1036800000},{"trait_type":"voting_id","value":0},{"trait_type":"kyc_level","value":1},{"trait_type":"expires_at","value":3628800000},{"trait_type":"status","value":"expired"}]}
+      """
+    When KycVoter voting with id 1 created by VA1 passes
+      | voting_contract | stake | arg1  | arg2  |
+      | KycVoter        | 100   | Alice | renew |
+    Then KYC Token of Alice has been issued by voting 1
+    And the token uri of KYC Token of Alice is
+      """
+      {"name":"kyc_token #0","symbol":"KYC","token_uri":"0","attributes":[{"trait_type":"issued_at","value":4665600000},{"trait_type":"voting_id","value":1},{"trait_type":"kyc_level","value":1},{"trait_type":"expires_at","value":7257600000},{"trait_type":"status","value":"active"}]}
+      """
+
+  Scenario: KYC Token minted without a voting has no issuing voting
+    Given Alice that owns a KYC Token
+    Then KYC Token of Alice has not been issued by a voting
@@ -19,4 +19,76 @@ Feature: Kyc Voter
       | voting_contract | stake | arg1  |
       | KycVoter        | 100   | Alice |
     Then Alice is not kyced
-     
\ No newline at end of file
+     
+  Scenario: KYC expires after the validity period
+    Given following configuration
+      | key               | value      |
+      | KycValidityPeriod | 2592000000 |
+    When KycVoter voting with id 0 created by VA1 passes
+      | voting_contract | stake | arg1  |
+      | KycVoter        | 100   | Alice |
+    Then Alice is kyced
+    And KYC of Alice is valid for 30 days
+    And KYC of Alice does not expire within 10 days
+    When 25 days passed
+    Then KYC of Alice expires within 10 days
+    When 5 days passed
+    Then Alice is not kyced
+    And KYC of Alice does not expire within 10 days
+    And the Alice's balance of KYC Token is 1
+    And VA1 can't start voting with the following config
+      | voting_contract | stake | arg1  | error            |
+      | KycVoter        | 100   | Alice | UserKycedAlready |
+
+  Scenario: Renewal voting extends the validity of the KYC
+    Given following configuration
+      | key               | value      |
+      | KycValidityPeriod | 2592000000 |
+    When KycVoter voting with id 0 created by VA1 passes
+      | voting_contract | stake | arg1  |
+      | KycVoter        | 100   | Alice |
+    And 30 days passed
+    Then Alice is not kyced
+    When KycVoter voting with id 1 created by VA1 passes
+      | voting_contract | stake | arg1  | arg2  |
+      | KycVoter        | 100   | Alice | renew |
+    Then Alice is kyced
+    And KYC of Alice is valid for 30 days
+    And the Alice's balance of KYC Token is 1
+
+  Scenario: Renewal voting requires a KYC token
+    Then VA1 can't start voting with the following config
+      | voting_contract | stake | arg1  | arg2  | error    |
+      | KycVoter        | 100   | Alice | renew | NotKyced |
+
+  Scenario: Revocation voting burns the KYC token
+    When KycVoter voting with id 0 created by VA1 passes
+      | voting_contract | stake | arg1  |
+      | KycVoter        | 100   | Alice |
+    Then Alice is kyced
+    When KycVoter voting with id 1 created by VA1 passes
+      | voting_contract | stake | arg1  | arg2   |
+      | KycVoter        | 100   | Alice | revoke |
+    Then Alice is not kyced
+    And the Alice's balance of KYC Token is 0
+
+  Scenario: Revocation voting requires a KYC token
+    Then VA1 can't start voting with the following config
+      | voting_contract | stake | arg1  | arg2   | error    |
+      | KycVoter        | 100   | Alice | revoke | NotKyced |
+
+  Scenario: KYC voting grants and upgrades the KYC level
+    When KycVoter voting with id 0 created by VA1 passes
+      | voting_contract | stake | arg1  | arg2 |
+      | KycVoter        | 100   | Alice | 2    |
+    Then Alice is kyced
+    And KYC level of Alice is 2
+    When KycVoter voting with id 1 created by VA1 passes
+      | voting_contract | stake | arg1  | arg2 |
+      | KycVoter        | 100   | Alice | 3    |
+    Then KYC level of Alice is 3
+    And the Alice's balance of KYC Token is 1
+    And VA1 can't start voting with the following config
+      | voting_contract | stake | arg1  | arg2 | error            |
+      | KycVoter        | 100   | Alice | 3    | UserKycedAlready |
+      | KycVoter        | 100   | Alice | 0    | InvalidKycLevel  |
@@ -23,9 +23,9 @@ Feature: Staking
         | VA2     | 1000        | 500       |
         | VA3     | 1000        | 200       |
       Examples:
-        | voting_contract  | arg1               | arg2             | arg3  |
-        | KycVoter         | Alice              |                  |       |      
-        | Admin            | ReputationToken    | add_to_whitelist | Alice |
+        | voting_contract  | arg1               | arg2                  | arg3  |
+        | KycVoter         | Alice              |                       |       |      
+        | Admin            | ReputationToken    | remove_from_whitelist | Alice |
         | SlashingVoter    | VA5                | 1                |       |
         | RepoVoter        | VariableRepository | PostJobDOSFee    | 1     | 
         | SimpleVoter      |                    |                  |       |
@@ -31,9 +31,9 @@ Feature: Tokens redistribution
         | VA6     | 1000         |
       And formal voting with id 0 in <voting_contract> contract does not start
       Examples:
-        | voting_contract  | arg1               | arg2             | arg3  |
-        | KycVoter         | Alice              |                  |       |      
-        | Admin            | ReputationToken    | add_to_whitelist | Alice |
+        | voting_contract  | arg1               | arg2                  | arg3  |
+        | KycVoter         | Alice              |                       |       |      
+        | Admin            | ReputationToken    | remove_from_whitelist | Alice |
         | SlashingVoter    | VA5                | 1                |       |
         | RepoVoter        | VariableRepository | PostJobDOSFee    | 1     | 
         | SimpleVoter      |                    |                  |       |
@@ -65,9 +65,9 @@ Feature: Tokens redistribution
         | VA3     | 1000         |
         | VA4     | 1000         |
       Examples:
-        | voting_contract  | arg1               | arg2             | arg3  |
-        | KycVoter         | Alice              |                  |       |      
-        | Admin            | ReputationToken    | add_to_whitelist | Alice |
+        | voting_contract  | arg1               | arg2                  | arg3  |
+        | KycVoter         | Alice              |                       |       |      
+        | Admin            | ReputationToken    | remove_from_whitelist | Alice |
         | SlashingVoter    | VA5                | 1                |       |
         | RepoVoter        | VariableRepository | PostJobDOSFee    | 1     | 
         | SimpleVoter      |                    |                  |       |
@@ -102,9 +102,9 @@ Feature: Tokens redistribution
         | VA3     | 1062.5       |
         | VA4     | 750          |
      Examples:
-        | voting_contract  | arg1               | arg2             | arg3  |
-        | KycVoter         | Alice              |                  |       | 
-        | Admin            | ReputationToken    | add_to_whitelist | Alice |
+        | voting_contract  | arg1               | arg2                  | arg3  |
+        | KycVoter         | Alice              |                       |       | 
+        | Admin            | ReputationToken    | remove_from_whitelist | Alice |
         | SlashingVoter    | VA5                | 1                |       |
         | RepoVoter        | VariableRepository | PostJobDOSFee    | 1     | 
         | SimpleVoter      |                    |                  |       |
@@ -138,9 +138,9 @@ Feature: Tokens redistribution
         | VA3     | 1100         |
         | VA4     | 750          |
      Examples:
-        | voting_contract  | arg1               | arg2             | arg3  |
-        | KycVoter         | Alice              |                  |       |      
-        | Admin            | ReputationToken    | add_to_whitelist | Alice |
+        | voting_contract  | arg1               | arg2                  | arg3  |
+        | KycVoter         | Alice              |                       |       |      
+        | Admin            | ReputationToken    | remove_from_whitelist | Alice |
         | SlashingVoter    | VA5                | 1                |       |
         | RepoVoter        | VariableRepository | PostJobDOSFee    | 1     | 
         | SimpleVoter      |                    |                  |       |
@@ -3,9 +3,11 @@ use cucumber::{gherkin::Step, then, when};
 use crate::common::params::ReputationBalance;
 use crate::common::{
     helpers,
-    params::{Account, CsprBalance},
+    params::{Account, Contract, CsprBalance},
     DaoWorld,
 };
+use dao::utils::Error;
+use odra::test_env;
 
 #[when(expr = "{account} burns all reputation of {account}")]
 fn burn_all_reputation(world: &mut DaoWorld, burner: Account, holder: Account) {
@@ -46,6 +48,13 @@ fn assert_balances(world: &mut DaoWorld, step: &Step) {
         .first()
         .expect("Missing labels");
 
+    if labels
+        .iter()
+        .any(|label| label == "CSPR balance" || label == "token balance")
+    {
+        world.claim_all_pending();
+    }
+
     let table = step.table.as_ref().unwrap().rows.iter().skip(1);
     for row in table {
         let account = helpers::parse::<Account>(row.get(0), "Could't parse account");
@@ -66,8 +75,70 @@ fn assert_balances(world: &mut DaoWorld, step: &Step) {
                         helpers::parse_or_default::<CsprBalance>(row.get(idx));
                     world.assert_cspr_balance(&account, expected_cspr_balance);
                 }
+                "token balance" => {
+                    let expected_token_balance =
+                        helpers::parse_or_default::<CsprBalance>(row.get(idx));
+                    world.assert_payment_token_balance(&account, expected_token_balance);
+                }
                 _ => {}
             }
         }
     }
 }
+
+#[when(expr = "{account} claims CSPR from {contract}")]
+fn claim(world: &mut DaoWorld, account: Account, contract: Contract) {
+    world.claim(&account, &contract);
+}
+
+#[then(expr = "{account} cannot claim CSPR from {contract}")]
+fn claim_fails(world: &mut DaoWorld, account: Account, contract: Contract) {
+    test_env::assert_exception(Error::NothingToClaim, || {
+        world.claim(&account, &contract);
+    });
+}
+
+#[when(expr = "{account} claims payment tokens from BidEscrow")]
+fn claim_token(world: &mut DaoWorld, account: Account) {
+    world.claim_payment_token(&account);
+}
+
+#[then(expr = "{account} cannot claim payment tokens from BidEscrow")]
+fn claim_token_fails(world: &mut DaoWorld, account: Account) {
+    test_env::assert_exception(Error::NothingToClaim, || {
+        world.claim_payment_token(&account);
+    });
+}
+
+#[then(expr = "{account} has {balance} payment tokens to claim from BidEscrow")]
+fn assert_pending_token_claims(
+    world: &mut DaoWorld,
+    account: Account,
+    expected_amount: CsprBalance,
+) {
+    let amount = world.get_pending_payment_token_claims(&account);
+    assert!(
+        helpers::is_cspr_balance_close_enough(expected_amount, amount),
+        "For account {:?} pending token claims should be {:?} but are {:?}",
+        account,
+        expected_amount,
+        amount
+    );
+}
+
+#[then(expr = "{account} has {balance} CSPR to claim from {contract}")]
+fn assert_pending_claims(
+    world: &mut DaoWorld,
+    account: Account,
+    expected_amount: CsprBalance,
+    contract: Contract,
+) {
+    let amount = world.get_pending_claims(&account, &contract);
+    assert!(
+        helpers::is_cspr_balance_close_enough(expected_amount, amount),
+        "For account {:?} pending claims should be {:?} but are {:?}",
+        account,
+        expected_amount,
+        amount
+    );
+}
@@ -1,13 +1,14 @@
-use cucumber::{then, when};
+use cucumber::{given, then, when};
 use dao::bid_escrow::bid::BidStatus;
 use dao::bid_escrow::contract::BidEscrowContractRef;
 use dao::bid_escrow::job::JobStatus;
-use dao::bid_escrow::job_offer::JobOfferStatus;
+use dao::bid_escrow::job_offer::{JobOfferParams, JobOfferStatus};
 use dao::bid_escrow::types::JobId;
-use dao::utils::types::DocumentHash;
+use dao::utils::types::{DocumentHash, KycLevel};
 use dao::utils::Error;
+use dao::voting_contracts::{InsurancePoolContractRef, OnboardingRequestContractRef};
 use odra::test_env;
-use odra::types::{Balance, BlockTime};
+use odra::types::{Balance, BlockTime, Bytes};
 
 use crate::common::params::ReputationBalance;
 use crate::common::{
@@ -32,12 +33,120 @@ fn post_job_offer(
     suppress(|| w.post_offer(job_poster, timeframe, *maximum_budget, *dos_fee));
 }
 
+#[allow(clippy::too_many_arguments)]
+#[when(
+    expr = "{account} posted a JobOffer with expected timeframe of {int} {time_unit}, maximum budget of {balance} CSPR and {balance} CSPR DOS Fee requiring KYC level {int}"
+)]
+fn post_job_offer_with_kyc_level(
+    w: &mut DaoWorld,
+    job_poster: Account,
+    timeframe: BlockTime,
+    time_unit: TimeUnit,
+    maximum_budget: CsprBalance,
+    dos_fee: CsprBalance,
+    required_kyc_level: KycLevel,
+) {
+    let timeframe = helpers::to_milliseconds(timeframe, time_unit);
+    w.post_offer_with_kyc_level(
+        job_poster,
+        timeframe,
+        *maximum_budget,
+        *dos_fee,
+        required_kyc_level,
+    );
+}
+
+#[then(expr = "{account} cannot post a JobOffer requiring KYC level {int} due to {word}")]
+fn cannot_post_job_offer_with_kyc_level(
+    w: &mut DaoWorld,
+    job_poster: Account,
+    required_kyc_level: KycLevel,
+    error: String,
+) {
+    let error = error
+        .parse::<crate::common::params::Error>()
+        .expect("Valid error expected");
+    test_env::assert_exception(*error, || {
+        w.post_offer_with_kyc_level(
+            job_poster,
+            helpers::to_milliseconds(14, TimeUnit::Days),
+            Balance::from(1_000_000_000_000u64),
+            Balance::from(400_000_000_000u64),
+            required_kyc_level,
+        );
+    });
+}
+
+#[when(
+    expr = "{account} posted a sealed JobOffer with expected timeframe of {int} {time_unit}, maximum budget of {balance} CSPR and {balance} CSPR DOS Fee"
+)]
+fn post_sealed_job_offer(
+    w: &mut DaoWorld,
+    job_poster: Account,
+    timeframe: BlockTime,
+    time_unit: TimeUnit,
+    maximum_budget: CsprBalance,
+    dos_fee: CsprBalance,
+) {
+    let timeframe = helpers::to_milliseconds(timeframe, time_unit);
+    suppress(|| w.post_sealed_offer(job_poster, timeframe, *maximum_budget, *dos_fee));
+}
+
+#[given(expr = "BidEscrow accepts a payment token valued the same as CSPR")]
+fn whitelist_payment_token(w: &mut DaoWorld) {
+    let cspr_rate = w.get_cspr_rate();
+    w.whitelist_payment_token(*cspr_rate);
+}
+
+#[given(expr = "{account} holds {balance} payment tokens")]
+fn payment_token_balance(w: &mut DaoWorld, account: Account, amount: CsprBalance) {
+    w.set_payment_token_balance(&account, *amount);
+}
+
+#[when(
+    expr = "{account} posted a JobOffer paid in payment tokens with expected timeframe of {int} {time_unit}, maximum budget of {balance} tokens and {balance} CSPR DOS Fee"
+)]
+fn post_job_offer_with_token(
+    w: &mut DaoWorld,
+    job_poster: Account,
+    timeframe: BlockTime,
+    time_unit: TimeUnit,
+    maximum_budget: CsprBalance,
+    dos_fee: CsprBalance,
+) {
+    let timeframe = helpers::to_milliseconds(timeframe, time_unit);
+    suppress(|| w.post_offer_with_token(job_poster, timeframe, *maximum_budget, *dos_fee));
+}
+
 #[when(expr = "{account} cancels the JobOffer with id {int}")]
 fn cancel_job_offer(w: &mut DaoWorld, caller: Account, offer_id: u32) {
     test_env::set_caller(w.get_address(&caller));
     suppress(|| w.bid_escrow.cancel_job_offer(offer_id));
 }
 
+#[when(
+    expr = "{account} updates the JobOffer with id {int} to maximum budget of {balance} CSPR and expected timeframe of {int} {time_unit}"
+)]
+fn update_job_offer(
+    w: &mut DaoWorld,
+    caller: Account,
+    offer_id: u32,
+    maximum_budget: CsprBalance,
+    timeframe: BlockTime,
+    time_unit: TimeUnit,
+) {
+    let timeframe = helpers::to_milliseconds(timeframe, time_unit);
+    test_env::set_caller(w.get_address(&caller));
+    suppress(|| {
+        w.bid_escrow.update_job_offer(
+            offer_id,
+            *maximum_budget,
+            timeframe,
+            DocumentHash::from("Job description"),
+        )
+    });
+}
+
 #[when(expr = "{account} cancels the Job with id {int}")]
 fn cancel_job(w: &mut DaoWorld, caller: Account, offer_id: u32) {
     test_env::set_caller(w.get_address(&caller));
@@ -124,6 +233,253 @@ fn submit_bid_internal_cspr_stake(
     });
 }
 
+#[allow(clippy::too_many_arguments)]
+#[when(
+    expr = "{account} posted the team Bid with {account} for JobOffer {int} with proposed timeframe of {int} {time_unit} and {balance} CSPR price and {reputation} REP stake split {int}:{int}"
+)]
+fn submit_team_bid(
+    w: &mut DaoWorld,
+    leader: Account,
+    member: Account,
+    job_offer_id: u32,
+    timeframe: BlockTime,
+    time_unit: TimeUnit,
+    budget: CsprBalance,
+    stake: ReputationBalance,
+    leader_share: u32,
+    member_share: u32,
+) {
+    let timeframe = helpers::to_milliseconds(timeframe, time_unit);
+    let team = vec![
+        (w.get_address(&leader), leader_share),
+        (w.get_address(&member), member_share),
+    ];
+    suppress(|| w.post_team_bid(job_offer_id, leader, timeframe, *budget, *stake, None, team));
+}
+
+#[then(
+    expr = "{account} cannot post the team Bid with {account} for JobOffer {int} split {int}:{int}"
+)]
+fn cannot_submit_team_bid(
+    w: &mut DaoWorld,
+    leader: Account,
+    member: Account,
+    job_offer_id: u32,
+    leader_share: u32,
+    member_share: u32,
+) {
+    let team = vec![
+        (w.get_address(&leader), leader_share),
+        (w.get_address(&member), member_share),
+    ];
+    test_env::set_caller(w.get_address(&leader));
+    test_env::assert_exception(Error::InvalidTeamShares, || {
+        let mut bid_escrow = BidEscrowContractRef::at(w.bid_escrow.address());
+        bid_escrow.submit_team_bid(
+            job_offer_id,
+            1000,
+            Balance::one(),
+            Balance::one(),
+            None,
+            team,
+        )
+    });
+}
+
+#[allow(clippy::too_many_arguments)]
+#[when(
+    expr = "{account} posted the team Bid with {account} for JobOffer {int} with proposed timeframe of {int} {time_unit} and {balance} CSPR price and {balance} CSPR stake split {int}:{int}"
+)]
+fn submit_team_bid_cspr_stake(
+    w: &mut DaoWorld,
+    leader: Account,
+    member: Account,
+    job_offer_id: u32,
+    timeframe: BlockTime,
+    time_unit: TimeUnit,
+    budget: CsprBalance,
+    stake: CsprBalance,
+    leader_share: u32,
+    member_share: u32,
+) {
+    let timeframe = helpers::to_milliseconds(timeframe, time_unit);
+    let team = vec![
+        (w.get_address(&leader), leader_share),
+        (w.get_address(&member), member_share),
+    ];
+    suppress(|| {
+        w.post_team_bid(
+            job_offer_id,
+            leader,
+            timeframe,
+            *budget,
+            Balance::zero(),
+            Some(*stake),
+            team,
+        )
+    });
+}
+
+#[when(expr = "{account} confirms the team Bid of {account} for JobOffer {int}")]
+fn confirm_team_bid(w: &mut DaoWorld, member: Account, leader: Account, job_offer_id: u32) {
+    suppress(|| w.confirm_team_bid(job_offer_id, member, leader));
+}
+
+#[then(
+    expr = "{account} cannot confirm the team Bid of {account} for JobOffer {int} due to {word}"
+)]
+fn cannot_confirm_team_bid(
+    w: &mut DaoWorld,
+    member: Account,
+    leader: Account,
+    job_offer_id: u32,
+    error: String,
+) {
+    let error = error
+        .parse::<crate::common::params::Error>()
+        .expect("Valid error expected");
+    test_env::assert_exception(*error, || w.confirm_team_bid(job_offer_id, member, leader));
+}
+
+#[then(expr = "{account} cannot pick the unconfirmed team Bid of {account}")]
+fn cannot_pick_unconfirmed_team_bid(w: &mut DaoWorld, job_poster: Account, leader: Account) {
+    let job_offer_id = *w.get_job_offer_id(&job_poster).unwrap();
+    let bid = w.get_bid(job_offer_id, leader).unwrap();
+    test_env::set_caller(w.get_address(&job_poster));
+    test_env::assert_exception(Error::TeamBidNotConfirmed, || {
+        let bid_escrow = BidEscrowContractRef::at(w.bid_escrow.address());
+        bid_escrow.with_tokens(bid.proposed_payment).pick_bid(
+            job_offer_id,
+            bid.bid_id,
+            bid.proposed_payment,
+        )
+    });
+}
+
+#[when(
+    expr = "{account} posted the sealed Bid for JobOffer {int} with proposed timeframe of {int} {time_unit} and {balance} CSPR price and {reputation} REP stake"
+)]
+fn submit_sealed_bid_internal(
+    w: &mut DaoWorld,
+    worker: Account,
+    job_offer_id: u32,
+    timeframe: BlockTime,
+    time_unit: TimeUnit,
+    budget: CsprBalance,
+    stake: ReputationBalance,
+) {
+    let timeframe = helpers::to_milliseconds(timeframe, time_unit);
+    suppress(|| w.post_sealed_bid(job_offer_id, worker, timeframe, *budget, *stake, None));
+}
+
+#[when(
+    expr = "{account} posted the sealed Bid for JobOffer {int} with proposed timeframe of {int} {time_unit} and {balance} CSPR price and {balance} CSPR stake"
+)]
+fn submit_sealed_bid_external(
+    w: &mut DaoWorld,
+    worker: Account,
+    job_offer_id: u32,
+    timeframe: BlockTime,
+    time_unit: TimeUnit,
+    budget: CsprBalance,
+    stake: CsprBalance,
+) {
+    let timeframe = helpers::to_milliseconds(timeframe, time_unit);
+    suppress(|| {
+        w.post_sealed_bid(
+            job_offer_id,
+            worker,
+            timeframe,
+            *budget,
+            Balance::zero(),
+            Some(*stake),
+        )
+    });
+}
+
+#[when(
+    expr = "{account} reveals the Bid for JobOffer {int} with proposed timeframe of {int} {time_unit} and {balance} CSPR price"
+)]
+fn reveal_bid(
+    w: &mut DaoWorld,
+    worker: Account,
+    job_offer_id: u32,
+    timeframe: BlockTime,
+    time_unit: TimeUnit,
+    budget: CsprBalance,
+) {
+    let timeframe = helpers::to_milliseconds(timeframe, time_unit);
+    suppress(|| w.reveal_bid(job_offer_id, worker, timeframe, *budget));
+}
+
+#[then(
+    expr = "{account} cannot reveal the Bid for JobOffer {int} with proposed timeframe of {int} {time_unit} and {balance} CSPR price"
+)]
+fn cannot_reveal_bid(
+    w: &mut DaoWorld,
+    worker: Account,
+    job_offer_id: u32,
+    timeframe: BlockTime,
+    time_unit: TimeUnit,
+    budget: CsprBalance,
+) {
+    let timeframe = helpers::to_milliseconds(timeframe, time_unit);
+    test_env::assert_exception(Error::BidCommitmentMismatch, || {
+        w.reveal_bid(job_offer_id, worker, timeframe, *budget)
+    });
+}
+
+#[then(expr = "{account} cannot reveal the Bid for JobOffer {int} during the auction")]
+fn cannot_reveal_bid_during_auction(w: &mut DaoWorld, worker: Account, job_offer_id: u32) {
+    let bid = w.get_bid(job_offer_id, worker).unwrap();
+    test_env::set_caller(w.get_address(&worker));
+    test_env::assert_exception(Error::CannotRevealBidDuringAuction, || {
+        let mut bid_escrow = BidEscrowContractRef::at(w.bid_escrow.address());
+        bid_escrow.reveal_bid(bid.bid_id, 0, Balance::zero(), Bytes::from(vec![]))
+    });
+}
+
+#[then(expr = "{account} cannot cancel the sealed Bid for JobOffer {int}")]
+fn cannot_cancel_sealed_bid(w: &mut DaoWorld, worker: Account, job_offer_id: u32) {
+    let bid = w.get_bid(job_offer_id, worker).unwrap();
+    test_env::set_caller(w.get_address(&worker));
+    test_env::assert_exception(Error::CannotCancelSealedBid, || {
+        let mut bid_escrow = BidEscrowContractRef::at(w.bid_escrow.address());
+        bid_escrow.cancel_bid(bid.bid_id)
+    });
+}
+
+#[then(expr = "{account} cannot post a regular Bid for JobOffer {int}")]
+fn cannot_submit_regular_bid(w: &mut DaoWorld, worker: Account, job_offer_id: u32) {
+    test_env::set_caller(w.get_address(&worker));
+    test_env::assert_exception(Error::SealedBidRequired, || {
+        let mut bid_escrow = BidEscrowContractRef::at(w.bid_escrow.address());
+        bid_escrow.submit_bid(
+            job_offer_id,
+            1000,
+            Balance::one(),
+            Balance::one(),
+            false,
+            None,
+        )
+    });
+}
+
+#[then(expr = "{account} cannot pick the unrevealed Bid of {account}")]
+fn cannot_pick_unrevealed_bid(w: &mut DaoWorld, job_poster: Account, worker: Account) {
+    let job_offer_id = *w.get_job_offer_id(&job_poster).unwrap();
+    let bid = w.get_bid(job_offer_id, worker).unwrap();
+    test_env::set_caller(w.get_address(&job_poster));
+    test_env::assert_exception(Error::BidNotRevealed, || {
+        let bid_escrow = BidEscrowContractRef::at(w.bid_escrow.address());
+        bid_escrow.with_tokens(bid.proposed_payment).pick_bid(
+            job_offer_id,
+            bid.bid_id,
+            bid.proposed_payment,
+        )
+    });
+}
+
 #[when(expr = "{account} picked the Bid of {account}")]
 fn bid_picked(w: &mut DaoWorld, job_poster: Account, worker: Account) {
     w.pick_bid(job_poster, worker);
@@ -202,6 +558,12 @@ fn is_job_offer_cancelled(w: &mut DaoWorld, job_offer_id: u32, cancelled: String
     }
 }
 
+#[then(expr = "JobOffer with id {int} has maximum budget of {balance} CSPR")]
+fn assert_job_offer_budget(w: &mut DaoWorld, job_offer_id: u32, maximum_budget: CsprBalance) {
+    let job_offer = w.bid_escrow.get_job_offer(job_offer_id).unwrap();
+    assert_eq!(job_offer.max_budget, *maximum_budget);
+}
+
 #[then(expr = "Job with id {int} {word} cancelled")]
 fn is_job_cancelled(w: &mut DaoWorld, job_id: u32, cancelled: String) {
     let cancelled = parse_bool(cancelled);
@@ -223,6 +585,68 @@ fn submit_onboarding_request(world: &mut DaoWorld, account: Account, cspr_stake:
     });
 }
 
+#[when(expr = "{account} sponsors an onboarding request of {account} with {reputation} REP stake")]
+fn sponsor_onboarding_request(
+    world: &mut DaoWorld,
+    sponsor: Account,
+    candidate: Account,
+    stake: ReputationBalance,
+) {
+    let candidate = world.get_address(&candidate);
+    world.set_caller(&sponsor);
+    world
+        .onboarding
+        .create_sponsored_voting(candidate, DocumentHash::default(), *stake);
+}
+
+#[then(expr = "{account} cannot sponsor an onboarding request of {account} due to {word}")]
+fn cannot_sponsor_onboarding_request(
+    world: &mut DaoWorld,
+    sponsor: Account,
+    candidate: Account,
+    error: String,
+) {
+    let error = error
+        .parse::<crate::common::params::Error>()
+        .expect("Valid error expected");
+    let candidate = world.get_address(&candidate);
+    world.set_caller(&sponsor);
+    test_env::assert_exception(*error, || {
+        let mut onboarding = OnboardingRequestContractRef::at(world.onboarding.address());
+        onboarding.create_sponsored_voting(candidate, DocumentHash::default(), Balance::from(100))
+    });
+}
+
+#[when(expr = "{account} releases the sponsorship of {account}")]
+fn release_sponsorship(world: &mut DaoWorld, caller: Account, va: Account) {
+    let va = world.get_address(&va);
+    world.set_caller(&caller);
+    world.onboarding.release_sponsorship(va);
+}
+
+#[then(expr = "{account} cannot release the sponsorship of {account} due to {word}")]
+fn cannot_release_sponsorship(world: &mut DaoWorld, caller: Account, va: Account, error: String) {
+    let error = error
+        .parse::<crate::common::params::Error>()
+        .expect("Valid error expected");
+    let va = world.get_address(&va);
+    world.set_caller(&caller);
+    test_env::assert_exception(*error, || {
+        let mut onboarding = OnboardingRequestContractRef::at(world.onboarding.address());
+        onboarding.release_sponsorship(va)
+    });
+}
+
+#[then(expr = "{account} sponsors {account}")]
+fn assert_sponsorship(world: &mut DaoWorld, sponsor: Account, va: Account) {
+    let sponsor = world.get_address(&sponsor);
+    let sponsorship = world
+        .onboarding
+        .get_sponsorship(world.get_address(&va))
+        .expect("Sponsorship expected");
+    assert_eq!(sponsorship.sponsor(), sponsor);
+}
+
 #[then(expr = "the JobOffer by {account} {word} posted")]
 fn assert_job_offer_status(world: &mut DaoWorld, job_poster: Account, job_offer_status: String) {
     let offer_id = world.get_job_offer_id(&job_poster);
@@ -239,6 +663,21 @@ fn assert_bid_status(world: &mut DaoWorld) {
     assert_eq!(bid.status, BidStatus::Created);
 }
 
+#[then(expr = "the Bid of {account} for JobOffer {int} is in state {word}")]
+fn assert_worker_bid_status(world: &mut DaoWorld, worker: Account, offer_id: u32, status: String) {
+    let bid = world.get_bid(offer_id, worker).unwrap();
+    let expected = match status.as_str() {
+        "Created" => BidStatus::Created,
+        "Picked" => BidStatus::Picked,
+        "Rejected" => BidStatus::Rejected,
+        "Reclaimed" => BidStatus::Reclaimed,
+        "Canceled" => BidStatus::Canceled,
+        "Sealed" => BidStatus::Sealed,
+        _ => panic!("Unknown bid status {:?}", status),
+    };
+    assert_eq!(bid.status, expected);
+}
+
 #[then(expr = "{account} cannot submit the JobProof of Job {int}")]
 fn cannot_submit_job_proof(w: &mut DaoWorld, worker: Account, job_id: JobId) {
     let worker = w.get_address(&worker);
@@ -259,6 +698,26 @@ fn cannot_submit_job_proof_second_time(w: &mut DaoWorld, worker: Account, job_id
     });
 }
 
+#[when(expr = "BidEscrow stops accepting the payment token")]
+fn remove_payment_token(w: &mut DaoWorld) {
+    let token = *w.payment_token().address();
+    test_env::set_caller(w.get_address(&Account::Owner));
+    w.bid_escrow.remove_payment_token(token);
+}
+
+#[then(expr = "{account} cannot post a JobOffer paid in payment tokens")]
+fn cannot_post_job_offer_with_token(w: &mut DaoWorld, job_poster: Account) {
+    let token = *w.payment_token().address();
+    test_env::set_caller(w.get_address(&job_poster));
+    test_env::assert_exception(Error::PaymentTokenNotWhitelisted, || {
+        let mut bid_escrow = BidEscrowContractRef::at(w.bid_escrow.address());
+        bid_escrow.post_job_offer(JobOfferParams {
+            payment_token: Some(token),
+            ..JobOfferParams::new(1000, Balance::one(), Balance::one())
+        })
+    });
+}
+
 #[then(expr = "{account} fails to pick the Bid of {account}")]
 fn bid_pick_failed(w: &mut DaoWorld, job_poster: Account, worker: Account) {
     w.pick_bid_failed(job_poster, worker);
@@ -273,3 +732,351 @@ fn submit_outdated_job_proof(w: &mut DaoWorld, worker: Account, job_id: JobId) {
             .submit_job_proof(job_id, DocumentHash::from("Job Proof"));
     });
 }
+
+#[when(expr = "{account} requests extending the Job {int} by {int} {time_unit}")]
+fn request_job_extension(
+    w: &mut DaoWorld,
+    worker: Account,
+    job_id: JobId,
+    time: BlockTime,
+    time_unit: TimeUnit,
+) {
+    let time = helpers::to_milliseconds(time, time_unit);
+    test_env::set_caller(w.get_address(&worker));
+    suppress(|| {
+        w.bid_escrow
+            .request_job_extension(job_id, time, DocumentHash::from("Reason"))
+    });
+}
+
+#[when(expr = "{account} {word} the extension of Job {int}")]
+fn respond_to_job_extension(
+    w: &mut DaoWorld,
+    job_poster: Account,
+    response: String,
+    job_id: JobId,
+) {
+    test_env::set_caller(w.get_address(&job_poster));
+    suppress(|| match response.as_str() {
+        "accepts" => w.bid_escrow.accept_job_extension(job_id),
+        "rejects" => w.bid_escrow.reject_job_extension(job_id),
+        _ => panic!("Unknown response"),
+    });
+}
+
+#[then(expr = "{account} cannot accept the extension of Job {int} due to {word}")]
+fn cannot_accept_job_extension(
+    w: &mut DaoWorld,
+    job_poster: Account,
+    job_id: JobId,
+    error: String,
+) {
+    let error = error
+        .parse::<crate::common::params::Error>()
+        .expect("Valid error expected");
+    test_env::set_caller(w.get_address(&job_poster));
+    test_env::assert_exception(*error, || {
+        let mut bid_escrow = BidEscrowContractRef::at(w.bid_escrow.address());
+        bid_escrow.accept_job_extension(job_id)
+    });
+}
+
+#[then(expr = "{account} cannot request extending the Job {int} any more")]
+fn cannot_request_job_extension(w: &mut DaoWorld, worker: Account, job_id: JobId) {
+    test_env::set_caller(w.get_address(&worker));
+    test_env::assert_exception(Error::JobExtensionsLimitReached, || {
+        let mut bid_escrow = BidEscrowContractRef::at(w.bid_escrow.address());
+        bid_escrow.request_job_extension(job_id, 1000, DocumentHash::from("Reason"))
+    });
+}
+
+#[then(expr = "Job with id {int} has {int} extension(s)")]
+fn assert_job_extensions(w: &mut DaoWorld, job_id: JobId, extensions: u32) {
+    let job = w.bid_escrow.get_job(job_id).unwrap();
+    assert_eq!(job.extensions(), extensions);
+    assert!(job.pending_extension().is_none());
+}
+
+#[then(expr = "{account} has {int} completed, {int} rejected and {int} cancelled Job(s)")]
+fn assert_worker_jobs(
+    w: &mut DaoWorld,
+    worker: Account,
+    completed: u32,
+    rejected: u32,
+    cancelled: u32,
+) {
+    let stats = w.bid_escrow.get_worker_stats(w.get_address(&worker));
+    assert_eq!(stats.jobs_completed, completed);
+    assert_eq!(stats.jobs_rejected, rejected);
+    assert_eq!(stats.jobs_cancelled, cancelled);
+}
+
+#[then(expr = "{account} has been paid {balance} CSPR with the average delay of {int} {time_unit}")]
+fn assert_worker_payment_and_delay(
+    w: &mut DaoWorld,
+    worker: Account,
+    total_paid: CsprBalance,
+    delay: BlockTime,
+    time_unit: TimeUnit,
+) {
+    let stats = w.bid_escrow.get_worker_stats(w.get_address(&worker));
+    assert_eq!(stats.total_paid, *total_paid);
+    assert_eq!(
+        stats.average_delay,
+        helpers::to_milliseconds(delay, time_unit)
+    );
+}
+
+#[when(expr = "{account} settles expired JobOffers and Jobs with limit {int}")]
+fn process_expired(w: &mut DaoWorld, keeper: Account, limit: u32) {
+    test_env::set_caller(w.get_address(&keeper));
+    w.bid_escrow.process_expired(limit);
+}
+
+#[then(expr = "JobOffer with id {int} is expired")]
+fn is_job_offer_expired(w: &mut DaoWorld, job_offer_id: u32) {
+    let job_offer = w.bid_escrow.get_job_offer(job_offer_id).unwrap();
+    assert_eq!(job_offer.status, JobOfferStatus::Expired);
+}
+
+#[when(expr = "{account} collects the Insurance Pool deposits")]
+fn collect_insurance_pool_deposits(w: &mut DaoWorld, caller: Account) {
+    w.set_caller(&caller);
+    w.insurance_pool.collect_deposits();
+}
+
+#[then(expr = "{account} cannot collect the Insurance Pool deposits due to {word}")]
+fn cannot_collect_insurance_pool_deposits(w: &mut DaoWorld, caller: Account, error: String) {
+    let error = error
+        .parse::<crate::common::params::Error>()
+        .expect("Valid error expected");
+    w.set_caller(&caller);
+    test_env::assert_exception(*error, || {
+        let mut insurance_pool = InsurancePoolContractRef::at(w.insurance_pool.address());
+        insurance_pool.collect_deposits()
+    });
+}
+
+#[when(expr = "{account} files an insurance claim for Job {int} of {balance} CSPR")]
+fn file_insurance_claim(w: &mut DaoWorld, job_poster: Account, job_id: JobId, amount: CsprBalance) {
+    w.set_caller(&job_poster);
+    w.insurance_pool
+        .file_claim(job_id, *amount, DocumentHash::from("Defect"));
+}
+
+#[then(
+    expr = "{account} cannot file an insurance claim for Job {int} of {balance} CSPR due to {word}"
+)]
+fn cannot_file_insurance_claim(
+    w: &mut DaoWorld,
+    job_poster: Account,
+    job_id: JobId,
+    amount: CsprBalance,
+    error: String,
+) {
+    let error = error
+        .parse::<crate::common::params::Error>()
+        .expect("Valid error expected");
+    w.set_caller(&job_poster);
+    test_env::assert_exception(*error, || {
+        let mut insurance_pool = InsurancePoolContractRef::at(w.insurance_pool.address());
+        insurance_pool.file_claim(job_id, *amount, DocumentHash::from("Defect"))
+    });
+}
+
+#[allow(clippy::too_many_arguments)]
+#[when(
+    expr = "{account} posted a JobOffer in category {word} requiring {word} with expected timeframe of {int} {time_unit}, maximum budget of {balance} CSPR and {balance} CSPR DOS Fee"
+)]
+fn post_categorized_job_offer(
+    w: &mut DaoWorld,
+    job_poster: Account,
+    category: String,
+    skills: String,
+    timeframe: BlockTime,
+    time_unit: TimeUnit,
+    maximum_budget: CsprBalance,
+    dos_fee: CsprBalance,
+) {
+    let timeframe = helpers::to_milliseconds(timeframe, time_unit);
+    let skills = helpers::parse_list(&skills);
+    w.post_categorized_offer(
+        job_poster,
+        timeframe,
+        *maximum_budget,
+        *dos_fee,
+        category,
+        skills,
+    );
+}
+
+#[then(expr = "{account} cannot post a JobOffer in category {word} requiring {word} due to {word}")]
+fn cannot_post_categorized_job_offer(
+    w: &mut DaoWorld,
+    job_poster: Account,
+    category: String,
+    skills: String,
+    error: String,
+) {
+    let error = error
+        .parse::<crate::common::params::Error>()
+        .expect("Valid error expected");
+    let skills = helpers::parse_list(&skills);
+    let dos_fee = Balance::from(400_000_000_000u64);
+    w.set_caller(&job_poster);
+    test_env::assert_exception(*error, || {
+        let bid_escrow = BidEscrowContractRef::at(w.bid_escrow.address());
+        bid_escrow
+            .with_tokens(dos_fee)
+            .post_job_offer(JobOfferParams {
+                category: Some(category),
+                skills,
+                ..JobOfferParams::new(1000, dos_fee, dos_fee)
+            })
+    });
+}
+
+#[when(expr = "{account} declares skills {word}")]
+fn declare_skills(w: &mut DaoWorld, va: Account, skills: String) {
+    w.set_caller(&va);
+    w.bid_escrow.declare_skills(helpers::parse_list(&skills));
+}
+
+#[then(expr = "{account} cannot declare skills {word} due to {word}")]
+fn cannot_declare_skills(w: &mut DaoWorld, account: Account, skills: String, error: String) {
+    let error = error
+        .parse::<crate::common::params::Error>()
+        .expect("Valid error expected");
+    w.set_caller(&account);
+    test_env::assert_exception(*error, || {
+        let mut bid_escrow = BidEscrowContractRef::at(w.bid_escrow.address());
+        bid_escrow.declare_skills(helpers::parse_list(&skills))
+    });
+}
+
+#[then(expr = "skills of {account} are {word}")]
+fn assert_skills(w: &mut DaoWorld, account: Account, skills: String) {
+    let address = w.get_address(&account);
+    assert_eq!(
+        w.bid_escrow.get_skills(address),
+        helpers::parse_list(&skills)
+    );
+}
+
+#[then(expr = "open JobOffers in category {word} are {word}")]
+fn assert_open_job_offers_in_category(w: &mut DaoWorld, category: String, ids: String) {
+    let expected: Vec<u32> = helpers::parse_list(&ids)
+        .iter()
+        .map(|id| id.parse().unwrap())
+        .collect();
+    assert_eq!(
+        w.bid_escrow.get_open_job_offers_by_category(category),
+        expected
+    );
+}
+
+#[then(expr = "{account} cannot post the Bid for JobOffer {int} due to {word}")]
+fn cannot_submit_bid(w: &mut DaoWorld, worker: Account, job_offer_id: u32, error: String) {
+    let error = error
+        .parse::<crate::common::params::Error>()
+        .expect("Valid error expected");
+    w.set_caller(&worker);
+    test_env::assert_exception(*error, || {
+        let mut bid_escrow = BidEscrowContractRef::at(w.bid_escrow.address());
+        bid_escrow.submit_bid(
+            job_offer_id,
+            1000,
+            Balance::one(),
+            Balance::one(),
+            false,
+            None,
+        )
+    });
+}
+
+#[when(
+    expr = "{account} posted a JobOffer recurring {int} times with expected timeframe of {int} {time_unit}, maximum budget of {balance} CSPR and {balance} CSPR DOS Fee"
+)]
+fn post_recurring_job_offer(
+    w: &mut DaoWorld,
+    job_poster: Account,
+    periods: u32,
+    timeframe: BlockTime,
+    time_unit: TimeUnit,
+    maximum_budget: CsprBalance,
+    dos_fee: CsprBalance,
+) {
+    let timeframe = helpers::to_milliseconds(timeframe, time_unit);
+    w.post_recurring_offer(job_poster, timeframe, *maximum_budget, *dos_fee, periods);
+}
+
+#[then(expr = "{account} cannot pick the Bid of {account} paying for a single period")]
+fn cannot_pick_recurring_bid_paying_once(w: &mut DaoWorld, job_poster: Account, worker: Account) {
+    let job_offer_id = *w.get_job_offer_id(&job_poster).unwrap();
+    let bid = w.get_bid(job_offer_id, worker).unwrap();
+    w.set_caller(&job_poster);
+    test_env::assert_exception(Error::PurseBalanceMismatch, || {
+        let bid_escrow = BidEscrowContractRef::at(w.bid_escrow.address());
+        bid_escrow.with_tokens(bid.proposed_payment).pick_bid(
+            job_offer_id,
+            bid.bid_id,
+            bid.proposed_payment,
+        )
+    });
+}
+
+#[then(expr = "{account} cannot post the Bid for JobOffer {int} with onboarding due to {word}")]
+fn cannot_submit_onboarding_bid(
+    w: &mut DaoWorld,
+    worker: Account,
+    job_offer_id: u32,
+    error: String,
+) {
+    let error = error
+        .parse::<crate::common::params::Error>()
+        .expect("Valid error expected");
+    let cspr_stake = Balance::from(100_000_000_000u64);
+    w.set_caller(&worker);
+    test_env::assert_exception(*error, || {
+        let bid_escrow = BidEscrowContractRef::at(w.bid_escrow.address());
+        bid_escrow.with_tokens(cspr_stake).submit_bid(
+            job_offer_id,
+            1000,
+            Balance::one(),
+            Balance::zero(),
+            true,
+            Some(cspr_stake),
+        )
+    });
+}
+
+#[when(expr = "{account} terminates the Job {int}")]
+fn terminate_job(w: &mut DaoWorld, caller: Account, job_id: JobId) {
+    w.set_caller(&caller);
+    w.bid_escrow.terminate_job(job_id);
+}
+
+#[then(expr = "{account} cannot terminate the Job {int} due to {word}")]
+fn cannot_terminate_job(w: &mut DaoWorld, caller: Account, job_id: JobId, error: String) {
+    let error = error
+        .parse::<crate::common::params::Error>()
+        .expect("Valid error expected");
+    w.set_caller(&caller);
+    test_env::assert_exception(*error, || {
+        let mut bid_escrow = BidEscrowContractRef::at(w.bid_escrow.address());
+        bid_escrow.terminate_job(job_id)
+    });
+}
+
+#[then(expr = "Job {int} is in period {int} of {int}")]
+fn assert_job_period(w: &mut DaoWorld, job_id: JobId, period: u32, periods: u32) {
+    let job = w.bid_escrow.get_job(job_id).unwrap();
+    assert_eq!(job.period(), period);
+    assert_eq!(job.periods(), periods);
+}
+
+#[then(expr = "Job {int} is followed by Job {int}")]
+fn assert_job_followed_by(w: &mut DaoWorld, job_id: JobId, next_job_id: JobId) {
+    let job = w.bid_escrow.get_job(job_id).unwrap();
+    assert_eq!(job.followed_by(), Some(next_job_id));
+}
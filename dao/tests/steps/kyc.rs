@@ -1,7 +1,10 @@
-use cucumber::{given, then, when};
+use cucumber::{gherkin::Step, given, then, when};
+use dao::utils::types::KycLevel;
+use odra::test_env;
 
 use crate::common::{
-    params::{Account, Contract, TokenId},
+    helpers::to_milliseconds,
+    params::{Account, Contract, TimeUnit, TokenId},
     DaoWorld,
 };
 
@@ -49,10 +52,192 @@ fn assert_total_supply(world: &mut DaoWorld, expected_total_supply: u32) {
 
 #[then(expr = "{account} is kyced")]
 fn assert_kyced(world: &mut DaoWorld, account: Account) {
-    assert!(world.has_nft_token(Contract::KycToken, &account));
+    assert!(world.is_kycd(&account));
 }
 
 #[then(expr = "{account} is not kyced")]
 fn assert_not_kyced(world: &mut DaoWorld, account: Account) {
-    assert!(!world.has_nft_token(Contract::KycToken, &account));
+    assert!(!world.is_kycd(&account));
+}
+
+#[then(expr = "KYC of {account} is valid for {int} days")]
+fn assert_kyc_validity(world: &mut DaoWorld, account: Account, days: u64) {
+    let (issued_at, expires_at) = world.kyc_validity(&account);
+    assert_eq!(
+        expires_at.map(|expires_at| expires_at - issued_at),
+        Some(to_milliseconds(days, TimeUnit::Days))
+    );
+}
+
+#[then(expr = "KYC of {account} expires within {int} days")]
+fn assert_kyc_expires(world: &mut DaoWorld, account: Account, days: u64) {
+    let address = world.get_address(&account);
+    assert!(world
+        .get_expiring_kycs(to_milliseconds(days, TimeUnit::Days))
+        .contains(&address));
+}
+
+#[then(expr = "KYC of {account} does not expire within {int} days")]
+fn assert_kyc_does_not_expire(world: &mut DaoWorld, account: Account, days: u64) {
+    let address = world.get_address(&account);
+    assert!(!world
+        .get_expiring_kycs(to_milliseconds(days, TimeUnit::Days))
+        .contains(&address));
+}
+
+#[given(expr = "{account} has KYC level {int}")]
+fn upgrade_kyc_level(world: &mut DaoWorld, account: Account, level: KycLevel) {
+    world.upgrade_kyc_level(&account, level);
+}
+
+#[then(expr = "KYC level of {account} is {int}")]
+fn assert_kyc_level(world: &mut DaoWorld, account: Account, level: KycLevel) {
+    assert_eq!(world.kyc_level(&account), level);
+}
+
+#[then(expr = "KYC Token of {account} has been issued by voting {int}")]
+fn assert_issuing_voting(world: &mut DaoWorld, account: Account, voting_id: u32) {
+    let metadata = world.nft_metadata(Contract::KycToken, &account);
+    assert_eq!(metadata.voting_id, Some(voting_id));
+}
+
+#[then(expr = "KYC Token of {account} has not been issued by a voting")]
+fn assert_no_issuing_voting(world: &mut DaoWorld, account: Account) {
+    let metadata = world.nft_metadata(Contract::KycToken, &account);
+    assert_eq!(metadata.voting_id, None);
+}
+
+#[then(expr = "the token uri of KYC Token of {account} is")]
+fn assert_token_uri(world: &mut DaoWorld, step: &Step, account: Account) {
+    let expected = step.docstring.as_ref().expect("Token uri expected").trim();
+    assert_eq!(world.nft_token_uri(Contract::KycToken, &account), expected);
+}
+
+#[then(expr = "KYC Token exposes the CEP-78 configuration")]
+fn assert_cep78_configuration(world: &mut DaoWorld, step: &Step) {
+    let rows = step.table.as_ref().unwrap().rows.iter().skip(1);
+    for row in rows {
+        let key = row[0].as_str();
+        let value = row[1].as_str();
+        assert_eq!(
+            world.nft_cep78_named_key(Contract::KycToken, key),
+            value,
+            "Unexpected value of {}",
+            key
+        );
+    }
+}
+
+#[then(expr = "KYC Token publishes the CEP-78 metadata schema")]
+fn assert_cep78_json_schema(world: &mut DaoWorld, step: &Step) {
+    let expected = step.docstring.as_ref().expect("Schema expected").trim();
+    assert_eq!(
+        world.nft_cep78_named_key(Contract::KycToken, "json_schema"),
+        expected
+    );
+}
+
+#[then(expr = "the CEP-78 metadata of KYC Token of {account} is")]
+fn assert_cep78_metadata(world: &mut DaoWorld, step: &Step, account: Account) {
+    let expected = step.docstring.as_ref().expect("Metadata expected").trim();
+    assert_eq!(
+        world.nft_cep78_metadata(Contract::KycToken, &account),
+        expected
+    );
+}
+
+#[then(expr = "{account} is a registered KYC provider")]
+fn assert_kyc_provider(world: &mut DaoWorld, account: Account) {
+    assert!(world.is_kyc_provider(&account));
+}
+
+#[then(expr = "{account} is not a registered KYC provider")]
+fn assert_not_kyc_provider(world: &mut DaoWorld, account: Account) {
+    assert!(!world.is_kyc_provider(&account));
+}
+
+#[when(
+    expr = "{account} submits a KYC attestation of {account} by {account} expiring on day {int}"
+)]
+fn attest_kyc(
+    world: &mut DaoWorld,
+    sender: Account,
+    subject: Account,
+    provider: Account,
+    day: u64,
+) {
+    let expires_at = to_milliseconds(day, TimeUnit::Days);
+    world.attest_kyc(&sender, &subject, &provider, &provider, expires_at);
+}
+
+#[then(
+    expr = "{account} cannot submit a KYC attestation of {account} by {account} expiring on day {int} due to {word}"
+)]
+fn cannot_attest_kyc(
+    world: &mut DaoWorld,
+    sender: Account,
+    subject: Account,
+    provider: Account,
+    day: u64,
+    error: String,
+) {
+    let error = error
+        .parse::<crate::common::params::Error>()
+        .expect("Valid error expected");
+    let expires_at = to_milliseconds(day, TimeUnit::Days);
+    test_env::assert_exception(*error, || {
+        world.attest_kyc(&sender, &subject, &provider, &provider, expires_at)
+    });
+}
+
+#[when(expr = "{account} submits a KYC attestation of {account} by {account} made for {word}")]
+fn attest_kyc_on_chain(
+    world: &mut DaoWorld,
+    sender: Account,
+    subject: Account,
+    provider: Account,
+    chain_name: String,
+) {
+    let expires_at = to_milliseconds(365, TimeUnit::Days);
+    world.attest_kyc_on_chain(&sender, &subject, &provider, &chain_name, expires_at);
+}
+
+#[then(
+    expr = "{account} cannot submit a KYC attestation of {account} by {account} made for {word} due to {word}"
+)]
+fn cannot_attest_kyc_on_chain(
+    world: &mut DaoWorld,
+    sender: Account,
+    subject: Account,
+    provider: Account,
+    chain_name: String,
+    error: String,
+) {
+    let error = error
+        .parse::<crate::common::params::Error>()
+        .expect("Valid error expected");
+    let expires_at = to_milliseconds(365, TimeUnit::Days);
+    test_env::assert_exception(*error, || {
+        world.attest_kyc_on_chain(&sender, &subject, &provider, &chain_name, expires_at)
+    });
+}
+
+#[then(
+    expr = "{account} cannot submit a KYC attestation of {account} by {account} signed by {account} due to {word}"
+)]
+fn cannot_attest_kyc_with_forged_signature(
+    world: &mut DaoWorld,
+    sender: Account,
+    subject: Account,
+    provider: Account,
+    signer: Account,
+    error: String,
+) {
+    let error = error
+        .parse::<crate::common::params::Error>()
+        .expect("Valid error expected");
+    let expires_at = to_milliseconds(365, TimeUnit::Days);
+    test_env::assert_exception(*error, || {
+        world.attest_kyc(&sender, &subject, &provider, &signer, expires_at)
+    });
 }
@@ -1,6 +1,11 @@
-use cucumber::{given, then, when};
+use cucumber::{gherkin::Step, given, then, when};
+use dao::modules::{OwnerAction, OwnerActionArgs};
+use odra::test_env;
 
-use crate::common::{params::Account, DaoWorld};
+use crate::common::{
+    params::{Account, Error, Role},
+    DaoWorld,
+};
 
 use super::suppress;
 
@@ -9,17 +14,26 @@ fn change_ownership(world: &mut DaoWorld, caller: Account, new_owner: Account, c
     suppress(|| world.change_ownership(&contract, &caller, &new_owner));
 }
 
-#[when(expr = "{account} adds {account} to whitelist in {account} contract")]
-#[given(expr = "{account} added {account} to whitelist in {account} contract")]
-fn whitelist(world: &mut DaoWorld, caller: Account, target: Account, contract: Account) {
-    suppress(|| world.whitelist_account(&contract, &caller, &target));
-}
-
 #[when(expr = "{account} removes {account} from whitelist in {account} contract")]
 fn remove_from_whitelist(world: &mut DaoWorld, caller: Account, user: Account, contract: Account) {
     suppress(|| world.remove_from_whitelist(&contract, &caller, &user));
 }
 
+#[then(
+    expr = "{account} cannot remove {account} from whitelist in {account} contract due to {word}"
+)]
+fn cannot_remove_from_whitelist(
+    world: &mut DaoWorld,
+    caller: Account,
+    user: Account,
+    contract: Account,
+    error: Error,
+) {
+    test_env::assert_exception(*error, || {
+        world.remove_from_whitelist(&contract, &caller, &user)
+    });
+}
+
 #[then(expr = "{account} is not whitelisted in {account} contract")]
 fn assert_not_whitelisted(world: &mut DaoWorld, account: Account, contract: Account) {
     assert!(!world.is_whitelisted(&contract, &account));
@@ -68,3 +82,286 @@ fn assert_not_proposed_ownership(world: &mut DaoWorld, user: Account, contract:
     let owner = world.get_owner(&contract);
     assert_ne!(owner, Some(user_address));
 }
+
+#[when(expr = "{account} grants {word} role to {account} in {account} contract")]
+#[given(expr = "{account} granted {word} role to {account} in {account} contract")]
+fn grant_role(world: &mut DaoWorld, caller: Account, role: Role, user: Account, contract: Account) {
+    suppress(|| world.grant_role(&contract, &caller, *role, &user));
+}
+
+#[when(expr = "{account} revokes {word} role from {account} in {account} contract")]
+fn revoke_role(
+    world: &mut DaoWorld,
+    caller: Account,
+    role: Role,
+    user: Account,
+    contract: Account,
+) {
+    suppress(|| world.revoke_role(&contract, &caller, *role, &user));
+}
+
+#[when(expr = "{account} sets {word} as the admin role of {word} in {account} contract")]
+fn set_role_admin(
+    world: &mut DaoWorld,
+    caller: Account,
+    admin_role: Role,
+    role: Role,
+    contract: Account,
+) {
+    suppress(|| world.set_role_admin(&contract, &caller, *role, *admin_role));
+}
+
+#[when(expr = "{account} migrates {account} from whitelist to {word} role in {account} contract")]
+fn migrate_to_roles(
+    world: &mut DaoWorld,
+    caller: Account,
+    user: Account,
+    role: Role,
+    contract: Account,
+) {
+    suppress(|| world.migrate_to_roles(&contract, &caller, &user, vec![*role]));
+}
+
+#[then(
+    expr = "{account} cannot migrate {account} from whitelist to {word} role in {account} contract due to {word}"
+)]
+fn cannot_migrate_to_roles(
+    world: &mut DaoWorld,
+    caller: Account,
+    user: Account,
+    role: Role,
+    contract: Account,
+    error: Error,
+) {
+    test_env::assert_exception(*error, || {
+        world.migrate_to_roles(&contract, &caller, &user, vec![*role])
+    });
+}
+
+#[then(expr = "{account} has {word} role in {account} contract")]
+fn assert_has_role(world: &mut DaoWorld, account: Account, role: Role, contract: Account) {
+    assert!(
+        world.has_role(&contract, *role, &account),
+        "{:?} does not have {:?} role in {:?}",
+        account,
+        *role,
+        contract
+    );
+}
+
+#[then(expr = "{account} does not have {word} role in {account} contract")]
+fn assert_has_no_role(world: &mut DaoWorld, account: Account, role: Role, contract: Account) {
+    assert!(!world.has_role(&contract, *role, &account));
+}
+
+#[then(expr = "{word} is the admin role of {word} in {account} contract")]
+fn assert_role_admin(world: &mut DaoWorld, admin_role: Role, role: Role, contract: Account) {
+    assert_eq!(world.get_role_admin(&contract, *role), *admin_role);
+}
+
+#[then(
+    expr = "{account} cannot grant {word} role to {account} in {account} contract due to {word}"
+)]
+fn cannot_grant_role(
+    world: &mut DaoWorld,
+    caller: Account,
+    role: Role,
+    user: Account,
+    contract: Account,
+    error: Error,
+) {
+    test_env::assert_exception(*error, || {
+        world.grant_role(&contract, &caller, *role, &user)
+    });
+}
+
+#[given(expr = "{account} enabled multisig ownership in {account} contract with threshold {int}")]
+#[when(expr = "{account} enables multisig ownership in {account} contract with threshold {int}")]
+fn enable_multisig_ownership(
+    world: &mut DaoWorld,
+    step: &Step,
+    caller: Account,
+    contract: Account,
+    threshold: u32,
+) {
+    let signers = step
+        .table
+        .as_ref()
+        .unwrap()
+        .rows
+        .iter()
+        .skip(1)
+        .map(|row| row[0].parse().unwrap())
+        .collect();
+    suppress(|| world.enable_multisig_ownership(&contract, &caller, signers, threshold));
+}
+
+#[when(expr = "{account} proposes to grant {word} role to {account} in {account} contract")]
+fn propose_grant_role(
+    world: &mut DaoWorld,
+    caller: Account,
+    role: Role,
+    user: Account,
+    contract: Account,
+) {
+    let args = OwnerActionArgs {
+        address: Some(world.get_address(&user)),
+        role: Some(*role),
+        ..Default::default()
+    };
+    suppress(|| world.propose_owner_action(&contract, &caller, OwnerAction::GrantRole, args));
+}
+
+#[when(expr = "{account} proposes {account} as a new owner of {account} contract")]
+fn propose_new_owner(world: &mut DaoWorld, caller: Account, user: Account, contract: Account) {
+    let args = OwnerActionArgs {
+        address: Some(world.get_address(&user)),
+        ..Default::default()
+    };
+    suppress(|| world.propose_owner_action(&contract, &caller, OwnerAction::ProposeNewOwner, args));
+}
+
+#[when(expr = "{account} proposes to add {account} to signers in {account} contract")]
+fn propose_add_signer(world: &mut DaoWorld, caller: Account, user: Account, contract: Account) {
+    let args = OwnerActionArgs {
+        address: Some(world.get_address(&user)),
+        ..Default::default()
+    };
+    suppress(|| world.propose_owner_action(&contract, &caller, OwnerAction::AddSigner, args));
+}
+
+#[when(expr = "{account} proposes to remove {account} from signers in {account} contract")]
+fn propose_remove_signer(world: &mut DaoWorld, caller: Account, user: Account, contract: Account) {
+    let args = OwnerActionArgs {
+        address: Some(world.get_address(&user)),
+        ..Default::default()
+    };
+    suppress(|| world.propose_owner_action(&contract, &caller, OwnerAction::RemoveSigner, args));
+}
+
+#[when(expr = "{account} proposes to change the threshold to {int} in {account} contract")]
+fn propose_change_threshold(
+    world: &mut DaoWorld,
+    caller: Account,
+    threshold: u32,
+    contract: Account,
+) {
+    let args = OwnerActionArgs {
+        threshold: Some(threshold),
+        ..Default::default()
+    };
+    suppress(|| world.propose_owner_action(&contract, &caller, OwnerAction::ChangeThreshold, args));
+}
+
+#[when(expr = "{account} approves owner action {int} in {account} contract")]
+fn approve_owner_action(
+    world: &mut DaoWorld,
+    caller: Account,
+    proposal_id: u32,
+    contract: Account,
+) {
+    suppress(|| world.approve_owner_action(&contract, &caller, proposal_id));
+}
+
+#[when(expr = "{account} executes owner action {int} in {account} contract")]
+fn execute_owner_action(
+    world: &mut DaoWorld,
+    caller: Account,
+    proposal_id: u32,
+    contract: Account,
+) {
+    suppress(|| world.execute_owner_action(&contract, &caller, proposal_id));
+}
+
+#[then(
+    expr = "{account} cannot propose to grant {word} role to {account} in {account} contract due to {word}"
+)]
+fn cannot_propose_owner_action(
+    world: &mut DaoWorld,
+    caller: Account,
+    role: Role,
+    user: Account,
+    contract: Account,
+    error: Error,
+) {
+    let args = OwnerActionArgs {
+        address: Some(world.get_address(&user)),
+        role: Some(*role),
+        ..Default::default()
+    };
+    test_env::assert_exception(*error, || {
+        world.propose_owner_action(&contract, &caller, OwnerAction::GrantRole, args);
+    });
+}
+
+#[then(expr = "{account} cannot approve owner action {int} in {account} contract due to {word}")]
+fn cannot_approve_owner_action(
+    world: &mut DaoWorld,
+    caller: Account,
+    proposal_id: u32,
+    contract: Account,
+    error: Error,
+) {
+    test_env::assert_exception(*error, || {
+        world.approve_owner_action(&contract, &caller, proposal_id)
+    });
+}
+
+#[then(expr = "{account} cannot execute owner action {int} in {account} contract due to {word}")]
+fn cannot_execute_owner_action(
+    world: &mut DaoWorld,
+    caller: Account,
+    proposal_id: u32,
+    contract: Account,
+    error: Error,
+) {
+    test_env::assert_exception(*error, || {
+        world.execute_owner_action(&contract, &caller, proposal_id)
+    });
+}
+
+#[then(expr = "{account} is a signer in {account} contract")]
+fn assert_signer(world: &mut DaoWorld, account: Account, contract: Account) {
+    assert!(world.is_signer(&contract, &account));
+}
+
+#[then(expr = "{account} is not a signer in {account} contract")]
+fn assert_not_signer(world: &mut DaoWorld, account: Account, contract: Account) {
+    assert!(!world.is_signer(&contract, &account));
+}
+
+#[then(expr = "the threshold of {account} contract is {int}")]
+fn assert_threshold(world: &mut DaoWorld, contract: Account, threshold: u32) {
+    assert_eq!(world.get_threshold(&contract), threshold);
+}
+
+#[when(expr = "{account} pauses {account} contract")]
+#[given(expr = "{account} paused {account} contract")]
+fn pause(world: &mut DaoWorld, caller: Account, contract: Account) {
+    suppress(|| world.pause(&contract, &caller));
+}
+
+#[when(expr = "{account} unpauses {account} contract")]
+fn unpause(world: &mut DaoWorld, caller: Account, contract: Account) {
+    suppress(|| world.unpause(&contract, &caller));
+}
+
+#[then(expr = "{account} cannot pause {account} contract due to {word}")]
+fn cannot_pause(world: &mut DaoWorld, caller: Account, contract: Account, error: Error) {
+    test_env::assert_exception(*error, || world.pause(&contract, &caller));
+}
+
+#[then(expr = "{account} cannot unpause {account} contract due to {word}")]
+fn cannot_unpause(world: &mut DaoWorld, caller: Account, contract: Account, error: Error) {
+    test_env::assert_exception(*error, || world.unpause(&contract, &caller));
+}
+
+#[then(expr = "{account} contract is paused")]
+fn assert_paused(world: &mut DaoWorld, contract: Account) {
+    assert!(world.is_paused(&contract));
+}
+
+#[then(expr = "{account} contract is not paused")]
+fn assert_not_paused(world: &mut DaoWorld, contract: Account) {
+    assert!(!world.is_paused(&contract));
+}
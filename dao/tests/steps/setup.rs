@@ -26,10 +26,6 @@ fn users_setup(world: &mut DaoWorld, step: &Step) {
         let reputation_balance = config.reputation_balance();
         let cspr_balance = config.cspr_balance();
 
-        for contract in config.get_contracts_to_be_whitelisted_in() {
-            world.whitelist_account(contract, &owner, account);
-        }
-
         if config.is_kyced() {
             world.mint_nft_token(Contract::KycToken, &owner, account);
         }
@@ -1,7 +1,9 @@
-use cucumber::{given, then, when};
+use cucumber::{gherkin::Step, given, then, when};
+use dao::voting_contracts::OffboardingRequestContractRef;
+use odra::test_env;
 
 use crate::common::{
-    params::{Account, Contract, TokenId},
+    params::{Account, Contract, Error, TokenId},
     DaoWorld,
 };
 
@@ -24,6 +26,20 @@ fn burn(world: &mut DaoWorld, burner: Account, holder: Account) {
     suppress(|| world.burn_nft_token(Contract::VaToken, &burner, &holder));
 }
 
+#[then(expr = "{account} cannot mint a VA Token to {account} due to {word}")]
+fn cannot_mint(world: &mut DaoWorld, minter: Account, recipient: Account, error: Error) {
+    test_env::assert_exception(*error, || {
+        world.mint_nft_token(Contract::VaToken, &minter, &recipient)
+    });
+}
+
+#[then(expr = "{account} cannot burn {account}'s VA token due to {word}")]
+fn cannot_burn(world: &mut DaoWorld, burner: Account, holder: Account, error: Error) {
+    test_env::assert_exception(*error, || {
+        world.burn_nft_token(Contract::VaToken, &burner, &holder)
+    });
+}
+
 #[then(expr = "the {account}'s balance of VA Token is {int}")]
 fn assert_balance(world: &mut DaoWorld, user: Account, expected_balance: u32) {
     assert_eq!(
@@ -56,3 +72,60 @@ fn assert_is_va(world: &mut DaoWorld, va: Account) {
 fn is_not_va(world: &mut DaoWorld, va: Account) {
     assert!(!world.has_nft_token(Contract::VaToken, &va));
 }
+
+#[then(expr = "VA Token of {account} has been issued by voting {int}")]
+fn assert_issuing_voting(world: &mut DaoWorld, va: Account, voting_id: u32) {
+    let metadata = world.nft_metadata(Contract::VaToken, &va);
+    assert_eq!(metadata.voting_id, Some(voting_id));
+}
+
+#[then(expr = "VA Token exposes the CEP-78 configuration")]
+fn assert_cep78_configuration(world: &mut DaoWorld, step: &Step) {
+    let rows = step.table.as_ref().unwrap().rows.iter().skip(1);
+    for row in rows {
+        let key = row[0].as_str();
+        let value = row[1].as_str();
+        assert_eq!(
+            world.nft_cep78_named_key(Contract::VaToken, key),
+            value,
+            "Unexpected value of {}",
+            key
+        );
+    }
+}
+
+#[then(expr = "VA Token publishes the CEP-78 metadata schema")]
+fn assert_cep78_json_schema(world: &mut DaoWorld, step: &Step) {
+    let expected = step.docstring.as_ref().expect("Schema expected").trim();
+    assert_eq!(
+        world.nft_cep78_named_key(Contract::VaToken, "json_schema"),
+        expected
+    );
+}
+
+#[then(expr = "the CEP-78 metadata of VA Token of {account} is")]
+fn assert_cep78_metadata(world: &mut DaoWorld, step: &Step, account: Account) {
+    let expected = step.docstring.as_ref().expect("Metadata expected").trim();
+    assert_eq!(
+        world.nft_cep78_metadata(Contract::VaToken, &account),
+        expected
+    );
+}
+
+#[when(expr = "{account} requests offboarding")]
+fn request_offboarding(world: &mut DaoWorld, va: Account) {
+    world.set_caller(&va);
+    world.offboarding.request_offboarding();
+}
+
+#[then(expr = "{account} cannot request offboarding due to {word}")]
+fn cannot_request_offboarding(world: &mut DaoWorld, account: Account, error: String) {
+    let error = error
+        .parse::<crate::common::params::Error>()
+        .expect("Valid error expected");
+    world.set_caller(&account);
+    test_env::assert_exception(*error, || {
+        let mut offboarding = OffboardingRequestContractRef::at(world.offboarding.address());
+        offboarding.request_offboarding()
+    });
+}
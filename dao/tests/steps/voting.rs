@@ -67,6 +67,21 @@ fn create_test_voting(
     world.create_test_voting(contract, creator, stake);
 }
 
+#[then(
+    expr = "{account} cannot create test voting in {contract} with {reputation} stake due to {word}"
+)]
+fn cannot_create_test_voting(
+    world: &mut DaoWorld,
+    creator: Account,
+    contract: Contract,
+    stake: ReputationBalance,
+    error: Error,
+) {
+    test_env::assert_exception(*error, || {
+        world.create_test_voting(contract, creator, stake)
+    });
+}
+
 #[when(expr = "{voting_type} voting with id {int} ends in {account} contract")]
 fn end_voting(world: &mut DaoWorld, voting_type: VotingType, voting_id: u32, contract: Account) {
     world.finish_voting(&contract, voting_id, Some(voting_type));
@@ -94,6 +109,26 @@ fn cannot_cancel_finished_voting(
     });
 }
 
+#[when(expr = "{account} vetoes the account recovery with id {int}")]
+fn veto_recovery(world: &mut DaoWorld, caller: Account, voting_id: u32) {
+    world.veto_recovery(&caller, voting_id);
+}
+
+#[when(expr = "{account} executes the account recovery with id {int}")]
+fn execute_recovery(world: &mut DaoWorld, caller: Account, voting_id: u32) {
+    world.execute_recovery(&caller, voting_id);
+}
+
+#[then(expr = "{account} cannot veto the account recovery with id {int} due to {word}")]
+fn cannot_veto_recovery(world: &mut DaoWorld, caller: Account, voting_id: u32, error: Error) {
+    test_env::assert_exception(*error, || world.veto_recovery(&caller, voting_id));
+}
+
+#[then(expr = "{account} cannot execute the account recovery with id {int} due to {word}")]
+fn cannot_execute_recovery(world: &mut DaoWorld, caller: Account, voting_id: u32, error: Error) {
+    test_env::assert_exception(*error, || world.execute_recovery(&caller, voting_id));
+}
+
 #[when(expr = "{account} calls {account} to slash {account}")]
 fn slash_voter(world: &mut DaoWorld, caller: Account, contract: Account, voter: Account) {
     world.slash_voter(caller, contract, voter);
@@ -154,6 +189,17 @@ fn assert_vote_fails(
                 world.failing_vote(&contract, &ballot, DaoError::InsufficientBalanceForStake)
             }
             DaoError::ZeroStake => world.failing_vote(&contract, &ballot, DaoError::ZeroStake),
+            DaoError::ProbationStakeLimitExceeded => {
+                world.failing_vote(&contract, &ballot, DaoError::ProbationStakeLimitExceeded)
+            }
+            DaoError::ProbationaryVaCannotBeSoleApprover => world.failing_vote(
+                &contract,
+                &ballot,
+                DaoError::ProbationaryVaCannotBeSoleApprover,
+            ),
+            DaoError::SubjectOfAccountRecovery => {
+                world.failing_vote(&contract, &ballot, DaoError::SubjectOfAccountRecovery)
+            }
             _ => panic!("Unknown error"),
         }
     });
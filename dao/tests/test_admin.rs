@@ -2,6 +2,8 @@ use dao::{
     core_contracts::{
         ReputationContractDeployer, VaNftContractDeployer, VariableRepositoryContractDeployer,
     },
+    modules::Role,
+    utils::Error,
     utils_contracts::{CSPRRateProviderContractDeployer, DaoIdsContractDeployer},
     voting_contracts::{AdminAction, AdminContractDeployer},
 };
@@ -30,8 +32,10 @@ fn test_admin() {
         *va_token.address(),
     );
 
-    ids.add_to_whitelist(*admin.address());
-    reputation_token.add_to_whitelist(*admin.address());
+    ids.grant_role(Role::VotingCreator, *admin.address());
+    reputation_token.grant_role(Role::Staker, *admin.address());
+    reputation_token.grant_role(Role::Minter, default_account);
+    va_token.grant_role(Role::Minter, default_account);
     reputation_token.mint(alice, Balance::one() * Balance::from(1000));
 
     // Onboard Alice
@@ -40,8 +44,31 @@ fn test_admin() {
     test_env::set_caller(alice);
     admin.create_voting(
         *variable_repository.address(),
-        AdminAction::AddToWhitelist,
-        *reputation_token.address(),
+        AdminAction::RemoveFromWhitelist,
+        Some(*reputation_token.address()),
+        None,
         Balance::one() * Balance::from(1000),
     );
+
+    // A role action requires the role.
+    test_env::assert_exception(Error::InvalidAdminActionArgs, || {
+        admin.create_voting(
+            *reputation_token.address(),
+            AdminAction::GrantRole,
+            Some(alice),
+            None,
+            Balance::one() * Balance::from(100),
+        );
+    });
+
+    // Pausing takes no address.
+    test_env::assert_exception(Error::InvalidAdminActionArgs, || {
+        admin.create_voting(
+            *va_token.address(),
+            AdminAction::Pause,
+            Some(alice),
+            None,
+            Balance::one() * Balance::from(100),
+        );
+    });
 }